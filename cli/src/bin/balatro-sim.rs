@@ -0,0 +1,219 @@
+// Headless batch runner: plays games with a built-in or scripted policy
+// and streams one JSON line per game as it ends, then prints the win rate
+// and mean ante to stderr.
+//
+//   balatro-sim --seed 1 --games 1000 --policy greedy --out results.jsonl
+//
+// A `.py` policy is run as a child process speaking JSON lines: for every
+// decision it reads {"seed", "summary", "board", "actions"} from stdin and
+// answers with the index of the action to take. Anything else, or the
+// script exiting, is reported on stderr and stops the game.
+use balatro_rs::action::Action;
+use balatro_rs::config::Config;
+use balatro_rs::game::Game;
+use balatro_rs::rng::GameRng;
+use balatro_rs::simulation::{run_batch_streaming, GameResult, GreedyPolicy, Policy, RandomPolicy};
+use serde_json::{json, Value};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::Mutex;
+
+const USAGE: &str = "usage: balatro-sim [--seed N] [--games N] [--policy random|greedy|SCRIPT.py] [--threads N] [--out FILE]";
+
+struct Args {
+    // Game i plays seed + i, entropy when not given
+    seed: Option<u64>,
+    games: usize,
+    policy: String,
+    // 0 uses one thread per core
+    threads: usize,
+    // stdout when not given
+    out: Option<String>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args {
+        seed: None,
+        games: 100,
+        policy: "random".to_string(),
+        threads: 0,
+        out: None,
+    };
+    while let Some(flag) = args.next() {
+        if flag == "--help" || flag == "-h" {
+            return Err(String::new());
+        }
+        let value = args.next().ok_or(format!("{} needs a value", flag))?;
+        let number = |value: &str| value.parse().map_err(|_| format!("{} takes a number, got {}", flag, value));
+        match flag.as_str() {
+            "--seed" => parsed.seed = Some(number(&value)?),
+            "--games" => parsed.games = number(&value)? as usize,
+            "--policy" => parsed.policy = value,
+            "--threads" => parsed.threads = number(&value)? as usize,
+            "--out" => parsed.out = Some(value),
+            _ => return Err(format!("unknown argument {}", flag)),
+        }
+    }
+    return Ok(parsed);
+}
+
+// Policy asking a python script for every decision. The script is shared
+// by all worker threads, requests are answered one at a time.
+struct ScriptPolicy {
+    child: Mutex<Child>,
+    pipes: Mutex<(ChildStdin, BufReader<ChildStdout>)>,
+}
+
+impl ScriptPolicy {
+    // The interpreter is `python3` unless BALATRO_PYTHON says otherwise
+    fn spawn(path: &str) -> io::Result<Self> {
+        let python = std::env::var("BALATRO_PYTHON").unwrap_or_else(|_| "python3".to_string());
+        let mut child = Command::new(python)
+            .arg(path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("piped stdin");
+        let stdout = BufReader::new(child.stdout.take().expect("piped stdout"));
+        return Ok(Self {
+            child: Mutex::new(child),
+            pipes: Mutex::new((stdin, stdout)),
+        });
+    }
+
+    fn ask(&self, game: &Game, actions: &[Action]) -> io::Result<usize> {
+        let request = json!({
+            "seed": game.seed,
+            "summary": game.run_summary(),
+            "board": game.render(),
+            "actions": actions,
+        });
+        let mut pipes = self.pipes.lock().expect("script pipes");
+        let (stdin, stdout) = &mut *pipes;
+        writeln!(stdin, "{}", request)?;
+        stdin.flush()?;
+        let mut reply = String::new();
+        if stdout.read_line(&mut reply)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "script closed its output"));
+        }
+        let reply = reply.trim();
+        let index: usize = reply.parse().map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("expected an action index, got {:?}", reply))
+        })?;
+        if index >= actions.len() {
+            let message = format!("action index {} out of range, {} legal actions", index, actions.len());
+            return Err(io::Error::new(io::ErrorKind::InvalidData, message));
+        }
+        return Ok(index);
+    }
+}
+
+impl Policy for ScriptPolicy {
+    // `Policy` has no error channel, a failed request is logged and stops
+    // the game
+    fn choose(&self, game: &Game, _rng: &mut GameRng) -> Option<Action> {
+        let actions: Vec<Action> = game.gen_actions().collect();
+        return match self.ask(game, &actions) {
+            Ok(index) => Some(actions[index].clone()),
+            Err(e) => {
+                eprintln!("policy script, game with seed {}: {}", game.seed, e);
+                None
+            }
+        };
+    }
+}
+
+impl Drop for ScriptPolicy {
+    fn drop(&mut self) {
+        let child = self.child.get_mut().expect("script process");
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+// Running totals for the report on stderr
+#[derive(Default)]
+struct Totals {
+    games: usize,
+    wins: usize,
+    antes: usize,
+    truncated: usize,
+}
+
+impl Totals {
+    fn add(&mut self, result: &GameResult) {
+        self.games += 1;
+        self.wins += result.summary.won as usize;
+        self.antes += result.summary.ante;
+        self.truncated += result.truncated as usize;
+    }
+
+    fn report(&self) -> String {
+        let games = self.games.max(1) as f64;
+        return format!(
+            "games {} | wins {} ({:.1}%) | mean ante {:.2} | truncated {}",
+            self.games,
+            self.wins,
+            100.0 * self.wins as f64 / games,
+            self.antes as f64 / games,
+            self.truncated
+        );
+    }
+}
+
+fn run(args: Args) -> Result<Totals, String> {
+    let policy: Box<dyn Policy> = match args.policy.as_str() {
+        "random" => Box::new(RandomPolicy),
+        "greedy" => Box::new(GreedyPolicy),
+        script if script.ends_with(".py") => {
+            Box::new(ScriptPolicy::spawn(script).map_err(|e| format!("cannot start {}: {}", script, e))?)
+        }
+        other => return Err(format!("unknown policy {}", other)),
+    };
+    let out: Box<dyn Write + Send> = match &args.out {
+        Some(path) => Box::new(File::create(path).map_err(|e| format!("cannot create {}: {}", path, e))?),
+        None => Box::new(io::stdout()),
+    };
+    let out = Mutex::new(BufWriter::new(out));
+    let totals = Mutex::new(Totals::default());
+    let seeds: Vec<u64> = match args.seed {
+        Some(seed) => (0..args.games as u64).map(|i| seed.wrapping_add(i)).collect(),
+        None => Vec::new(),
+    };
+
+    let on_result = |game: usize, result: GameResult| {
+        totals.lock().expect("totals").add(&result);
+        let mut line = serde_json::to_value(&result).expect("results serialize");
+        if let Value::Object(map) = &mut line {
+            map.insert("game".to_string(), json!(game));
+            map.insert("policy".to_string(), json!(args.policy));
+        }
+        let mut out = out.lock().expect("output");
+        // A closed pipe (e.g. `| head`) just loses the remaining lines
+        let _ = writeln!(out, "{}", line).and_then(|_| out.flush());
+    };
+    run_batch_streaming(&Config::default(), policy.as_ref(), args.games, &seeds, args.threads, on_result)
+        .map_err(|e| e.to_string())?;
+    return Ok(totals.into_inner().expect("totals"));
+}
+
+fn main() {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            if !message.is_empty() {
+                eprintln!("{}", message);
+            }
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    };
+    match run(args) {
+        Ok(totals) => eprintln!("{}", totals.report()),
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    }
+}
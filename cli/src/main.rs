@@ -2,6 +2,9 @@ use balatro_rs::action::Action;
 use balatro_rs::game::Game;
 use text_io::read;
 
+#[cfg(feature = "tui")]
+mod tui;
+
 fn input_loop(max: usize) -> usize {
     loop {
         let i: usize = read!();
@@ -37,6 +40,12 @@ fn game_loop(game: &mut Game) {
 fn main() {
     let mut game = Game::default();
     game.start();
+    #[cfg(feature = "tui")]
+    if std::env::args().any(|arg| arg == "--tui") {
+        let game = tui::run(game).expect("terminal interface");
+        println!("{}", game);
+        return;
+    }
     println!("Starting game...");
     game_loop(&mut game);
     println!("Game over!");
@@ -0,0 +1,344 @@
+// Terminal interface for playing a run by hand (`tui` feature).
+//
+// The board (blind progress, jokers, shop or pack, hand) is drawn on the
+// left and the legal actions from `gen_actions` on the right. Every key
+// press is mapped to an `Action` and goes through `handle_action`, so the
+// TUI can't do anything an agent couldn't.
+use balatro_rs::action::{Action, SortMode};
+use balatro_rs::booster::PackSelection;
+use balatro_rs::card::{Card, Edition, Suit};
+use balatro_rs::game::Game;
+use balatro_rs::stage::Stage;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Gauge, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+const HELP: &str =
+    "↑/↓ choose  enter take action  1-9 toggle card  p play  d discard  c clear  s/S sort  q quit";
+
+struct App {
+    game: Game,
+    actions: Vec<Action>,
+    list: ListState,
+    // Outcome of the last action, errors included
+    status: String,
+}
+
+impl App {
+    fn new(game: Game) -> Self {
+        let mut app = Self {
+            game,
+            actions: Vec::new(),
+            list: ListState::default(),
+            status: "Starting game...".to_string(),
+        };
+        app.refresh_actions();
+        return app;
+    }
+
+    // Regenerate the legal actions, keeping the cursor in range
+    fn refresh_actions(&mut self) {
+        self.actions = self.game.gen_actions().collect();
+        let selected = match self.actions.is_empty() {
+            true => None,
+            false => Some(self.list.selected().unwrap_or(0).min(self.actions.len() - 1)),
+        };
+        self.list.select(selected);
+    }
+
+    fn apply(&mut self, action: Action) {
+        self.status = match self.game.handle_action(action.clone()) {
+            Ok(()) => action.to_string(),
+            Err(e) => format!("{}: {}", action, e),
+        };
+        if let Some(end) = self.game.result() {
+            self.status = format!("Game over: {:?}, press q to quit", end);
+        }
+        self.refresh_actions();
+    }
+
+    // Returns whether to quit
+    fn on_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return true,
+            KeyCode::Up | KeyCode::Char('k') => self.list.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => self.list.select_next(),
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                let chosen = self.list.selected().and_then(|i| self.actions.get(i));
+                if let Some(action) = chosen.cloned() {
+                    self.apply(action);
+                }
+            }
+            KeyCode::Char(c @ '1'..='9') => {
+                let index = c as usize - '1' as usize;
+                self.apply(Action::ToggleCardIndex(index));
+            }
+            KeyCode::Char('p') => self.apply(Action::Play()),
+            KeyCode::Char('d') => self.apply(Action::Discard()),
+            KeyCode::Char('c') => self.apply(Action::DeselectAll()),
+            KeyCode::Char('s') => self.apply(Action::SortHand(SortMode::ByRank)),
+            KeyCode::Char('S') => self.apply(Action::SortHand(SortMode::BySuit)),
+            _ => {}
+        }
+        return false;
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [header, progress, body, footer] = Layout::vertical([
+            Constraint::Length(4),
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(2),
+        ])
+        .areas(frame.area());
+        let [board, actions] =
+            Layout::horizontal([Constraint::Percentage(65), Constraint::Percentage(35)]).areas(body);
+        let jokers_height = self.game.jokers.len().max(1) as u16 + 2;
+        let [jokers, consumables, shop, hand] = Layout::vertical([
+            Constraint::Length(jokers_height),
+            Constraint::Length(3),
+            Constraint::Min(3),
+            Constraint::Length(4),
+        ])
+        .areas(board);
+
+        self.draw_header(frame, header);
+        self.draw_progress(frame, progress);
+        self.draw_jokers(frame, jokers);
+        self.draw_consumables(frame, consumables);
+        self.draw_shop(frame, shop);
+        self.draw_hand(frame, hand);
+        self.draw_actions(frame, actions);
+
+        let footer_text = vec![Line::raw(self.status.as_str()), Line::raw(HELP).style(dim())];
+        frame.render_widget(Paragraph::new(footer_text), footer);
+    }
+
+    fn draw_header(&self, frame: &mut Frame, area: Rect) {
+        let g = &self.game;
+        let lines = vec![
+            Line::raw(format!(
+                "Ante {}/{} | Round {} | {}",
+                g.ante_current.number(),
+                g.ante_end.number(),
+                g.round,
+                g.render_stage()
+            )),
+            Line::raw(format!(
+                "Hands {} | Discards {} | ${} | Deck {}",
+                g.plays,
+                g.discards,
+                g.money,
+                g.deck.cards().len()
+            )),
+        ];
+        let block = Block::bordered().title(" balatro-rs ");
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }).block(block), area);
+    }
+
+    fn draw_progress(&self, frame: &mut Frame, area: Rect) {
+        let required = self.game.required_score();
+        let ratio = match required {
+            0 => 0.0,
+            required => (self.game.score as f64 / required as f64).min(1.0),
+        };
+        let gauge = Gauge::default()
+            .block(Block::bordered().title(" Blind "))
+            .gauge_style(Style::default().fg(Color::Red))
+            .ratio(ratio)
+            .label(format!("{} / {}", self.game.score, required));
+        frame.render_widget(gauge, area);
+    }
+
+    fn draw_jokers(&self, frame: &mut Frame, area: Rect) {
+        let labels = self.game.render_jokers();
+        let items: Vec<ListItem> = match labels.is_empty() {
+            true => vec![ListItem::new("-").style(dim())],
+            false => labels
+                .into_iter()
+                .enumerate()
+                .map(|(i, label)| ListItem::new(format!("{}: {}", i, label)))
+                .collect(),
+        };
+        let title = format!(
+            " Jokers {}/{} ",
+            self.game.jokers.len(),
+            self.game.joker_slots()
+        );
+        frame.render_widget(List::new(items).block(Block::bordered().title(title)), area);
+    }
+
+    fn draw_consumables(&self, frame: &mut Frame, area: Rect) {
+        let names: Vec<String> = self
+            .game
+            .consumables
+            .iter()
+            .enumerate()
+            .map(|(i, c)| format!("{}: {}", i, c))
+            .collect();
+        let text = match names.is_empty() {
+            true => "-".to_string(),
+            false => names.join("  "),
+        };
+        let title = format!(
+            " Consumables {}/{} ",
+            self.game.consumables.len(),
+            self.game.config.consumable_slots
+        );
+        frame.render_widget(Paragraph::new(text).block(Block::bordered().title(title)), area);
+    }
+
+    // The shop's stock, the open pack or the blinds ahead, whichever the
+    // stage is about
+    fn draw_shop(&self, frame: &mut Frame, area: Rect) {
+        let g = &self.game;
+        let shop = &g.shop;
+        let (title, lines) = match g.stage {
+            Stage::Shop() => {
+                let mut lines = Vec::new();
+                for joker in &shop.jokers {
+                    lines.push(format!("Joker      {} ${}", joker, shop.joker_price(joker)));
+                }
+                for consumable in &shop.consumables {
+                    let price = shop.consumable_price(consumable);
+                    lines.push(format!("Consumable {} ${}", consumable, price));
+                }
+                for pack in &shop.packs {
+                    lines.push(format!("Pack       {} ${}", pack, shop.pack_price(pack)));
+                }
+                if let Some(voucher) = &shop.voucher {
+                    let price = shop.voucher_price(voucher);
+                    lines.push(format!("Voucher    {} ${}", voucher, price));
+                }
+                lines.push(format!("Reroll     ${}", shop.reroll_cost()));
+                (" Shop ", lines)
+            }
+            Stage::OpenPack() => {
+                let lines = match &shop.open_pack {
+                    Some(pack) => (0..pack.len())
+                        .filter_map(|i| pack.select(i))
+                        .enumerate()
+                        .map(|(i, item)| format!("{}: {}", i, selection_name(&item)))
+                        .collect(),
+                    None => Vec::new(),
+                };
+                (" Pack ", lines)
+            }
+            _ => {
+                let lines = g
+                    .blind_schedule()
+                    .iter()
+                    .map(|info| {
+                        let name = match info.boss {
+                            Some(modifier) => modifier.name().to_string(),
+                            None => info.blind.to_string(),
+                        };
+                        format!(
+                            "{:<16} {:>8} ${:<3} {:?}",
+                            name, info.required_score, info.reward, info.state
+                        )
+                    })
+                    .collect();
+                (" Blinds ", lines)
+            }
+        };
+        let items: Vec<ListItem> = lines.into_iter().map(ListItem::new).collect();
+        frame.render_widget(List::new(items).block(Block::bordered().title(title)), area);
+    }
+
+    fn draw_hand(&self, frame: &mut Frame, area: Rect) {
+        let mut spans = Vec::new();
+        for (i, card) in self.game.available.iter_cards().enumerate() {
+            let mut style = Style::default().fg(suit_color(card.suit));
+            if self.game.available.is_selected_index(i) {
+                style = style.add_modifier(Modifier::REVERSED | Modifier::BOLD);
+            }
+            if card.debuffed {
+                style = style.add_modifier(Modifier::CROSSED_OUT);
+            }
+            spans.push(Span::styled(format!("{}:", i + 1), dim()));
+            spans.push(Span::styled(card_label(&card), style));
+            spans.push(Span::raw("  "));
+        }
+        let title = format!(
+            " Hand {} ({} selected) ",
+            self.game.available.cards().len(),
+            self.game.available.selected_count()
+        );
+        let block = Block::bordered().title(title);
+        frame.render_widget(Paragraph::new(Line::from(spans)).wrap(Wrap { trim: true }).block(block), area);
+    }
+
+    fn draw_actions(&mut self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self.actions.iter().map(|a| ListItem::new(a.to_string())).collect();
+        let list = List::new(items)
+            .block(Block::bordered().title(format!(" Actions ({}) ", self.actions.len())))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(list, area, &mut self.list);
+    }
+}
+
+fn dim() -> Style {
+    return Style::default().add_modifier(Modifier::DIM);
+}
+
+fn suit_color(suit: Suit) -> Color {
+    return match suit {
+        Suit::Spade => Color::White,
+        Suit::Club => Color::Green,
+        Suit::Heart => Color::Red,
+        Suit::Diamond => Color::Blue,
+    };
+}
+
+// Rank and suit followed by whatever else is on the card, e.g. "K♥ Gold Foil"
+fn card_label(card: &Card) -> String {
+    let mut label = card.to_string();
+    if let Some(enhancement) = card.enhancement {
+        label.push_str(&format!(" {:?}", enhancement));
+    }
+    if card.edition != Edition::Base {
+        label.push_str(&format!(" {:?}", card.edition));
+    }
+    if let Some(seal) = card.seal {
+        label.push_str(&format!(" {:?} Seal", seal));
+    }
+    return label;
+}
+
+fn selection_name(item: &PackSelection) -> String {
+    return match item {
+        PackSelection::Joker(joker) => joker.to_string(),
+        PackSelection::Card(card) => card_label(card),
+        other => other.to_consumable().map_or_else(String::new, |c| c.to_string()),
+    };
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, app: &mut App) -> std::io::Result<()> {
+    loop {
+        terminal.draw(|frame| app.draw(frame))?;
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && app.on_key(key.code) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Play `game` in the terminal until the player quits, returning the game
+/// as it was left.
+pub fn run(game: Game) -> std::io::Result<Game> {
+    // Cards are colored by the TUI, not by escape codes in their Display
+    colored::control::set_override(false);
+    let mut app = App::new(game);
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut app);
+    ratatui::restore();
+    colored::control::unset_override();
+    result?;
+    return Ok(app.game);
+}
@@ -1,11 +1,26 @@
-use balatro_rs::{action::Action, game::Game};
-use criterion::{criterion_group, criterion_main, Criterion};
+use balatro_rs::{action::Action, game::Game, stage::Blind};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use rand::Rng;
 
 pub fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("run game gen actions", |b| {
         b.iter(|| run_game_gen_actions())
     });
+
+    // Per-state cost of enumerating actions, without the cost of playing the game
+    let blind = blind_state();
+    c.bench_function("gen actions blind state", |b| {
+        b.iter(|| black_box(&blind).gen_actions().count())
+    });
+}
+
+// A game just after selecting the small blind, with a full hand dealt
+fn blind_state() -> Game {
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small))
+        .expect("select small blind");
+    g
 }
 
 fn run_game_gen_actions() {
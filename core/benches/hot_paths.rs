@@ -0,0 +1,153 @@
+use balatro_rs::action::Action;
+use balatro_rs::card::{Card, Suit, Value};
+use balatro_rs::config::Config;
+use balatro_rs::game::Game;
+use balatro_rs::hand::SelectHand;
+use balatro_rs::joker::Jokers;
+use balatro_rs::stage::{Blind, Stage};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use itertools::Itertools;
+
+// Benchmarks for the paths simulation throughput depends on. Games are
+// seeded so runs are comparable between commits.
+const SEED: u64 = 1234;
+
+fn hands() -> Vec<SelectHand> {
+    let cards = |cs: &[(Value, Suit)]| -> SelectHand {
+        SelectHand::new(cs.iter().map(|(v, s)| Card::new(*v, *s)).collect())
+    };
+    vec![
+        // High card
+        cards(&[
+            (Value::Two, Suit::Heart),
+            (Value::Five, Suit::Club),
+            (Value::Nine, Suit::Spade),
+            (Value::Jack, Suit::Diamond),
+            (Value::King, Suit::Heart),
+        ]),
+        // Full house
+        cards(&[
+            (Value::Ten, Suit::Heart),
+            (Value::Ten, Suit::Club),
+            (Value::Ten, Suit::Spade),
+            (Value::Four, Suit::Diamond),
+            (Value::Four, Suit::Heart),
+        ]),
+        // Straight flush
+        cards(&[
+            (Value::Five, Suit::Spade),
+            (Value::Six, Suit::Spade),
+            (Value::Seven, Suit::Spade),
+            (Value::Eight, Suit::Spade),
+            (Value::Nine, Suit::Spade),
+        ]),
+    ]
+}
+
+// A game in the small blind holding the first five common jokers
+fn game_with_jokers() -> Game {
+    let mut g = Game::with_seed(Config::default(), SEED).unwrap();
+    g.start();
+    g.stage = Stage::Shop();
+    g.money = 1000;
+    for joker in Jokers::all_common().into_iter().take(5) {
+        g.shop.jokers = vec![joker.clone()];
+        g.handle_action(Action::BuyJoker(joker)).expect("buy joker");
+    }
+    g.stage = Stage::PreBlind();
+    g.handle_action(Action::SelectBlind(Blind::Small))
+        .expect("select small blind");
+    g
+}
+
+fn bench_hand_evaluation(c: &mut Criterion) {
+    let hands = hands();
+    c.bench_function("hand evaluation", |b| {
+        b.iter(|| {
+            for hand in &hands {
+                black_box(hand.best_hand().expect("valid hand"));
+            }
+        })
+    });
+}
+
+// Every 5-card hand from the top of a seeded deck, the mix a search over
+// plays evaluates
+fn bench_hand_evaluation_mix(c: &mut Criterion) {
+    let g = Game::with_seed(Config::default(), SEED).unwrap();
+    let cards: Vec<Card> = g.deck.cards().into_iter().take(12).collect();
+    let hands: Vec<SelectHand> = cards
+        .into_iter()
+        .combinations(5)
+        .map(SelectHand::new)
+        .collect();
+    c.bench_function("hand evaluation mix", |b| {
+        b.iter(|| {
+            for hand in &hands {
+                black_box(hand.best_hand().expect("valid hand"));
+            }
+        })
+    });
+}
+
+fn bench_calc_score(c: &mut Criterion) {
+    let g = game_with_jokers();
+    let hand = hands()[1].best_hand().expect("valid hand");
+    c.bench_function("calc_score 5 jokers", |b| {
+        b.iter_batched(
+            || (g.clone(), hand.clone()),
+            |(mut g, hand)| black_box(g.calc_score(hand)),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn bench_gen_action_space(c: &mut Criterion) {
+    let g = game_with_jokers();
+    c.bench_function("gen action space", |b| {
+        b.iter(|| black_box(&g).gen_action_space())
+    });
+}
+
+fn bench_snapshot_restore(c: &mut Criterion) {
+    // Tree search pushes and pops states, with a long history behind them
+    let mut g = game_with_jokers();
+    for _ in 0..200 {
+        let _ = g.handle_action(Action::SelectCardIndex(0));
+        let _ = g.handle_action(Action::DeselectAll());
+    }
+    c.bench_function("snapshot restore", |b| {
+        b.iter(|| {
+            let snapshot = g.snapshot();
+            g.restore(black_box(&snapshot));
+        })
+    });
+}
+
+fn bench_full_game(c: &mut Criterion) {
+    // Always takes the first legal action, so every iteration plays the same run
+    c.bench_function("full game first action", |b| {
+        b.iter(|| {
+            let mut g = Game::with_seed(Config::default(), SEED).unwrap();
+            g.start();
+            while !g.is_over() {
+                let Some(action) = g.gen_actions().next() else {
+                    break;
+                };
+                let _ = g.handle_action(action);
+            }
+            black_box(g.score)
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_hand_evaluation,
+    bench_hand_evaluation_mix,
+    bench_calc_score,
+    bench_gen_action_space,
+    bench_snapshot_restore,
+    bench_full_game
+);
+criterion_main!(benches);
@@ -1,7 +1,11 @@
-use crate::card::Card;
+use crate::booster::BoosterPack;
+use crate::card::{Card, Value};
 use crate::consumable::Consumables;
 use crate::joker::Jokers;
 use crate::stage::Blind;
+use crate::voucher::Vouchers;
+use enumset::EnumSetType;
+#[cfg(feature = "python")]
 use pyo3::pyclass;
 use std::fmt;
 
@@ -26,23 +30,71 @@ impl fmt::Display for MoveDirection {
     }
 }
 
+/// Order of the hand after `Action::SortHand`, like the in-game sort buttons
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(eq))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum SortMode {
+    // Highest rank first, suits in order within a rank
+    ByRank,
+    // Suits in order, highest rank first within a suit
+    BySuit,
+}
+
+impl SortMode {
+    // Key cards are sorted by, Stone cards have no rank or suit and go last
+    pub(crate) fn key(&self, card: &Card) -> (bool, usize, usize) {
+        let rank = Value::Ace as usize - card.value as usize;
+        let suit = card.suit as usize;
+        return match self {
+            Self::ByRank => (card.is_stone(), rank, suit),
+            Self::BySuit => (card.is_stone(), suit, rank),
+        };
+    }
+}
+
+impl fmt::Display for SortMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ByRank => {
+                write!(f, "by rank")
+            }
+            Self::BySuit => {
+                write!(f, "by suit")
+            }
+        }
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "python", pyclass(eq))]
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum Action {
     SelectCard(Card),
+    SelectCardIndex(usize), // Select a card by its position in available
+    ToggleCardIndex(usize), // Flip selection of a card by its position in available
+    DeselectAll(),
     MoveCard(MoveDirection, Card),
+    SortHand(SortMode), // Sort the hand, cards drawn later are sorted in. Not generated.
     Play(),
     Discard(),
     CashOut(usize),
     BuyJoker(Jokers),
-    BuyConsumable(Consumables),
-    UseConsumable(Consumables, Option<Vec<Card>>),
+    BuyConsumable(usize), // Buy the consumable in this shop slot
+    BuyAndUseConsumable(usize), // Buy the consumable in this shop slot and use it at once
+    UseConsumable(Consumables, Option<Vec<usize>>), // Use a held consumable on the cards in hand with these ids
     NextRound(),
     SelectBlind(Blind),
     SkipBlind(), // Skip Small or Big blind for a tag
     SelectFromTagPack(usize), // Select an item from a pending tag pack by index
-    SellJoker(Jokers), // Sell a joker during shop phase
+    SellJoker(usize), // Sell the joker in this slot during shop or blind
+    MoveJoker(usize, usize), // Move the joker in one slot to another, shifting the rest
+    SellConsumable(usize), // Sell the consumable in this slot during shop or blind
+    BuyBooster(BoosterPack), // Buy a booster pack from the shop and open it
+    SelectFromPack(usize), // Take an item from the open booster pack by index
+    SkipPack(), // Close the open booster pack without taking anything more
+    BuyVoucher(Vouchers), // Buy the shop's voucher, applying it for the rest of the run
+    Reroll(), // Restock the shop's jokers and consumables for the reroll cost
 }
 
 impl fmt::Display for Action {
@@ -51,6 +103,18 @@ impl fmt::Display for Action {
             Self::SelectCard(card) => {
                 write!(f, "SelectCard: {}", card)
             }
+            Self::SelectCardIndex(index) => {
+                write!(f, "SelectCardIndex: index {}", index)
+            }
+            Self::ToggleCardIndex(index) => {
+                write!(f, "ToggleCardIndex: index {}", index)
+            }
+            Self::DeselectAll() => {
+                write!(f, "DeselectAll")
+            }
+            Self::SortHand(mode) => {
+                write!(f, "SortHand: {}", mode)
+            }
             Self::Play() => {
                 write!(f, "Play")
             }
@@ -66,8 +130,11 @@ impl fmt::Display for Action {
             Self::BuyJoker(joker) => {
                 write!(f, "BuyJoker: {}", joker)
             }
-            Self::BuyConsumable(consumable) => {
-                write!(f, "BuyConsumable: {}", consumable)
+            Self::BuyConsumable(slot) => {
+                write!(f, "BuyConsumable: slot {}", slot)
+            }
+            Self::BuyAndUseConsumable(slot) => {
+                write!(f, "BuyAndUseConsumable: slot {}", slot)
             }
             Self::UseConsumable(consumable, targets) => {
                 if let Some(cards) = targets {
@@ -93,13 +160,139 @@ impl fmt::Display for Action {
             Self::SelectFromTagPack(index) => {
                 write!(f, "SelectFromTagPack: index {}", index)
             }
-            Self::SellJoker(joker) => {
-                write!(f, "SellJoker: {}", joker)
+            Self::SellJoker(slot) => {
+                write!(f, "SellJoker: slot {}", slot)
+            }
+            Self::MoveJoker(from, to) => {
+                write!(f, "MoveJoker: slot {} to {}", from, to)
+            }
+            Self::SellConsumable(slot) => {
+                write!(f, "SellConsumable: slot {}", slot)
+            }
+            Self::BuyBooster(pack) => {
+                write!(f, "BuyBooster: {}", pack)
+            }
+            Self::SelectFromPack(index) => {
+                write!(f, "SelectFromPack: index {}", index)
+            }
+            Self::SkipPack() => {
+                write!(f, "SkipPack")
+            }
+            Self::BuyVoucher(voucher) => {
+                write!(f, "BuyVoucher: {}", voucher)
+            }
+            Self::Reroll() => {
+                write!(f, "Reroll")
             }
         }
     }
 }
 
+/// What an action does, without its payload. `Game::legal_action_kinds`
+/// gives the kinds currently legal as an `EnumSet`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[derive(Debug, Hash, EnumSetType)]
+pub enum ActionKind {
+    SelectCard,
+    SelectCardIndex,
+    ToggleCardIndex,
+    DeselectAll,
+    MoveCard,
+    SortHand,
+    Play,
+    Discard,
+    CashOut,
+    BuyJoker,
+    BuyConsumable,
+    BuyAndUseConsumable,
+    UseConsumable,
+    NextRound,
+    SelectBlind,
+    SkipBlind,
+    SelectFromTagPack,
+    SellJoker,
+    MoveJoker,
+    SellConsumable,
+    BuyBooster,
+    SelectFromPack,
+    SkipPack,
+    BuyVoucher,
+    Reroll,
+}
+
+impl ActionKind {
+    /// Name of the `Action` variant, e.g. "BuyJoker"
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Self::SelectCard => "SelectCard",
+            Self::SelectCardIndex => "SelectCardIndex",
+            Self::ToggleCardIndex => "ToggleCardIndex",
+            Self::DeselectAll => "DeselectAll",
+            Self::MoveCard => "MoveCard",
+            Self::SortHand => "SortHand",
+            Self::Play => "Play",
+            Self::Discard => "Discard",
+            Self::CashOut => "CashOut",
+            Self::BuyJoker => "BuyJoker",
+            Self::BuyConsumable => "BuyConsumable",
+            Self::BuyAndUseConsumable => "BuyAndUseConsumable",
+            Self::UseConsumable => "UseConsumable",
+            Self::NextRound => "NextRound",
+            Self::SelectBlind => "SelectBlind",
+            Self::SkipBlind => "SkipBlind",
+            Self::SelectFromTagPack => "SelectFromTagPack",
+            Self::SellJoker => "SellJoker",
+            Self::MoveJoker => "MoveJoker",
+            Self::SellConsumable => "SellConsumable",
+            Self::BuyBooster => "BuyBooster",
+            Self::SelectFromPack => "SelectFromPack",
+            Self::SkipPack => "SkipPack",
+            Self::BuyVoucher => "BuyVoucher",
+            Self::Reroll => "Reroll",
+        };
+    }
+}
+
+impl fmt::Display for ActionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Action {
+    /// Variant without its payload, e.g. `ActionKind::BuyJoker`
+    pub fn kind(&self) -> ActionKind {
+        return match self {
+            Self::SelectCard(_) => ActionKind::SelectCard,
+            Self::SelectCardIndex(_) => ActionKind::SelectCardIndex,
+            Self::ToggleCardIndex(_) => ActionKind::ToggleCardIndex,
+            Self::DeselectAll() => ActionKind::DeselectAll,
+            Self::MoveCard(_, _) => ActionKind::MoveCard,
+            Self::SortHand(_) => ActionKind::SortHand,
+            Self::Play() => ActionKind::Play,
+            Self::Discard() => ActionKind::Discard,
+            Self::CashOut(_) => ActionKind::CashOut,
+            Self::BuyJoker(_) => ActionKind::BuyJoker,
+            Self::BuyConsumable(_) => ActionKind::BuyConsumable,
+            Self::BuyAndUseConsumable(_) => ActionKind::BuyAndUseConsumable,
+            Self::UseConsumable(_, _) => ActionKind::UseConsumable,
+            Self::NextRound() => ActionKind::NextRound,
+            Self::SelectBlind(_) => ActionKind::SelectBlind,
+            Self::SkipBlind() => ActionKind::SkipBlind,
+            Self::SelectFromTagPack(_) => ActionKind::SelectFromTagPack,
+            Self::SellJoker(_) => ActionKind::SellJoker,
+            Self::MoveJoker(_, _) => ActionKind::MoveJoker,
+            Self::SellConsumable(_) => ActionKind::SellConsumable,
+            Self::BuyBooster(_) => ActionKind::BuyBooster,
+            Self::SelectFromPack(_) => ActionKind::SelectFromPack,
+            Self::SkipPack() => ActionKind::SkipPack,
+            Self::BuyVoucher(_) => ActionKind::BuyVoucher,
+            Self::Reroll() => ActionKind::Reroll,
+        };
+    }
+}
+
 #[cfg(feature = "python")]
 impl Action {
     fn __repr__(&self) -> String {
@@ -3,7 +3,7 @@ use crate::config::Config;
 use crate::consumable::Consumables;
 use crate::joker::Jokers;
 use crate::voucher::Vouchers;
-use rand::{seq::SliceRandom, thread_rng};
+use rand::seq::SliceRandom;
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "python", pyo3::pyclass(eq))]
@@ -91,7 +91,7 @@ impl DeckType {
                 config.interest_rate = 0.0;
                 config.interest_max = 0;
                 config.money_per_hand = 2;
-                // Note: money_per_discard would need to be added to Config
+                config.money_per_discard = 1;
             }
             DeckType::BlackDeck => {
                 // +1 joker slot (total: 6), -1 hand (total: 3)
@@ -136,9 +136,8 @@ impl DeckType {
                 // This is a game mechanic, not a config change
             }
             DeckType::PlasmaDeck => {
-                // Balances chips and mult
-                // This affects scoring, not config
-                // Blinds are 2x base size would need to be in blind generation
+                // Balances chips and mult and doubles blind size
+                // This affects scoring and required score, not config
             }
             DeckType::ErraticDeck => {
                 // All ranks and suits randomized
@@ -249,7 +248,7 @@ impl DeckType {
             }
             DeckType::ErraticDeck => {
                 // 52 random cards (any rank, any suit)
-                let mut rng = thread_rng();
+                let mut rng = crate::rng::rng_for("deck", "erratic deck cards");
                 let values = Value::values();
                 let suits = Suit::suits();
                 let mut cards = Vec::new();
@@ -292,6 +291,14 @@ impl DeckType {
     pub fn grants_double_tag(&self) -> bool {
         matches!(self, DeckType::AnaglyPhDeck)
     }
+
+    /// Multiplier on every blind's required score (X2 for Plasma Deck)
+    pub fn blind_size_multiplier(&self) -> usize {
+        match self {
+            DeckType::PlasmaDeck => 2,
+            _ => 1,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -354,6 +361,7 @@ mod tests {
         assert_eq!(config.interest_rate, 0.0, "Green Deck should disable interest");
         assert_eq!(config.interest_max, 0, "Green Deck should disable interest");
         assert_eq!(config.money_per_hand, 2, "Green Deck should pay $2 per remaining hand");
+        assert_eq!(config.money_per_discard, 1, "Green Deck should pay $1 per remaining discard");
         assert!(DeckType::GreenDeck.disables_interest());
     }
 
@@ -530,7 +538,7 @@ mod tests {
         use crate::game::Game;
 
         let config = Config::with_deck(DeckType::RedDeck);
-        let game = Game::new(config);
+        let game = Game::new(config).unwrap();
 
         // Red Deck should have +1 discard (base 4 + 1 = 5)
         assert_eq!(game.discards, 5);
@@ -543,7 +551,7 @@ mod tests {
         use crate::game::Game;
 
         let config = Config::with_deck(DeckType::BlueDeck);
-        let game = Game::new(config);
+        let game = Game::new(config).unwrap();
 
         // Blue Deck should have +1 hand (base 4 + 1 = 5)
         assert_eq!(game.plays, 5);
@@ -556,7 +564,7 @@ mod tests {
         use crate::game::Game;
 
         let config = Config::with_deck(DeckType::YellowDeck);
-        let game = Game::new(config);
+        let game = Game::new(config).unwrap();
 
         // Yellow Deck should have +$10 starting money (base $4 + $10 = $14)
         assert_eq!(game.money, 14);
@@ -569,7 +577,7 @@ mod tests {
         use crate::game::Game;
 
         let config = Config::with_deck(DeckType::AbandonedDeck);
-        let game = Game::new(config);
+        let game = Game::new(config).unwrap();
 
         // Abandoned Deck should have 40 cards (no face cards)
         assert_eq!(game.deck.len(), 40);
@@ -591,7 +599,7 @@ mod tests {
         use crate::game::Game;
 
         let config = Config::with_deck(DeckType::CheckeredDeck);
-        let game = Game::new(config);
+        let game = Game::new(config).unwrap();
 
         // Checkered Deck should have 52 cards (26 Spades + 26 Hearts)
         assert_eq!(game.deck.len(), 52);
@@ -610,7 +618,7 @@ mod tests {
         use crate::tarot::Tarots;
 
         let config = Config::with_deck(DeckType::MagicDeck);
-        let game = Game::new(config);
+        let game = Game::new(config).unwrap();
 
         // Magic Deck should start with Crystal Ball voucher
         assert_eq!(game.vouchers.len(), 1);
@@ -634,7 +642,7 @@ mod tests {
         use crate::voucher::Vouchers;
 
         let config = Config::with_deck(DeckType::ZodiacDeck);
-        let game = Game::new(config);
+        let game = Game::new(config).unwrap();
 
         // Zodiac Deck should start with 3 vouchers
         assert_eq!(game.vouchers.len(), 3);
@@ -649,7 +657,7 @@ mod tests {
         use crate::game::Game;
 
         let config = Config::with_deck(DeckType::PaintedDeck);
-        let game = Game::new(config);
+        let game = Game::new(config).unwrap();
 
         // Painted Deck should have +2 hand size (base 8 + 2 = 10)
         assert_eq!(game.hand_size, 10);
@@ -663,7 +671,7 @@ mod tests {
         use crate::game::Game;
 
         let config = Config::with_deck(DeckType::BlackDeck);
-        let game = Game::new(config);
+        let game = Game::new(config).unwrap();
 
         // Black Deck should have +1 joker slot (base 5 + 1 = 6)
         assert_eq!(game.config.joker_slots, 6);
@@ -0,0 +1,329 @@
+use crate::card::Card;
+use crate::game::Game;
+use crate::hand::{HandContext, MadeHand, SelectHand};
+use crate::rank::HandRank;
+use crate::rng::GameRng;
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use std::collections::HashMap;
+
+// Refills are enumerated when there are at most this many, sampled above
+const EXACT_DRAW_LIMIT: u64 = 20_000;
+// Refills sampled when there are too many to enumerate
+const OUTS_SAMPLES: usize = 5_000;
+// Mixed into the game seed for sampled refills
+const OUTS_SEED_SALT: u64 = 0x6A09_E667_F3BC_C908;
+
+/// Exact expected score of playing `hand` now, before it is rounded down.
+/// Probabilistic effects count with their average instead of a roll:
+/// Lucky cards +4 Mult per trigger, Misprint +11.5 Mult and Bloodstone
+/// X1.25 Mult per scoring Heart. Each of them changes the running mult by
+/// an amount independent of it and chips are never random, so averaging
+/// step by step gives the exact expectation. Effects that only pay money
+/// (Business Card, Lucky $20) do not change it. The Plasma Deck squares
+/// the final mult, there the result is the score of the average hand.
+///
+/// The game is not changed, the hand is scored on a clone.
+pub fn expected_score(game: &Game, hand: MadeHand) -> f64 {
+    let mut game = game.clone();
+    game.expected_rolls = true;
+    return game.with_rng(|g| g.calc_score_unrounded(hand));
+}
+
+/// Chance of each hand rank being the best hand held after keeping the
+/// available cards with ids in `keep` and discarding the others. The hand
+/// is refilled from the cards left in the deck and the best five of it
+/// count, with the game's joker modifiers. Ranks that can't be reached are
+/// left out, the chances add up to 1.
+///
+/// Every refill is enumerated when there are few enough, otherwise they
+/// are sampled with a generator seeded from the game, so the answer is the
+/// same every time. The game is not changed.
+pub fn discard_outs(game: &Game, keep: &[usize]) -> HashMap<HandRank, f64> {
+    let context = HandContext {
+        modifiers: &game.modifiers,
+    };
+    let kept: Vec<Card> = game.available.cards().into_iter().filter(|c| keep.contains(&c.id)).collect();
+    let deck = game.deck.cards();
+    let draws = game.hand_size_target().saturating_sub(kept.len()).min(deck.len());
+    let rank_after = |drawn: &[Card]| {
+        let mut hand = kept.clone();
+        hand.extend_from_slice(drawn);
+        return best_rank(&hand, &context);
+    };
+
+    let mut counts: HashMap<HandRank, usize> = HashMap::new();
+    let mut total = 0;
+    if choose(deck.len(), draws) <= EXACT_DRAW_LIMIT {
+        for drawn in deck.iter().copied().combinations(draws) {
+            if let Some(rank) = rank_after(&drawn) {
+                *counts.entry(rank).or_insert(0) += 1;
+            }
+            total += 1;
+        }
+    } else {
+        let mut rng = GameRng::new(game.seed ^ OUTS_SEED_SALT);
+        for _ in 0..OUTS_SAMPLES {
+            let drawn: Vec<Card> = deck.choose_multiple(&mut rng, draws).copied().collect();
+            if let Some(rank) = rank_after(&drawn) {
+                *counts.entry(rank).or_insert(0) += 1;
+            }
+            total += 1;
+        }
+    }
+    return counts
+        .into_iter()
+        .map(|(rank, count)| (rank, count as f64 / total as f64))
+        .collect();
+}
+
+// Best rank of any five of `cards`, a hand only gets better with more cards
+// so smaller subsets needn't be checked
+fn best_rank(cards: &[Card], context: &HandContext) -> Option<HandRank> {
+    if cards.len() <= 5 {
+        let hand = SelectHand::new(cards.to_vec()).best_hand_with_context(context).ok()?;
+        return Some(hand.rank);
+    }
+    return cards
+        .iter()
+        .copied()
+        .combinations(5)
+        .filter_map(|five| SelectHand::new(five).best_hand_with_context(context).ok())
+        .map(|hand| hand.rank)
+        .max();
+}
+
+// Ways to pick `k` of `n`, `u64::MAX` when it doesn't fit
+fn choose(n: usize, k: usize) -> u64 {
+    let (n, k) = (n as u64, k.min(n - k) as u64);
+    return (0..k)
+        .try_fold(1u64, |ways, i| Some(ways.checked_mul(n - i)? / (i + 1)))
+        .unwrap_or(u64::MAX);
+}
+
+impl Game {
+    /// The play of up to five available cards with the highest
+    /// `expected_score`: the cards to play, the hand they make and its
+    /// projected score. Joker modifiers (Four Fingers, Shortcut...) and the
+    /// hands the boss allows are honored, face-down cards are left out.
+    /// `None` when nothing can be played.
+    pub fn best_play(&self) -> Option<(SelectHand, MadeHand, f64)> {
+        return self.top_k_plays(1).pop();
+    }
+
+    /// The `k` plays with the highest projected score, best first, see
+    /// `best_play`. Ties keep the order subsets are enumerated in, fewer
+    /// cards first.
+    pub fn top_k_plays(&self, k: usize) -> Vec<(SelectHand, MadeHand, f64)> {
+        let context = HandContext {
+            modifiers: &self.modifiers,
+        };
+        let boss = self.boss_rule();
+        let cards: Vec<_> = self.available.cards().into_iter().filter(|c| c.is_visible()).collect();
+        let mut plays: Vec<_> = (1..=cards.len().min(5))
+            .flat_map(|n| cards.iter().copied().combinations(n))
+            .filter_map(|subset| {
+                let played = SelectHand::new(subset);
+                let hand = played.best_hand_with_context(&context).ok()?;
+                if !boss.is_none_or(|rule| rule.allows_hand(self, hand.rank)) {
+                    return None;
+                }
+                let score = expected_score(self, hand.clone());
+                return Some((played, hand, score));
+            })
+            .collect();
+        // Stable, so ties stay in enumeration order
+        plays.sort_by(|(_, _, a), (_, _, b)| b.total_cmp(a));
+        plays.truncate(k);
+        return plays;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::boss_modifier::BossModifier;
+    use crate::card::{Enhancement, Suit, Value};
+    use crate::deck::Deck;
+    use crate::game::GameModifiers;
+    use crate::joker::{Bloodstone, BusinessCard, Jokers, Misprint};
+    use crate::stage::{Blind, Stage};
+
+    fn pair(enhancement: Option<Enhancement>) -> MadeHand {
+        let mut king = Card::new(Value::King, Suit::Heart);
+        king.enhancement = enhancement;
+        let other = Card::new(Value::King, Suit::Spade);
+        return SelectHand::new(vec![king, other]).best_hand().unwrap();
+    }
+
+    fn game_with(jokers: Vec<Jokers>) -> Game {
+        let mut g = Game::default();
+        g.stage = Stage::Blind(Blind::Small, None);
+        g.jokers = jokers;
+        g.refresh_joker_effects();
+        return g;
+    }
+
+    #[test]
+    fn test_expected_score_deterministic_hand() {
+        let mut g = game_with(vec![]);
+        let hand = pair(None);
+        // Pair 10 chips 2 mult, Kings 20 chips
+        assert_eq!(expected_score(&g, hand.clone()), 60.0);
+        assert_eq!(g.calc_score(hand), 60);
+    }
+
+    #[test]
+    fn test_expected_score_probabilistic_effects() {
+        let plain = game_with(vec![]);
+        let hand = pair(Some(Enhancement::Lucky));
+        // Lucky: +20 mult 1 in 5
+        assert_eq!(expected_score(&plain, hand.clone()), 30.0 * (2.0 + 4.0));
+        // Not rolled, and the game is left as it was
+        assert_eq!(plain.hands_played_this_blind, 0);
+
+        let misprint = game_with(vec![Jokers::Misprint(Misprint {})]);
+        assert_eq!(expected_score(&misprint, pair(None)), 30.0 * (2.0 + 11.5));
+
+        // One scoring Heart: X1.5 half of the time
+        let bloodstone = game_with(vec![Jokers::Bloodstone(Bloodstone {})]);
+        assert_eq!(expected_score(&bloodstone, pair(None)), 30.0 * 2.0 * 1.25);
+
+        let business = game_with(vec![Jokers::BusinessCard(BusinessCard {})]);
+        assert_eq!(expected_score(&business, pair(None)), 60.0);
+    }
+
+    #[test]
+    fn test_expected_score_matches_sampled_mean() {
+        let g = game_with(vec![Jokers::Misprint(Misprint {}), Jokers::Bloodstone(Bloodstone {})]);
+        let hand = pair(Some(Enhancement::Lucky));
+        let expected = expected_score(&g, hand.clone());
+
+        let mut sampled = g.clone();
+        let samples = 4000;
+        let total: usize = (0..samples)
+            .map(|_| sampled.with_rng(|s| s.calc_score(hand.clone())))
+            .sum();
+        let mean = total as f64 / samples as f64;
+        assert!((mean - expected).abs() / expected < 0.05, "{mean} vs {expected}");
+    }
+
+    // Three Kings, a pair of Sevens and a Two
+    fn kings_and_sevens(g: &mut Game) {
+        g.available.empty();
+        g.available.extend(vec![
+            Card::new(Value::King, Suit::Heart),
+            Card::new(Value::Seven, Suit::Club),
+            Card::new(Value::King, Suit::Spade),
+            Card::new(Value::Two, Suit::Diamond),
+            Card::new(Value::King, Suit::Club),
+            Card::new(Value::Seven, Suit::Heart),
+        ]);
+    }
+
+    #[test]
+    fn test_best_play() {
+        let mut g = game_with(vec![]);
+        kings_and_sevens(&mut g);
+        let (played, hand, score) = g.best_play().unwrap();
+        assert_eq!(hand.rank, HandRank::FullHouse);
+        // Full house 40 chips 4 mult plus the chips of all five cards
+        let chips: usize = g.available.cards().iter().filter(|c| c.value != Value::Two).map(|c| c.chips()).sum();
+        assert_eq!(score, (40 + chips) as f64 * 4.0);
+        assert_eq!(played.len(), 5);
+
+        // No other subset scores more
+        let cards = g.available.cards();
+        for n in 1..=5 {
+            for subset in cards.iter().copied().combinations(n) {
+                let hand = SelectHand::new(subset).best_hand().unwrap();
+                assert!(expected_score(&g, hand) <= score);
+            }
+        }
+    }
+
+    #[test]
+    fn test_top_k_plays() {
+        let mut g = game_with(vec![]);
+        kings_and_sevens(&mut g);
+        let plays = g.top_k_plays(3);
+        assert_eq!(plays.len(), 3);
+        assert_eq!(plays[0], g.best_play().unwrap());
+        assert!(plays.windows(2).all(|w| w[0].2 >= w[1].2));
+        // Three Kings alone: the Two or a Seven added scores the same, the
+        // smallest play comes first
+        assert_eq!(plays[1].1.rank, HandRank::ThreeOfAKind);
+        assert_eq!(plays[1].0.len(), 3);
+
+        // 6 cards make 62 subsets of at most five
+        assert_eq!(g.top_k_plays(100).len(), 62);
+        assert!(g.top_k_plays(0).is_empty());
+    }
+
+    #[test]
+    fn test_best_play_honors_boss() {
+        let mut g = game_with(vec![]);
+        g.stage = Stage::Blind(Blind::Boss, Some(BossModifier::TheEye));
+        kings_and_sevens(&mut g);
+        g.played_hand_ranks.insert(HandRank::FullHouse);
+        let (_, hand, _) = g.best_play().unwrap();
+        assert_eq!(hand.rank, HandRank::ThreeOfAKind);
+
+        g.available.empty();
+        assert_eq!(g.best_play(), None);
+    }
+
+    #[test]
+    fn test_discard_outs_exact() {
+        let mut g = game_with(vec![]);
+        let kept = vec![
+            Card::new(Value::Nine, Suit::Heart),
+            Card::new(Value::Ten, Suit::Spade),
+            Card::new(Value::Jack, Suit::Diamond),
+            Card::new(Value::Queen, Suit::Club),
+            Card::new(Value::Two, Suit::Heart),
+            Card::new(Value::Two, Suit::Spade),
+            Card::new(Value::Four, Suit::Diamond),
+        ];
+        let keep: Vec<usize> = kept.iter().map(|c| c.id).collect();
+        g.available.empty();
+        g.available.extend(kept);
+        g.available.extend(vec![Card::new(Value::Six, Suit::Club)]);
+        g.deck = Deck::new();
+        g.deck.extend(vec![
+            Card::new(Value::King, Suit::Heart),
+            Card::new(Value::Eight, Suit::Club),
+            Card::new(Value::Two, Suit::Diamond),
+            Card::new(Value::Five, Suit::Spade),
+        ]);
+
+        // One card is drawn: King or Eight straight, Two trips
+        let outs = discard_outs(&g, &keep);
+        assert_eq!(outs.len(), 3);
+        assert_eq!(outs[&HandRank::Straight], 0.5);
+        assert_eq!(outs[&HandRank::ThreeOfAKind], 0.25);
+        assert_eq!(outs[&HandRank::OnePair], 0.25);
+
+        // Four Fingers: 9 T J Q is already a straight
+        g.modifiers = GameModifiers {
+            four_card_straights: true,
+            ..Default::default()
+        };
+        let outs = discard_outs(&g, &keep);
+        assert_eq!(outs, HashMap::from([(HandRank::Straight, 1.0)]));
+    }
+
+    #[test]
+    fn test_discard_outs_sampled() {
+        let mut g = Game::with_seed(crate::config::Config::default(), 5).unwrap();
+        g.start();
+        // Discarding everything draws 8 of the 44 cards left, too many
+        // refills to enumerate
+        let outs = discard_outs(&g, &[]);
+        assert!((outs.values().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(outs[&HandRank::OnePair] > outs[&HandRank::FourOfAKind]);
+        assert_eq!(outs, discard_outs(&g, &[]));
+        // The game is left as it was
+        assert_eq!(g.available.cards().len(), 8);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::config::Stake;
 use std::convert::TryFrom;
 
 /// All ante levels.
@@ -14,11 +15,12 @@ pub enum Ante {
     Six,
     Seven,
     Eight,
+    // Antes past the last one, numbered from 9
+    Endless(usize),
 }
 
 impl Ante {
-    // Base chip requirement.
-    // Eventually this depends on deck and stake.
+    // Base chip requirement at White Stake.
     pub fn base(&self) -> usize {
         match self {
             Self::Zero => 100,
@@ -30,22 +32,65 @@ impl Ante {
             Self::Six => 20000,
             Self::Seven => 35000,
             Self::Eight => 50000,
+            Self::Endless(n) => endless_base(Self::Eight.base(), *n),
+        }
+    }
+    /// Base chip requirement for a stake, Green and Purple Stake make later
+    /// antes scale faster
+    pub fn scaled_base(&self, stake: Stake) -> usize {
+        return match (stake.ante_scaling(), self) {
+            (_, Self::Endless(n)) => endless_base(Self::Eight.scaled_base(stake), *n),
+            (1, _) | (_, Self::Zero) | (_, Self::One) => self.base(),
+            (2, Self::Two) => 900,
+            (2, Self::Three) => 2600,
+            (2, Self::Four) => 8000,
+            (2, Self::Five) => 20000,
+            (2, Self::Six) => 36000,
+            (2, Self::Seven) => 60000,
+            (2, Self::Eight) => 100000,
+            (_, Self::Two) => 1000,
+            (_, Self::Three) => 3200,
+            (_, Self::Four) => 9000,
+            (_, Self::Five) => 25000,
+            (_, Self::Six) => 60000,
+            (_, Self::Seven) => 110000,
+            (_, Self::Eight) => 200000,
+        };
+    }
+    /// Ante as a number (Ante::One => 1)
+    pub fn number(&self) -> usize {
+        match self {
+            Self::Zero => 0,
+            Self::One => 1,
+            Self::Two => 2,
+            Self::Three => 3,
+            Self::Four => 4,
+            Self::Five => 5,
+            Self::Six => 6,
+            Self::Seven => 7,
+            Self::Eight => 8,
+            Self::Endless(n) => *n,
         }
     }
     pub fn next(&self, max: Ante) -> Option<Self> {
         if *self == max {
             return None;
         }
+        return Some(self.following());
+    }
+    /// The ante after this one, with no final ante (endless mode)
+    pub fn following(&self) -> Self {
         match self {
-            Self::Zero => Some(Self::One),
-            Self::One => Some(Self::Two),
-            Self::Two => Some(Self::Three),
-            Self::Three => Some(Self::Four),
-            Self::Four => Some(Self::Five),
-            Self::Five => Some(Self::Six),
-            Self::Six => Some(Self::Seven),
-            Self::Seven => Some(Self::Eight),
-            Self::Eight => None,
+            Self::Zero => Self::One,
+            Self::One => Self::Two,
+            Self::Two => Self::Three,
+            Self::Three => Self::Four,
+            Self::Four => Self::Five,
+            Self::Five => Self::Six,
+            Self::Six => Self::Seven,
+            Self::Seven => Self::Eight,
+            Self::Eight => Self::Endless(9),
+            Self::Endless(n) => Self::Endless(n + 1),
         }
     }
 }
@@ -63,7 +108,47 @@ impl TryFrom<usize> for Ante {
             6 => Ok(Self::Six),
             7 => Ok(Self::Seven),
             8 => Ok(Self::Eight),
+            n if n > 8 => Ok(Self::Endless(n)),
             _ => Err(()),
         }
     }
 }
+
+// Past ante 8 requirements grow exponentially from the ante 8 base, kept to
+// two significant digits: base * (1.6 + (0.75 * c)^(1 + 0.2 * c))^c with
+// c antes past 8.
+fn endless_base(base: usize, ante: usize) -> usize {
+    let c = ante.saturating_sub(8) as f64;
+    let amount = (base as f64 * (1.6 + (0.75 * c).powf(1.0 + 0.2 * c)).powf(c)).floor();
+    if !amount.is_finite() || amount >= usize::MAX as f64 {
+        return usize::MAX;
+    }
+    let unit = 10f64.powf((amount.log10() - 1.0).floor().max(0.0));
+    return (amount - amount % unit) as usize;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endless_antes() {
+        assert_eq!(Ante::Eight.next(Ante::Eight), None);
+        assert_eq!(Ante::Eight.following(), Ante::Endless(9));
+        assert_eq!(Ante::Endless(9).next(Ante::Eight), Some(Ante::Endless(10)));
+        assert_eq!(Ante::try_from(12), Ok(Ante::Endless(12)));
+        assert_eq!(Ante::Endless(12).number(), 12);
+        assert!(Ante::Endless(9) > Ante::Eight);
+
+        assert_eq!(Ante::Endless(9).base(), 110000);
+        assert_eq!(Ante::Endless(10).base(), 560000);
+        assert_eq!(Ante::Endless(9).scaled_base(Stake::Purple), 460000);
+        let mut last = Ante::Eight.base();
+        for n in 9..40 {
+            let base = Ante::Endless(n).base();
+            assert!(base >= last);
+            last = base;
+        }
+        assert_eq!(Ante::Endless(1000).base(), usize::MAX);
+    }
+}
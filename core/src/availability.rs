@@ -0,0 +1,157 @@
+use crate::booster::{PackSelection, PackType};
+use crate::consumable::{Consumable, Consumables};
+use crate::joker::{Joker, Jokers};
+use crate::voucher::Vouchers;
+use std::sync::Arc;
+
+/// Kind of item an ante gate applies to.
+/// Names are only unique within a kind (e.g. "Seance" is both a joker and a voucher).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemKind {
+    Joker,
+    Consumable,
+    Pack,
+    Voucher,
+}
+
+/// A single rule: the named item may only be offered from `min_ante` onwards.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnteGate {
+    pub kind: ItemKind,
+    pub name: String,
+    pub min_ante: usize,
+}
+
+/// Per-ante availability rules consulted by shop and pack generation.
+/// Items without a gate are always available.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Availability {
+    // Shared between clones of the config, shop and game until changed
+    gates: Arc<Vec<AnteGate>>,
+}
+
+impl Availability {
+    /// No gating at all, every item can appear from ante 1
+    pub fn none() -> Self {
+        Availability {
+            gates: Arc::new(Vec::new()),
+        }
+    }
+
+    /// Gate an item to appear only from `min_ante` onwards.
+    /// Replaces any existing gate for the same item.
+    pub fn gate(&mut self, kind: ItemKind, name: impl Into<String>, min_ante: usize) {
+        let name = name.into();
+        let gates = Arc::make_mut(&mut self.gates);
+        gates.retain(|g| !(g.kind == kind && g.name == name));
+        gates.push(AnteGate {
+            kind,
+            name,
+            min_ante,
+        });
+    }
+
+    /// Remove the gate for an item so it is always available
+    pub fn ungate(&mut self, kind: ItemKind, name: &str) {
+        Arc::make_mut(&mut self.gates).retain(|g| !(g.kind == kind && g.name == name));
+    }
+
+    pub fn gates(&self) -> &[AnteGate] {
+        &self.gates
+    }
+
+    /// Earliest ante the item may appear in (0 if ungated)
+    pub fn min_ante(&self, kind: ItemKind, name: &str) -> usize {
+        self.gates
+            .iter()
+            .find(|g| g.kind == kind && g.name == name)
+            .map_or(0, |g| g.min_ante)
+    }
+
+    /// Returns true if the item may be offered at the given ante
+    pub fn is_available(&self, kind: ItemKind, name: &str, ante: usize) -> bool {
+        ante >= self.min_ante(kind, name)
+    }
+
+    pub fn joker_available(&self, joker: &Jokers, ante: usize) -> bool {
+        self.is_available(ItemKind::Joker, &joker.name(), ante)
+    }
+
+    pub fn consumable_available(&self, consumable: &Consumables, ante: usize) -> bool {
+        self.is_available(ItemKind::Consumable, &consumable.name(), ante)
+    }
+
+    pub fn pack_available(&self, pack: PackType, ante: usize) -> bool {
+        self.is_available(ItemKind::Pack, pack.name(), ante)
+    }
+
+    pub fn voucher_available(&self, voucher: Vouchers, ante: usize) -> bool {
+        self.is_available(ItemKind::Voucher, voucher.name(), ante)
+    }
+
+    /// Returns true if a booster pack may contain this item at the given ante
+    pub fn selection_available(&self, selection: &PackSelection, ante: usize) -> bool {
+        match selection {
+            PackSelection::Joker(joker) => self.joker_available(joker, ante),
+            other => other
+                .to_consumable()
+                .is_none_or(|c| self.consumable_available(&c, ante)),
+        }
+    }
+}
+
+impl Default for Availability {
+    /// Tier 2 vouchers and Spectral Packs are held back until ante 2
+    fn default() -> Self {
+        let mut availability = Self::none();
+        for voucher in Vouchers::tier_2() {
+            availability.gate(ItemKind::Voucher, voucher.name(), 2);
+        }
+        availability.gate(ItemKind::Pack, PackType::Spectral.name(), 2);
+        availability
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::joker::TheJoker;
+
+    #[test]
+    fn test_default_gates() {
+        let a = Availability::default();
+        assert!(a.voucher_available(Vouchers::Overstock, 1));
+        assert!(!a.voucher_available(Vouchers::Overstock2, 1));
+        assert!(a.voucher_available(Vouchers::Overstock2, 2));
+        assert!(!a.pack_available(PackType::Spectral, 1));
+        assert!(a.pack_available(PackType::Buffoon, 1));
+    }
+
+    #[test]
+    fn test_custom_gate_replaces_and_ungates() {
+        let mut a = Availability::none();
+        let joker = Jokers::TheJoker(TheJoker::default());
+        assert!(a.joker_available(&joker, 1));
+
+        a.gate(ItemKind::Joker, "Joker", 3);
+        a.gate(ItemKind::Joker, "Joker", 4);
+        assert_eq!(a.gates().len(), 1);
+        assert!(!a.joker_available(&joker, 3));
+        assert!(a.joker_available(&joker, 4));
+
+        a.ungate(ItemKind::Joker, "Joker");
+        assert!(a.joker_available(&joker, 1));
+    }
+
+    #[test]
+    fn test_gates_are_per_kind() {
+        // "Seance" is both a joker and a voucher name
+        let mut a = Availability::none();
+        a.gate(ItemKind::Voucher, "Seance", 5);
+        assert!(!a.is_available(ItemKind::Voucher, "Seance", 1));
+        assert!(a.is_available(ItemKind::Joker, "Seance", 1));
+    }
+}
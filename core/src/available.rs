@@ -1,4 +1,4 @@
-use crate::action::MoveDirection;
+use crate::action::{MoveDirection, SortMode};
 use crate::card::Card;
 use crate::error::GameError;
 use itertools::Itertools;
@@ -10,6 +10,9 @@ use itertools::Itertools;
 pub struct Available {
     // Tuple (card, bool) where bool represents if card is selected or not
     cards: Vec<(Card, bool)>,
+    // Last sort asked for, drawn cards are inserted where it puts them
+    #[cfg_attr(feature = "serde", serde(default))]
+    sort_mode: Option<SortMode>,
 }
 
 impl Available {
@@ -22,8 +25,42 @@ impl Available {
         }
     }
 
-    /// Deselect all cards (for The Pillar boss modifier)
-    pub(crate) fn deselect_all(&mut self) {
+    /// Select the card at position `i` in available.
+    pub fn select_by_index(&mut self, i: usize) -> Result<(), GameError> {
+        if i >= self.cards.len() {
+            return Err(GameError::NoCardMatch);
+        }
+        self.cards[i].1 = true;
+        return Ok(());
+    }
+
+    /// Deselect a single card, matched by id.
+    pub fn deselect_card(&mut self, card: Card) -> Result<(), GameError> {
+        if let Some((i, _)) = self.cards.iter().find_position(|(c, _a)| c.id == card.id) {
+            self.cards[i].1 = false;
+            return Ok(());
+        } else {
+            return Err(GameError::NoCardMatch);
+        }
+    }
+
+    /// Flip the selection state of the card at position `i`.
+    /// Returns the new selection state.
+    pub fn toggle_by_index(&mut self, i: usize) -> Result<bool, GameError> {
+        if i >= self.cards.len() {
+            return Err(GameError::NoCardMatch);
+        }
+        self.cards[i].1 = !self.cards[i].1;
+        return Ok(self.cards[i].1);
+    }
+
+    /// Whether the card at position `i` is currently selected.
+    pub fn is_selected_index(&self, i: usize) -> bool {
+        return self.cards.get(i).is_some_and(|(_, s)| *s);
+    }
+
+    /// Deselect all cards (also used by The Pillar boss modifier)
+    pub fn deselect_all(&mut self) {
         for (_, selected) in &mut self.cards {
             *selected = false;
         }
@@ -38,6 +75,16 @@ impl Available {
             .collect();
     }
 
+    /// Number of selected cards, without collecting them
+    pub fn selected_count(&self) -> usize {
+        return self.cards.iter().filter(|(_, s)| *s).count();
+    }
+
+    /// Iterate over cards that are not selected, without collecting them
+    pub fn iter_not_selected(&self) -> impl Iterator<Item = Card> + '_ {
+        return self.cards.iter().filter(|(_, s)| !*s).map(|(c, _)| *c);
+    }
+
     pub fn not_selected(&self) -> Vec<Card> {
         return self
             .cards
@@ -54,22 +101,28 @@ impl Available {
         return Some(self.cards[i].0);
     }
 
+    // Position of the card with the same id as `card`
+    pub(crate) fn index_of(&self, card: Card) -> Option<usize> {
+        return self.cards.iter().position(|(c, _)| c.id == card.id);
+    }
+
     pub(crate) fn remove_selected(&mut self) -> usize {
-        let remove_count = self.selected().len();
+        let remove_count = self.selected_count();
         self.cards.retain(|(_c, a)| !*a);
         return remove_count;
     }
 
-    /// Randomly remove up to `count` cards from available (for The Hook boss modifier)
-    pub(crate) fn remove_random(&mut self, count: usize) -> usize {
+    /// Randomly remove up to `count` cards from available (for The Hook boss modifier).
+    /// Returns the removed cards.
+    pub(crate) fn remove_random(&mut self, count: usize) -> Vec<Card> {
         use rand::seq::SliceRandom;
-        let mut rng = rand::thread_rng();
+        let mut rng = crate::rng::rng_for("boss", "the hook discard");
 
         let available_count = self.cards.len();
         let to_remove = count.min(available_count);
 
         if to_remove == 0 {
-            return 0;
+            return Vec::new();
         }
 
         // Shuffle indices and take the first `to_remove`
@@ -78,11 +131,12 @@ impl Available {
         indices.truncate(to_remove);
         indices.sort_by(|a, b| b.cmp(a)); // Sort descending to remove from back
 
+        let mut removed = Vec::with_capacity(to_remove);
         for idx in indices {
-            self.cards.remove(idx);
+            removed.push(self.cards.remove(idx).0);
         }
 
-        return to_remove;
+        return removed;
     }
 
     pub(crate) fn move_card(
@@ -116,18 +170,53 @@ impl Available {
         self.cards = Vec::new();
     }
 
+    /// Add cards to the end, or with a sort mode set each right after the
+    /// last held card that sorts before or equal to it. Cards already held
+    /// keep their order, including cards moved after sorting.
     pub(crate) fn extend(&mut self, cards: Vec<Card>) {
         for c in cards {
-            self.cards.push((c, false));
+            let i = match self.sort_mode {
+                Some(mode) => {
+                    let key = mode.key(&c);
+                    self.cards
+                        .iter()
+                        .rposition(|(held, _)| mode.key(held) <= key)
+                        .map_or(0, |i| i + 1)
+                }
+                None => self.cards.len(),
+            };
+            self.cards.insert(i, (c, false));
         }
     }
 
+    /// Stable sort of the held cards, equal cards keep their order. The mode
+    /// is kept for cards drawn later.
+    pub(crate) fn sort(&mut self, mode: SortMode) {
+        self.cards.sort_by_key(|(c, _)| mode.key(c));
+        self.sort_mode = Some(mode);
+    }
+
+    pub fn sort_mode(&self) -> Option<SortMode> {
+        return self.sort_mode;
+    }
+
+    /// Iterate over all available cards in order, without collecting them
+    pub fn iter_cards(&self) -> impl ExactSizeIterator<Item = Card> + DoubleEndedIterator + '_ {
+        return self.cards.iter().map(|(c, _)| *c);
+    }
+
     pub fn cards(&self) -> Vec<Card> {
         return self.cards.iter().map(|(c, _)| *c).collect();
     }
 
-    pub(crate) fn cards_and_selected(&self) -> Vec<(Card, bool)> {
-        return self.cards.clone();
+    /// Modify every available card
+    pub(crate) fn modify_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Card),
+    {
+        for (card, _) in self.cards.iter_mut() {
+            f(card);
+        }
     }
 
     /// Modify a card by its ID (for Tarot effects)
@@ -145,7 +234,10 @@ impl Available {
 
 impl Default for Available {
     fn default() -> Self {
-        return Available { cards: Vec::new() };
+        return Available {
+            cards: Vec::new(),
+            sort_mode: None,
+        };
     }
 }
 
@@ -199,4 +291,63 @@ mod tests {
         let res = a.move_card(MoveDirection::Right, ace);
         assert!(res.is_err());
     }
+
+    #[test]
+    fn test_sort_and_draw_into_place() {
+        let ace = Card::new(Value::Ace, Suit::Diamond);
+        let two = Card::new(Value::Two, Suit::Spade);
+        let king = Card::new(Value::King, Suit::Spade);
+        let mut a = Available::default();
+        a.extend(vec![two, ace, king]);
+        assert_eq!(a.cards(), vec![two, ace, king]);
+
+        a.select_card(two).unwrap();
+        a.sort(SortMode::ByRank);
+        assert_eq!(a.cards(), vec![ace, king, two]);
+        assert_eq!(a.selected(), vec![two]);
+        a.sort(SortMode::BySuit);
+        assert_eq!(a.cards(), vec![king, two, ace]);
+
+        // Drawn cards go to their sorted place, after equal cards
+        let queen = Card::new(Value::Queen, Suit::Spade);
+        let mut stone = Card::new(Value::Ace, Suit::Spade);
+        stone.enhancement = Some(crate::card::Enhancement::Stone);
+        let king2 = Card::new(Value::King, Suit::Spade);
+        a.extend(vec![stone, queen, king2]);
+        assert_eq!(a.cards(), vec![king, king2, queen, two, ace, stone]);
+
+        // Moved cards stay where they were put
+        a.move_card(MoveDirection::Left, ace).unwrap();
+        let three = Card::new(Value::Three, Suit::Heart);
+        a.extend(vec![three]);
+        assert_eq!(a.cards(), vec![king, king2, queen, ace, two, three, stone]);
+        assert_eq!(a.sort_mode(), Some(SortMode::BySuit));
+    }
+
+    #[test]
+    fn test_select_by_index_and_toggle() {
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let king = Card::new(Value::King, Suit::Diamond);
+        let mut a = Available::default();
+        a.extend(vec![ace, king]);
+
+        a.select_by_index(1).unwrap();
+        assert_eq!(a.selected(), vec![king]);
+        assert!(a.is_selected_index(1));
+        assert!(a.select_by_index(2).is_err());
+
+        assert_eq!(a.toggle_by_index(0), Ok(true));
+        assert_eq!(a.selected().len(), 2);
+        assert_eq!(a.toggle_by_index(1), Ok(false));
+        assert_eq!(a.selected(), vec![ace]);
+        assert!(a.toggle_by_index(5).is_err());
+
+        a.deselect_card(ace).unwrap();
+        assert!(a.selected().is_empty());
+
+        a.select_by_index(0).unwrap();
+        a.select_by_index(1).unwrap();
+        a.deselect_all();
+        assert!(a.selected().is_empty());
+    }
 }
@@ -1,20 +1,25 @@
+use crate::card::{Card, Suit, Value};
 use crate::consumable::Consumables;
 use crate::joker::Jokers;
 use crate::planet::Planets;
 use crate::spectral::Spectrals;
 use crate::tarot::Tarots;
+#[cfg(feature = "python")]
+use pyo3::pyclass;
 use rand::seq::SliceRandom;
 use std::fmt;
 
 /// Booster Pack Types
 /// Packs are purchased from the shop and opened to receive consumables or jokers
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PackType {
     Arcana,    // Contains Tarot cards
     Celestial, // Contains Planet cards
     Spectral,  // Contains Spectral cards
     Buffoon,   // Contains Jokers
+    Standard,  // Contains playing cards
 }
 
 impl PackType {
@@ -24,6 +29,7 @@ impl PackType {
             PackType::Celestial => "Celestial Pack",
             PackType::Spectral => "Spectral Pack",
             PackType::Buffoon => "Buffoon Pack",
+            PackType::Standard => "Standard Pack",
         }
     }
 
@@ -33,6 +39,7 @@ impl PackType {
             PackType::Celestial => "Choose 1 of up to 3 Planet cards to be used immediately",
             PackType::Spectral => "Choose 1 of up to 3 Spectral cards to be used immediately",
             PackType::Buffoon => "Choose 1 of up to 2 Joker cards",
+            PackType::Standard => "Choose 1 of up to 3 Playing cards to add to your deck",
         }
     }
 
@@ -42,6 +49,7 @@ impl PackType {
             PackType::Celestial => 4,
             PackType::Spectral => 4,
             PackType::Buffoon => 4,
+            PackType::Standard => 4,
         }
     }
 
@@ -52,6 +60,7 @@ impl PackType {
             PackType::Celestial => 3,
             PackType::Spectral => 3,
             PackType::Buffoon => 2,
+            PackType::Standard => 3,
         }
     }
 
@@ -62,6 +71,7 @@ impl PackType {
             PackType::Celestial => 1,
             PackType::Spectral => 1,
             PackType::Buffoon => 1,
+            PackType::Standard => 1,
         }
     }
 }
@@ -72,12 +82,89 @@ impl fmt::Display for PackType {
     }
 }
 
+/// Booster pack sizes. Jumbo packs hold two more cards, Mega packs hold as
+/// many as Jumbo but let the player choose twice.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PackSize {
+    Normal,
+    Jumbo,
+    Mega,
+}
+
+impl PackSize {
+    pub fn name(&self) -> &str {
+        match self {
+            PackSize::Normal => "",
+            PackSize::Jumbo => "Jumbo ",
+            PackSize::Mega => "Mega ",
+        }
+    }
+}
+
+/// A pack offered in a shop slot: its type and size
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(eq))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoosterPack {
+    pub pack_type: PackType,
+    pub size: PackSize,
+}
+
+impl BoosterPack {
+    pub fn new(pack_type: PackType, size: PackSize) -> Self {
+        BoosterPack { pack_type, size }
+    }
+
+    pub fn name(&self) -> String {
+        format!("{}{}", self.size.name(), self.pack_type.name())
+    }
+
+    pub fn base_cost(&self) -> usize {
+        match self.size {
+            PackSize::Normal => self.pack_type.base_cost(),
+            PackSize::Jumbo => self.pack_type.base_cost() + 2,
+            PackSize::Mega => self.pack_type.base_cost() + 4,
+        }
+    }
+
+    pub fn card_count(&self) -> usize {
+        match self.size {
+            PackSize::Normal => self.pack_type.card_count(),
+            PackSize::Jumbo | PackSize::Mega => self.pack_type.card_count() + 2,
+        }
+    }
+
+    pub fn choices(&self) -> usize {
+        match self.size {
+            PackSize::Normal | PackSize::Jumbo => self.pack_type.choices(),
+            PackSize::Mega => self.pack_type.choices() + 1,
+        }
+    }
+}
+
+impl From<PackType> for BoosterPack {
+    fn from(pack_type: PackType) -> Self {
+        BoosterPack::new(pack_type, PackSize::Normal)
+    }
+}
+
+impl fmt::Display for BoosterPack {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 /// A booster pack instance with its contents
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Pack {
     pub pack_type: PackType,
+    pub size: PackSize,
     pub contents: PackContents,
+    // Selections the player can still make before the pack closes
+    pub choices_left: usize,
 }
 
 /// Contents of a booster pack
@@ -88,56 +175,128 @@ pub enum PackContents {
     Planets(Vec<Planets>),
     Spectrals(Vec<Spectrals>),
     Jokers(Vec<Jokers>),
+    Cards(Vec<Card>),
 }
 
 impl Pack {
     /// Create a new pack with randomly generated contents
-    pub fn new(pack_type: PackType) -> Self {
+    pub fn new(pack: impl Into<BoosterPack>) -> Self {
+        Self::new_where(pack, |_| true)
+    }
+
+    /// Create a new pack whose contents only include items that pass `allowed`
+    /// (e.g. per-ante availability rules)
+    pub fn new_where<F>(pack: impl Into<BoosterPack>, allowed: F) -> Self
+    where
+        F: Fn(&PackSelection) -> bool,
+    {
+        let booster = pack.into();
+        let pack_type = booster.pack_type;
+        let count = booster.card_count();
         let contents = match pack_type {
             PackType::Arcana => {
-                let count = pack_type.card_count();
-                let all_tarots = Tarots::all();
+                let all_tarots: Vec<Tarots> = Tarots::all()
+                    .into_iter()
+                    .filter(|t| allowed(&PackSelection::Tarot(*t)))
+                    .collect();
                 let selected: Vec<Tarots> = all_tarots
-                    .choose_multiple(&mut rand::thread_rng(), count)
+                    .choose_multiple(&mut crate::rng::rng_for("pack", "arcana contents"), count)
                     .copied()
                     .collect();
                 PackContents::Tarots(selected)
             }
             PackType::Celestial => {
-                let count = pack_type.card_count();
-                let all_planets = Planets::all();
+                let all_planets: Vec<Planets> = Planets::all()
+                    .into_iter()
+                    .filter(|p| allowed(&PackSelection::Planet(*p)))
+                    .collect();
                 let selected: Vec<Planets> = all_planets
-                    .choose_multiple(&mut rand::thread_rng(), count)
+                    .choose_multiple(&mut crate::rng::rng_for("pack", "celestial contents"), count)
                     .copied()
                     .collect();
                 PackContents::Planets(selected)
             }
             PackType::Spectral => {
-                let count = pack_type.card_count();
-                let all_spectrals = Spectrals::all();
+                let all_spectrals: Vec<Spectrals> = Spectrals::all()
+                    .into_iter()
+                    .filter(|s| allowed(&PackSelection::Spectral(s.clone())))
+                    .collect();
                 let selected: Vec<Spectrals> = all_spectrals
-                    .choose_multiple(&mut rand::thread_rng(), count)
+                    .choose_multiple(&mut crate::rng::rng_for("pack", "spectral contents"), count)
                     .cloned()
                     .collect();
                 PackContents::Spectrals(selected)
             }
             PackType::Buffoon => {
-                let count = pack_type.card_count();
-                let all_jokers = Jokers::all_common(); // For now, only common jokers
+                let all_jokers: Vec<Jokers> = Jokers::all_common() // For now, only common jokers
+                    .into_iter()
+                    .filter(|j| allowed(&PackSelection::Joker(j.clone())))
+                    .collect();
                 let selected: Vec<Jokers> = all_jokers
-                    .choose_multiple(&mut rand::thread_rng(), count)
+                    .choose_multiple(&mut crate::rng::rng_for("pack", "buffoon contents"), count)
                     .cloned()
                     .collect();
                 PackContents::Jokers(selected)
             }
+            PackType::Standard => {
+                let mut rng = crate::rng::rng_for("pack", "standard contents");
+                let cards = (0..count)
+                    .map(|_| {
+                        let value = *Value::values().choose(&mut rng).unwrap();
+                        let suit = *Suit::suits().choose(&mut rng).unwrap();
+                        Card::new(value, suit)
+                    })
+                    .collect();
+                PackContents::Cards(cards)
+            }
         };
 
         Pack {
             pack_type,
+            size: booster.size,
             contents,
+            choices_left: booster.choices(),
         }
     }
 
+    pub fn booster(&self) -> BoosterPack {
+        BoosterPack::new(self.pack_type, self.size)
+    }
+
+    /// Number of items left in the pack
+    pub fn len(&self) -> usize {
+        match &self.contents {
+            PackContents::Tarots(items) => items.len(),
+            PackContents::Planets(items) => items.len(),
+            PackContents::Spectrals(items) => items.len(),
+            PackContents::Jokers(items) => items.len(),
+            PackContents::Cards(items) => items.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Take an item out of the pack, using up one of its choices
+    pub fn take(&mut self, index: usize) -> Option<PackSelection> {
+        let selection = self.select(index)?;
+        match &mut self.contents {
+            PackContents::Tarots(items) => drop(items.remove(index)),
+            PackContents::Planets(items) => drop(items.remove(index)),
+            PackContents::Spectrals(items) => drop(items.remove(index)),
+            PackContents::Jokers(items) => drop(items.remove(index)),
+            PackContents::Cards(items) => drop(items.remove(index)),
+        }
+        self.choices_left = self.choices_left.saturating_sub(1);
+        Some(selection)
+    }
+
+    /// Whether the pack closes: no choices or items left
+    pub fn is_done(&self) -> bool {
+        self.choices_left == 0 || self.is_empty()
+    }
+
     /// Get the items from this pack as consumables or jokers
     pub fn get_tarots(&self) -> Option<&Vec<Tarots>> {
         match &self.contents {
@@ -167,6 +326,13 @@ impl Pack {
         }
     }
 
+    pub fn get_cards(&self) -> Option<&Vec<Card>> {
+        match &self.contents {
+            PackContents::Cards(items) => Some(items),
+            _ => None,
+        }
+    }
+
     /// Select an item from the pack by index
     pub fn select(&self, index: usize) -> Option<PackSelection> {
         match &self.contents {
@@ -182,6 +348,7 @@ impl Pack {
             PackContents::Jokers(items) => {
                 items.get(index).map(|j| PackSelection::Joker(j.clone()))
             }
+            PackContents::Cards(items) => items.get(index).map(|c| PackSelection::Card(*c)),
         }
     }
 }
@@ -193,6 +360,7 @@ pub enum PackSelection {
     Planet(Planets),
     Spectral(Spectrals),
     Joker(Jokers),
+    Card(Card),
 }
 
 impl PackSelection {
@@ -202,7 +370,7 @@ impl PackSelection {
             PackSelection::Tarot(t) => Some(Consumables::Tarot(*t)),
             PackSelection::Planet(p) => Some(Consumables::Planet(*p)),
             PackSelection::Spectral(s) => Some(Consumables::Spectral(s.clone())),
-            PackSelection::Joker(_) => None,
+            PackSelection::Joker(_) | PackSelection::Card(_) => None,
         }
     }
 
@@ -269,6 +437,45 @@ mod tests {
         assert_eq!(jokers.unwrap().len(), 2);
     }
 
+    #[test]
+    fn test_pack_sizes() {
+        let mega = BoosterPack::new(PackType::Arcana, PackSize::Mega);
+        assert_eq!(mega.name(), "Mega Arcana Pack");
+        assert_eq!((mega.card_count(), mega.choices(), mega.base_cost()), (5, 2, 8));
+        let jumbo = BoosterPack::new(PackType::Buffoon, PackSize::Jumbo);
+        assert_eq!((jumbo.card_count(), jumbo.choices(), jumbo.base_cost()), (4, 1, 6));
+
+        let pack = Pack::new(mega);
+        assert_eq!(pack.len(), 5);
+        assert_eq!(pack.choices_left, 2);
+        assert_eq!(pack.booster(), mega);
+    }
+
+    #[test]
+    fn test_pack_creation_standard() {
+        let pack = Pack::new(PackType::Standard);
+        let cards = pack.get_cards().unwrap();
+        assert_eq!(cards.len(), 3);
+        assert!(matches!(pack.select(0), Some(PackSelection::Card(_))));
+        assert_eq!(pack.select(0).unwrap().to_consumable(), None);
+    }
+
+    #[test]
+    fn test_pack_take() {
+        let mut pack = Pack::new(BoosterPack::new(PackType::Celestial, PackSize::Mega));
+        let second = pack.select(1).unwrap();
+        assert_eq!(pack.take(1), Some(second.clone()));
+        assert_eq!(pack.len(), 4);
+        assert!(!pack.get_planets().unwrap().contains(match &second {
+            PackSelection::Planet(p) => p,
+            _ => unreachable!(),
+        }));
+        assert!(!pack.is_done());
+        pack.take(0).unwrap();
+        assert!(pack.is_done());
+        assert_eq!(pack.take(10), None);
+    }
+
     #[test]
     fn test_pack_selection() {
         let pack = Pack::new(PackType::Arcana);
@@ -1,4 +1,5 @@
 use crate::card::Card;
+#[cfg(feature = "python")]
 use pyo3::pyclass;
 use strum::{EnumIter, IntoEnumIterator};
 
@@ -92,10 +93,7 @@ impl BossModifier {
     /// Returns the score multiplier for this boss modifier
     /// Most bosses use 2.0x, The Wall uses 2.5x
     pub fn score_multiplier(&self) -> f64 {
-        match self {
-            Self::TheWall => 2.5,
-            _ => 2.0,
-        }
+        self.rule().score_multiplier()
     }
 
     /// Returns hand size modifier for this boss
@@ -128,16 +126,7 @@ impl BossModifier {
     /// Check if a card is debuffed by this modifier
     /// Debuffed cards don't contribute to scoring
     pub fn is_card_debuffed(&self, card: &Card) -> bool {
-        use crate::card::{Suit, Value};
-
-        match self {
-            Self::TheClub => card.suit == Suit::Club,
-            Self::TheGoad => card.suit == Suit::Spade,
-            Self::TheWindow => card.suit == Suit::Diamond,
-            Self::TheHead => card.suit == Suit::Heart,
-            Self::ThePlant => matches!(card.value, Value::Jack | Value::Queen | Value::King),
-            _ => false,
-        }
+        self.rule().debuffs(card)
     }
 
     /// Returns true if this modifier halves the final score
@@ -215,6 +204,13 @@ impl BossModifier {
         let all = Self::all();
         *all.choose(rng).unwrap()
     }
+
+    /// Get a random boss modifier other than `current` (for the Boss Tag re-roll)
+    pub fn random_except(rng: &mut impl rand::Rng, current: Self) -> Self {
+        use rand::seq::SliceRandom;
+        let others: Vec<Self> = Self::iter().filter(|m| *m != current).collect();
+        *others.choose(rng).unwrap()
+    }
 }
 
 #[cfg(test)]
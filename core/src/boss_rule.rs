@@ -0,0 +1,494 @@
+use crate::boss_modifier::BossModifier;
+use crate::card::{Card, Suit};
+use crate::error::GameError;
+use crate::game::Game;
+use crate::hand::MadeHand;
+use crate::rank::HandRank;
+
+/// How a boss modifier is enforced while its blind is played. Each
+/// `BossModifier` maps to one rule through `BossModifier::rule`. Every hook
+/// does nothing by default, so a new boss only implements the hooks it needs.
+///
+/// The game calls the hooks in this order:
+/// `on_blind_start`, then per deal `deal_size` and `on_draw`, then per hand
+/// `before_play`, `allows_hand`, `voids_hand`, `adjust_score`, `after_score`
+/// and `after_play`, and finally `on_blind_end` when the blind is beaten or
+/// the boss is disabled (Luchador, Chicot).
+pub trait BossRule: Send + Sync {
+    /// Multiplier on the ante's base score for this blind
+    fn score_multiplier(&self) -> f64 {
+        2.0
+    }
+
+    /// Blind selected, plays and discards are already set for the blind
+    fn on_blind_start(&self, _game: &mut Game) {}
+
+    /// Blind beaten or boss disabled: undo lasting effects
+    fn on_blind_end(&self, _game: &mut Game) {}
+
+    /// Number of cards to deal, `count` is the usual number
+    fn deal_size(&self, _game: &mut Game, count: usize) -> usize {
+        count
+    }
+
+    /// Cards were drawn, before they reach the hand
+    fn on_draw(&self, _drawn: &mut [Card]) {}
+
+    /// Play requested, before the selected cards are evaluated
+    fn before_play(&self, _game: &mut Game) -> Result<(), GameError> {
+        Ok(())
+    }
+
+    /// Whether a hand of this rank may be played
+    fn allows_hand(&self, _game: &Game, _rank: HandRank) -> bool {
+        true
+    }
+
+    /// Whether a card is debuffed: it neither scores nor triggers. Rules
+    /// that debuff cards mark them with `Game::debuff_cards` when the blind
+    /// starts, scoring only looks at `Card::debuffed`.
+    fn debuffs(&self, _card: &Card) -> bool {
+        false
+    }
+
+    /// Whether the hand being scored scores nothing at all
+    fn voids_hand(&self, _game: &Game) -> bool {
+        false
+    }
+
+    /// Final score of a hand, before it is rounded down
+    fn adjust_score(&self, score: f64) -> f64 {
+        score
+    }
+
+    /// Hand scored
+    fn after_score(&self, _game: &mut Game, _hand: &MadeHand) {}
+
+    /// Played cards left the hand, before it is refilled
+    fn after_play(&self, _game: &mut Game, _rank: HandRank) {}
+}
+
+impl BossModifier {
+    /// Rule enforcing this modifier
+    pub fn rule(&self) -> &'static dyn BossRule {
+        match self {
+            Self::TheWall => &TheWall,
+            Self::TheManacle => &TheManacle,
+            Self::TheWater => &TheWater,
+            Self::TheNeedle => &TheNeedle,
+            Self::TheArm => &TheArm,
+            Self::TheTooth => &TheTooth,
+            Self::TheClub => &SuitDebuff(Suit::Club),
+            Self::TheGoad => &SuitDebuff(Suit::Spade),
+            Self::TheWindow => &SuitDebuff(Suit::Diamond),
+            Self::TheHead => &SuitDebuff(Suit::Heart),
+            Self::ThePlant => &ThePlant,
+            Self::TheFlint => &TheFlint,
+            Self::TheEye => &TheEye,
+            Self::TheMouth => &TheMouth,
+            Self::TheSerpent => &TheSerpent,
+            Self::TheHook => &TheHook,
+            Self::TheOx => &TheOx,
+            Self::TheHouse => &TheHouse,
+            Self::TheWheel => &TheWheel,
+            Self::ThePillar => &ThePillar,
+        }
+    }
+}
+
+// Flip every face-down card in hand back up
+fn reveal_hand(game: &mut Game) {
+    for card in game.available.cards() {
+        if !card.is_visible() {
+            game.modify_card_everywhere(card.id, |c| c.set_face_down(false));
+        }
+    }
+}
+
+pub struct TheWall;
+impl BossRule for TheWall {
+    fn score_multiplier(&self) -> f64 {
+        2.5
+    }
+}
+
+pub struct TheManacle;
+impl BossRule for TheManacle {
+    fn on_blind_start(&self, game: &mut Game) {
+        game.modify_hand_size(BossModifier::TheManacle.hand_size_modifier());
+    }
+
+    fn on_blind_end(&self, game: &mut Game) {
+        game.modify_hand_size(-BossModifier::TheManacle.hand_size_modifier());
+    }
+}
+
+// Runs before `OnBlindSelect` jokers, so Burglar still adds its hands
+// under The Needle, while Drunkard's discard is taken by The Water
+pub struct TheWater;
+impl BossRule for TheWater {
+    fn on_blind_start(&self, game: &mut Game) {
+        game.round_state.boss_discards_taken = game.discards;
+        game.discards = 0;
+    }
+
+    fn on_blind_end(&self, game: &mut Game) {
+        game.discards += std::mem::take(&mut game.round_state.boss_discards_taken);
+    }
+}
+
+pub struct TheNeedle;
+impl BossRule for TheNeedle {
+    fn on_blind_start(&self, game: &mut Game) {
+        if let Some(max_hands) = BossModifier::TheNeedle.max_hands() {
+            game.round_state.boss_plays_taken = game.plays.saturating_sub(max_hands);
+            game.plays = game.plays.min(max_hands);
+        }
+    }
+
+    fn on_blind_end(&self, game: &mut Game) {
+        game.plays += std::mem::take(&mut game.round_state.boss_plays_taken);
+    }
+}
+
+pub struct TheArm;
+impl BossRule for TheArm {
+    fn after_score(&self, game: &mut Game, hand: &MadeHand) {
+        let (chips, mult) = game.config.hand_scaling.get(hand.rank);
+        if let Some(level) = game.hand_levels.get_mut(&hand.rank) {
+            if level.level > 1 {
+                *level = level.downgrade(chips, mult);
+                game.trigger_boss_blind_effects();
+            }
+        }
+    }
+}
+
+pub struct TheTooth;
+impl BossRule for TheTooth {
+    // Every played card costs, scoring or not
+    fn after_score(&self, game: &mut Game, hand: &MadeHand) {
+        let cost = BossModifier::TheTooth.money_per_card() * hand.all.len();
+        if cost > 0 {
+            game.lose_money(cost);
+            game.trigger_boss_blind_effects();
+        }
+    }
+}
+
+/// The Club, The Goad, The Window and The Head
+pub struct SuitDebuff(pub Suit);
+impl BossRule for SuitDebuff {
+    fn on_blind_start(&self, game: &mut Game) {
+        game.debuff_cards(|card| self.debuffs(card));
+    }
+
+    fn on_blind_end(&self, game: &mut Game) {
+        game.clear_card_debuffs();
+    }
+
+    fn debuffs(&self, card: &Card) -> bool {
+        card.suit == self.0
+    }
+}
+
+pub struct ThePlant;
+impl BossRule for ThePlant {
+    fn on_blind_start(&self, game: &mut Game) {
+        game.debuff_cards(|card| self.debuffs(card));
+    }
+
+    fn on_blind_end(&self, game: &mut Game) {
+        game.clear_card_debuffs();
+    }
+
+    fn debuffs(&self, card: &Card) -> bool {
+        card.is_face()
+    }
+}
+
+pub struct TheFlint;
+impl BossRule for TheFlint {
+    fn adjust_score(&self, score: f64) -> f64 {
+        score / 2.0
+    }
+}
+
+pub struct TheEye;
+impl BossRule for TheEye {
+    fn allows_hand(&self, game: &Game, rank: HandRank) -> bool {
+        !game.played_hand_ranks.contains(&rank)
+    }
+
+    fn after_play(&self, game: &mut Game, rank: HandRank) {
+        game.played_hand_ranks.insert(rank);
+    }
+}
+
+/// The first hand played decides the only hand type allowed
+pub struct TheMouth;
+impl BossRule for TheMouth {
+    fn allows_hand(&self, game: &Game, rank: HandRank) -> bool {
+        game.allowed_hand_rank.is_none_or(|allowed| allowed == rank)
+    }
+
+    fn after_play(&self, game: &mut Game, rank: HandRank) {
+        game.allowed_hand_rank.get_or_insert(rank);
+    }
+}
+
+pub struct TheSerpent;
+impl BossRule for TheSerpent {
+    fn voids_hand(&self, game: &Game) -> bool {
+        game.hands_played_this_blind == 0
+    }
+}
+
+pub struct TheHook;
+impl BossRule for TheHook {
+    fn after_play(&self, game: &mut Game, _rank: HandRank) {
+        let count = BossModifier::TheHook.cards_to_discard_after_play();
+        let hooked = game.available.remove_random(count);
+        if hooked.is_empty() {
+            return;
+        }
+        for card in &hooked {
+            if let Some(pos) = game.hand.iter().position(|c| c == card) {
+                game.hand.remove(pos);
+            }
+        }
+        game.discarded.extend(hooked);
+        game.trigger_boss_blind_effects();
+    }
+}
+
+pub struct TheOx;
+impl BossRule for TheOx {
+    fn on_draw(&self, drawn: &mut [Card]) {
+        if let Some(leftmost) = drawn.first_mut() {
+            leftmost.set_face_down(true);
+        }
+    }
+
+    fn on_blind_end(&self, game: &mut Game) {
+        reveal_hand(game);
+    }
+}
+
+pub struct TheHouse;
+impl BossRule for TheHouse {
+    fn deal_size(&self, game: &mut Game, count: usize) -> usize {
+        if !game.first_deal_this_blind {
+            return count;
+        }
+        game.first_deal_this_blind = false;
+        1
+    }
+}
+
+pub struct TheWheel;
+impl BossRule for TheWheel {
+    fn on_draw(&self, drawn: &mut [Card]) {
+        use rand::Rng;
+        let probability = BossModifier::TheWheel.face_down_probability();
+        let mut rng = crate::rng::rng_for("boss", "the wheel face-down");
+        for card in drawn.iter_mut() {
+            if rng.gen::<f64>() < probability {
+                card.set_face_down(true);
+            }
+        }
+    }
+
+    fn on_blind_end(&self, game: &mut Game) {
+        reveal_hand(game);
+    }
+}
+
+/// Plays a random selection of the same size as the player's
+pub struct ThePillar;
+impl BossRule for ThePillar {
+    fn before_play(&self, game: &mut Game) -> Result<(), GameError> {
+        use rand::seq::SliceRandom;
+        let count = game.available.selected().len();
+        if count == 0 {
+            return Ok(());
+        }
+        game.available.deselect_all();
+        let mut rng = crate::rng::rng_for("boss", "the pillar selection");
+        let cards: Vec<Card> = game.available.cards();
+        for card in cards.choose_multiple(&mut rng, count).copied().collect::<Vec<Card>>() {
+            game.available.select_card(card)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::Action;
+    use crate::card::Value;
+    use crate::joker::{Jokers, Luchador};
+    use crate::stage::{Blind, Stage};
+
+    // Game at the boss blind with `modifier`, set up like a selected blind
+    // so every hook runs as in a real run
+    fn boss_game(modifier: BossModifier) -> Game {
+        let mut g = Game::default();
+        g.start();
+        g.blind = Some(Blind::Boss);
+        g.begin_blind(Blind::Boss, Some(modifier)).unwrap();
+        return g;
+    }
+
+    #[test]
+    fn test_rules_match_modifier_data() {
+        for modifier in BossModifier::all() {
+            let rule = modifier.rule();
+            assert_eq!(rule.score_multiplier(), modifier.score_multiplier());
+            for value in Value::values() {
+                for suit in Suit::suits() {
+                    let card = Card::new(value, suit);
+                    assert_eq!(rule.debuffs(&card), modifier.is_card_debuffed(&card));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_blind_start_rules_survive_blind_setup() {
+        let g = boss_game(BossModifier::TheWater);
+        assert_eq!(g.discards, 0);
+
+        let g = boss_game(BossModifier::TheNeedle);
+        assert_eq!(g.plays, 1);
+    }
+
+    #[test]
+    fn test_resource_bosses_with_burglar_and_drunkard() {
+        use crate::joker::{Burglar, Drunkard};
+        let config = crate::config::Config::default();
+        let with_jokers = |modifier: BossModifier, jokers: Vec<Jokers>| -> Game {
+            let mut g = Game::default();
+            g.start();
+            g.jokers = jokers;
+            g.refresh_joker_effects();
+            g.update_modifiers();
+            g.blind = Some(Blind::Boss);
+            g.begin_blind(Blind::Boss, Some(modifier)).unwrap();
+            return g;
+        };
+
+        // Burglar's +3 Hands come after The Needle
+        let g = with_jokers(BossModifier::TheNeedle, vec![Jokers::Burglar(Burglar {})]);
+        assert_eq!((g.plays, g.discards), (4, 0));
+
+        // The Water takes Drunkard's discard too, disabling it gives all back
+        let mut g = with_jokers(BossModifier::TheWater, vec![Jokers::Drunkard(Drunkard {})]);
+        assert_eq!(g.discards, 0);
+        g.disable_boss_blind();
+        assert_eq!(g.discards, config.discards + 1);
+
+        let mut g = boss_game(BossModifier::TheNeedle);
+        g.disable_boss_blind();
+        assert_eq!(g.plays, config.plays);
+    }
+
+    #[test]
+    fn test_the_tooth_charges_per_card() {
+        let mut g = boss_game(BossModifier::TheTooth);
+        g.money = 10;
+        g.plays = 2;
+        for i in 0..3 {
+            g.handle_action(Action::SelectCardIndex(i)).unwrap();
+        }
+        g.handle_action(Action::Play()).unwrap();
+        assert_eq!(g.money, 10 - 3);
+    }
+
+    #[test]
+    fn test_the_manacle_restored_at_blind_end() {
+        let hand_size = Game::default().hand_size;
+        let mut g = boss_game(BossModifier::TheManacle);
+        assert_eq!(g.hand_size, hand_size - 1);
+        assert_eq!(g.available.cards().len(), g.hand_size_target());
+
+        g.score = g.required_score();
+        let card = g.available.card_from_index(0).unwrap();
+        g.handle_action(Action::SelectCard(card)).unwrap();
+        g.handle_action(Action::Play()).unwrap();
+        assert_eq!(g.stage, Stage::PostBlind());
+        assert_eq!(g.hand_size, hand_size);
+    }
+
+    #[test]
+    fn test_the_mouth_locks_first_hand_type() {
+        let mut g = boss_game(BossModifier::TheMouth);
+        assert_eq!(g.allowed_hand_rank, None);
+
+        let card = g.available.card_from_index(0).unwrap();
+        g.handle_action(Action::SelectCard(card)).unwrap();
+        g.handle_action(Action::Play()).unwrap();
+        assert_eq!(g.allowed_hand_rank, Some(HandRank::HighCard));
+        assert!(!TheMouth.allows_hand(&g, HandRank::OnePair));
+        assert!(TheMouth.allows_hand(&g, HandRank::HighCard));
+    }
+
+    #[test]
+    fn test_luchador_disables_boss() {
+        let hand_size = Game::default().hand_size;
+        let mut g = boss_game(BossModifier::TheManacle);
+        g.jokers.push(Jokers::Luchador(Luchador {}));
+        g.refresh_joker_effects();
+
+        g.sell_joker(Jokers::Luchador(Luchador {})).unwrap();
+        assert_eq!(g.stage, Stage::Blind(Blind::Boss, None));
+        assert_eq!(g.hand_size, hand_size);
+        assert_eq!(g.required_score(), g.ante_current.base() * 2);
+    }
+
+    #[test]
+    fn test_debuffs_marked_on_cards_until_blind_end() {
+        let mut g = boss_game(BossModifier::TheGoad);
+        let spades = |g: &Game| -> Vec<Card> {
+            let mut cards = g.deck.cards();
+            cards.extend(g.available.cards());
+            cards.into_iter().filter(|c| c.suit == Suit::Spade).collect()
+        };
+        assert_eq!(spades(&g).len(), 13);
+        assert!(spades(&g).iter().all(|c| c.debuffed));
+        assert!(g.deck.cards().iter().any(|c| c.suit != Suit::Spade && !c.debuffed));
+
+        // Debuffed cards score nothing, their enhancement included
+        let mut king = g.new_card(Value::King, Suit::Spade);
+        king.enhancement = Some(crate::card::Enhancement::Bonus);
+        king.debuffed = true;
+        let hand = crate::hand::SelectHand::new(vec![king]).best_hand().unwrap();
+        // High Card 5 chips 1 mult
+        assert_eq!(g.clone().calc_score(hand), 5);
+
+        g.disable_boss_blind();
+        assert!(spades(&g).iter().all(|c| !c.debuffed));
+    }
+
+    #[test]
+    fn test_debuffed_joker_has_no_effect() {
+        use crate::joker::TheJoker;
+        let mut g = Game::default();
+        g.jokers.push(Jokers::TheJoker(TheJoker {}));
+        g.refresh_joker_effects();
+        g.set_joker_debuffed(0, true);
+        assert!(g.joker_stickers(0).is_debuffed());
+        assert!(g.effect_registry.on_score.is_empty());
+        g.set_joker_debuffed(0, false);
+        assert_eq!(g.effect_registry.on_score.len(), 1);
+    }
+
+    #[test]
+    fn test_disable_reveals_face_down_cards() {
+        let mut g = boss_game(BossModifier::TheOx);
+        assert!(g.available.cards().iter().any(|c| !c.is_visible()));
+        g.disable_boss_blind();
+        assert!(g.available.cards().iter().all(|c| c.is_visible()));
+    }
+}
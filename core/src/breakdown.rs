@@ -0,0 +1,141 @@
+use crate::card::{Card, Edition};
+use crate::game::Game;
+use crate::hand::MadeHand;
+use crate::rank::HandRank;
+
+/// What a scoring step came from
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScoreSource {
+    // Chips and mult of the hand rank at its current level
+    BaseHand(HandRank),
+    // One trigger of a played card, with its enhancement, edition and Lucky
+    // roll. Glass and Polychrome add a separate step for their X mult.
+    PlayedCard(Card),
+    // A card held in hand, e.g. Steel
+    HeldCard(Card),
+    // `OnScore` effect of the joker in this slot, None for effects that were
+    // not registered from a joker
+    Joker(Option<usize>),
+    // Edition of the joker in this slot
+    JokerEdition(usize, Edition),
+    // Observatory X mult for Planet cards of the played hand
+    Observatory,
+}
+
+/// One step of scoring a hand, like one popup of the in-game sequence
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreStep {
+    pub source: ScoreSource,
+    // Chips and mult added by the step, X mult jokers show up as added mult
+    pub chips: f64,
+    pub mult: f64,
+    // Factor the running mult was multiplied by (played and held cards,
+    // Observatory), 1.0 for none
+    pub x_mult: f64,
+    // Running chips and mult after the step
+    pub chips_total: f64,
+    pub mult_total: f64,
+}
+
+/// How a hand scored, see `Game::calc_score_detailed`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreBreakdown {
+    pub rank: HandRank,
+    // Steps in the order they were applied, empty when the boss blind voided
+    // the hand (The Serpent)
+    pub steps: Vec<ScoreStep>,
+    // Chips and mult after the last step
+    pub chips: f64,
+    pub mult: f64,
+    // Product of the steps' X mult, already included in `mult`
+    pub x_mult: f64,
+    // Score of the hand, after the boss blind adjusted it (The Flint)
+    pub score: usize,
+}
+
+impl Game {
+    /// Score a hand like `calc_score` and record every step on the way.
+    /// Like `calc_score` this updates the game (hands played, Glass cards,
+    /// boss blind effects), call it on a clone to preview a hand.
+    pub fn calc_score_detailed(&mut self, hand: MadeHand) -> ScoreBreakdown {
+        let rank = hand.rank;
+        self.score_steps = Some(Vec::new());
+        let score = self.calc_score(hand);
+        let steps = self.score_steps.take().unwrap_or_default();
+        let (chips, mult) = steps
+            .last()
+            .map_or((0.0, 0.0), |step| (step.chips_total, step.mult_total));
+        return ScoreBreakdown {
+            rank,
+            chips,
+            mult,
+            x_mult: steps.iter().map(|step| step.x_mult).product(),
+            steps,
+            score,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Enhancement, Suit, Value};
+    use crate::hand::SelectHand;
+    use crate::joker::{Jokers, TheJoker};
+
+    #[test]
+    fn test_calc_score_detailed() {
+        let mut g = Game::default();
+        g.jokers.push(Jokers::TheJoker(TheJoker {}));
+        g.set_joker_edition(0, Edition::Foil);
+        g.refresh_joker_effects();
+
+        let mut glass = Card::new(Value::King, Suit::Heart);
+        glass.enhancement = Some(Enhancement::Glass);
+        let king = Card::new(Value::King, Suit::Spade);
+        let mut steel = Card::new(Value::Two, Suit::Club);
+        steel.enhancement = Some(Enhancement::Steel);
+        g.available.extend(vec![steel]);
+        let hand = SelectHand::new(vec![glass, king]).best_hand().unwrap();
+        let expected = g.clone().calc_score(hand.clone());
+        let breakdown = g.calc_score_detailed(hand);
+
+        let sources: Vec<&ScoreSource> = breakdown.steps.iter().map(|s| &s.source).collect();
+        assert_eq!(sources.len(), 7);
+        assert_eq!(sources[0], &ScoreSource::BaseHand(HandRank::OnePair));
+        assert_eq!(
+            sources[4..],
+            [
+                &ScoreSource::HeldCard(steel),
+                &ScoreSource::Joker(Some(0)),
+                &ScoreSource::JokerEdition(0, Edition::Foil)
+            ]
+        );
+        // Each King scores once, Glass adds its X mult as its own step
+        let glass_steps: Vec<&ScoreStep> = breakdown
+            .steps
+            .iter()
+            .filter(|s| s.source == ScoreSource::PlayedCard(glass))
+            .collect();
+        assert_eq!(glass_steps.len(), 2);
+        assert_eq!((glass_steps[0].chips, glass_steps[0].x_mult), (10.0, 1.0));
+        assert_eq!((glass_steps[1].chips, glass_steps[1].x_mult), (0.0, 2.0));
+
+        // Pair 10 chips 2 mult, Kings 20 chips, Glass x2, held Steel x1.5,
+        // then The Joker +4 mult and Foil +50 chips
+        assert_eq!(breakdown.steps[0].chips, 10.0);
+        assert_eq!(breakdown.steps[4].x_mult, 1.5);
+        assert_eq!(breakdown.steps[4].mult_total, 6.0);
+        assert_eq!(breakdown.steps[5].mult, 4.0);
+        assert_eq!(breakdown.steps[6].chips_total, 80.0);
+        assert_eq!((breakdown.chips, breakdown.mult, breakdown.x_mult), (80.0, 10.0, 3.0));
+        assert_eq!(breakdown.score, 800);
+        assert_eq!(breakdown.score, expected);
+
+        // Recording stops with the call
+        assert!(g.score_steps.is_none());
+    }
+}
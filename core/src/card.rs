@@ -1,5 +1,6 @@
 #[cfg(feature = "colored")]
 use colored::Colorize;
+#[cfg(feature = "python")]
 use pyo3::pyclass;
 use std::{
     fmt,
@@ -110,6 +111,18 @@ impl Suit {
     pub const fn suits() -> [Self; 4] {
         SUITS
     }
+    pub fn is_red(&self) -> bool {
+        matches!(self, Self::Heart | Self::Diamond)
+    }
+    /// Plural name, e.g. "Hearts"
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Spade => "Spades",
+            Self::Club => "Clubs",
+            Self::Heart => "Hearts",
+            Self::Diamond => "Diamonds",
+        }
+    }
     pub fn unicode(&self) -> &str {
         match self {
             Self::Spade => "♤",
@@ -158,6 +171,18 @@ pub enum Edition {
     Negative,
 }
 
+impl Edition {
+    /// Extra shop cost of an item with this edition
+    pub fn extra_cost(&self) -> usize {
+        match self {
+            Edition::Base => 0,
+            Edition::Foil => 2,
+            Edition::Holographic => 3,
+            Edition::Polychrome | Edition::Negative => 5,
+        }
+    }
+}
+
 /// Enum for card seals
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "python", pyclass(eq))]
@@ -174,6 +199,19 @@ pub enum Seal {
 // possible (i.e. for trashing, reordering, etc)
 static CARD_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
+// Games number their own cards from 0 so the same seed and actions give
+// the same ids (see `Game::new_card_id`), this counter only serves cards
+// created outside a game.
+fn alloc_card_id() -> usize {
+    return CARD_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
+}
+
+// Keep ids of cards created outside a game at or above `end`, so they do
+// not collide with the ids games have handed out
+pub(crate) fn reserve_card_ids(end: usize) {
+    CARD_ID_COUNTER.fetch_max(end, Ordering::SeqCst);
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "python", pyclass)]
 #[derive(PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Hash)]
@@ -185,11 +223,15 @@ pub struct Card {
     pub enhancement: Option<Enhancement>,
     pub seal: Option<Seal>,
     pub is_face_down: bool, // For The Ox, The Wheel boss modifiers
+    // Scores no chips or mult and triggers no enhancement, edition or seal
+    // (The Club, The Goad, The Window, The Head, The Plant)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub debuffed: bool,
 }
 
 impl Card {
     pub fn new(value: Value, suit: Suit) -> Self {
-        let id = CARD_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let id = alloc_card_id();
         Self {
             value,
             suit,
@@ -198,6 +240,7 @@ impl Card {
             enhancement: None,
             seal: None,
             is_face_down: false, // Default to face-up
+            debuffed: false,
         }
     }
 
@@ -216,8 +259,20 @@ impl Card {
         self.value == Value::Ace || !self.is_face() && self.value as u16 % 2 != 0
     }
 
+    /// Stone cards have no rank or suit, they always score but never help
+    /// form a hand
+    pub fn is_stone(&self) -> bool {
+        matches!(self.enhancement, Some(Enhancement::Stone))
+    }
+
+    /// Wild cards count as every suit
+    pub fn is_wild(&self) -> bool {
+        matches!(self.enhancement, Some(Enhancement::Wild))
+    }
+
     pub fn chips(&self) -> usize {
         let base_chips = match self.value {
+            _ if self.is_stone() => 0,
             Value::Two => 1,
             Value::Three => 2,
             Value::Four => 3,
@@ -274,7 +329,7 @@ impl Card {
         mult
     }
 
-    /// Get mult multiplier from this card (Glass, Steel, Polychrome)
+    /// Get mult multiplier from this card when scored (Glass, Polychrome)
     pub fn mult_multiplier(&self) -> f32 {
         let mut multiplier = 1.0;
 
@@ -282,7 +337,6 @@ impl Card {
         if let Some(enhancement) = self.enhancement {
             multiplier *= match enhancement {
                 Enhancement::Glass => 2.0,
-                Enhancement::Steel => 1.5,
                 _ => 1.0,
             };
         }
@@ -296,11 +350,54 @@ impl Card {
         multiplier
     }
 
+    /// Get mult multiplier from this card while held in hand (Steel)
+    pub fn held_mult_multiplier(&self) -> f32 {
+        match self.enhancement {
+            Some(Enhancement::Steel) => 1.5,
+            _ => 1.0,
+        }
+    }
+
+    /// Get money earned from this card if held in hand at end of round (Gold)
+    pub fn held_money_at_round_end(&self) -> usize {
+        match self.enhancement {
+            Some(Enhancement::Gold) => 3,
+            _ => 0,
+        }
+    }
+
+    /// Roll a Lucky card trigger: +20 mult with 1/5 chance and $20 with
+    /// 1/15 chance, rolled independently. Returns (mult, money).
+    pub fn roll_lucky(&self) -> (usize, usize) {
+        if let Some(Enhancement::Lucky) = self.enhancement {
+            use rand::Rng;
+            let mult = match crate::rng::rng_for("card", "lucky mult").gen_range(0..5) == 0 {
+                true => 20,
+                false => 0,
+            };
+            let money = match crate::rng::rng_for("card", "lucky money").gen_range(0..15) == 0 {
+                true => 20,
+                false => 0,
+            };
+            return (mult, money);
+        }
+        (0, 0)
+    }
+
+    /// Average mult a Lucky card adds per trigger, what `roll_lucky` gives
+    /// over many rolls (+20 Mult 1 in 5)
+    pub fn expected_lucky_mult(&self) -> f64 {
+        return match self.enhancement {
+            Some(Enhancement::Lucky) => 20.0 / 5.0,
+            _ => 0.0,
+        };
+    }
+
     /// Check if this card should be destroyed (Glass has 1/4 chance)
     pub fn should_destroy(&self) -> bool {
         if let Some(Enhancement::Glass) = self.enhancement {
             use rand::Rng;
-            return rand::thread_rng().gen_range(0..4) == 0; // 1/4 chance
+            return crate::rng::rng_for("card", "glass break").gen_range(0..4) == 0; // 1/4 chance
         }
         false
     }
@@ -445,9 +542,9 @@ mod tests {
         card.enhancement = Some(Enhancement::Bonus);
         assert_eq!(card.chips(), 34);
 
-        // Stone enhancement: +50 chips
+        // Stone enhancement: 50 chips, rank chips are lost
         card.enhancement = Some(Enhancement::Stone);
-        assert_eq!(card.chips(), 54);
+        assert_eq!(card.chips(), 50);
     }
 
     #[test]
@@ -498,7 +595,9 @@ mod tests {
     fn test_mult_multiplier_steel() {
         let mut card = Card::new(Value::Five, Suit::Heart);
         card.enhancement = Some(Enhancement::Steel);
-        assert_eq!(card.mult_multiplier(), 1.5);
+        // Steel only applies while held in hand
+        assert_eq!(card.mult_multiplier(), 1.0);
+        assert_eq!(card.held_mult_multiplier(), 1.5);
     }
 
     #[test]
@@ -517,6 +616,27 @@ mod tests {
         assert_eq!(card.mult_multiplier(), 3.0);
     }
 
+    #[test]
+    fn test_enhancement_held_money_gold() {
+        let mut card = Card::new(Value::Five, Suit::Heart);
+        assert_eq!(card.held_money_at_round_end(), 0);
+        card.enhancement = Some(Enhancement::Gold);
+        assert_eq!(card.held_money_at_round_end(), 3);
+        // Only the Gold seal pays when played
+        assert_eq!(card.seal_money_on_play(), 0);
+    }
+
+    #[test]
+    fn test_enhancement_lucky_rolls() {
+        let mut card = Card::new(Value::Five, Suit::Heart);
+        assert_eq!(card.roll_lucky(), (0, 0));
+        card.enhancement = Some(Enhancement::Lucky);
+        let rolls: Vec<(usize, usize)> = (0..300).map(|_| card.roll_lucky()).collect();
+        assert!(rolls.iter().all(|(m, d)| [0, 20].contains(m) && [0, 20].contains(d)));
+        assert!(rolls.iter().any(|(m, _)| *m == 20));
+        assert!(rolls.iter().any(|(_, d)| *d == 20));
+    }
+
     #[test]
     fn test_seal_money_gold() {
         let mut card = Card::new(Value::Five, Suit::Heart);
@@ -0,0 +1,122 @@
+use crate::game::Game;
+use crate::stage::Blind;
+#[cfg(feature = "python")]
+use pyo3::{pyclass, pymethods};
+
+/// Money paid out after beating a blind, split by source so UIs and bots
+/// can show where the reward came from.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CashOutBreakdown {
+    pub blind: Blind,
+    // Reward of the blind itself, 0 for the Small Blind from Red Stake on
+    pub blind_reward: usize,
+    // Hands left unplayed and the money they pay
+    pub hands: usize,
+    pub hand_money: usize,
+    // Discards left unused and the money they pay (Green Deck)
+    pub discards: usize,
+    pub discard_money: usize,
+    // $1 per $5 held, capped by `Config::interest_max`
+    pub interest: usize,
+}
+
+impl CashOutBreakdown {
+    pub fn total(&self) -> usize {
+        return self.blind_reward + self.hand_money + self.discard_money + self.interest;
+    }
+}
+
+impl Game {
+    /// Interest on the money held, up to the cap (raised by Seed Money and
+    /// Money Tree, none for the Green Deck)
+    pub fn interest(&self) -> usize {
+        let interest = (self.money_held() as f32 * self.config.interest_rate).floor() as usize;
+        return interest.min(self.config.interest_max);
+    }
+
+    /// Reward of `blind` itself, before hands, discards and interest
+    pub fn blind_reward(&self, blind: Blind) -> usize {
+        // Red Stake and above: the Small Blind pays no reward
+        return match blind == Blind::Small && !self.config.stake.small_blind_reward() {
+            true => 0,
+            false => blind.reward(),
+        };
+    }
+
+    /// What beating `blind` with the current hands, discards and money pays
+    pub fn cash_out_for(&self, blind: Blind) -> CashOutBreakdown {
+        return CashOutBreakdown {
+            blind,
+            blind_reward: self.blind_reward(blind),
+            hands: self.plays,
+            hand_money: self.plays * self.config.money_per_hand,
+            discards: self.discards,
+            discard_money: self.discards * self.config.money_per_discard,
+            interest: self.interest(),
+        };
+    }
+
+    /// Breakdown of the reward waiting to be cashed out, set when a blind is
+    /// beaten and cleared on `CashOut`
+    pub fn cash_out(&self) -> Option<&CashOutBreakdown> {
+        return self.pending_cash_out.as_ref();
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl CashOutBreakdown {
+    #[getter]
+    fn get_total(&self) -> usize {
+        self.total()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::voucher::Vouchers;
+
+    #[test]
+    fn test_interest_cap() {
+        let mut g = Game::default();
+        g.money = 14;
+        assert_eq!(g.interest(), 2);
+        g.money = 100;
+        assert_eq!(g.interest(), 5);
+
+        Vouchers::SeedMoney.apply_effect(&mut g);
+        assert_eq!(g.interest(), 10);
+        Vouchers::MoneyTree.apply_effect(&mut g);
+        assert_eq!(g.interest(), 20);
+        g.money = 37;
+        assert_eq!(g.interest(), 7);
+
+        // Green Deck earns no interest, even with the vouchers
+        let mut g = Game::new(Config::with_deck(crate::alternative_deck::DeckType::GreenDeck)).unwrap();
+        Vouchers::MoneyTree.apply_effect(&mut g);
+        g.money = 100;
+        assert_eq!(g.interest(), 0);
+    }
+
+    #[test]
+    fn test_cash_out_breakdown() {
+        let mut g = Game::default();
+        g.money = 23;
+        g.plays = 2;
+        g.discards = 3;
+        let breakdown = g.cash_out_for(Blind::Big);
+        assert_eq!(breakdown.blind_reward, Blind::Big.reward());
+        assert_eq!((breakdown.hands, breakdown.hand_money), (2, 2));
+        assert_eq!((breakdown.discards, breakdown.discard_money), (3, 0));
+        assert_eq!(breakdown.interest, 4);
+        assert_eq!(breakdown.total(), Blind::Big.reward() + 2 + 4);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::action::SortMode;
+use crate::card::{Card, Enhancement, Suit, Value};
+use crate::config::ObservabilityMode;
+use crate::game::Game;
+#[cfg(feature = "python")]
+use pyo3::{pyclass, pymethods};
+
+// Enhancement variants, sizes `DeckComposition::by_enhancement`
+const ENHANCEMENT_COUNT: usize = 8;
+
+/// Card counts of the undrawn deck, what the deck view of the game shows.
+/// Arrays are indexed like the observation one-hots: `Value as usize`,
+/// `Suit as usize` and `Enhancement as usize`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DeckComposition {
+    pub total: usize,
+    // Stone cards have no rank or suit and are left out of these two
+    pub by_rank: [usize; 13],
+    pub by_suit: [usize; 4],
+    pub by_enhancement: [usize; ENHANCEMENT_COUNT],
+    pub unenhanced: usize,
+}
+
+impl DeckComposition {
+    pub fn from_cards(cards: &[Card]) -> Self {
+        let mut composition = Self::default();
+        for card in cards {
+            composition.total += 1;
+            match card.enhancement {
+                Some(enhancement) => composition.by_enhancement[enhancement as usize] += 1,
+                None => composition.unenhanced += 1,
+            }
+            if !card.is_stone() {
+                composition.by_rank[card.value as usize] += 1;
+                composition.by_suit[card.suit as usize] += 1;
+            }
+        }
+        return composition;
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl DeckComposition {
+    /// Cards of this rank left
+    pub fn rank(&self, value: Value) -> usize {
+        return self.by_rank[value as usize];
+    }
+
+    /// Cards of this suit left
+    pub fn suit(&self, suit: Suit) -> usize {
+        return self.by_suit[suit as usize];
+    }
+
+    /// Cards with this enhancement left
+    pub fn enhancement(&self, enhancement: Enhancement) -> usize {
+        return self.by_enhancement[enhancement as usize];
+    }
+}
+
+impl Game {
+    /// Cards left to draw. With `ObservabilityMode::Full` they come in draw
+    /// order, otherwise sorted by suit and rank so the order gives nothing
+    /// away. Agents should use this rather than `deck.cards()`.
+    pub fn remaining_cards(&self) -> Vec<Card> {
+        let mut cards = self.deck.cards();
+        if self.config.observability == ObservabilityMode::Hidden {
+            cards.sort_by_key(|card| (SortMode::BySuit.key(card), card.id));
+        }
+        return cards;
+    }
+
+    /// Counts by rank, suit and enhancement of the cards left to draw
+    pub fn remaining_deck_composition(&self) -> DeckComposition {
+        return DeckComposition::from_cards(&self.deck.cards());
+    }
+
+    /// Whether face-down cards (The Wheel, The Ox...) are shown to the
+    /// player, only with `ObservabilityMode::Full`
+    pub fn reveals_face_down(&self) -> bool {
+        return self.config.observability == ObservabilityMode::Full;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn test_remaining_deck_composition() {
+        let mut g = Game::default();
+        let composition = g.remaining_deck_composition();
+        assert_eq!(composition.total, 52);
+        assert_eq!(composition.rank(Value::Ace), 4);
+        assert_eq!(composition.suit(Suit::Heart), 13);
+        assert_eq!(composition.unenhanced, 52);
+
+        let mut cards = g.deck.cards();
+        cards[0].enhancement = Some(Enhancement::Stone);
+        cards[1].enhancement = Some(Enhancement::Gold);
+        let composition = DeckComposition::from_cards(&cards);
+        assert_eq!(composition.enhancement(Enhancement::Stone), 1);
+        assert_eq!(composition.enhancement(Enhancement::Gold), 1);
+        assert_eq!(composition.unenhanced, 50);
+        // The Stone card has no rank or suit
+        assert_eq!(composition.by_rank.iter().sum::<usize>(), 51);
+        assert_eq!(composition.by_suit.iter().sum::<usize>(), 51);
+
+        // Cards in hand are no longer counted
+        g.start();
+        let composition = g.remaining_deck_composition();
+        assert_eq!(composition.total, 52 - g.available.cards().len());
+        let kings = g.available.cards().iter().filter(|c| c.value == Value::King).count();
+        assert_eq!(composition.rank(Value::King), 4 - kings);
+    }
+
+    #[test]
+    fn test_remaining_cards_order() {
+        let mut hidden = Game::with_seed(Config::default(), 3).unwrap();
+        let mut config = Config::default();
+        config.observability = ObservabilityMode::Full;
+        let mut full = Game::with_seed(config, 3).unwrap();
+        hidden.start();
+        full.start();
+
+        // Full information gives the draw order
+        assert_eq!(full.remaining_cards(), full.deck.cards());
+
+        // Hidden gives the same cards in an order independent of the shuffle
+        let cards = hidden.remaining_cards();
+        assert_eq!(cards.len(), hidden.deck.cards().len());
+        let keys: Vec<_> = cards.iter().map(|c| SortMode::BySuit.key(c)).collect();
+        assert!(keys.windows(2).all(|w| w[0] <= w[1]));
+        assert!(!hidden.reveals_face_down());
+        assert!(full.reveals_face_down());
+    }
+}
@@ -1,4 +1,9 @@
 use crate::alternative_deck::DeckType;
+use crate::availability::Availability;
+use crate::error::ConfigError;
+use crate::rank::HandScaling;
+use crate::shop::RarityWeights;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
 const DEFAULT_ROUND_START: usize = 0;
@@ -8,6 +13,7 @@ const DEFAULT_MONEY_START: usize = 4; // Changed to match Balatro default $4
 const DEFAULT_MONEY_MAX: usize = 500;
 const DEFAULT_REWARD_BASE: usize = 0;
 const DEFAULT_MONEY_PER_HAND: usize = 1;
+const DEFAULT_MONEY_PER_DISCARD: usize = 0;
 const DEFAULT_INTEREST_RATE: f32 = 0.2;
 const DEFAULT_INTEREST_MAX: usize = 5;
 const DEFAULT_BASE_MULT: usize = 0;
@@ -26,35 +32,177 @@ const DEFAULT_DECK_MAX: usize = 100;
 const DEFAULT_DISCARDED_MAX: usize = 100;
 const DEFAULT_SELECTED_MAX: usize = 5;
 
+// Upper bounds checked by `Config::validate`
+const MAX_PLAYS: usize = 100;
+const MAX_DISCARDS: usize = 100;
+const MAX_ANTE: usize = 1000;
+const MAX_AVAILABLE: usize = 100;
+
+const SHORT_RUN_ANTE_END: usize = 3;
+const RL_TRAINING_FAST_ANTE_END: usize = 2;
+
+/// What happens when a draw needs more cards than are left in the deck
+/// during a blind.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeckExhaustion {
+    /// Balatro rules: draw what is left, the hand shrinks until the next blind
+    #[default]
+    NoRefill,
+    /// Shuffle the discard pile back into the deck and keep drawing
+    ReshuffleDiscards,
+}
+
+/// What the player is allowed to know about the undrawn deck, see
+/// `Game::remaining_cards`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ObservabilityMode {
+    /// Balatro rules: which cards are left but not their order, face-down
+    /// cards stay hidden
+    #[default]
+    Hidden,
+    /// Perfect information: the draw order and face-down cards are known
+    Full,
+}
+
+/// Difficulty of a run. Every stake also has the effects of the stakes
+/// below it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Stake {
+    /// Base difficulty
+    #[default]
+    White,
+    /// Small Blind gives no reward money
+    Red,
+    /// Required score scales faster for each Ante
+    Green,
+    /// Shop can have Eternal jokers (can't be sold or destroyed)
+    Black,
+    /// -1 discard
+    Blue,
+    /// Required score scales even faster for each Ante
+    Purple,
+    /// Shop can have Perishable jokers (debuffed after 5 rounds)
+    Orange,
+    /// Shop can have Rental jokers ($1 to buy, costs $3 per round)
+    Gold,
+}
+
+impl Stake {
+    pub fn name(&self) -> &str {
+        return match self {
+            Stake::White => "White Stake",
+            Stake::Red => "Red Stake",
+            Stake::Green => "Green Stake",
+            Stake::Black => "Black Stake",
+            Stake::Blue => "Blue Stake",
+            Stake::Purple => "Purple Stake",
+            Stake::Orange => "Orange Stake",
+            Stake::Gold => "Gold Stake",
+        };
+    }
+
+    /// Whether beating the Small Blind pays its reward
+    pub fn small_blind_reward(&self) -> bool {
+        return *self < Stake::Red;
+    }
+
+    /// Ante scaling tier for the blind base requirement (see `Ante::scaled_base`)
+    pub fn ante_scaling(&self) -> usize {
+        return match self {
+            Stake::White | Stake::Red => 1,
+            Stake::Green | Stake::Black | Stake::Blue => 2,
+            Stake::Purple | Stake::Orange | Stake::Gold => 3,
+        };
+    }
+
+    /// Discards removed from every round
+    pub fn discard_penalty(&self) -> usize {
+        return match *self >= Stake::Blue {
+            true => 1,
+            false => 0,
+        };
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "python", pyclass)]
 #[derive(Debug, Clone)]
 pub struct Config {
+    /// Round counter the run starts from
     pub round_start: usize,
+    /// Hands per blind, at least 1
     pub plays: usize,
+    /// Discards per blind
     pub discards: usize,
+    /// Money the run starts with, at most `money_max`
     pub money_start: usize,
+    /// Cap on money held
     pub money_max: usize,
+    /// Money added to every blind reward
     pub reward_base: usize,
+    /// Money paid per unused hand when a blind is beaten
     pub money_per_hand: usize,
+    /// Money paid per unused discard when a blind is beaten
+    pub money_per_discard: usize,
+    /// Interest paid per dollar held at cash out, between 0 and 1
     pub interest_rate: f32,
+    /// Cap on interest paid at cash out
     pub interest_max: usize,
+    /// Mult every hand starts from, on top of the hand rank's
     pub base_mult: usize,
+    /// Chips every hand starts from, on top of the hand rank's
     pub base_chips: usize,
+    /// Score each blind starts from
     pub base_score: usize,
+    /// Ante the run starts in, at least 1 and at most `ante_end`
     pub ante_start: usize,
+    /// Ante whose Boss Blind wins the run
     pub ante_end: usize,
+    /// Keep playing past `ante_end` instead of winning
+    pub endless: bool,
+    /// Jokers that can be held, at most `joker_slots_max`
     pub joker_slots: usize,
+    /// Cap on joker slots, also sizes the observation
     pub joker_slots_max: usize,
+    /// Consumables that can be held, between 1 and `consumable_slots_max`
     pub consumable_slots: usize,
+    /// Cap on consumable slots, also sizes the observation
     pub consumable_slots_max: usize,
+    /// Cards that can be selected for a play or discard, between 1 and
+    /// `available`
     pub selected_max: usize,
+    /// Hand size, between 1 and `available_max`
     pub available: usize,
+    /// Cap on hand size, also sizes the observation and action space
     pub available_max: usize,
+    /// Consumable slots in the shop, also sizes the observation
     pub store_consumable_slots_max: usize,
+    /// Cap on deck size
     pub deck_max: usize,
+    /// Cap on discard pile size
     pub discarded_max: usize,
-    pub deck_type: Option<DeckType>, // None = standard 52-card deck
+    /// Starting deck, None for the standard 52-card deck
+    pub deck_type: Option<DeckType>,
+    /// What happens when the deck runs out during a blind
+    pub deck_exhaustion: DeckExhaustion,
+    /// Shop joker rarity odds, override for stakes/custom rules
+    pub joker_rarity_weights: RarityWeights,
+    /// Per-ante item gating for shop and packs
+    pub availability: Availability,
+    /// Applied by `Game::new`
+    pub stake: Stake,
+    /// Chips and mult each hand rank gains per level
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hand_scaling: HandScaling,
+    /// Whether the draw order and face-down cards can be seen
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub observability: ObservabilityMode,
 }
 
 impl Config {
@@ -67,6 +215,7 @@ impl Config {
             money_max: DEFAULT_MONEY_MAX,
             reward_base: DEFAULT_REWARD_BASE,
             money_per_hand: DEFAULT_MONEY_PER_HAND,
+            money_per_discard: DEFAULT_MONEY_PER_DISCARD,
             interest_rate: DEFAULT_INTEREST_RATE,
             interest_max: DEFAULT_INTEREST_MAX,
             base_mult: DEFAULT_BASE_MULT,
@@ -74,6 +223,7 @@ impl Config {
             base_score: DEFAULT_BASE_SCORE,
             ante_start: DEFAULT_ANTE_START,
             ante_end: DEFAULT_ANTE_END,
+            endless: false,
             joker_slots: DEFAULT_JOKER_SLOTS,
             joker_slots_max: DEFAULT_JOKER_SLOTS_MAX,
             consumable_slots: DEFAULT_CONSUMABLE_SLOTS,
@@ -85,6 +235,12 @@ impl Config {
             deck_max: DEFAULT_DECK_MAX,
             discarded_max: DEFAULT_DISCARDED_MAX,
             deck_type: None, // Standard deck by default
+            deck_exhaustion: DeckExhaustion::NoRefill,
+            joker_rarity_weights: RarityWeights::default(),
+            availability: Availability::default(),
+            stake: Stake::White,
+            hand_scaling: HandScaling::default(),
+            observability: ObservabilityMode::Hidden,
         };
     }
 
@@ -96,6 +252,181 @@ impl Config {
         deck_type.apply_to_config(&mut config);
         config
     }
+
+    /// Create a config for a stake
+    pub fn with_stake(stake: Stake) -> Self {
+        let mut config = Self::new();
+        config.stake = stake;
+        config
+    }
+
+    /// Balatro's rules, same as `Config::new`
+    pub fn standard() -> Self {
+        return Self::new();
+    }
+
+    /// Standard rules, won after ante 3
+    pub fn short_run() -> Self {
+        let mut config = Self::new();
+        config.ante_end = SHORT_RUN_ANTE_END;
+        return config;
+    }
+
+    /// Short episodes for training agents: won after ante 2, and the discard
+    /// pile is reshuffled into an empty deck so hands never run dry
+    pub fn rl_training_fast() -> Self {
+        let mut config = Self::new();
+        config.ante_end = RL_TRAINING_FAST_ANTE_END;
+        config.deck_exhaustion = DeckExhaustion::ReshuffleDiscards;
+        return config;
+    }
+
+    pub fn preset(preset: ConfigPreset) -> Self {
+        return match preset {
+            ConfigPreset::Standard => Self::standard(),
+            ConfigPreset::ShortRun => Self::short_run(),
+            ConfigPreset::RlTrainingFast => Self::rl_training_fast(),
+        };
+    }
+
+    pub fn builder() -> ConfigBuilder {
+        return ConfigBuilder::new();
+    }
+
+    /// Check that every field is in range. `Game::new` refuses configs that
+    /// fail this.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        check_range("plays", self.plays, 1, MAX_PLAYS)?;
+        check_range("discards", self.discards, 0, MAX_DISCARDS)?;
+        check_range("money_start", self.money_start, 0, self.money_max)?;
+        if !(0.0..=1.0).contains(&self.interest_rate) {
+            return Err(ConfigError::InterestRate(self.interest_rate));
+        }
+        check_range("ante_end", self.ante_end, 1, MAX_ANTE)?;
+        check_range("ante_start", self.ante_start, 1, self.ante_end)?;
+        check_range("joker_slots", self.joker_slots, 0, self.joker_slots_max)?;
+        check_range("consumable_slots", self.consumable_slots, 1, self.consumable_slots_max)?;
+        check_range("available_max", self.available_max, 1, MAX_AVAILABLE)?;
+        check_range("available", self.available, 1, self.available_max)?;
+        check_range("selected_max", self.selected_max, 1, self.available)?;
+        return Ok(());
+    }
+}
+
+fn check_range(field: &'static str, value: usize, min: usize, max: usize) -> Result<(), ConfigError> {
+    if value < min || value > max {
+        return Err(ConfigError::OutOfRange {
+            field,
+            value,
+            min,
+            max,
+        });
+    }
+    return Ok(());
+}
+
+/// Named starting points for `ConfigBuilder`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfigPreset {
+    /// Balatro's rules
+    #[default]
+    Standard,
+    /// Won after ante 3
+    ShortRun,
+    /// Won after ante 2, discards reshuffle into an empty deck
+    RlTrainingFast,
+}
+
+// Setter on `ConfigBuilder` for a `Config` field of the same name
+macro_rules! config_setters {
+    ($($field:ident: $ty:ty),* $(,)?) => {
+        $(
+            pub fn $field(mut self, $field: $ty) -> Self {
+                self.config.$field = $field;
+                return self;
+            }
+        )*
+    };
+}
+
+/// Builds a `Config` from a preset, checking it with `Config::validate`
+/// when done.
+///
+/// ```
+/// use balatro_rs::config::{ConfigBuilder, ConfigPreset};
+///
+/// let config = ConfigBuilder::preset(ConfigPreset::ShortRun)
+///     .plays(5)
+///     .joker_slots(6)
+///     .build()
+///     .unwrap();
+/// assert_eq!(config.ante_end, 3);
+/// assert!(ConfigBuilder::new().consumable_slots(0).build().is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConfigBuilder {
+    config: Config,
+}
+
+impl ConfigBuilder {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    pub fn preset(preset: ConfigPreset) -> Self {
+        return Self {
+            config: Config::preset(preset),
+        };
+    }
+
+    config_setters!(
+        round_start: usize,
+        plays: usize,
+        discards: usize,
+        money_start: usize,
+        money_max: usize,
+        reward_base: usize,
+        money_per_hand: usize,
+        money_per_discard: usize,
+        interest_rate: f32,
+        interest_max: usize,
+        base_mult: usize,
+        base_chips: usize,
+        base_score: usize,
+        ante_start: usize,
+        ante_end: usize,
+        endless: bool,
+        joker_slots: usize,
+        joker_slots_max: usize,
+        consumable_slots: usize,
+        consumable_slots_max: usize,
+        selected_max: usize,
+        available: usize,
+        available_max: usize,
+        store_consumable_slots_max: usize,
+        deck_max: usize,
+        discarded_max: usize,
+        deck_exhaustion: DeckExhaustion,
+        joker_rarity_weights: RarityWeights,
+        availability: Availability,
+        stake: Stake,
+        hand_scaling: HandScaling,
+        observability: ObservabilityMode,
+    );
+
+    /// Starting deck, with its modifiers applied to the fields set so far
+    pub fn deck(mut self, deck_type: DeckType) -> Self {
+        self.config.deck_type = Some(deck_type);
+        deck_type.apply_to_config(&mut self.config);
+        return self;
+    }
+
+    pub fn build(self) -> Result<Config, ConfigError> {
+        self.config.validate()?;
+        return Ok(self.config);
+    }
 }
 
 impl Default for Config {
@@ -112,6 +443,18 @@ impl Config {
         Self::default()
     }
 
+    #[staticmethod]
+    #[pyo3(name = "preset")]
+    fn py_preset(preset: ConfigPreset) -> Self {
+        return Self::preset(preset);
+    }
+
+    /// Raises if a field is out of range
+    #[pyo3(name = "validate")]
+    fn py_validate(&self) -> Result<(), ConfigError> {
+        return self.validate();
+    }
+
     #[getter]
     fn get_ante_end(&mut self) -> usize {
         return self.ante_end;
@@ -122,6 +465,46 @@ impl Config {
         self.ante_end = i;
     }
 
+    #[getter]
+    fn get_endless(&mut self) -> bool {
+        return self.endless;
+    }
+
+    #[setter]
+    fn set_endless(&mut self, endless: bool) {
+        self.endless = endless;
+    }
+
+    #[getter]
+    fn get_deck_exhaustion(&mut self) -> DeckExhaustion {
+        return self.deck_exhaustion;
+    }
+
+    #[setter]
+    fn set_deck_exhaustion(&mut self, policy: DeckExhaustion) {
+        self.deck_exhaustion = policy;
+    }
+
+    #[getter]
+    fn get_observability(&mut self) -> ObservabilityMode {
+        return self.observability;
+    }
+
+    #[setter]
+    fn set_observability(&mut self, mode: ObservabilityMode) {
+        self.observability = mode;
+    }
+
+    #[getter]
+    fn get_stake(&mut self) -> Stake {
+        return self.stake;
+    }
+
+    #[setter]
+    fn set_stake(&mut self, stake: Stake) {
+        self.stake = stake;
+    }
+
     #[getter]
     fn get_plays(&mut self) -> usize {
         return self.plays;
@@ -216,3 +599,76 @@ impl Config {
         return 8;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Game;
+
+    #[test]
+    fn test_presets_are_valid() {
+        for preset in [ConfigPreset::Standard, ConfigPreset::ShortRun, ConfigPreset::RlTrainingFast] {
+            let config = ConfigBuilder::preset(preset).build().unwrap();
+            assert!(Game::with_seed(config, 1).is_ok());
+        }
+        assert_eq!(Config::short_run().ante_end, 3);
+        let fast = Config::rl_training_fast();
+        assert_eq!(fast.ante_end, 2);
+        assert_eq!(fast.deck_exhaustion, DeckExhaustion::ReshuffleDiscards);
+    }
+
+    #[test]
+    fn test_builder_sets_fields() {
+        let config = Config::builder()
+            .plays(6)
+            .discards(0)
+            .joker_slots(7)
+            .stake(Stake::Blue)
+            .deck(DeckType::RedDeck)
+            .build()
+            .unwrap();
+        assert_eq!(config.plays, 6);
+        // Red Deck adds a discard to what was set
+        assert_eq!(config.discards, 1);
+        assert_eq!(config.joker_slots, 7);
+        assert_eq!(config.stake, Stake::Blue);
+        assert_eq!(config.deck_type, Some(DeckType::RedDeck));
+    }
+
+    #[test]
+    fn test_validate_ranges() {
+        let err = ConfigBuilder::new().consumable_slots(0).build().unwrap_err();
+        assert_eq!(
+            err,
+            ConfigError::OutOfRange {
+                field: "consumable_slots",
+                value: 0,
+                min: 1,
+                max: DEFAULT_CONSUMABLE_SLOTS_MAX,
+            }
+        );
+        assert!(ConfigBuilder::new().plays(0).build().is_err());
+        assert!(ConfigBuilder::new().available(0).build().is_err());
+        assert!(ConfigBuilder::new().available(30).build().is_err());
+        assert!(ConfigBuilder::new().selected_max(9).build().is_err());
+        assert!(ConfigBuilder::new().joker_slots(11).build().is_err());
+        assert!(ConfigBuilder::new().ante_start(9).build().is_err());
+        assert!(ConfigBuilder::new().money_start(501).build().is_err());
+        assert_eq!(
+            ConfigBuilder::new().interest_rate(-0.5).build().unwrap_err(),
+            ConfigError::InterestRate(-0.5)
+        );
+        assert!(ConfigBuilder::new().interest_rate(f32::NAN).build().is_err());
+    }
+
+    #[test]
+    fn test_game_rejects_invalid_config() {
+        let mut config = Config::default();
+        config.consumable_slots = 0;
+        assert!(Game::new(config.clone()).is_err());
+        assert!(matches!(
+            Game::from_seed_str(config, "7LB2WVPK"),
+            Err(crate::error::SeedError::Config(_))
+        ));
+    }
+}
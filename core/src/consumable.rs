@@ -16,6 +16,11 @@ pub trait Consumable: std::fmt::Debug + Clone {
     /// Get the cost to purchase this consumable in the shop
     fn cost(&self) -> usize;
 
+    /// Money gained from selling this consumable, half its cost (at least $1)
+    fn sell_value(&self) -> usize {
+        (self.cost() / 2).max(1)
+    }
+
     /// Check if this consumable requires card targets
     fn requires_target(&self) -> bool;
 
@@ -31,6 +36,14 @@ pub trait Consumable: std::fmt::Debug + Clone {
         }
     }
 
+    /// How many cards in hand the consumable targets
+    fn target_spec(&self) -> TargetSpec {
+        TargetSpec {
+            min: self.min_targets(),
+            max: self.max_targets(),
+        }
+    }
+
     /// Execute the consumable's effect
     /// Returns Ok(()) if successful, Err if the effect failed
     fn use_effect(&self, game: &mut Game, targets: Option<Vec<Card>>) -> Result<(), GameError>;
@@ -39,6 +52,28 @@ pub trait Consumable: std::fmt::Debug + Clone {
     fn consumable_type(&self) -> ConsumableType;
 }
 
+/// Number of cards a consumable is used on, for UIs and agents building a
+/// `UseConsumable` action. Targets are given as card ids of cards in hand.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TargetSpec {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl TargetSpec {
+    /// True if the consumable is used on cards at all
+    pub fn requires_target(&self) -> bool {
+        self.max > 0
+    }
+
+    /// True if `count` targets are allowed
+    pub fn accepts(&self, count: usize) -> bool {
+        (self.min..=self.max).contains(&count)
+    }
+}
+
 /// Type of consumable
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -134,6 +169,28 @@ impl Consumable for Consumables {
     }
 }
 
+impl Consumables {
+    /// Stable position of this consumable within its type, e.g. The Fool is
+    /// Tarot 0 (used by observations)
+    pub fn index(&self) -> usize {
+        let position = match self {
+            Self::Tarot(t) => Tarots::all().iter().position(|x| x == t),
+            Self::Planet(p) => Planets::all().iter().position(|x| x == p),
+            Self::Spectral(s) => Spectrals::all().iter().position(|x| x == s),
+        };
+        return position.expect("every consumable is listed in all()");
+    }
+}
+
+#[cfg(feature = "python")]
+#[pyo3::pymethods]
+impl Consumables {
+    #[pyo3(name = "target_spec")]
+    fn py_target_spec(&self) -> TargetSpec {
+        self.target_spec()
+    }
+}
+
 impl std::fmt::Display for Consumables {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}", self.name())
@@ -203,6 +260,24 @@ mod tests {
         assert_eq!(star.min_targets(), 1);
     }
 
+    #[test]
+    fn test_target_spec() {
+        let star = Consumables::Tarot(Tarots::TheStar).target_spec();
+        assert_eq!(star, TargetSpec { min: 1, max: 3 });
+        assert!(star.requires_target());
+        assert!(!star.accepts(0));
+        assert!(star.accepts(3));
+        assert!(!star.accepts(4));
+
+        let mercury = Consumables::Planet(Planets::Mercury).target_spec();
+        assert!(!mercury.requires_target());
+        assert!(mercury.accepts(0));
+        for spectral in Spectrals::all() {
+            let consumable = Consumables::Spectral(spectral);
+            assert_eq!(consumable.target_spec().requires_target(), consumable.requires_target());
+        }
+    }
+
     #[test]
     fn test_all_tarots_have_descriptions() {
         for tarot in Tarots::all() {
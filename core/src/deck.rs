@@ -1,5 +1,5 @@
 use crate::card::{Card, Suit, Value};
-use rand::{seq::SliceRandom, thread_rng};
+use rand::seq::SliceRandom;
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone)]
@@ -16,18 +16,17 @@ impl Deck {
     pub fn empty() -> Self {
         Self::new()
     }
-    pub(crate) fn draw(&mut self, n: usize) -> Option<Vec<Card>> {
-        if self.cards.len() < n {
-            return None;
-        }
-        return Some(self.cards.drain(0..n).collect());
+    /// Draw up to `n` cards, taking whatever is left if the deck runs short.
+    pub(crate) fn draw_up_to(&mut self, n: usize) -> Vec<Card> {
+        let n = n.min(self.cards.len());
+        return self.cards.drain(0..n).collect();
     }
     pub(crate) fn len(&self) -> usize {
         self.cards.len()
     }
 
     pub(crate) fn shuffle(&mut self) {
-        self.cards.shuffle(&mut thread_rng());
+        self.cards.shuffle(&mut crate::rng::rng_for("deck", "shuffle"));
     }
 
     pub(crate) fn append(&mut self, other: &mut Vec<Card>) {
@@ -42,6 +41,11 @@ impl Deck {
         self.cards.clone()
     }
 
+    /// Card with this id, as it is now
+    pub(crate) fn card_by_id(&self, card_id: usize) -> Option<Card> {
+        return self.cards.iter().find(|c| c.id == card_id).copied();
+    }
+
     /// Remove a specific card from the deck (for destruction, etc.)
     pub(crate) fn remove_card(&mut self, card: Card) {
         if let Some(index) = self.cards.iter().position(|c| c.id == card.id) {
@@ -61,19 +65,30 @@ impl Deck {
         false
     }
 
+    /// Modify every card in the deck
+    pub(crate) fn modify_all<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut Card),
+    {
+        self.cards.iter_mut().for_each(f);
+    }
+
     /// Add a card to the deck (for Tarot/Spectral generation effects)
     pub(crate) fn add_card(&mut self, card: Card) {
         self.cards.push(card);
     }
 
-    // // Loops through cards, assigning index to each equal to index in deck
-    // pub(crate) fn index_cards(&mut self) {
-    //     let mut i = 0;
-    //     for card in &mut self.cards {
-    //         card.index = Some(i);
-    //         i += 1;
-    //     }
-    // }
+    /// Give the cards ids 0, 1, ... in deck order
+    pub(crate) fn number_cards(&mut self) {
+        for (id, card) in self.cards.iter_mut().enumerate() {
+            card.id = id;
+        }
+    }
+
+    /// One past the highest card id in the deck
+    pub(crate) fn next_id(&self) -> usize {
+        return self.cards.iter().map(|c| c.id + 1).max().unwrap_or(0);
+    }
 }
 
 impl Default for Deck {
@@ -1,109 +1,168 @@
+use crate::card::Card;
 use crate::game::Game;
 use crate::hand::MadeHand;
-use crate::joker::{Joker, Jokers};
-use std::sync::{Arc, Mutex};
+use crate::joker::{ability_source, Joker, Jokers};
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct EffectRegistry {
     pub on_play: Vec<Effects>,
+    pub on_play_slots: Vec<usize>,
+    pub on_card_scored: Vec<Effects>,
+    pub on_card_scored_slots: Vec<usize>,
+    pub on_hand_end: Vec<Effects>,
+    pub on_hand_end_slots: Vec<usize>,
     pub on_discard: Vec<Effects>,
+    pub on_discard_slots: Vec<usize>,
     pub on_score: Vec<Effects>,
+    // Joker slot each `on_score` effect was registered for, used to
+    // attribute score breakdown steps
+    pub on_score_slots: Vec<usize>,
     pub on_handrank: Vec<Effects>,
     pub on_round_begin: Vec<Effects>,
     pub on_round_end: Vec<Effects>,
     pub on_blind_select: Vec<Effects>,
     pub on_sell: Vec<Effects>,
     pub on_pack_open: Vec<Effects>,      // For Hallucination joker
+    pub on_pack_skip: Vec<Effects>,      // For Red Card joker
+    pub on_pack_skip_slots: Vec<usize>,
     pub on_shop_end: Vec<Effects>,       // For Perkeo joker
     pub on_boss_blind_trigger: Vec<Effects>, // For Matador joker
+    pub on_would_lose: Vec<Effects>,         // For Mr. Bones joker
+    // Joker slot each `on_would_lose` effect was registered for, recorded
+    // when it saves the run
+    pub on_would_lose_slots: Vec<usize>,
 }
 
 impl EffectRegistry {
     pub fn new() -> Self {
         return Self {
             on_play: Vec::new(),
+            on_play_slots: Vec::new(),
+            on_card_scored: Vec::new(),
+            on_card_scored_slots: Vec::new(),
+            on_hand_end: Vec::new(),
+            on_hand_end_slots: Vec::new(),
             on_discard: Vec::new(),
+            on_discard_slots: Vec::new(),
             on_score: Vec::new(),
+            on_score_slots: Vec::new(),
             on_handrank: Vec::new(),
             on_round_begin: Vec::new(),
             on_round_end: Vec::new(),
             on_blind_select: Vec::new(),
             on_sell: Vec::new(),
             on_pack_open: Vec::new(),
+            on_pack_skip: Vec::new(),
+            on_pack_skip_slots: Vec::new(),
             on_shop_end: Vec::new(),
             on_boss_blind_trigger: Vec::new(),
+            on_would_lose: Vec::new(),
+            on_would_lose_slots: Vec::new(),
         };
     }
+    /// Register the effects of `jokers`, given in slot order. Blueprint and
+    /// Brainstorm register the effects of the joker they copy, see
+    /// `ability_source`; debuffed jokers register nothing.
     pub(crate) fn register_jokers(&mut self, jokers: Vec<Jokers>, game: &Game) {
-        for (i, j) in jokers.iter().enumerate() {
-            // Handle effect copying jokers specially
-            let effects = match j {
-                // Blueprint: Copy effects from joker to the right
-                Jokers::Blueprint(_) => {
-                    if i + 1 < jokers.len() {
-                        jokers[i + 1].effects(game)
-                    } else {
-                        vec![]
-                    }
-                }
-                // Brainstorm: Copy effects from leftmost joker
-                Jokers::Brainstorm(_) => {
-                    if i > 0 {
-                        // Not the leftmost, copy from index 0
-                        jokers[0].effects(game)
-                    } else if jokers.len() > 1 {
-                        // Brainstorm IS leftmost, copy from second joker
-                        jokers[1].effects(game)
-                    } else {
-                        // Only Brainstorm exists
-                        vec![]
-                    }
-                }
-                // All other jokers: get their own effects
-                _ => j.effects(game),
+        for i in 0..jokers.len() {
+            let disabled = |slot: usize| game.joker_stickers(slot).is_debuffed();
+            let effects = match ability_source(&jokers, i, disabled) {
+                Some(source) => jokers[source].effects(game),
+                None => vec![],
             };
 
             // Register the effects
             for e in effects {
                 match e {
-                    Effects::OnPlay(_) => self.on_play.push(e),
-                    Effects::OnDiscard(_) => self.on_discard.push(e),
-                    Effects::OnScore(_) => self.on_score.push(e),
+                    Effects::OnPlay(_) => {
+                        self.on_play.push(e);
+                        self.on_play_slots.push(i);
+                    }
+                    Effects::OnCardScored(_) => {
+                        self.on_card_scored.push(e);
+                        self.on_card_scored_slots.push(i);
+                    }
+                    Effects::OnHandEnd(_) => {
+                        self.on_hand_end.push(e);
+                        self.on_hand_end_slots.push(i);
+                    }
+                    Effects::OnDiscard(_) => {
+                        self.on_discard.push(e);
+                        self.on_discard_slots.push(i);
+                    }
+                    Effects::OnScore(_) => {
+                        self.on_score.push(e);
+                        self.on_score_slots.push(i);
+                    }
                     Effects::OnHandRank(_) => self.on_handrank.push(e),
                     Effects::OnRoundBegin(_) => self.on_round_begin.push(e),
                     Effects::OnRoundEnd(_) => self.on_round_end.push(e),
                     Effects::OnBlindSelect(_) => self.on_blind_select.push(e),
                     Effects::OnSell(_) => self.on_sell.push(e),
                     Effects::OnPackOpen(_) => self.on_pack_open.push(e),
+                    Effects::OnPackSkip(_) => {
+                        self.on_pack_skip.push(e);
+                        self.on_pack_skip_slots.push(i);
+                    }
                     Effects::OnShopEnd(_) => self.on_shop_end.push(e),
                     Effects::OnBossBlindTrigger(_) => self.on_boss_blind_trigger.push(e),
+                    Effects::OnWouldLose(_) => {
+                        self.on_would_lose.push(e);
+                        self.on_would_lose_slots.push(i);
+                    }
                 }
             }
         }
     }
 }
 
+// Callbacks that also get the slot of the joker that registered them
+pub type PlayFn = dyn Fn(&mut Game, MadeHand, usize) + Send + Sync + 'static;
+pub type CardScoredFn = dyn Fn(&mut Game, Card, usize) + Send + Sync + 'static;
+// Also gets the hand's score, before the slot
+pub type HandEndFn = dyn Fn(&mut Game, MadeHand, usize, usize) + Send + Sync + 'static;
+pub type DiscardFn = dyn Fn(&mut Game, MadeHand, usize) + Send + Sync + 'static;
+pub type PackSkipFn = dyn Fn(&mut Game, usize) + Send + Sync + 'static;
+pub type ScoreFn = dyn Fn(&mut Game, MadeHand) + Send + Sync + 'static;
+// Callbacks that only need the game
+pub type GameFn = dyn Fn(&mut Game) + Send + Sync + 'static;
+pub type WouldLoseFn = dyn Fn(&mut Game) -> bool + Send + Sync + 'static;
+
 #[derive(Clone)]
 // signature of these callbacks are more complicated so they
 // can be used by pyo3 as part of python class.
+// OnPlay, OnCardScored, OnHandEnd and OnDiscard also get the slot of the
+// joker that registered them, so scaling jokers can update their own state.
 pub enum Effects {
-    OnPlay(Arc<Mutex<dyn Fn(&mut Game, MadeHand) + Send + 'static>>),
-    OnDiscard(Arc<Mutex<dyn Fn(&mut Game, MadeHand) + Send + 'static>>),
-    OnScore(Arc<Mutex<dyn Fn(&mut Game, MadeHand) + Send + 'static>>),
-    OnHandRank(Arc<Mutex<dyn Fn(&mut Game) + Send + 'static>>),
-    OnRoundBegin(Arc<Mutex<dyn Fn(&mut Game) + Send + 'static>>),
-    OnRoundEnd(Arc<Mutex<dyn Fn(&mut Game) + Send + 'static>>),
-    OnBlindSelect(Arc<Mutex<dyn Fn(&mut Game) + Send + 'static>>),
-    OnSell(Arc<Mutex<dyn Fn(&mut Game) + Send + 'static>>),
-    OnPackOpen(Arc<Mutex<dyn Fn(&mut Game) + Send + 'static>>),      // Hallucination
-    OnShopEnd(Arc<Mutex<dyn Fn(&mut Game) + Send + 'static>>),       // Perkeo
-    OnBossBlindTrigger(Arc<Mutex<dyn Fn(&mut Game) + Send + 'static>>), // Matador
+    // Hand played, before it is scored
+    OnPlay(Arc<PlayFn>),
+    // Each trigger of a scoring card, after the card's own chips and mult
+    OnCardScored(Arc<CardScoredFn>),
+    // Hand scored, with its score
+    OnHandEnd(Arc<HandEndFn>),
+    // Cards discarded, all of them in `MadeHand::all`
+    OnDiscard(Arc<DiscardFn>),
+    OnScore(Arc<ScoreFn>),
+    OnHandRank(Arc<GameFn>),
+    OnRoundBegin(Arc<GameFn>),
+    OnRoundEnd(Arc<GameFn>),
+    OnBlindSelect(Arc<GameFn>),
+    OnSell(Arc<GameFn>),
+    OnPackOpen(Arc<GameFn>),          // Hallucination
+    OnPackSkip(Arc<PackSkipFn>),      // Red Card
+    OnShopEnd(Arc<GameFn>),           // Perkeo
+    OnBossBlindTrigger(Arc<GameFn>),  // Matador
+    // Returns true to cancel the loss of the run
+    OnWouldLose(Arc<WouldLoseFn>),    // Mr. Bones
 }
 
 impl std::fmt::Debug for Effects {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::OnPlay(_) => write!(f, "OnPlay"),
+            Self::OnCardScored(_) => write!(f, "OnCardScored"),
+            Self::OnHandEnd(_) => write!(f, "OnHandEnd"),
             Self::OnDiscard(_) => write!(f, "OnDiscard"),
             Self::OnScore(_) => write!(f, "OnScore"),
             Self::OnHandRank(_) => write!(f, "OnHandRank"),
@@ -112,8 +171,10 @@ impl std::fmt::Debug for Effects {
             Self::OnBlindSelect(_) => write!(f, "OnBlindSelect"),
             Self::OnSell(_) => write!(f, "OnSell"),
             Self::OnPackOpen(_) => write!(f, "OnPackOpen"),
+            Self::OnPackSkip(_) => write!(f, "OnPackSkip"),
             Self::OnShopEnd(_) => write!(f, "OnShopEnd"),
             Self::OnBossBlindTrigger(_) => write!(f, "OnBossBlindTrigger"),
+            Self::OnWouldLose(_) => write!(f, "OnWouldLose"),
         }
     }
 }
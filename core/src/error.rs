@@ -1,5 +1,11 @@
+#[cfg(feature = "python")]
 use pyo3::exceptions::PyException;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
+use crate::action::{Action, ActionKind};
+use crate::summary::RunSummary;
+use enumset::EnumSet;
+use std::fmt;
 use thiserror::Error;
 
 #[derive(Error, Debug, Clone, PartialEq)]
@@ -22,14 +28,17 @@ pub enum GameError {
     InvalidHand(#[from] PlayHandError),
     #[error("Invalid stage")]
     InvalidStage,
-    #[error("Invalid action")]
-    InvalidAction,
+    // `handle_action` fills in the legal actions at the time
+    #[error("{}", .0.as_ref().map_or("Invalid action".to_string(), |r| r.to_string()))]
+    InvalidAction(Option<Box<RejectedAction>>),
     #[error("No blind match")]
     InvalidBlind,
     #[error("No card match")]
     NoCardMatch,
     #[error("No joker match")]
     NoJokerMatch,
+    #[error("No consumable match")]
+    NoConsumableMatch,
     #[error("Invalid move direction")]
     InvalidMoveDirection,
     #[error("No available slot")]
@@ -38,10 +47,79 @@ pub enum GameError {
     InvalidBalance,
     #[error("Invalid move card")]
     InvalidMoveCard,
+    #[error("Invalid move joker")]
+    InvalidMoveJoker,
     #[error("Invalid select card")]
     InvalidSelectCard,
     #[error("Invalid action space")]
     InvalidActionSpace,
+    #[error("Cannot step back past the earliest checkpoint")]
+    InvalidStepBack,
+    #[error("No card with id {0} to target")]
+    MissingTarget(usize),
+    #[error("Card with id {0} targeted more than once")]
+    DuplicateTarget(usize),
+    #[error("Invalid number of targets")]
+    InvalidTargetCount,
+}
+
+// Number of legal actions listed in a rejected action error
+pub const LEGAL_ACTIONS_SHOWN: usize = 8;
+
+/// An action `handle_action` rejected, with what was legal at the time.
+/// `legal_kinds` has the kind of every legal action. Only the first
+/// `LEGAL_ACTIONS_SHOWN` legal actions are kept, `total` counts all of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedAction {
+    pub action: Action,
+    pub legal_kinds: EnumSet<ActionKind>,
+    pub legal: Vec<Action>,
+    pub total: usize,
+}
+
+impl RejectedAction {
+    pub fn new(action: Action, legal: impl Iterator<Item = Action>) -> Self {
+        let mut legal_kinds = EnumSet::new();
+        let mut shown = Vec::new();
+        let mut total = 0;
+        for a in legal {
+            legal_kinds.insert(a.kind());
+            if shown.len() < LEGAL_ACTIONS_SHOWN {
+                shown.push(a);
+            }
+            total += 1;
+        }
+        return Self {
+            action,
+            legal_kinds,
+            legal: shown,
+            total,
+        };
+    }
+}
+
+impl fmt::Display for RejectedAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid action {}, legal kinds: [", self.action)?;
+        for (i, kind) in self.legal_kinds.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", kind)?;
+        }
+        write!(f, "]")?;
+        write!(f, ", legal actions ({}): [", self.total)?;
+        for (i, a) in self.legal.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", a)?;
+        }
+        if self.total > self.legal.len() {
+            write!(f, ", ... {} more", self.total - self.legal.len())?;
+        }
+        write!(f, "]")
+    }
 }
 
 impl std::convert::From<ActionSpaceError> for GameError {
@@ -65,4 +143,102 @@ pub enum ActionSpaceError {
     InvalidActionConversion,
     #[error("Masked action")]
     MaskedAction,
+    #[error("Action space too large for mask")]
+    MaskOverflow,
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ConfigError {
+    #[error("{field} is {value}, must be between {min} and {max}")]
+    OutOfRange {
+        field: &'static str,
+        value: usize,
+        min: usize,
+        max: usize,
+    },
+    #[error("interest_rate is {0}, must be between 0 and 1")]
+    InterestRate(f32),
+}
+
+#[cfg(feature = "python")]
+impl std::convert::From<ConfigError> for PyErr {
+    fn from(err: ConfigError) -> PyErr {
+        PyException::new_err(err.to_string())
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SeedError {
+    #[error("Empty seed")]
+    Empty,
+    #[error("Invalid seed character: {0}")]
+    InvalidChar(char),
+    #[error("Seed out of range")]
+    Overflow,
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+}
+
+#[cfg(feature = "python")]
+impl std::convert::From<SeedError> for PyErr {
+    fn from(err: SeedError) -> PyErr {
+        PyException::new_err(err.to_string())
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SessionError {
+    #[error("No session with id {0}")]
+    NotFound(u64),
+    #[error(transparent)]
+    Game(#[from] GameError),
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+}
+
+#[cfg(feature = "python")]
+impl std::convert::From<SessionError> for PyErr {
+    fn from(err: SessionError) -> PyErr {
+        PyException::new_err(err.to_string())
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SimulationError {
+    #[error("A batch needs at least one policy")]
+    NoPolicies,
+    #[error("Could not start worker threads: {0}")]
+    ThreadPool(String),
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SaveError {
+    #[error("Save format version {0} is newer than supported version {1}")]
+    UnsupportedVersion(u32, u32),
+    #[error("Invalid save: {0}")]
+    Format(String),
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ReplayError {
+    #[error("Replay format version {0} is newer than supported version {1}")]
+    UnsupportedVersion(u32, u32),
+    #[error("Invalid replay: {0}")]
+    Format(String),
+    #[error("Replay ended in a different state than recorded: expected {expected:?}, got {actual:?}")]
+    Diverged {
+        expected: Box<RunSummary>,
+        actual: Box<RunSummary>,
+    },
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+}
+
+#[cfg(feature = "python")]
+impl std::convert::From<SaveError> for PyErr {
+    fn from(err: SaveError) -> PyErr {
+        PyException::new_err(err.to_string())
+    }
 }
@@ -0,0 +1,61 @@
+use crate::card::Card;
+use crate::cashout::CashOutBreakdown;
+use crate::rank::HandRank;
+use crate::stage::Blind;
+
+/// Events emitted by the game as state changes, in the order they happen.
+/// Consumers (observation layers, loggers) can read them with
+/// `Game::events` or take ownership with `Game::drain_events`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// Cards moved from the deck into the hand
+    CardsDrawn(Vec<Card>),
+    /// A draw asked for more cards than the deck had left
+    DeckExhausted { requested: usize, drawn: usize },
+    /// The discard pile was shuffled back into the deck
+    DiscardsReshuffled(usize),
+    /// A scoring phase finished with these running chips and mult
+    Scored {
+        phase: ScorePhase,
+        chips: f64,
+        mult: f64,
+    },
+    /// One trigger of a played card added these chips and mult
+    CardScored { card: Card, chips: f64, mult: f64 },
+    /// The `OnScore` effect of the joker in this slot changed chips or mult
+    /// (slot None for effects not registered from a joker)
+    JokerTriggered {
+        slot: Option<usize>,
+        chips: f64,
+        mult: f64,
+    },
+    /// A hand was played and added this much to the blind's score
+    HandPlayed { rank: HandRank, score: usize },
+    /// A card left the deck for good, destroyed by the named source
+    CardDestroyed { card: Card, by: String },
+    /// The blind's required score was reached with this score
+    BlindDefeated { blind: Blind, score: usize },
+    /// The last hand of a blind fell short and the named joker cancelled
+    /// the loss, the blind counts as beaten
+    RunSaved { by: String },
+    /// A blind was beaten and this reward is waiting to be cashed out
+    CashOut(CashOutBreakdown),
+    /// The shop was rerolled for this much, 0 when it was free
+    ShopRerolled { cost: usize },
+    /// Net money gained by an action, emitted after its other events
+    MoneyGained(usize),
+    /// Net money spent by an action, emitted after its other events
+    MoneySpent(usize),
+}
+
+/// Phases of `Game::calc_score`, in the order they run
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScorePhase {
+    BaseHand,
+    PlayedCards,
+    HeldInHand,
+    Jokers,
+    JokerEditions,
+}
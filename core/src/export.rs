@@ -0,0 +1,119 @@
+use crate::action::Action;
+use crate::error::GameError;
+use crate::game::Game;
+use crate::summary::RunSummary;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// One exported step: the action taken, the run summary before and after
+/// it, and the reward assigned by the caller.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ActionRecord {
+    pub step: usize,
+    pub action: Action,
+    pub legal: bool,
+    pub error: Option<String>,
+    pub reward: f64,
+    pub pre: RunSummary,
+    pub post: RunSummary,
+}
+
+/// Writes one JSON object per line, suitable for offline RL datasets.
+pub struct JsonlExporter<W: Write> {
+    writer: W,
+    step: usize,
+}
+
+impl JsonlExporter<BufWriter<File>> {
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        return Ok(Self::new(BufWriter::new(File::create(path)?)));
+    }
+}
+
+impl<W: Write> JsonlExporter<W> {
+    pub fn new(writer: W) -> Self {
+        return Self { writer, step: 0 };
+    }
+
+    /// Number of records written so far
+    pub fn steps(&self) -> usize {
+        return self.step;
+    }
+
+    pub fn write(&mut self, record: &ActionRecord) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, record)?;
+        self.writer.write_all(b"\n")?;
+        self.step += 1;
+        return Ok(());
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        return self.writer.flush();
+    }
+
+    pub fn into_inner(self) -> W {
+        return self.writer;
+    }
+}
+
+impl Game {
+    /// Handle an action and export it. `reward` computes the step reward from
+    /// the summaries before and after the action.
+    pub fn handle_action_exported<W: Write>(
+        &mut self,
+        action: Action,
+        exporter: &mut JsonlExporter<W>,
+        reward: impl Fn(&RunSummary, &RunSummary) -> f64,
+    ) -> io::Result<Result<(), GameError>> {
+        let pre = self.run_summary();
+        let res = self.handle_action(action.clone());
+        let post = self.run_summary();
+        let record = ActionRecord {
+            step: exporter.steps(),
+            action,
+            legal: res.is_ok(),
+            error: res.as_ref().err().map(|e| e.to_string()),
+            reward: reward(&pre, &post),
+            pre,
+            post,
+        };
+        exporter.write(&record)?;
+        return Ok(res);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stage::Blind;
+
+    #[test]
+    fn test_export_jsonl() {
+        let mut g = Game::default();
+        g.start();
+        let mut exporter = JsonlExporter::new(Vec::new());
+        let score_gain = |pre: &RunSummary, post: &RunSummary| post.score as f64 - pre.score as f64;
+
+        g.handle_action_exported(Action::SelectBlind(Blind::Small), &mut exporter, score_gain)
+            .unwrap()
+            .unwrap();
+        let res = g
+            .handle_action_exported(Action::NextRound(), &mut exporter, score_gain)
+            .unwrap();
+        assert!(res.is_err());
+
+        let out = String::from_utf8(exporter.into_inner()).unwrap();
+        let records: Vec<ActionRecord> = out
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].step, 0);
+        assert!(records[0].legal);
+        assert_eq!(records[0].action, Action::SelectBlind(Blind::Small));
+        assert_eq!(records[0].post.actions, 1);
+        assert!(!records[1].legal);
+        assert!(records[1].error.is_some());
+    }
+}
@@ -1,40 +1,91 @@
-use crate::action::{Action, MoveDirection};
+use crate::action::{Action, MoveDirection, SortMode};
 use crate::ante::Ante;
 use crate::available::Available;
+use crate::booster::{BoosterPack, PackSelection};
 use crate::boss_modifier::BossModifier;
-use crate::card::{Card, Suit, Value};
-use crate::config::Config;
+use crate::boss_rule::BossRule;
+use crate::breakdown::{ScoreSource, ScoreStep};
+use crate::card::{Card, Edition, Suit, Value};
+use crate::cashout::CashOutBreakdown;
+use crate::config::{Config, DeckExhaustion};
 use crate::consumable::Consumables;
 use crate::deck::Deck;
 use crate::effect::{EffectRegistry, Effects};
-use crate::error::GameError;
+use crate::error::{ConfigError, GameError, RejectedAction};
+use crate::event::{Event, ScorePhase};
 use crate::hand::{MadeHand, SelectHand};
-use crate::joker::{Joker, Jokers};
-use crate::rank::{HandRank, Level};
+use crate::history::ActionHistory;
+use crate::provenance::{CardLedger, CardOrigin};
+use crate::joker::{ability_source, Joker, Jokers};
+use crate::rank::{HandLevelInfo, HandRank, Level};
+use crate::rng::{GameRng, RngScope};
 use crate::shop::Shop;
+use crate::snapshot::Checkpoints;
+use crate::stats::GameStats;
 use crate::stage::{Blind, End, Stage};
+use crate::sticker::Stickers;
 use crate::tag::{Tag, TagPack};
+use crate::voucher::Vouchers;
 
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 
+// Most events kept between drains, the oldest are dropped past this
+const MAX_PENDING_EVENTS: usize = 4096;
+
 /// Per-round state that resets at the start of each blind
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Default)]
 pub struct RoundState {
-    // Random selections that change each round
+    // Random selections that change each round. The Idol, Ancient Joker
+    // and To Do List roll theirs when the round begins
     pub idol_rank: Option<Value>,
     pub idol_suit: Option<Suit>,
     pub ancient_suit: Option<Suit>,
     pub todo_hand: Option<HandRank>,
     pub mail_rebate_rank: Option<Value>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub castle_suit: Option<Suit>,
 
     // Round tracking
     pub hands_played_this_round: HashSet<HandRank>,
-    pub consecutive_hands_without_faces: usize,
+    // Hands played this round, counted before the hand scores (DNA)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hands_played: usize,
     pub jacks_discarded_this_round: usize,
+    // Final poker hand played this round (for Blue seals)
+    pub last_hand_rank: Option<HandRank>,
+    // Hands and discards The Needle and The Water took when the blind
+    // began, given back if the boss is disabled (Luchador)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub boss_plays_taken: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub boss_discards_taken: usize,
+}
+
+// Outcome of the played cards scoring phase, settled after the jokers
+struct PlayedCards {
+    seal_money: usize,
+    destroyed: Vec<Card>,
+}
+
+// Score of a hand from its chips x mult total, rounded down and saturating
+// instead of overflowing for huge late game totals
+fn hand_score(total: f64) -> usize {
+    return total.floor() as usize;
+}
+
+// Move the item at `from` to `to`, shifting the items in between
+fn move_slot<T>(items: &mut [T], from: usize, to: usize) {
+    if from < to {
+        items[from..=to].rotate_left(1);
+    } else {
+        items[to..=from].rotate_right(1);
+    }
 }
 
 /// Game rule modifiers applied by jokers
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Default)]
 pub struct GameModifiers {
     // Hand detection modifiers
@@ -52,15 +103,49 @@ pub struct GameModifiers {
     pub discard_bonus: i32,             // Merry Andy (+3), Drunkard (+1), etc.
 
     // Economy modifiers
-    pub min_money: i32,                 // Credit Card (-20), allows going into debt
+    pub min_money: i64,                 // Credit Card (-20), allows going into debt
+}
+
+impl GameModifiers {
+    /// Whether `card` counts as a face card, every card does with Pareidolia
+    pub fn is_face(&self, card: &Card) -> bool {
+        return self.all_cards_are_faces || card.is_face();
+    }
+
+    /// Whether `card` counts as `suit`. Wild cards are every suit, Stone
+    /// cards none, and with Smeared Joker Hearts and Diamonds are one suit,
+    /// as are Spades and Clubs.
+    pub fn is_suit(&self, card: &Card, suit: Suit) -> bool {
+        if card.is_stone() {
+            return false;
+        }
+        if card.suit == suit || card.is_wild() {
+            return true;
+        }
+        return self.smeared_suits && card.suit.is_red() == suit.is_red();
+    }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone)]
 pub struct Game {
     pub config: Config,
+    // Config the game was created with, before stakes and vouchers changed
+    // it, so the run can be recreated (see `Game::to_replay`)
+    #[cfg(feature = "serde")]
+    #[serde(default)]
+    pub(crate) start_config: Config,
     pub shop: Shop,
+
+    // Seed the game was created from and the generator all rolls come from
+    pub seed: u64,
+    pub(crate) rng: GameRng,
+    // Id of the next card created in this game, see `new_card_id`
+    pub(crate) next_card_id: usize,
+
     pub deck: Deck,
     pub available: Available,
+    pub(crate) card_ledger: CardLedger,
     pub discarded: Vec<Card>,
     pub destroyed: Vec<Card>,
     pub blind: Option<Blind>,
@@ -68,17 +153,21 @@ pub struct Game {
     pub ante_start: Ante,
     pub ante_end: Ante,
     pub ante_current: Ante,
-    pub action_history: Vec<Action>,
+    pub action_history: ActionHistory,
     pub round: usize,
 
     // jokers and their effects
     pub jokers: Vec<Jokers>,
+    // Edition of each joker by index into `jokers`, missing entries are Base
+    pub joker_editions: Vec<Edition>,
+    pub joker_stickers: Vec<Stickers>,
+    // Rebuilt from the jokers on load, see `Game::from_save`
+    #[cfg_attr(feature = "serde", serde(skip, default = "EffectRegistry::new"))]
     pub effect_registry: EffectRegistry,
 
     // consumables
     pub consumables: Vec<Consumables>,
     pub last_consumable_used: Option<Consumables>,
-    pub unique_planets_used: HashSet<HandRank>, // Track unique Planet cards used (for Satellite joker)
 
     // vouchers
     pub vouchers: Vec<crate::voucher::Vouchers>,
@@ -90,12 +179,16 @@ pub struct Game {
     pub plays: usize,
     pub discards: usize,
     pub reward: usize,
-    pub money: usize,
+    // How `reward` is made up, see `Game::cash_out`
+    pub pending_cash_out: Option<CashOutBreakdown>,
+    // Below 0 only in debt, down to `modifiers.min_money` (Credit Card)
+    pub money: i64,
     pub hand_size: usize, // Number of cards drawn, default 8, modified by Ouija/Ectoplasm
 
-    // for scoring
-    pub chips: usize,
-    pub mult: usize,
+    // for scoring, chips and mult are floats so X mult keeps its fractions
+    // and late game hands do not overflow, the hand total is floored
+    pub chips: f64,
+    pub mult: f64,
     pub score: usize,
 
     // Phase 4B: Category C Boss Modifier State
@@ -122,44 +215,105 @@ pub struct Game {
     // Phase 8: Stateful Joker Support
     pub hand: Vec<Card>,                           // Current cards in player's hand
     pub round_state: RoundState,                   // Per-round state for stateful jokers
-    pub hand_rank_play_counts: HashMap<HandRank, usize>,  // Count of times each hand rank has been played (for Supernova)
 
     // Phase 9: Game Rule Modifiers
     pub modifiers: GameModifiers,                  // Rule changes from jokers (4-card hands, etc.)
+
+    // Boss Blind of the current ante, rolled when the ante starts so it can
+    // be seen before it is played, see `Game::blind_schedule`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub upcoming_boss: Option<BossModifier>,
+
+    // Jokers that cancelled a lost run, in order, see `Game::on_would_lose`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub saved_by: Vec<String>,
+
+    // Run statistics (hands played per rank, rerolls, money...)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stats: GameStats,
+
+    // Events emitted since the last drain (cards drawn, etc.), capped at
+    // MAX_PENDING_EVENTS
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub events: Vec<Event>,
+
+    // Periodic snapshots backing `step_back`, disabled by default
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) checkpoints: Checkpoints,
+
+    // Steps of the hand being scored, only recorded for `calc_score_detailed`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) score_steps: Option<Vec<ScoreStep>>,
+
+    // Probabilistic scoring effects add their expected value instead of
+    // rolling, see `analysis::expected_score`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) expected_rolls: bool,
 }
 
 impl Game {
-    pub fn new(config: Config) -> Self {
+    /// Create a game seeded from entropy. Fails if the config is out of
+    /// range, see `Config::validate`.
+    pub fn new(config: Config) -> Result<Self, ConfigError> {
+        use rand::Rng;
+        let seed = rand::thread_rng().gen_range(0..crate::seed::SEED_STR_SPACE);
+        return Self::with_seed(config, seed);
+    }
+
+    /// Create a game whose random rolls (shuffles, shop, packs, jokers, tags)
+    /// are all determined by `seed`, so the same seed and actions replay the same run.
+    pub fn with_seed(config: Config, seed: u64) -> Result<Self, ConfigError> {
+        config.validate()?;
+        let mut rng = GameRng::new(seed);
+        let scope = RngScope::enter(&mut rng);
+        let mut game = Self::init(config, seed);
+        scope.exit(&mut rng);
+        game.rng = rng;
+        return Ok(game);
+    }
+
+    /// Every RNG draw made by this game so far, labelled by subsystem and purpose
+    #[cfg(feature = "rng-audit")]
+    pub fn rng_log(&self) -> &[crate::rng::RngDraw] {
+        return self.rng.log();
+    }
+
+    #[cfg(feature = "rng-audit")]
+    pub fn clear_rng_log(&mut self) {
+        self.rng.clear_log();
+    }
+
+    // Run `f` with this game's generator installed as the active one. Every
+    // public entry point that rolls goes through here, otherwise draws fall
+    // back to `thread_rng` and a seeded game stops being reproducible.
+    pub(crate) fn with_rng<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let scope = RngScope::enter(&mut self.rng);
+        let out = f(self);
+        scope.exit(&mut self.rng);
+        return out;
+    }
+
+    fn init(mut config: Config, seed: u64) -> Self {
+        #[cfg(feature = "serde")]
+        let start_config = config.clone();
         let ante_start = Ante::try_from(config.ante_start).unwrap_or(Ante::One);
 
         // Initialize all hand levels to their default Level 1 values
         let mut hand_levels = HashMap::new();
-        for hand_rank in [
-            HandRank::HighCard,
-            HandRank::OnePair,
-            HandRank::TwoPair,
-            HandRank::ThreeOfAKind,
-            HandRank::Straight,
-            HandRank::Flush,
-            HandRank::FullHouse,
-            HandRank::FourOfAKind,
-            HandRank::StraightFlush,
-            HandRank::RoyalFlush,
-            HandRank::FiveOfAKind,
-            HandRank::FlushHouse,
-            HandRank::FlushFive,
-        ] {
+        for hand_rank in HandRank::all() {
             hand_levels.insert(hand_rank, hand_rank.level());
         }
 
         // Generate deck based on deck type
-        let deck = if let Some(deck_type) = config.deck_type {
+        let mut deck = if let Some(deck_type) = config.deck_type {
             let mut d = Deck::empty();
             d.extend(deck_type.generate_cards());
             d
         } else {
             Deck::default()
         };
+        deck.number_cards();
+        crate::card::reserve_card_ids(deck.next_id());
 
         // Get starting items from deck type
         let (starting_vouchers, starting_consumables, starting_jokers) = if let Some(deck_type) = config.deck_type {
@@ -172,18 +326,35 @@ impl Game {
             (Vec::new(), Vec::new(), Vec::new())
         };
 
-        Self {
-            shop: Shop::new(),
+        let mut card_ledger = CardLedger::default();
+        for card in deck.cards() {
+            card_ledger.record_origin(card.id, CardOrigin::StartingDeck, config.round_start);
+        }
+
+        let mut shop = Shop::new();
+        shop.joker_gen.set_base_weights(config.joker_rarity_weights);
+        shop.availability = config.availability.clone();
+        shop.stake = config.stake;
+        shop.spectrals_in_shop = config.deck_type.is_some_and(|d| d.allows_spectrals_in_shop());
+        config.discards = config.discards.saturating_sub(config.stake.discard_penalty());
+
+        let mut game = Self {
+            shop,
+            seed,
+            rng: GameRng::new(seed),
+            next_card_id: deck.next_id(),
             deck,
+            card_ledger,
             available: Available::default(),
             discarded: Vec::new(),
             destroyed: Vec::new(),
-            action_history: Vec::new(),
+            action_history: ActionHistory::new(),
             jokers: starting_jokers,
+            joker_editions: Vec::new(),
+            joker_stickers: Vec::new(),
             effect_registry: EffectRegistry::new(),
             consumables: starting_consumables,
             last_consumable_used: None,
-            unique_planets_used: HashSet::new(),
             vouchers: starting_vouchers,
             hand_levels,
             blind: None,
@@ -195,10 +366,11 @@ impl Game {
             plays: config.plays,
             discards: config.discards,
             reward: config.reward_base,
-            money: config.money_start,
+            pending_cash_out: None,
+            money: config.money_start as i64,
             hand_size: config.available, // Use config.available for hand size
-            chips: config.base_chips,
-            mult: config.base_mult,
+            chips: config.base_chips as f64,
+            mult: config.base_mult as f64,
             score: config.base_score,
             played_hand_ranks: HashSet::new(),
             allowed_hand_rank: None,
@@ -215,16 +387,30 @@ impl Game {
             tag_pack_selections_made: 0,
             hand: Vec::new(),
             round_state: RoundState::default(),
-            hand_rank_play_counts: HashMap::new(),
             modifiers: GameModifiers::default(),
+            upcoming_boss: None,
+            saved_by: Vec::new(),
+            stats: GameStats::default(),
+            events: Vec::new(),
+            checkpoints: Checkpoints::default(),
+            score_steps: None,
+            expected_rolls: false,
             config,
-        }
+            #[cfg(feature = "serde")]
+            start_config,
+        };
+        game.apply_starting_vouchers();
+        return game;
     }
 
     pub fn start(&mut self) {
-        // for now just move state to small blind
-        self.stage = Stage::PreBlind();
-        self.deal();
+        self.with_rng(|game| {
+            // for now just move state to small blind
+            game.stage = Stage::PreBlind();
+            game.deal();
+            game.roll_boss();
+            game.roll_skip_tag();
+        })
     }
 
     pub fn result(&self) -> Option<End> {
@@ -265,18 +451,78 @@ impl Game {
 
     // draw from deck to available
     fn draw(&mut self, count: usize) {
-        if let Some(drawn) = self.deck.draw(count) {
-            self.hand.extend(drawn.clone());  // Update hand tracking
-            self.available.extend(drawn);
+        let drawn = self.draw_from_deck(count);
+        self.receive_drawn(drawn);
+    }
+
+    // take up to count cards off the deck. What happens when the deck runs
+    // short is decided by config.deck_exhaustion (Balatro never reshuffles
+    // mid-blind, so by default a short deck just draws what it has).
+    fn draw_from_deck(&mut self, count: usize) -> Vec<Card> {
+        let mut drawn = self.deck.draw_up_to(count);
+        if drawn.len() < count {
+            self.events.push(Event::DeckExhausted {
+                requested: count,
+                drawn: drawn.len(),
+            });
+            if self.config.deck_exhaustion == DeckExhaustion::ReshuffleDiscards
+                && !self.discarded.is_empty()
+            {
+                self.events
+                    .push(Event::DiscardsReshuffled(self.discarded.len()));
+                self.deck.append(&mut self.discarded);
+                self.deck.shuffle();
+                drawn.extend(self.deck.draw_up_to(count - drawn.len()));
+            }
+        }
+        return drawn;
+    }
+
+    // put drawn cards into the hand and announce them
+    fn receive_drawn(&mut self, drawn: Vec<Card>) {
+        if drawn.is_empty() {
+            return;
         }
+        self.hand.extend(drawn.clone()); // Update hand tracking
+        self.available.extend(drawn.clone());
+        self.events.push(Event::CardsDrawn(drawn));
+    }
+
+    /// Number of cards the hand holds when full
+    pub fn hand_size_target(&self) -> usize {
+        let base = self.config.available;
+        if self.modifiers.hand_size_bonus >= 0 {
+            return base + self.modifiers.hand_size_bonus as usize;
+        }
+        return base.saturating_sub(self.modifiers.hand_size_bonus.unsigned_abs() as usize);
+    }
+
+    // draw from deck until the hand is back to full size (after a play or discard)
+    fn refill_hand(&mut self) {
+        let missing = self
+            .hand_size_target()
+            .saturating_sub(self.available.cards().len());
+        if missing > 0 {
+            self.draw(missing);
+        }
+    }
+
+    /// Events emitted since the last drain. Callers that never drain keep
+    /// only the most recent 4096, older events are dropped after each action.
+    pub fn events(&self) -> &[Event] {
+        return &self.events;
+    }
+
+    /// Take all pending events, leaving the queue empty
+    pub fn drain_events(&mut self) -> Vec<Event> {
+        return std::mem::take(&mut self.events);
     }
 
     /// Reset and randomize RoundState at the start of each blind
     fn reset_round_state(&mut self) {
         use rand::seq::SliceRandom;
-        let mut rng = rand::thread_rng();
+        let mut rng = crate::rng::rng_for("round", "per-round joker targets");
 
-        // Randomize idol selections (The Idol joker)
         let all_ranks = vec![
             Value::Two, Value::Three, Value::Four, Value::Five, Value::Six,
             Value::Seven, Value::Eight, Value::Nine, Value::Ten,
@@ -284,32 +530,23 @@ impl Game {
         ];
         let all_suits = vec![Suit::Club, Suit::Diamond, Suit::Heart, Suit::Spade];
 
-        self.round_state.idol_rank = all_ranks.choose(&mut rng).copied();
-        self.round_state.idol_suit = all_suits.choose(&mut rng).copied();
-
-        // Randomize ancient suit (Ancient Joker)
-        self.round_state.ancient_suit = all_suits.choose(&mut rng).copied();
-
-        // Randomize todo hand (To Do List joker)
-        let all_hand_ranks = vec![
-            HandRank::HighCard,
-            HandRank::OnePair,
-            HandRank::TwoPair,
-            HandRank::ThreeOfAKind,
-            HandRank::Straight,
-            HandRank::Flush,
-            HandRank::FullHouse,
-            HandRank::FourOfAKind,
-            HandRank::StraightFlush,
-        ];
-        self.round_state.todo_hand = all_hand_ranks.choose(&mut rng).copied();
+        // The Idol, Ancient Joker and To Do List re-roll these in their
+        // OnRoundBegin hooks, once the hand is dealt
+        self.round_state.idol_rank = None;
+        self.round_state.idol_suit = None;
+        self.round_state.ancient_suit = None;
+        self.round_state.todo_hand = None;
 
         // Randomize mail rebate rank (Mail-In Rebate joker)
         self.round_state.mail_rebate_rank = all_ranks.choose(&mut rng).copied();
 
+        // Randomize castle suit (Castle)
+        self.round_state.castle_suit = all_suits.choose(&mut rng).copied();
+
         // Reset round tracking
         self.round_state.hands_played_this_round.clear();
-        self.round_state.consecutive_hands_without_faces = 0;
+        self.round_state.hands_played = 0;
+        self.round_state.last_hand_rank = None;
     }
 
     /// Update game modifiers based on active jokers
@@ -361,54 +598,26 @@ impl Game {
         // add available back to deck and empty
         self.deck.extend(self.available.cards());
         self.available.empty();
+        // the returned cards are no longer held
+        self.hand.clear();
         self.deck.shuffle();
 
         // The House: first hand dealt with 1 card
-        let base_cards = if let Some(modifier) = self.stage.boss_modifier() {
-            if modifier.first_hand_one_card() && self.first_deal_this_blind {
-                self.first_deal_this_blind = false; // Mark first deal as done
-                1
-            } else {
-                self.config.available
-            }
-        } else {
-            self.config.available
-        };
-
-        // Apply hand size modifiers from jokers
-        let cards_to_draw = if self.modifiers.hand_size_bonus >= 0 {
-            base_cards + self.modifiers.hand_size_bonus as usize
-        } else {
-            base_cards.saturating_sub(self.modifiers.hand_size_bonus.abs() as usize)
-        };
+        let mut cards_to_draw = self.hand_size_target();
+        let boss = self.boss_rule();
+        if let Some(rule) = boss {
+            cards_to_draw = rule.deal_size(self, cards_to_draw);
+        }
 
-        self.draw(cards_to_draw);
-
-        // The Ox: mark leftmost card as face-down
-        if let Some(modifier) = self.stage.boss_modifier() {
-            if modifier.leftmost_face_down() {
-                let cards = self.available.cards();
-                if !cards.is_empty() {
-                    let mut leftmost = cards[0];
-                    leftmost.set_face_down(true);
-                    // Replace the card in available
-                    self.available.modify_card(leftmost.id, |c| c.set_face_down(true));
-                }
-            }
+        let mut drawn = self.draw_from_deck(cards_to_draw);
 
-            // The Wheel: probabilistically mark cards as face-down
-            let probability = modifier.face_down_probability();
-            if probability > 0.0 {
-                use rand::Rng;
-                let mut rng = rand::thread_rng();
-                let cards = self.available.cards();
-                for card in cards {
-                    if rng.gen::<f64>() < probability {
-                        self.available.modify_card(card.id, |c| c.set_face_down(true));
-                    }
-                }
-            }
+        // Face-down cards (The Ox, The Wheel) are flipped before they reach
+        // the hand so drawn events never reveal more than the player would see
+        if let Some(rule) = boss {
+            rule.on_draw(&mut drawn);
         }
+
+        self.receive_drawn(drawn);
     }
 
     pub(crate) fn select_card(&mut self, card: Card) -> Result<(), GameError> {
@@ -418,6 +627,28 @@ impl Game {
         return self.available.select_card(card);
     }
 
+    pub(crate) fn select_card_index(&mut self, index: usize) -> Result<(), GameError> {
+        let card = self
+            .available
+            .card_from_index(index)
+            .ok_or(GameError::NoCardMatch)?;
+        return self.select_card(card);
+    }
+
+    pub(crate) fn toggle_card_index(&mut self, index: usize) -> Result<(), GameError> {
+        if self.available.is_selected_index(index) {
+            self.available.toggle_by_index(index)?;
+            return Ok(());
+        }
+        return self.select_card_index(index);
+    }
+
+    /// Sort the hand, see `Action::SortHand`. Handled as an action so it is
+    /// recorded and replays keep card positions.
+    pub fn sort_hand(&mut self, mode: SortMode) -> Result<(), GameError> {
+        return self.handle_action(Action::SortHand(mode));
+    }
+
     pub(crate) fn move_card(
         &mut self,
         direction: MoveDirection,
@@ -432,22 +663,9 @@ impl Game {
         }
 
         // The Pillar: randomly select cards instead of using player selection
-        if let Some(modifier) = self.stage.boss_modifier() {
-            if modifier.random_card_selection() {
-                use rand::seq::SliceRandom;
-                let selected_count = self.available.selected().len();
-                if selected_count > 0 {
-                    // Clear current selection
-                    self.available.deselect_all();
-                    // Randomly select the same number of cards
-                    let mut rng = rand::thread_rng();
-                    let cards: Vec<Card> = self.available.cards();
-                    let random_cards: Vec<Card> = cards.choose_multiple(&mut rng, selected_count).copied().collect();
-                    for card in random_cards {
-                        self.available.select_card(card)?;
-                    }
-                }
-            }
+        let boss = self.boss_rule();
+        if let Some(rule) = boss {
+            rule.before_play(self)?;
         }
 
         let selected = SelectHand::new(self.available.selected());
@@ -456,67 +674,44 @@ impl Game {
         let context = crate::hand::HandContext {
             modifiers: &self.modifiers,
         };
-        let best = selected.best_hand_with_context(&context)?;
-
-        // The Mouth: check if hand matches the allowed hand type
-        if let Some(modifier) = self.stage.boss_modifier() {
-            if modifier.restricts_to_one_hand_type() {
-                if let Some(allowed_rank) = self.allowed_hand_rank {
-                    if best.rank != allowed_rank {
-                        return Err(GameError::InvalidAction); // Only one hand type allowed
-                    }
-                }
-            }
-        }
+        let mut best = selected.best_hand_with_context(&context)?;
 
-        // The Eye: check if hand type has already been played
-        if let Some(modifier) = self.stage.boss_modifier() {
-            if modifier.prevents_repeats() && self.played_hand_ranks.contains(&best.rank) {
-                return Err(GameError::InvalidAction); // Hand type already played
-            }
+        // The Mouth and The Eye: the boss may forbid this hand type
+        if !boss.is_none_or(|rule| rule.allows_hand(self, best.rank)) {
+            return Err(GameError::InvalidAction(None));
         }
 
-        self.plays -= 1;
         self.hands_played_count += 1; // Track for Handy Tag
 
         // Track hand rank play count (for Supernova joker)
-        *self.hand_rank_play_counts.entry(best.rank).or_insert(0) += 1;
-        // Track hands played this round (for Card Sharp joker)
-        self.round_state.hands_played_this_round.insert(best.rank);
+        *self.stats.hands_played.entry(best.rank).or_insert(0) += 1;
+        self.round_state.hands_played += 1;
+        self.round_state.last_hand_rank = Some(best.rank);
 
-        // Track consecutive hands without face cards (for Ride the Bus joker)
-        let has_face_card = self.available.selected().iter().any(|c| c.is_face());
-        if has_face_card {
-            self.round_state.consecutive_hands_without_faces = 0;
-        } else {
-            self.round_state.consecutive_hands_without_faces += 1;
-        }
+        // Scaling jokers update before the hand scores (Green Joker, Ride
+        // the Bus, Square Joker, Obelisk)
+        self.trigger_play(&mut best);
 
         let score = self.calc_score(best.clone());
+        self.stats.best_hand_score = self.stats.best_hand_score.max(score);
+        self.stats.total_score = self.stats.total_score.saturating_add(score);
+        self.events.push(Event::HandPlayed {
+            rank: best.rank,
+            score,
+        });
+        self.trigger_hand_end(&best, score);
+
+        // The hand is only spent once it has scored, so Acrobat and Dusk see
+        // it as the final hand and Card Sharp only the hands before it
+        self.plays -= 1;
+        self.round_state.hands_played_this_round.insert(best.rank);
 
-        // Trigger stateful joker updates for hand played (Green Joker, Loyalty Card, Obelisk)
-        // Find most-played hand rank for Obelisk
-        let most_played_rank = self.hand_rank_play_counts.iter()
-            .max_by_key(|(_, count)| *count)
-            .map(|(rank, _)| *rank);
-
+        // Count the hand for Loyalty Card, re-registering its effect so the
+        // next hand sees the new counter
         for joker in &mut self.jokers {
-            if let crate::joker::Jokers::GreenJoker(ref mut j) = joker {
-                j.on_hand_played();
-            }
-            if let crate::joker::Jokers::LoyaltyCard(ref mut j) = joker {
+            if let Jokers::LoyaltyCard(j) = joker {
                 j.on_hand_played();
             }
-            if let crate::joker::Jokers::Obelisk(ref mut j) = joker {
-                j.on_hand_played(best.rank, most_played_rank);
-            }
-        }
-
-        // The Eye: track this hand rank
-        if let Some(modifier) = self.stage.boss_modifier() {
-            if modifier.prevents_repeats() {
-                self.played_hand_ranks.insert(best.rank);
-            }
         }
 
         let clear_blind = self.handle_score(score)?;
@@ -530,32 +725,17 @@ impl Game {
             }
         }
 
-        let removed = self.available.remove_selected();
+        self.available.remove_selected();
 
-        // The Hook: discard random cards after play (before drawing)
-        if let Some(modifier) = self.stage.boss_modifier() {
-            let cards_to_discard = modifier.cards_to_discard_after_play();
-            if cards_to_discard > 0 {
-                let discarded_count = self.available.remove_random(cards_to_discard);
-
-                // Trigger OnBossBlindTrigger (e.g., Matador)
-                let effects = self.effect_registry.on_boss_blind_trigger.clone();
-                for effect in effects {
-                    if let crate::effect::Effects::OnBossBlindTrigger(callback) = effect {
-                        let cb = callback.lock().unwrap();
-                        cb(self);
-                    }
-                }
-
-                // Draw to replace both played and discarded cards
-                self.draw(removed + discarded_count);
-            } else {
-                self.draw(removed);
-            }
-        } else {
-            self.draw(removed);
+        // The Eye and The Mouth track the hand type, The Hook discards
+        // random cards (before drawing). Skipped once the blind is over.
+        if let Some(rule) = self.boss_rule() {
+            rule.after_play(self, best.rank);
         }
 
+        // Draw back up to hand size to replace played (and Hook-discarded) cards
+        self.refill_hand();
+
         if clear_blind {
             self.clear_blind();
         }
@@ -571,6 +751,7 @@ impl Game {
         self.discards_used += 1; // Track for Garbage Tag
         self.discards_this_blind += 1; // Track discards this blind for TradingCard
         let selected_cards = self.available.selected();
+        self.stats.cards_discarded += selected_cards.len();
         self.discarded.extend(selected_cards.clone());
 
         // Trigger stateful joker updates for discard used
@@ -587,16 +768,42 @@ impl Game {
             }
         }
 
+        // Purple seals create a Tarot per discarded card (must have room)
+        let purple = selected_cards
+            .iter()
+            .filter(|c| !c.debuffed && c.seal == Some(crate::card::Seal::Purple))
+            .count();
+        for _ in 0..purple {
+            if self.consumables.len() < self.config.consumable_slots {
+                self.create_random_tarot();
+            }
+        }
+
+        // Castle gains chips per discarded card of the round's suit
+        let castle_cards = selected_cards
+            .iter()
+            .filter(|c| !c.debuffed && Some(c.suit) == self.round_state.castle_suit)
+            .count();
+        if castle_cards > 0 {
+            for joker in &mut self.jokers {
+                if let Jokers::Castle(j) = joker {
+                    (0..castle_cards).for_each(|_| j.on_suit_card_discarded());
+                }
+            }
+        }
+
         // Track jacks discarded for Hit the Road joker
         let jacks_discarded = selected_cards.iter().filter(|c| c.value == crate::card::Value::Jack).count();
         self.round_state.jacks_discarded_this_round += jacks_discarded;
 
-        // Mail-In Rebate: Earn $3 for each discarded rank card
-        if let Some(rebate_rank) = self.round_state.mail_rebate_rank {
-            let has_mail_rebate = self.jokers.iter().any(|j| matches!(j, crate::joker::Jokers::MailInRebate(_)));
-            if has_mail_rebate {
-                let matching_cards = selected_cards.iter().filter(|c| c.value == rebate_rank).count();
-                self.money += matching_cards * 3;
+        // OnDiscard effects see every discarded card in `MadeHand::all`
+        if let Ok(discarded_hand) = SelectHand::new(selected_cards.clone()).best_hand() {
+            let effects = self.effect_registry.on_discard.clone();
+            let slots = self.effect_registry.on_discard_slots.clone();
+            for (e, slot) in effects.into_iter().zip(slots) {
+                if let Effects::OnDiscard(f) = e {
+                    f(self, discarded_hand.clone(), slot);
+                }
             }
         }
 
@@ -607,215 +814,418 @@ impl Game {
             }
         }
 
-        let removed = self.available.remove_selected();
-        self.draw(removed);
+        self.available.remove_selected();
+        self.refill_hand();
         return Ok(());
     }
 
-    pub(crate) fn calc_score(&mut self, hand: MadeHand) -> usize {
-        // Get boss modifier if active
-        let boss_modifier = self.stage.boss_modifier();
+    /// Score a made hand with the current jokers, hand levels and boss modifier.
+    ///
+    /// Scoring runs in fixed phases, each emitting `Event::Scored` with the
+    /// running chips and mult when it finishes:
+    /// 1. `BaseHand`: chips and mult of the hand rank at its current level
+    /// 2. `PlayedCards`: each scoring card (enhancement, edition, seal) once
+    ///    per trigger, see `card_trigger_count`, then its X mult (Glass,
+    ///    Polychrome)
+    /// 3. `HeldInHand`: abilities of cards left in hand (Steel X mult)
+    /// 4. `Jokers`: joker `OnScore` effects, then `Joker::on_score` for the
+    ///    same joker, left to right
+    /// 5. `JokerEditions`: editions on jokers
+    ///
+    /// Every step applies to the running chips and mult, so a joker's +mult
+    /// is not multiplied by X mult from cards scored before it. Observatory
+    /// X mult comes last, followed by boss blind penalties and end of hand
+    /// bookkeeping.
+    ///
+    /// `calc_score_detailed` also lists the steps within each phase.
+    pub fn calc_score(&mut self, hand: MadeHand) -> usize {
+        return self.with_rng(|game| hand_score(game.calc_score_unrounded(hand)));
+    }
+
+    // `calc_score` before the score is rounded down
+    pub(crate) fn calc_score_unrounded(&mut self, hand: MadeHand) -> f64 {
+        let boss = self.boss_rule();
 
         // The Serpent: first hand scores 0
-        if boss_modifier.map(|m| m.first_hand_scores_zero()).unwrap_or(false) {
-            if self.hands_played_this_blind == 0 {
-                self.hands_played_this_blind += 1;
-
-                // Trigger OnBossBlindTrigger (e.g., Matador)
-                let effects = self.effect_registry.on_boss_blind_trigger.clone();
-                for effect in effects {
-                    if let crate::effect::Effects::OnBossBlindTrigger(callback) = effect {
-                        let cb = callback.lock().unwrap();
-                        cb(self);
-                    }
-                }
-
-                return 0;
-            }
+        if boss.is_some_and(|rule| rule.voids_hand(self)) {
+            self.hands_played_this_blind += 1;
+            self.trigger_boss_blind_effects();
+            return 0.0;
         }
         self.hands_played_this_blind += 1;
 
-        // compute chips and mult from current hand level (upgradeable by Planet cards)
-        let level = self.get_hand_level(hand.rank);
-        self.chips += level.chips;
-        self.mult += level.mult;
-
-        // Process each scored card (with retriggers)
-        let mut cards_to_destroy = Vec::new();
-        let mut seal_money = 0;
-        let mut cards_played_count = 0;
+        self.score_base_hand(&hand);
+        let played = self.score_played_cards(&hand);
+        self.score_held_in_hand(&hand);
+        self.score_jokers(&hand);
+        self.score_joker_editions();
+
+        // Held Planet cards are evaluated after the jokers
+        let observatory = self.observatory_x_mult(hand.rank);
+        if observatory != 1.0 {
+            self.mult *= observatory;
+            self.record_step(ScoreSource::Observatory, 0.0, 0.0, observatory);
+        }
+        let mut total = self.chips * self.mult;
+        // Plasma Deck: chips and final mult are averaged before multiplying
+        if self.config.deck_type.is_some_and(|d| d.uses_plasma_scoring()) {
+            let balanced = (self.chips + self.mult) / 2.0;
+            total = balanced * balanced;
+        }
 
-        // Use all cards if Splash joker modifier is active, otherwise just scoring cards
-        let cards_to_score = if self.modifiers.all_cards_score {
-            &hand.all
-        } else {
-            &hand.hand.cards()
-        };
+        self.money += played.seal_money as i64;
 
-        for card in cards_to_score.iter() {
-            // Check if card is debuffed by boss modifier
-            let is_debuffed = boss_modifier
-                .map(|m| m.is_card_debuffed(card))
-                .unwrap_or(false);
+        // The Flint halves the score, The Tooth charges per card played and
+        // The Arm lowers the hand's level
+        if let Some(rule) = boss {
+            total = rule.adjust_score(total);
+            rule.after_score(self, &hand);
+        }
 
-            if !is_debuffed {
-                let mut trigger_count = 1;
+        for card in played.destroyed {
+            self.destroy_card_by(card, "Glass");
+        }
 
-                // Red seal retriggers the card
-                if card.has_retrigger() {
-                    trigger_count += 1;
-                }
+        // reset chips and mult
+        self.mult = self.config.base_mult as f64;
+        self.chips = self.config.base_chips as f64;
+        return total;
+    }
+
+    // Add a step to the breakdown when `calc_score_detailed` is recording
+    fn record_step(&mut self, source: ScoreSource, chips: f64, mult: f64, x_mult: f64) {
+        if let Some(steps) = self.score_steps.as_mut() {
+            steps.push(ScoreStep {
+                source,
+                chips,
+                mult,
+                x_mult,
+                chips_total: self.chips,
+                mult_total: self.mult,
+            });
+        }
+    }
 
-                // Jokers can add retrigger bonuses
-                trigger_count += self.get_joker_retrigger_bonus(card, cards_played_count);
+    fn emit_scored(&mut self, phase: ScorePhase) {
+        self.events.push(Event::Scored {
+            phase,
+            chips: self.chips,
+            mult: self.mult,
+        });
+    }
 
-                for _ in 0..trigger_count {
-                    // Add chips from card (includes enhancement and edition bonuses)
-                    self.chips += card.chips();
+    // Phase 1: chips and mult from the hand level (upgradeable by Planet cards)
+    fn score_base_hand(&mut self, hand: &MadeHand) {
+        let level = self.get_hand_level(hand.rank);
+        self.chips += level.chips as f64;
+        self.mult += level.mult as f64;
+        self.record_step(ScoreSource::BaseHand(hand.rank), level.chips as f64, level.mult as f64, 1.0);
+        self.emit_scored(ScorePhase::BaseHand);
+    }
 
-                    // Add mult from card (includes enhancement and edition bonuses)
-                    self.mult += card.mult();
+    // Phase 2: played cards, skipping cards debuffed by the boss modifier.
+    // Splash makes every played card score, not only those in the hand.
+    fn score_played_cards(&mut self, hand: &MadeHand) -> PlayedCards {
+        let cards_to_score: &[Card] = if self.modifiers.all_cards_score {
+            &hand.all
+        } else {
+            hand.hand.as_slice()
+        };
 
-                    // Collect seal money
-                    seal_money += card.seal_money_on_play();
+        let mut played = PlayedCards {
+            seal_money: 0,
+            destroyed: Vec::new(),
+        };
+        for (position, card) in cards_to_score.iter().enumerate() {
+            if !card.debuffed {
+                let trigger_count = self.card_trigger_count(card, position);
+                for _ in 0..trigger_count {
+                    // Chips and mult include enhancement and edition bonuses
+                    let chips = card.chips() as f64;
+                    self.chips += chips;
+                    played.seal_money += card.seal_money_on_play();
+                    // Lucky cards roll again on every trigger
+                    let (lucky_mult, lucky_money) = match self.expected_rolls {
+                        true => (card.expected_lucky_mult(), 0),
+                        false => {
+                            let (mult, money) = card.roll_lucky();
+                            (mult as f64, money)
+                        }
+                    };
+                    let mult = card.mult() as f64 + lucky_mult;
+                    self.mult += mult;
+                    played.seal_money += lucky_money;
+                    self.record_step(ScoreSource::PlayedCard(*card), chips, mult, 1.0);
+                    self.events.push(Event::CardScored { card: *card, chips, mult });
+                    self.trigger_card_scored(*card);
+                }
+                let x_mult = card.mult_multiplier() as f64;
+                if x_mult != 1.0 {
+                    self.mult *= x_mult;
+                    self.record_step(ScoreSource::PlayedCard(*card), 0.0, 0.0, x_mult);
                 }
 
-                // Check for glass card destruction (after all triggers)
+                // Glass cards break after all triggers
                 if card.should_destroy() {
-                    cards_to_destroy.push(*card);
+                    played.destroyed.push(*card);
                 }
             }
-
-            // Count cards played for The Tooth
-            cards_played_count += 1;
         }
-
-        // Apply mult multipliers from enhancements and editions (only non-debuffed cards)
-        let mut total_multiplier = 1.0;
-        for card in cards_to_score.iter() {
-            let is_debuffed = boss_modifier
-                .map(|m| m.is_card_debuffed(card))
-                .unwrap_or(false);
-            if !is_debuffed {
-                total_multiplier *= card.mult_multiplier();
+        self.emit_scored(ScorePhase::PlayedCards);
+        return played;
+    }
+
+    // OnCardScored effects for one trigger of a scoring card, recorded as
+    // steps of the joker that registered them
+    fn trigger_card_scored(&mut self, card: Card) {
+        let mut i = 0;
+        while let Some(e) = self.effect_registry.on_card_scored.get(i).cloned() {
+            if let Effects::OnCardScored(f) = e {
+                let slot = self.effect_registry.on_card_scored_slots[i];
+                let (chips, mult) = (self.chips, self.mult);
+                f(self, card, slot);
+                if self.chips != chips || self.mult != mult {
+                    let slot = Some(slot);
+                    let (chips, mult) = (self.chips - chips, self.mult - mult);
+                    self.record_step(ScoreSource::Joker(slot), chips, mult, 1.0);
+                    self.events.push(Event::JokerTriggered { slot, chips, mult });
+                }
             }
+            i += 1;
         }
+    }
 
-        // Apply effects that modify game.chips and game.mult
-        for e in self.effect_registry.on_score.clone() {
-            match e {
-                Effects::OnScore(f) => f.lock().unwrap()(self, hand.clone()),
-                _ => (),
+    // Phase 3: held in hand abilities, left to right. Steel cards give x1.5
+    // mult on each trigger (red seal, Mime).
+    fn score_held_in_hand(&mut self, _hand: &MadeHand) {
+        let card_x_mult = |card: &Card| -> f64 {
+            if card.debuffed {
+                return 1.0;
             }
+            return (card.held_mult_multiplier() as f64).powi(self.held_trigger_count(card) as i32);
+        };
+        let held: Vec<(Card, f64)> = self
+            .available
+            .iter_not_selected()
+            .map(|card| (card, card_x_mult(&card)))
+            .filter(|(_, x_mult)| *x_mult != 1.0)
+            .collect();
+        for (card, x_mult) in held {
+            self.mult *= x_mult;
+            self.record_step(ScoreSource::HeldCard(card), 0.0, 0.0, x_mult);
         }
+        self.emit_scored(ScorePhase::HeldInHand);
+    }
+
+    // Phase 4: joker effects that modify chips and mult, in joker order.
+    // Each joker's `Joker::on_score` runs right after its `OnScore` effects.
+    // Index rather than clone the registry, the callbacks need `&mut self`.
+    fn score_jokers(&mut self, hand: &MadeHand) {
+        // Next slot whose `Joker::on_score` is still to run
+        let mut next_hook = 0;
+        let mut i = 0;
+        while let Some(e) = self.effect_registry.on_score.get(i).cloned() {
+            let slot = self.effect_registry.on_score_slots.get(i).copied();
+            while slot.is_some_and(|slot| next_hook < slot) {
+                self.score_joker_hook(next_hook, hand);
+                next_hook += 1;
+            }
+            if let Effects::OnScore(f) = e {
+                let (chips, mult) = (self.chips, self.mult);
+                f(self, hand.clone());
+                self.record_joker_step(slot, chips, mult);
+            }
+            i += 1;
+        }
+        while next_hook < self.jokers.len() {
+            self.score_joker_hook(next_hook, hand);
+            next_hook += 1;
+        }
+        self.emit_scored(ScorePhase::Jokers);
+    }
 
-        // Apply multipliers and compute final score
-        let base_score = self.chips * self.mult;
-        let mut score = (base_score as f32 * total_multiplier) as usize;
+    // `Joker::on_score` for the joker in `slot`, or a clone of the joker it
+    // copies (Blueprint, Brainstorm). Debuffed jokers are skipped.
+    fn score_joker_hook(&mut self, slot: usize, hand: &MadeHand) {
+        let disabled = |s: usize| self.joker_stickers(s).is_debuffed();
+        let Some(source) = crate::joker::ability_source(&self.jokers, slot, disabled) else {
+            return;
+        };
+        let (chips, mult) = (self.chips, self.mult);
+        let mut joker = self.jokers[source].clone();
+        joker.on_score(self, hand);
+        // The joker may have been removed while scoring
+        if source == slot && slot < self.jokers.len() {
+            self.jokers[slot] = joker;
+        }
+        self.record_joker_step(Some(slot), chips, mult);
+    }
+
+    // Record a joker's scoring step if it changed chips or mult from
+    // `chips` and `mult`
+    fn record_joker_step(&mut self, slot: Option<usize>, chips: f64, mult: f64) {
+        if self.chips != chips || self.mult != mult {
+            let (chips, mult) = (self.chips - chips, self.mult - mult);
+            self.record_step(ScoreSource::Joker(slot), chips, mult, 1.0);
+            self.events.push(Event::JokerTriggered { slot, chips, mult });
+        }
+    }
 
-        // The Flint: halves chips and mult (halves final score)
-        if boss_modifier.map(|m| m.halves_score()).unwrap_or(false) {
-            score = score / 2;
+    // Phase 5: joker editions in joker order. Foil +50 chips, Holographic
+    // +10 mult, Polychrome x1.5 mult.
+    fn score_joker_editions(&mut self) {
+        for i in 0..self.jokers.len() {
+            if self.joker_stickers(i).is_debuffed() {
+                continue;
+            }
+            let (chips, mult) = (self.chips, self.mult);
+            let edition = self.joker_edition(i);
+            match edition {
+                Edition::Foil => self.chips += 50.0,
+                Edition::Holographic => self.mult += 10.0,
+                Edition::Polychrome => self.mult *= 1.5,
+                Edition::Base | Edition::Negative => continue,
+            }
+            self.record_step(ScoreSource::JokerEdition(i, edition), self.chips - chips, self.mult - mult, 1.0);
         }
+        self.emit_scored(ScorePhase::JokerEditions);
+    }
 
-        // Add seal money
-        self.money += seal_money;
+    /// Whether the boss blind lets the selected cards be played (The Mouth
+    /// and The Eye forbid some hand types)
+    pub(crate) fn selected_hand_allowed(&self) -> bool {
+        let Some(rule) = self.boss_rule() else {
+            return true;
+        };
+        let context = crate::hand::HandContext {
+            modifiers: &self.modifiers,
+        };
+        return match SelectHand::new(self.available.selected()).best_hand_with_context(&context) {
+            Ok(best) => rule.allows_hand(self, best.rank),
+            Err(_) => true,
+        };
+    }
 
-        // The Tooth: lose $1 per card played
-        if let Some(modifier) = boss_modifier {
-            let money_cost = modifier.money_per_card() * cards_played_count;
-            if money_cost > 0 {
-                self.money = self.money.saturating_sub(money_cost);
+    /// Rule enforcing the current boss modifier, if any
+    pub(crate) fn boss_rule(&self) -> Option<&'static dyn BossRule> {
+        return self.stage.boss_modifier().map(|m| m.rule());
+    }
 
-                // Trigger OnBossBlindTrigger (e.g., Matador)
-                let effects = self.effect_registry.on_boss_blind_trigger.clone();
-                for effect in effects {
-                    if let crate::effect::Effects::OnBossBlindTrigger(callback) = effect {
-                        let cb = callback.lock().unwrap();
-                        cb(self);
-                    }
-                }
-            }
+    /// Disable the current Boss Blind (Luchador): its lasting effects are
+    /// undone and it stops applying for the rest of the blind.
+    pub fn disable_boss_blind(&mut self) {
+        if let Some(rule) = self.boss_rule() {
+            rule.on_blind_end(self);
+        }
+        if let Stage::Blind(Blind::Boss, ref mut modifier) = self.stage {
+            *modifier = None;
         }
+    }
 
-        // The Arm: decrease hand level by 1 after play
-        if boss_modifier.map(|m| m.decreases_hand_level()).unwrap_or(false) {
-            if let Some(current_level) = self.hand_levels.get_mut(&hand.rank) {
-                if current_level.level > 1 {
-                    *current_level = current_level.downgrade();
+    // Run OnBossBlindTrigger effects (e.g. Matador)
+    pub(crate) fn trigger_boss_blind_effects(&mut self) {
+        let effects = self.effect_registry.on_boss_blind_trigger.clone();
+        for effect in effects {
+            if let Effects::OnBossBlindTrigger(callback) = effect {
+                callback(self);
+            }
+        }
+    }
 
-                    // Trigger OnBossBlindTrigger (e.g., Matador)
-                    let effects = self.effect_registry.on_boss_blind_trigger.clone();
-                    for effect in effects {
-                        if let crate::effect::Effects::OnBossBlindTrigger(callback) = effect {
-                            let cb = callback.lock().unwrap();
-                            cb(self);
-                        }
-                    }
+    /// Called when the last hand of a blind fell short of the required
+    /// score. `OnWouldLose` effects (Mr. Bones) run in slot order until one
+    /// cancels the loss, the joker it came from is added to `saved_by`.
+    /// Returns whether the run was saved.
+    pub fn on_would_lose(&mut self) -> bool {
+        let effects = self.effect_registry.on_would_lose.clone();
+        let slots = self.effect_registry.on_would_lose_slots.clone();
+        for (effect, slot) in effects.into_iter().zip(slots) {
+            if let Effects::OnWouldLose(callback) = effect {
+                // Named before the callback, the joker may destroy itself
+                let by = self.jokers.get(slot).map_or(String::new(), |j| j.name());
+                if callback(self) {
+                    self.saved_by.push(by.clone());
+                    self.events.push(Event::RunSaved { by });
+                    return true;
                 }
             }
         }
+        return false;
+    }
 
-        // Destroy glass cards
-        for card in cards_to_destroy {
-            self.destroy_card(card);
-        }
+    /// Debuff every card matching `debuffs`, in the deck, hand and discard
+    /// pile. Debuffed cards score nothing and trigger no enhancement,
+    /// edition or seal until `clear_card_debuffs`.
+    pub fn debuff_cards<F>(&mut self, debuffs: F)
+    where
+        F: Fn(&Card) -> bool,
+    {
+        self.modify_all_cards(|card| {
+            if debuffs(card) {
+                card.debuffed = true;
+            }
+        });
+    }
 
-        // reset chips and mult
-        self.mult = self.config.base_mult;
-        self.chips = self.config.base_chips;
-        return score;
+    /// Lift every card debuff, when the blind that set them ends
+    pub fn clear_card_debuffs(&mut self) {
+        self.modify_all_cards(|card| card.debuffed = false);
+    }
+
+    // Apply `f` to every card of the deck wherever it is
+    fn modify_all_cards<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Card),
+    {
+        self.deck.modify_all(&mut f);
+        self.available.modify_all(&mut f);
+        self.discarded.iter_mut().for_each(&mut f);
+        self.hand.iter_mut().for_each(&mut f);
     }
 
     /// Remove a card from the deck permanently (for glass destruction, tarot effects, etc.)
     pub fn destroy_card(&mut self, card: Card) {
-        // Remove from deck if present
-        self.deck.remove_card(card);
-        // Track destroyed cards
-        self.destroyed.push(card);
+        self.destroy_card_by(card, "unknown");
     }
 
     pub fn required_score(&self) -> usize {
-        let base = self.ante_current.base();
-        let required = match self.blind {
-            None => base,
-            Some(Blind::Small) => base,
-            Some(Blind::Big) => (base as f32 * 1.5) as usize,
-            Some(Blind::Boss) => {
+        let blind = self.blind.unwrap_or(Blind::Small);
+        return self.required_score_for(blind, self.stage.boss_modifier());
+    }
+
+    /// Score needed to beat `blind` of the current ante, with `boss` as the
+    /// Boss Blind's modifier
+    pub fn required_score_for(&self, blind: Blind, boss: Option<BossModifier>) -> usize {
+        return self.required_score_in(self.ante_current, blind, boss);
+    }
+
+    /// Score needed to beat `blind` of `ante`, with the run's stake and deck
+    pub fn required_score_in(&self, ante: Ante, blind: Blind, boss: Option<BossModifier>) -> usize {
+        let base = ante.scaled_base(self.config.stake);
+        let required = match blind {
+            Blind::Small => base,
+            Blind::Big => (base as f32 * 1.5) as usize,
+            Blind::Boss => {
                 // Apply boss modifier score multiplier (2.5x for The Wall, 2.0x for others)
-                let multiplier = self.stage.boss_modifier()
-                    .map(|m| m.score_multiplier())
-                    .unwrap_or(2.0);
+                let multiplier = boss.map(|m| m.score_multiplier()).unwrap_or(2.0);
                 (base as f64 * multiplier) as usize
             },
         };
-        return required;
-    }
-
-    fn calc_reward(&mut self, blind: Blind) -> Result<usize, GameError> {
-        let mut interest = (self.money as f32 * self.config.interest_rate).floor() as usize;
-        if interest > self.config.interest_max {
-            interest = self.config.interest_max
-        }
-        let base = blind.reward();
-        let hand_bonus = self.plays * self.config.money_per_hand;
-        let reward = base + interest + hand_bonus;
-        return Ok(reward);
+        // Plasma Deck doubles every blind
+        return required.saturating_mul(self.config.deck_type.map_or(1, |d| d.blind_size_multiplier()));
     }
 
     fn cashout(&mut self) -> Result<(), GameError> {
-        self.money += self.reward;
+        self.money += self.reward as i64;
         self.reward = 0;
+        self.pending_cash_out = None;
         self.stage = Stage::Shop();
 
         // Update shop config based on vouchers and refresh
         self.shop.update_config(&self.vouchers);
-        self.shop.refresh(&self.vouchers);
-
-        // Generate a voucher for the shop if applicable
-        if let Some(voucher) = crate::voucher::Vouchers::random_available(&self.vouchers) {
-            self.shop.voucher = Some(voucher);
-        }
+        self.shop.ante = self.ante_current.number();
+        self.shop.restock_with_jokers(&self.jokers, &self.vouchers);
+        self.shop.restock_voucher(&self.vouchers);
 
         // Process shop tags
         self.process_shop_tags();
@@ -823,60 +1233,162 @@ impl Game {
         return Ok(());
     }
 
-    /// Get actual joker slots including bonuses from Negative editions
-    pub(crate) fn max_joker_slots(&self) -> usize {
-        let slots = self.config.joker_slots;
-        // Negative edition cards grant +1 joker slot each
-        // Note: Jokers don't have editions in current implementation
-        // This is placeholder for when joker editions are added
-        slots
+    /// Joker slots: the config's base, which already counts the deck and
+    /// Antimatter, plus one per Negative joker held
+    pub fn joker_slots(&self) -> usize {
+        // Negative jokers grant +1 joker slot each
+        let negative = (0..self.jokers.len())
+            .filter(|i| self.joker_edition(*i) == Edition::Negative)
+            .count();
+        return self.config.joker_slots + negative;
+    }
+
+    /// Whether a joker of this edition fits in the joker slots, a Negative
+    /// joker brings its own slot
+    pub fn has_joker_room(&self, edition: Edition) -> bool {
+        return edition == Edition::Negative || self.jokers.len() < self.joker_slots();
+    }
+
+    /// Edition of the joker at `index`
+    pub fn joker_edition(&self, index: usize) -> Edition {
+        return self.joker_editions.get(index).copied().unwrap_or(Edition::Base);
+    }
+
+    /// Set the edition of the joker at `index` (for Aura-like effects on
+    /// jokers: Wheel of Fortune, Hex, Ectoplasm)
+    pub fn set_joker_edition(&mut self, index: usize, edition: Edition) {
+        if index >= self.jokers.len() {
+            return;
+        }
+        if self.joker_editions.len() < self.jokers.len() {
+            self.joker_editions.resize(self.jokers.len(), Edition::Base);
+        }
+        self.joker_editions[index] = edition;
+    }
+
+    /// Stickers of the joker at `index`
+    pub fn joker_stickers(&self, index: usize) -> Stickers {
+        return self.joker_stickers.get(index).copied().unwrap_or_default();
+    }
+
+    pub fn set_joker_stickers(&mut self, index: usize, stickers: Stickers) {
+        if index >= self.jokers.len() {
+            return;
+        }
+        if self.joker_stickers.len() < self.jokers.len() {
+            self.joker_stickers.resize(self.jokers.len(), Stickers::default());
+        }
+        self.joker_stickers[index] = stickers;
+    }
+
+    /// Debuff the joker at `index` or lift its debuff, its effects stop
+    /// applying until then
+    pub fn set_joker_debuffed(&mut self, index: usize, debuffed: bool) {
+        let mut stickers = self.joker_stickers(index);
+        stickers.debuffed = debuffed;
+        self.set_joker_stickers(index, stickers);
+        self.refresh_joker_effects();
+    }
+
+    /// Jokers whose effects apply, Perishable jokers out of rounds are debuffed
+    pub fn active_jokers(&self) -> Vec<Jokers> {
+        return self
+            .jokers
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !self.joker_stickers(*i).is_debuffed())
+            .map(|(_, j)| j.clone())
+            .collect();
+    }
+
+    /// Indices of owned jokers that have no edition yet
+    pub(crate) fn base_edition_jokers(&self) -> Vec<usize> {
+        return (0..self.jokers.len())
+            .filter(|i| self.joker_edition(*i) == Edition::Base)
+            .collect();
     }
 
     pub(crate) fn buy_joker(&mut self, joker: Jokers) -> Result<(), GameError> {
         if self.stage != Stage::Shop() {
             return Err(GameError::InvalidStage);
         }
-        if self.jokers.len() >= self.max_joker_slots() {
+        let edition = self.shop.joker_edition(&joker);
+        let stickers = self.shop.joker_stickers(&joker);
+        if !self.has_joker_room(edition) {
             return Err(GameError::NoAvailableSlot);
         }
-        if joker.cost() > self.money {
+        let cost = self.shop.joker_cost(&joker);
+        if !self.can_afford(cost) {
             return Err(GameError::InvalidBalance);
         }
         self.shop.buy_joker(&joker)?;
-        self.money -= joker.cost();
+        self.money -= cost as i64;
+        self.stats.jokers_bought += 1;
         self.jokers.push(joker);
-        self.effect_registry
-            .register_jokers(self.jokers.clone(), &self.clone());
+        self.set_joker_edition(self.jokers.len() - 1, edition);
+        self.set_joker_stickers(self.jokers.len() - 1, stickers);
+        self.refresh_joker_effects();
         self.update_modifiers(); // Apply passive joker modifiers
         return Ok(());
     }
 
-    pub(crate) fn sell_joker(&mut self, joker: Jokers) -> Result<(), GameError> {
-        // Can sell during Shop or Blind stages (Luchador needs to sell during Boss Blind)
-        match self.stage {
-            Stage::Shop() | Stage::Blind(_, _) => {},
-            _ => return Err(GameError::InvalidStage),
-        }
+    // Jokers taken from Buffoon Packs roll stickers like jokers for sale
+    fn add_pack_joker(&mut self, joker: Jokers) {
+        let stickers = self.shop.gen_stickers(&joker);
+        self.jokers.push(joker);
+        self.set_joker_stickers(self.jokers.len() - 1, stickers);
+        self.refresh_joker_effects();
+    }
 
-        // Find and remove the joker
+    // Sell the first joker equal to `joker`, tests name jokers rather than slots
+    #[cfg(test)]
+    pub(crate) fn sell_joker(&mut self, joker: Jokers) -> Result<(), GameError> {
         let index = self.jokers.iter().position(|j| j == &joker)
             .ok_or(GameError::NoJokerMatch)?;
+        return self.sell_joker_at(index);
+    }
+
+    // Jokers and consumables can be sold, and jokers rearranged, during Shop
+    // or Blind stages (Luchador needs to sell during Boss Blind)
+    pub(crate) fn can_manage_items(&self) -> bool {
+        return matches!(self.stage, Stage::Shop() | Stage::Blind(_, _));
+    }
+
+    pub(crate) fn sell_joker_at(&mut self, index: usize) -> Result<(), GameError> {
+        if !self.can_manage_items() {
+            return Err(GameError::InvalidStage);
+        }
+        if index >= self.jokers.len() {
+            return Err(GameError::NoJokerMatch);
+        }
+
+        // Eternal jokers cannot be sold
+        if self.joker_stickers(index).eternal {
+            return Err(GameError::InvalidAction(None));
+        }
         let sold_joker = self.jokers.remove(index);
+        let sold_edition = match index < self.joker_editions.len() {
+            true => self.joker_editions.remove(index),
+            false => Edition::Base,
+        };
+        if index < self.joker_stickers.len() {
+            self.joker_stickers.remove(index);
+        }
 
         // Trigger OnSell effects before adding money
         for effect in &self.effect_registry.on_sell.clone() {
             if let crate::effect::Effects::OnSell(callback) = effect {
-                let func = callback.lock().unwrap();
-                func(self);
+                callback(self);
             }
         }
 
         // Add sell value to money
-        self.money += sold_joker.sell_value();
+        self.money += (sold_joker.sell_value() + sold_edition.extra_cost() / 2) as i64;
+        self.stats.jokers_sold += 1;
+        self.notify_card_sold();
 
         // Re-register jokers after removal
-        self.effect_registry = crate::effect::EffectRegistry::new();
-        self.effect_registry.register_jokers(self.jokers.clone(), &self.clone());
+        self.refresh_joker_effects();
 
         // Update modifiers after selling
         self.update_modifiers();
@@ -884,34 +1396,84 @@ impl Game {
         return Ok(());
     }
 
+    // Remove the joker in slot `index` with its edition and stickers, for
+    // jokers that destroy themselves
+    pub(crate) fn remove_joker_at(&mut self, index: usize) -> Jokers {
+        let joker = self.jokers.remove(index);
+        if index < self.joker_editions.len() {
+            self.joker_editions.remove(index);
+        }
+        if index < self.joker_stickers.len() {
+            self.joker_stickers.remove(index);
+        }
+        self.refresh_joker_effects();
+        self.update_modifiers();
+        return joker;
+    }
+
+    /// Move the joker in slot `from` to slot `to`, shifting the jokers in
+    /// between. Order matters for Blueprint, Brainstorm and Ceremonial Dagger.
+    pub(crate) fn move_joker(&mut self, from: usize, to: usize) -> Result<(), GameError> {
+        if !self.can_manage_items() {
+            return Err(GameError::InvalidStage);
+        }
+        if from >= self.jokers.len() || to >= self.jokers.len() || from == to {
+            return Err(GameError::InvalidMoveJoker);
+        }
+        // Keep the per-slot editions and stickers with their joker
+        let len = self.jokers.len();
+        self.joker_editions.resize(len, Edition::Base);
+        self.joker_stickers.resize(len, Stickers::default());
+        move_slot(&mut self.jokers, from, to);
+        move_slot(&mut self.joker_editions, from, to);
+        move_slot(&mut self.joker_stickers, from, to);
+        self.refresh_joker_effects();
+        return Ok(());
+    }
+
+    // Stock `consumable` in the shop and buy it, tests name consumables
+    // rather than slots
+    #[cfg(test)]
     pub(crate) fn buy_consumable(&mut self, consumable: Consumables) -> Result<(), GameError> {
-        use crate::consumable::Consumable;
+        self.shop.consumables.push(consumable);
+        let slot = self.shop.consumables.len() - 1;
+        let bought = self.buy_consumable_at(slot);
+        if bought.is_err() {
+            self.shop.consumables.pop();
+        }
+        return bought;
+    }
 
+    pub(crate) fn buy_consumable_at(&mut self, slot: usize) -> Result<(), GameError> {
         if self.stage != Stage::Shop() {
             return Err(GameError::InvalidStage);
         }
+        let consumable = self
+            .shop
+            .consumable_from_index(slot)
+            .ok_or(GameError::InvalidAction(None))?;
         if self.consumables.len() >= self.config.consumable_slots {
             return Err(GameError::NoAvailableSlot);
         }
-
-        // Check if Astronomer joker makes this consumable free
-        let has_astronomer = self.jokers.iter().any(|j| matches!(j, Jokers::Astronomer(_)));
-        let is_planet = matches!(consumable, Consumables::Planet(_));
-        let cost = if has_astronomer && is_planet {
-            0 // Planet cards are free with Astronomer
-        } else {
-            consumable.cost()
-        };
-
-        if cost > self.money {
+        let cost = self.consumable_cost(&consumable);
+        if !self.can_afford(cost) {
             return Err(GameError::InvalidBalance);
         }
-        // TODO: shop.buy_consumable when shop has consumables
-        self.money -= cost;
+        self.shop.buy_consumable(slot)?;
+        self.money -= cost as i64;
         self.consumables.push(consumable);
         return Ok(());
     }
 
+    // Shop price of a consumable, Planet cards are free with Astronomer
+    pub(crate) fn consumable_cost(&self, consumable: &Consumables) -> usize {
+        let has_astronomer = self.jokers.iter().any(|j| matches!(j, Jokers::Astronomer(_)));
+        if has_astronomer && matches!(consumable, Consumables::Planet(_)) {
+            return 0;
+        }
+        return self.shop.consumable_price(consumable);
+    }
+
     pub(crate) fn consumable_from_index(&self, i: usize) -> Option<Consumables> {
         if i < self.consumables.len() {
             return Some(self.consumables[i].clone());
@@ -919,57 +1481,246 @@ impl Game {
         None
     }
 
-    pub(crate) fn use_consumable(
+    pub(crate) fn sell_consumable(&mut self, index: usize) -> Result<(), GameError> {
+        use crate::consumable::Consumable;
+
+        if !self.can_manage_items() {
+            return Err(GameError::InvalidStage);
+        }
+        if index >= self.consumables.len() {
+            return Err(GameError::NoConsumableMatch);
+        }
+        let sold = self.consumables.remove(index);
+        self.money += sold.sell_value() as i64;
+        self.notify_card_sold();
+        return Ok(());
+    }
+
+    /// Use a held consumable on these cards, only their ids are used, see
+    /// `use_consumable_on`
+    pub fn use_consumable(
         &mut self,
         consumable: Consumables,
         targets: Option<Vec<Card>>,
+    ) -> Result<(), GameError> {
+        let target_ids = targets.map(|cards| cards.iter().map(|c| c.id).collect());
+        return self.with_rng(|game| game.use_consumable_on(consumable, target_ids));
+    }
+
+    /// Use a held consumable on the cards with these ids. The cards are
+    /// looked up again so the effect sees their current state, see
+    /// `resolve_targets`. The number of targets must fit the consumable's
+    /// `TargetSpec`.
+    pub(crate) fn use_consumable_on(
+        &mut self,
+        consumable: Consumables,
+        target_ids: Option<Vec<usize>>,
     ) -> Result<(), GameError> {
         use crate::consumable::Consumable;
 
         // Check if we have this consumable
         if !self.consumables.contains(&consumable) {
-            return Err(GameError::InvalidAction);
+            return Err(GameError::InvalidAction(None));
         }
 
-        // Validate targets if needed
-        if consumable.requires_target() {
-            if targets.is_none() {
-                return Err(GameError::InvalidAction);
-            }
-            let target_count = targets.as_ref().unwrap().len();
-            if target_count < consumable.min_targets() || target_count > consumable.max_targets()
-            {
-                return Err(GameError::InvalidAction);
-            }
+        let targets = match target_ids {
+            Some(ids) => Some(self.resolve_targets(&ids)?),
+            None => None,
+        };
+        let spec = consumable.target_spec();
+        if spec.requires_target() && !spec.accepts(targets.as_ref().map_or(0, |t| t.len())) {
+            return Err(GameError::InvalidTargetCount);
         }
 
-        // Execute the consumable's effect
-        consumable.use_effect(self, targets)?;
+        self.apply_consumable(&consumable, targets)?;
 
         // Remove from consumables
         if let Some(index) = self.consumables.iter().position(|c| c == &consumable) {
             self.consumables.remove(index);
         }
+        return Ok(());
+    }
+
+    /// Current state of the cards with these ids, from the hand or else the
+    /// deck. Fails with `MissingTarget` for an id in neither and with
+    /// `DuplicateTarget` for an id given twice.
+    pub fn resolve_targets(&self, ids: &[usize]) -> Result<Vec<Card>, GameError> {
+        let mut cards: Vec<Card> = Vec::with_capacity(ids.len());
+        for id in ids {
+            if cards.iter().any(|c| c.id == *id) {
+                return Err(GameError::DuplicateTarget(*id));
+            }
+            let card = self
+                .available
+                .iter_cards()
+                .find(|c| c.id == *id)
+                .or_else(|| self.deck.card_by_id(*id))
+                .ok_or(GameError::MissingTarget(*id))?;
+            cards.push(card);
+        }
+        return Ok(cards);
+    }
+
+    // Run a consumable's effect, whether it was held or used straight from
+    // the shop or a pack
+    fn apply_consumable(
+        &mut self,
+        consumable: &Consumables,
+        targets: Option<Vec<Card>>,
+    ) -> Result<(), GameError> {
+        use crate::consumable::Consumable;
+        consumable.use_effect(self, targets)?;
 
         // Track last used consumable (for The Fool tarot)
-        self.last_consumable_used = Some(consumable);
+        self.last_consumable_used = Some(consumable.clone());
 
+        self.stats.record_consumable(consumable);
+        self.notify_consumable_used(consumable);
         return Ok(());
     }
 
+    /// Buy the consumable in this shop slot and use it right away, it never
+    /// takes a consumable slot. Only consumables without targets can be
+    /// used this way, there are no cards in hand during the shop.
+    pub(crate) fn buy_and_use_consumable(&mut self, slot: usize) -> Result<(), GameError> {
+        use crate::consumable::Consumable;
+        if self.stage != Stage::Shop() {
+            return Err(GameError::InvalidStage);
+        }
+        let consumable = self
+            .shop
+            .consumable_from_index(slot)
+            .ok_or(GameError::InvalidAction(None))?;
+        if consumable.requires_target() {
+            return Err(GameError::InvalidAction(None));
+        }
+        let cost = self.consumable_cost(&consumable);
+        if !self.can_afford(cost) {
+            return Err(GameError::InvalidBalance);
+        }
+        self.shop.buy_consumable(slot)?;
+        self.money -= cost as i64;
+        return self.apply_consumable(&consumable, None);
+    }
+
+    // update usage counters and stateful jokers after a consumable is used
+    // (Constellation, Fortune Teller, Satellite)
+    fn notify_consumable_used(&mut self, consumable: &Consumables) {
+        match consumable {
+            Consumables::Planet(_) => {
+                for joker in &mut self.jokers {
+                    if let Jokers::Constellation(ref mut j) = joker {
+                        j.on_planet_used();
+                    }
+                }
+            }
+            Consumables::Tarot(_) => {
+                for joker in &mut self.jokers {
+                    if let Jokers::FortuneTeller(ref mut j) = joker {
+                        j.on_tarot_used();
+                    }
+                }
+            }
+            Consumables::Spectral(_) => {}
+        }
+    }
+
+    // Campfire gains X mult for every joker or consumable sold
+    fn notify_card_sold(&mut self) {
+        for joker in &mut self.jokers {
+            if let Jokers::Campfire(j) = joker {
+                j.on_card_sold();
+            }
+        }
+    }
+
+    // Hologram gains X mult for every playing card added to the deck
+    pub(crate) fn notify_card_added(&mut self) {
+        for joker in &mut self.jokers {
+            if let Jokers::Hologram(j) = joker {
+                j.on_card_added();
+            }
+        }
+    }
+
+    // Glass Joker gains X mult for every Glass card destroyed
+    pub(crate) fn notify_card_destroyed(&mut self, card: &Card) {
+        if card.enhancement != Some(crate::card::Enhancement::Glass) {
+            return;
+        }
+        for joker in &mut self.jokers {
+            if let Jokers::GlassJoker(j) = joker {
+                j.on_glass_card_destroyed();
+            }
+        }
+    }
+
+    // Madness gains X mult when the Small or Big Blind is selected and
+    // destroys a random other joker that is not Eternal
+    fn trigger_madness(&mut self) {
+        use rand::seq::SliceRandom;
+        let mut rng = crate::rng::rng_for("joker", "madness");
+        let mut slot = 0;
+        while slot < self.jokers.len() {
+            if let Jokers::Madness(ref mut j) = self.jokers[slot] {
+                j.on_blind_selected();
+                let others: Vec<usize> = (0..self.jokers.len())
+                    .filter(|i| *i != slot && !self.joker_stickers(*i).eternal)
+                    .collect();
+                if let Some(&victim) = others.choose(&mut rng) {
+                    self.remove_joker_at(victim);
+                    if victim < slot {
+                        slot -= 1;
+                    }
+                }
+            }
+            slot += 1;
+        }
+    }
+
+    /// Rebuild the effect registry from the current jokers. Needed after
+    /// the lineup changes (a joker added, removed, moved or debuffed), not
+    /// after a joker's state changes: stateful jokers read their state in
+    /// `Joker::on_score`, and callbacks that update it find the joker by slot.
+    pub(crate) fn refresh_joker_effects(&mut self) {
+        // build against a borrow of self rather than a clone of the game
+        let mut registry = EffectRegistry::new();
+        registry.register_jokers(self.jokers.clone(), self);
+        self.effect_registry = registry;
+    }
+
     /// Get the current level for a hand rank
     pub fn get_hand_level(&self, rank: HandRank) -> Level {
         *self.hand_levels.get(&rank).unwrap_or(&rank.level())
     }
 
+    /// Hand level table for every hand rank (run info screen): level,
+    /// chips, mult, times played and the Planet that upgrades it
+    pub fn hand_levels(&self) -> Vec<HandLevelInfo> {
+        use crate::consumable::Consumable;
+        return HandRank::all()
+            .into_iter()
+            .map(|rank| {
+                let level = self.get_hand_level(rank);
+                let planet = crate::planet::Planets::planet_for(rank);
+                HandLevelInfo {
+                    rank,
+                    level: level.level,
+                    chips: level.chips,
+                    mult: level.mult,
+                    times_played: self.stats.times_played(rank),
+                    planet,
+                    planet_name: planet.map(|p| p.name()),
+                }
+            })
+            .collect();
+    }
+
     /// Upgrade a hand rank to the next level
     pub fn upgrade_hand(&mut self, rank: HandRank) {
         let current = self.get_hand_level(rank);
-        let upgraded = current.upgrade();
-        self.hand_levels.insert(rank, upgraded);
-
-        // Track unique Planet cards used (for Satellite joker)
-        self.unique_planets_used.insert(rank);
+        let (chips, mult) = self.config.hand_scaling.get(rank);
+        self.hand_levels.insert(rank, current.upgrade(chips, mult));
     }
 
     /// Helper method for testing - calculates score without side effects
@@ -980,39 +1731,114 @@ impl Game {
         self.calc_score(hand)
     }
 
-    /// Modify a card in the deck by ID (for Tarot effects)
+    /// Modify a card by ID (for Tarot effects), see `modify_card_everywhere`
     pub fn modify_card_in_deck<F>(&mut self, card_id: usize, f: F)
     where
         F: FnOnce(&mut Card),
     {
-        // Check where the card is located
-        let in_deck = self.deck.cards().iter().any(|c| c.id == card_id);
-        if in_deck {
-            self.deck.modify_card(card_id, f);
-            return;
-        }
+        self.modify_card_everywhere(card_id, f);
+    }
 
-        let in_available = self.available.cards().iter().any(|c| c.id == card_id);
-        if in_available {
-            self.available.modify_card(card_id, f);
-            return;
+    /// Modify a card by ID in whichever zone holds it (deck, available or
+    /// discarded), then sync the hand tracking copy so zones stay consistent.
+    /// Returns false if no card has this id.
+    pub fn modify_card_everywhere<F>(&mut self, card_id: usize, f: F) -> bool
+    where
+        F: FnOnce(&mut Card),
+    {
+        let mut updated: Option<Card> = None;
+        let apply = |card: &mut Card| {
+            f(card);
+            updated = Some(*card);
+        };
+
+        if self.deck.cards().iter().any(|c| c.id == card_id) {
+            self.deck.modify_card(card_id, apply);
+        } else if self.available.cards().iter().any(|c| c.id == card_id) {
+            self.available.modify_card(card_id, apply);
+        } else if let Some(card) = self.discarded.iter_mut().find(|c| c.id == card_id) {
+            apply(card);
         }
 
-        // Check discarded
-        if let Some(card) = self.discarded.iter_mut().find(|c| c.id == card_id) {
-            f(card);
-            return;
+        let Some(updated) = updated else {
+            return false;
+        };
+        for card in self.hand.iter_mut().filter(|c| c.id == card_id) {
+            *card = updated;
         }
+        return true;
     }
 
     /// Add a new card to the deck (for Tarot/Spectral generation effects)
     pub fn add_card_to_deck(&mut self, card: Card) {
-        self.deck.add_card(card);
+        self.add_card_with_origin(card, CardOrigin::Added);
+    }
+
+    /// Allocate an id not used by any card in this game. Every path that
+    /// creates or copies cards during a run goes through here. Ids count up
+    /// from the starting deck's, so replays hand out the same ids.
+    pub fn new_card_id(&mut self) -> usize {
+        let id = self.next_card_id;
+        self.next_card_id += 1;
+        crate::card::reserve_card_ids(self.next_card_id);
+        return id;
+    }
+
+    /// Create a card with a fresh id
+    pub fn new_card(&mut self, value: Value, suit: Suit) -> Card {
+        let mut card = Card::new(value, suit);
+        card.id = self.new_card_id();
+        return card;
+    }
+
+    /// Copy a card (edition, enhancement, seal included) under a fresh id
+    pub fn copy_card(&mut self, card: &Card) -> Card {
+        let mut copy = *card;
+        copy.id = self.new_card_id();
+        return copy;
+    }
+
+    /// Add a copy of a card to the deck and draw it straight into the hand
+    /// (for DNA). Returns the copy.
+    pub fn copy_card_to_hand(&mut self, card: &Card, by: &str) -> Card {
+        let copy = self.copy_card(card);
+        let origin = CardOrigin::Copied {
+            from: card.id,
+            by: by.to_string(),
+        };
+        self.add_card_with_origin(copy, origin);
+        self.deck.remove_card(copy);
+        self.receive_drawn(vec![copy]);
+        return copy;
+    }
+
+    /// Whether `cost` can be paid without going below the money floor, $0
+    /// or -$20 with Credit Card
+    pub fn can_afford(&self, cost: usize) -> bool {
+        return self.money - cost as i64 >= self.modifiers.min_money;
+    }
+
+    /// Money that can be spent before reaching the floor
+    pub fn spendable_money(&self) -> usize {
+        return (self.money - self.modifiers.min_money).max(0) as usize;
+    }
+
+    /// Money held, 0 when in debt. Jokers and interest that scale with
+    /// money use this.
+    pub fn money_held(&self) -> usize {
+        return self.money.max(0) as usize;
+    }
+
+    /// Take money that is owed rather than spent (rent, The Tooth), stopping
+    /// at the money floor
+    pub fn lose_money(&mut self, amount: usize) {
+        let floor = self.modifiers.min_money.min(self.money);
+        self.money = (self.money - amount as i64).max(floor);
     }
 
     /// Add money with a cap (for The Hermit, etc.)
     pub fn add_money_capped(&mut self, amount: usize, cap: usize) {
-        self.money = (self.money + amount).min(cap);
+        self.money = (self.money + amount as i64).min(cap as i64);
     }
 
     /// Get total sell value of all jokers (for Temperance tarot)
@@ -1026,7 +1852,7 @@ impl Game {
         use rand::seq::SliceRandom;
 
         let all_planets = Planets::all();
-        let planet = all_planets.choose(&mut rand::thread_rng()).unwrap();
+        let planet = all_planets.choose(&mut crate::rng::rng_for("game", "random planet")).unwrap();
         Consumables::Planet(*planet)
     }
 
@@ -1036,17 +1862,14 @@ impl Game {
         use rand::seq::SliceRandom;
 
         let all_tarots = Tarots::all();
-        let tarot = all_tarots.choose(&mut rand::thread_rng()).unwrap();
+        let tarot = all_tarots.choose(&mut crate::rng::rng_for("game", "random tarot")).unwrap();
         Consumables::Tarot(*tarot)
     }
 
-    /// Generate a random joker (for Judgement tarot, Wraith/Soul spectrals)
+    /// Generate a random joker (for Judgement tarot)
+    /// Rarity is rolled with the shop's joker rarity weights
     pub fn generate_random_joker(&self) -> Jokers {
-        use crate::joker::Jokers;
-        use rand::seq::SliceRandom;
-
-        let all_jokers = Jokers::all_common(); // For now, just common
-        all_jokers.choose(&mut rand::thread_rng()).unwrap().clone()
+        self.shop.joker_gen.gen_joker()
     }
 
     // ==================== Phase 3C: Spectral Infrastructure ====================
@@ -1055,7 +1878,7 @@ impl Game {
     pub fn get_random_card_from_deck(&self) -> Option<Card> {
         use rand::seq::SliceRandom;
         let cards = self.deck.cards();
-        cards.choose(&mut rand::thread_rng()).copied()
+        cards.choose(&mut crate::rng::rng_for("game", "random deck card")).copied()
     }
 
     /// Get multiple random cards from the deck
@@ -1063,104 +1886,92 @@ impl Game {
         use rand::seq::SliceRandom;
         let cards = self.deck.cards();
         let actual_count = count.min(cards.len());
-        cards.choose_multiple(&mut rand::thread_rng(), actual_count).copied().collect()
+        cards.choose_multiple(&mut crate::rng::rng_for("game", "random deck cards"), actual_count).copied().collect()
     }
 
     /// Create an enhanced face card (J, Q, or K with random enhancement)
-    pub fn create_enhanced_face_card(&self) -> Card {
-        use crate::card::{Card, Enhancement, Suit, Value};
-        use rand::seq::SliceRandom;
+    pub fn create_enhanced_face_card(&mut self) -> Card {
+        return self.with_rng(|game| {
+            use crate::card::{Enhancement, Suit, Value};
+            use rand::seq::SliceRandom;
 
-        let faces = vec![Value::Jack, Value::Queen, Value::King];
-        let suits = vec![Suit::Heart, Suit::Diamond, Suit::Club, Suit::Spade];
-        let enhancements = vec![
-            Enhancement::Bonus, Enhancement::Mult, Enhancement::Wild,
-            Enhancement::Glass, Enhancement::Steel, Enhancement::Stone,
-            Enhancement::Gold, Enhancement::Lucky
-        ];
+            let faces = vec![Value::Jack, Value::Queen, Value::King];
+            let suits = vec![Suit::Heart, Suit::Diamond, Suit::Club, Suit::Spade];
+            let enhancements = vec![
+                Enhancement::Bonus, Enhancement::Mult, Enhancement::Wild,
+                Enhancement::Glass, Enhancement::Steel, Enhancement::Stone,
+                Enhancement::Gold, Enhancement::Lucky
+            ];
 
-        let value = *faces.choose(&mut rand::thread_rng()).unwrap();
-        let suit = *suits.choose(&mut rand::thread_rng()).unwrap();
-        let enhancement = *enhancements.choose(&mut rand::thread_rng()).unwrap();
+            let value = *faces.choose(&mut crate::rng::rng_for("game", "enhanced face value")).unwrap();
+            let suit = *suits.choose(&mut crate::rng::rng_for("game", "enhanced face suit")).unwrap();
+            let enhancement = *enhancements.choose(&mut crate::rng::rng_for("game", "enhanced face enhancement")).unwrap();
 
-        let mut card = Card::new(value, suit);
-        card.set_enhancement(enhancement);
-        card
+            let mut card = game.new_card(value, suit);
+            card.set_enhancement(enhancement);
+            card
+        });
     }
 
     /// Create an enhanced Ace with random enhancement
-    pub fn create_enhanced_ace(&self) -> Card {
-        use crate::card::{Card, Enhancement, Suit, Value};
-        use rand::seq::SliceRandom;
+    pub fn create_enhanced_ace(&mut self) -> Card {
+        return self.with_rng(|game| {
+            use crate::card::{Enhancement, Suit, Value};
+            use rand::seq::SliceRandom;
 
-        let suits = vec![Suit::Heart, Suit::Diamond, Suit::Club, Suit::Spade];
-        let enhancements = vec![
-            Enhancement::Bonus, Enhancement::Mult, Enhancement::Wild,
-            Enhancement::Glass, Enhancement::Steel, Enhancement::Stone,
-            Enhancement::Gold, Enhancement::Lucky
-        ];
+            let suits = vec![Suit::Heart, Suit::Diamond, Suit::Club, Suit::Spade];
+            let enhancements = vec![
+                Enhancement::Bonus, Enhancement::Mult, Enhancement::Wild,
+                Enhancement::Glass, Enhancement::Steel, Enhancement::Stone,
+                Enhancement::Gold, Enhancement::Lucky
+            ];
 
-        let suit = *suits.choose(&mut rand::thread_rng()).unwrap();
-        let enhancement = *enhancements.choose(&mut rand::thread_rng()).unwrap();
+            let suit = *suits.choose(&mut crate::rng::rng_for("game", "enhanced ace suit")).unwrap();
+            let enhancement = *enhancements.choose(&mut crate::rng::rng_for("game", "enhanced ace enhancement")).unwrap();
 
-        let mut card = Card::new(Value::Ace, suit);
-        card.set_enhancement(enhancement);
-        card
+            let mut card = game.new_card(Value::Ace, suit);
+            card.set_enhancement(enhancement);
+            card
+        });
     }
 
     /// Create an enhanced number card (2-10 with random enhancement)
-    pub fn create_enhanced_number(&self) -> Card {
-        use crate::card::{Card, Enhancement, Suit, Value};
-        use rand::seq::SliceRandom;
-
-        let numbers = vec![
-            Value::Two, Value::Three, Value::Four, Value::Five, Value::Six,
-            Value::Seven, Value::Eight, Value::Nine, Value::Ten
-        ];
-        let suits = vec![Suit::Heart, Suit::Diamond, Suit::Club, Suit::Spade];
-        let enhancements = vec![
-            Enhancement::Bonus, Enhancement::Mult, Enhancement::Wild,
-            Enhancement::Glass, Enhancement::Steel, Enhancement::Stone,
-            Enhancement::Gold, Enhancement::Lucky
-        ];
-
-        let value = *numbers.choose(&mut rand::thread_rng()).unwrap();
-        let suit = *suits.choose(&mut rand::thread_rng()).unwrap();
-        let enhancement = *enhancements.choose(&mut rand::thread_rng()).unwrap();
-
-        let mut card = Card::new(value, suit);
-        card.set_enhancement(enhancement);
-        card
+    pub fn create_enhanced_number(&mut self) -> Card {
+        return self.with_rng(|game| {
+            use crate::card::{Enhancement, Suit, Value};
+            use rand::seq::SliceRandom;
+
+            let numbers = vec![
+                Value::Two, Value::Three, Value::Four, Value::Five, Value::Six,
+                Value::Seven, Value::Eight, Value::Nine, Value::Ten
+            ];
+            let suits = vec![Suit::Heart, Suit::Diamond, Suit::Club, Suit::Spade];
+            let enhancements = vec![
+                Enhancement::Bonus, Enhancement::Mult, Enhancement::Wild,
+                Enhancement::Glass, Enhancement::Steel, Enhancement::Stone,
+                Enhancement::Gold, Enhancement::Lucky
+            ];
+
+            let value = *numbers.choose(&mut crate::rng::rng_for("game", "enhanced number value")).unwrap();
+            let suit = *suits.choose(&mut crate::rng::rng_for("game", "enhanced number suit")).unwrap();
+            let enhancement = *enhancements.choose(&mut crate::rng::rng_for("game", "enhanced number enhancement")).unwrap();
+
+            let mut card = game.new_card(value, suit);
+            card.set_enhancement(enhancement);
+            card
+        });
     }
 
     /// Generate a rare joker (for Wraith spectral)
-    /// Falls back to common joker if no rare jokers exist
     pub fn generate_rare_joker(&self) -> Jokers {
-        use crate::joker::{Jokers, Rarity};
-        use rand::seq::SliceRandom;
-
-        let rare_jokers = Jokers::by_rarity(Rarity::Rare);
-        if !rare_jokers.is_empty() {
-            rare_jokers.choose(&mut rand::thread_rng()).unwrap().clone()
-        } else {
-            // Fallback to common joker if no rare jokers exist
-            self.generate_random_joker()
-        }
+        use crate::joker::Rarity;
+        self.shop.joker_gen.gen_joker_with_rarity(Rarity::Rare)
     }
 
     /// Generate a legendary joker (for The Soul spectral)
-    /// Falls back to rare or common joker if no legendary jokers exist
     pub fn generate_legendary_joker(&self) -> Jokers {
-        use crate::joker::{Jokers, Rarity};
-        use rand::seq::SliceRandom;
-
-        let legendary_jokers = Jokers::by_rarity(Rarity::Legendary);
-        if !legendary_jokers.is_empty() {
-            legendary_jokers.choose(&mut rand::thread_rng()).unwrap().clone()
-        } else {
-            // Fallback to rare or common if no legendary jokers exist
-            self.generate_rare_joker()
-        }
+        use crate::joker::Rarity;
+        self.shop.joker_gen.gen_joker_with_rarity(Rarity::Legendary)
     }
 
     /// Copy a joker (for Ankh spectral)
@@ -1168,13 +1979,19 @@ impl Game {
         joker.clone()
     }
 
-    /// Destroy all jokers except the one at the specified index
-    pub fn destroy_all_jokers_except(&mut self, keep_idx: usize) {
-        if keep_idx < self.jokers.len() {
-            let kept_joker = self.jokers[keep_idx].clone();
-            self.jokers.clear();
-            self.jokers.push(kept_joker);
+    /// Destroy all jokers except the one at the specified index. Eternal
+    /// jokers are never destroyed. Returns where the kept joker ends up.
+    pub fn destroy_all_jokers_except(&mut self, keep_idx: usize) -> Option<usize> {
+        if keep_idx >= self.jokers.len() {
+            return None;
         }
+        let kept: Vec<usize> = (0..self.jokers.len())
+            .filter(|i| *i == keep_idx || self.joker_stickers(*i).eternal)
+            .collect();
+        self.joker_editions = kept.iter().map(|i| self.joker_edition(*i)).collect();
+        self.joker_stickers = kept.iter().map(|i| self.joker_stickers(*i)).collect();
+        self.jokers = kept.iter().map(|i| self.jokers[*i].clone()).collect();
+        return kept.iter().position(|i| *i == keep_idx);
     }
 
     /// Convert all cards in deck to the specified suit
@@ -1211,18 +2028,7 @@ impl Game {
     pub fn select_random_tag(&self) -> Tag {
         use rand::seq::SliceRandom;
 
-        // Convert Ante to usize for tag eligibility check
-        let ante_num = match self.ante_current {
-            Ante::Zero => 0,
-            Ante::One => 1,
-            Ante::Two => 2,
-            Ante::Three => 3,
-            Ante::Four => 4,
-            Ante::Five => 5,
-            Ante::Six => 6,
-            Ante::Seven => 7,
-            Ante::Eight => 8,
-        };
+        let ante_num = self.ante_current.number();
 
         let eligible_tags: Vec<Tag> = Tag::ALL
             .iter()
@@ -1231,30 +2037,31 @@ impl Game {
             .collect();
 
         *eligible_tags
-            .choose(&mut rand::thread_rng())
+            .choose(&mut crate::rng::rng_for("tag", "skip tag"))
             .unwrap_or(&Tag::Economy) // Fallback to Economy if no eligible tags
     }
 
     /// Add a tag to the queue, processing Double Tags
     pub fn add_tag(&mut self, tag: Tag) {
+        // Double Tags are never copied and wait for the next other tag
+        if tag == Tag::Double {
+            self.tags.push(tag);
+            return;
+        }
+
         // Count Double Tags in queue
         let double_count = self.tags.iter().filter(|t| **t == Tag::Double).count();
 
         // Remove all Double Tags
         self.tags.retain(|t| *t != Tag::Double);
 
-        // Add the original tag
-        self.tags.push(tag);
-
-        // Add copies from Double Tags (if tag is not Double itself)
-        if tag != Tag::Double {
-            for _ in 0..double_count {
-                self.tags.push(tag);
-            }
+        // Add the original tag plus a copy per Double Tag
+        for _ in 0..=double_count {
+            self.tags.push(tag);
         }
 
         // Process immediate tags
-        self.process_immediate_tags();
+        self.with_rng(|game| game.process_immediate_tags());
     }
 
     /// Process tags that trigger immediately
@@ -1288,25 +2095,21 @@ impl Game {
         match tag {
             // Immediate tags are processed here
             Tag::Economy => {
-                // Double money (max +$40)
-                if self.money < 40 {
-                    self.money = self.money * 2;
-                } else {
-                    self.money += 40;
-                }
+                // Double money (max +$40), nothing while in debt
+                self.money += self.money.clamp(0, 40);
             }
             Tag::Speed => {
                 // $5 per blind skipped (including this one)
-                self.money += self.blinds_skipped_count * 5;
+                self.money += (self.blinds_skipped_count * 5) as i64;
             }
             Tag::Handy => {
                 // $1 per hand played this run
-                self.money += self.hands_played_count;
+                self.money += self.hands_played_count as i64;
             }
             Tag::Garbage => {
                 // $1 per unused discard this run
                 let unused = self.discards_total.saturating_sub(self.discards_used);
-                self.money += unused;
+                self.money += unused as i64;
             }
             Tag::Orbital => {
                 // Upgrade random poker hand by 3 levels
@@ -1326,22 +2129,24 @@ impl Game {
                     HandRank::FlushHouse,
                     HandRank::FlushFive,
                 ];
-                let rank = *all_ranks.choose(&mut rand::thread_rng()).unwrap();
+                let rank = *all_ranks.choose(&mut crate::rng::rng_for("tag", "orbital hand")).unwrap();
                 for _ in 0..3 {
                     self.upgrade_hand(rank);
                 }
             }
             Tag::TopUp => {
                 // Create up to 2 Common Jokers
-                let slots_available = self.max_joker_slots().saturating_sub(self.jokers.len());
+                let slots_available = self.joker_slots().saturating_sub(self.jokers.len());
                 let to_create = slots_available.min(2);
                 for _ in 0..to_create {
-                    let joker = self.generate_random_joker();
+                    let joker = self
+                        .shop
+                        .joker_gen
+                        .gen_joker_with_rarity(crate::joker::Rarity::Common);
                     self.jokers.push(joker);
                 }
                 // Re-register joker effects
-                self.effect_registry
-                    .register_jokers(self.jokers.clone(), &self.clone());
+                self.refresh_joker_effects();
             }
             // Pack tags: generate pack for selection
             Tag::Charm => {
@@ -1398,30 +2203,31 @@ impl Game {
                 Tag::Uncommon => {
                     // Add a free uncommon joker to shop
                     let uncommon_joker = self.shop.joker_gen.gen_joker_with_rarity(Rarity::Uncommon);
-                    let idx = self.shop.jokers.len();
-                    self.shop.jokers.push(uncommon_joker);
+                    let idx = self.shop.push_joker(uncommon_joker, Edition::Base);
                     self.shop.free_joker_indices.push(idx);
                 }
                 Tag::Rare => {
                     // Add a free rare joker to shop
                     let rare_joker = self.shop.joker_gen.gen_joker_with_rarity(Rarity::Rare);
-                    let idx = self.shop.jokers.len();
-                    self.shop.jokers.push(rare_joker);
+                    let idx = self.shop.push_joker(rare_joker, Edition::Base);
                     self.shop.free_joker_indices.push(idx);
                 }
                 Tag::Foil | Tag::Holographic | Tag::Polychrome | Tag::Negative => {
-                    // In this implementation, jokers don't have editions (only cards do)
-                    // So we'll just add a free random joker to the shop
-                    // This is a simplification from the full Balatro game
+                    // Add a free joker of the tag's edition to the shop
+                    let edition = match tag {
+                        Tag::Foil => Edition::Foil,
+                        Tag::Holographic => Edition::Holographic,
+                        Tag::Polychrome => Edition::Polychrome,
+                        _ => Edition::Negative,
+                    };
                     let joker = self.shop.joker_gen.gen_joker();
-                    let idx = self.shop.jokers.len();
-                    self.shop.jokers.push(joker);
+                    let idx = self.shop.push_joker(joker, edition);
                     self.shop.free_joker_indices.push(idx);
                 }
                 Tag::Voucher => {
                     // Adds a voucher to shop (already has logic in select_blind)
                     if self.shop.voucher.is_none() {
-                        if let Some(voucher) = crate::voucher::Vouchers::random_available(&self.vouchers) {
+                        if let Some(voucher) = self.shop.random_voucher(&self.vouchers) {
                             self.shop.voucher = Some(voucher);
                         }
                     }
@@ -1475,7 +2281,7 @@ impl Game {
 
         // Apply Investment effect: $25 per tag
         if investment_count > 0 {
-            self.money += investment_count * 25;
+            self.money += (investment_count * 25) as i64;
         }
 
         // Remove processed Investment tags
@@ -1487,7 +2293,7 @@ impl Game {
         use crate::effect::Effects;
         for e in self.effect_registry.on_round_begin.clone() {
             match e {
-                Effects::OnRoundBegin(f) => f.lock().unwrap()(self),
+                Effects::OnRoundBegin(f) => f(self),
                 _ => (),
             }
         }
@@ -1498,11 +2304,36 @@ impl Game {
         use crate::effect::Effects;
         for e in self.effect_registry.on_round_end.clone() {
             match e {
-                Effects::OnRoundEnd(f) => f.lock().unwrap()(self),
+                Effects::OnRoundEnd(f) => f(self),
                 _ => (),
             }
         }
 
+        // Blue seals on cards held in hand create the Planet of the final
+        // hand played this round (must have room)
+        let planet = self.round_state.last_hand_rank.and_then(crate::planet::Planets::planet_for);
+        if let Some(planet) = planet {
+            let blue = self
+                .available
+                .iter_not_selected()
+                .filter(|c| !c.debuffed && c.seal == Some(crate::card::Seal::Blue))
+                .count();
+            for _ in 0..blue {
+                if self.consumables.len() < self.config.consumable_slots {
+                    self.consumables.push(Consumables::Planet(planet));
+                }
+            }
+        }
+
+        // Gold cards held in hand pay out
+        let gold: usize = self
+            .available
+            .iter_not_selected()
+            .filter(|c| !c.debuffed)
+            .map(|c| c.held_money_at_round_end())
+            .sum();
+        self.money += gold as i64;
+
         // Check if Gift Card is present
         let has_gift_card = self.jokers.iter().any(|j| matches!(j, crate::joker::Jokers::GiftCard(_)));
 
@@ -1520,18 +2351,69 @@ impl Game {
                 // Add more joker types here as they get sell_value_bonus field
             }
         }
-
-        // Re-register effects after state changes
-        self.effect_registry = crate::effect::EffectRegistry::new();
-        self.effect_registry.register_jokers(self.jokers.clone(), &self.clone());
+
+        // Rental jokers charge their rent, down to the money floor, and
+        // Perishable jokers count down
+        self.joker_stickers.resize(self.jokers.len(), Stickers::default());
+        for i in 0..self.joker_stickers.len() {
+            self.lose_money(self.joker_stickers[i].round_cost());
+            self.joker_stickers[i].end_round();
+        }
+
+        // Perishable jokers that ran out are debuffed, re-register effects
+        self.refresh_joker_effects();
+    }
+
+    /// Trigger OnBlindSelect effects for all jokers
+    // OnPlay effects run before the hand is scored, jokers that scale here
+    // score with their new state. Effects may change the played cards (Midas Mask, Vampire), so each effect and
+    // the scoring that follows see the cards as they are now.
+    fn trigger_play(&mut self, hand: &mut MadeHand) {
+        if self.effect_registry.on_play.is_empty() {
+            return;
+        }
+        let effects = self.effect_registry.on_play.clone();
+        let slots = self.effect_registry.on_play_slots.clone();
+        for (e, slot) in effects.into_iter().zip(slots) {
+            if let Effects::OnPlay(f) = e {
+                self.sync_played_cards(hand);
+                f(self, hand.clone(), slot);
+            }
+        }
+        self.sync_played_cards(hand);
+    }
+
+    // Replace the played hand's card copies with the cards in hand
+    fn sync_played_cards(&self, hand: &mut MadeHand) {
+        let current = |card: &mut Card| {
+            if let Some(i) = self.available.index_of(*card) {
+                *card = self.available.card_from_index(i).unwrap_or(*card);
+            }
+        };
+        hand.all.iter_mut().for_each(current);
+        hand.hand.as_mut_slice().iter_mut().for_each(current);
+    }
+
+    // OnHandEnd effects run once the hand has scored, before the blind is
+    // settled
+    fn trigger_hand_end(&mut self, hand: &MadeHand, score: usize) {
+        if self.effect_registry.on_hand_end.is_empty() {
+            return;
+        }
+        let effects = self.effect_registry.on_hand_end.clone();
+        let slots = self.effect_registry.on_hand_end_slots.clone();
+        for (e, slot) in effects.into_iter().zip(slots) {
+            if let Effects::OnHandEnd(f) = e {
+                f(self, hand.clone(), score, slot);
+            }
+        }
     }
 
-    /// Trigger OnBlindSelect effects for all jokers
     fn trigger_blind_select(&mut self) {
         use crate::effect::Effects;
         for e in self.effect_registry.on_blind_select.clone() {
             match e {
-                Effects::OnBlindSelect(f) => f.lock().unwrap()(self),
+                Effects::OnBlindSelect(f) => f(self),
                 _ => (),
             }
         }
@@ -1553,74 +2435,97 @@ impl Game {
         }
     }
 
-    /// Get additional retrigger count for a card from active jokers
-    fn get_joker_retrigger_bonus(&self, card: &crate::card::Card, card_index: usize) -> usize {
-        use crate::card::Value;
-        use crate::joker::Jokers;
+    /// Number of times a scored card triggers. Sources are applied in a
+    /// fixed order: the card itself, its red seal, then each joker's
+    /// `retriggers` left to right. Blueprint and Brainstorm count the
+    /// retriggers of the joker they copy, as they do for effects.
+    pub fn card_trigger_count(&self, card: &Card, index: usize) -> usize {
+        return self.trigger_count(card, |joker| joker.retriggers(self, card, index));
+    }
 
-        let mut bonus = 0;
+    /// Number of times the ability of a card held in hand triggers (Steel,
+    /// held in hand jokers), from the same sources as `card_trigger_count`
+    /// using each joker's `held_retriggers` (Mime).
+    pub fn held_trigger_count(&self, card: &Card) -> usize {
+        return self.trigger_count(card, |joker| joker.held_retriggers(self, card));
+    }
 
-        for joker in &self.jokers {
-            match joker {
-                // HangingChad: Retrigger first card 2 additional times
-                Jokers::HangingChad(_) => {
-                    if card_index == 0 {
-                        bonus += 2;
-                    }
-                }
-                // Hack: Retrigger 2, 3, 4, or 5
-                Jokers::Hack(_) => {
-                    if matches!(card.value, Value::Two | Value::Three | Value::Four | Value::Five) {
-                        bonus += 1;
-                    }
-                }
-                // SockAndBuskin: Retrigger all face cards
-                Jokers::SockAndBuskin(_) => {
-                    if card.is_face() {
-                        bonus += 1;
-                    }
-                }
-                // Seltzer: Retrigger all cards for 10 hands
-                Jokers::Seltzer(seltzer) => {
-                    if seltzer.hands_remaining > 0 {
-                        bonus += 1;
-                    }
-                }
-                // Dusk: Retrigger all cards on final hand
-                Jokers::Dusk(_) => {
-                    if self.plays == 1 {
-                        bonus += 1;
-                    }
-                }
-                _ => {}
+    fn trigger_count<F>(&self, card: &Card, retriggers: F) -> usize
+    where
+        F: Fn(&Jokers) -> usize,
+    {
+        let mut count = 1;
+        if card.has_retrigger() {
+            count += 1;
+        }
+        for i in 0..self.jokers.len() {
+            let disabled = |slot: usize| self.joker_stickers(slot).is_debuffed();
+            if let Some(source) = ability_source(&self.jokers, i, disabled) {
+                count += retriggers(&self.jokers[source]);
             }
         }
+        return count;
+    }
 
-        bonus
+    /// Triggers of the scored cards matching `matches`, for jokers giving a
+    /// bonus per scored card so retriggered cards count again
+    pub fn scored_triggers<F>(&self, hand: &MadeHand, matches: F) -> usize
+    where
+        F: Fn(&Card) -> bool,
+    {
+        let scored: &[Card] = match self.modifiers.all_cards_score {
+            true => &hand.all,
+            false => hand.hand.as_slice(),
+        };
+        return scored
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| !card.debuffed && matches(card))
+            .map(|(i, card)| self.card_trigger_count(card, i))
+            .sum();
+    }
+
+    /// Triggers of the cards held in hand (not played in `hand`) matching
+    /// `matches`, for jokers giving a bonus per held card (Baron, Shoot the Moon)
+    pub fn held_triggers<F>(&self, hand: &MadeHand, matches: F) -> usize
+    where
+        F: Fn(&Card) -> bool,
+    {
+        return self
+            .hand
+            .iter()
+            .filter(|card| !hand.all.contains(card))
+            .filter(|card| !card.debuffed && matches(card))
+            .map(|card| self.held_trigger_count(card))
+            .sum();
     }
 
     /// Create a random Tarot card and add it to consumables
     pub fn create_random_tarot(&mut self) {
-        use crate::consumable::Consumables;
-        use crate::tarot::Tarots;
-        use rand::seq::SliceRandom;
-
-        let all_tarots = Tarots::all();
-        if let Some(tarot) = all_tarots.choose(&mut rand::thread_rng()) {
-            self.consumables.push(Consumables::Tarot(*tarot));
-        }
+        return self.with_rng(|game| {
+            use crate::consumable::Consumables;
+            use crate::tarot::Tarots;
+            use rand::seq::SliceRandom;
+
+            let all_tarots = Tarots::all();
+            if let Some(tarot) = all_tarots.choose(&mut crate::rng::rng_for("game", "random tarot")) {
+                game.consumables.push(Consumables::Tarot(*tarot));
+            }
+        });
     }
 
     /// Create a random Planet card and add it to consumables
     pub fn create_random_planet(&mut self) {
-        use crate::consumable::Consumables;
-        use crate::planet::Planets;
-        use rand::seq::SliceRandom;
-
-        let all_planets = Planets::all();
-        if let Some(planet) = all_planets.choose(&mut rand::thread_rng()) {
-            self.consumables.push(Consumables::Planet(*planet));
-        }
+        return self.with_rng(|game| {
+            use crate::consumable::Consumables;
+            use crate::planet::Planets;
+            use rand::seq::SliceRandom;
+
+            let all_planets = Planets::all();
+            if let Some(planet) = all_planets.choose(&mut crate::rng::rng_for("game", "random planet")) {
+                game.consumables.push(Consumables::Planet(*planet));
+            }
+        });
     }
 
     /// Skip the current blind (Small or Big only) to receive a tag
@@ -1639,13 +2544,13 @@ impl Game {
 
         // Boss blind cannot be skipped
         if next_blind == Blind::Boss {
-            return Err(GameError::InvalidAction);
+            return Err(GameError::InvalidAction(None));
         }
 
-        // Generate and give tag
+        // Give the offered tag
         let tag = self.pending_skip_tag.unwrap_or_else(|| self.select_random_tag());
-        self.add_tag(tag);
         self.pending_skip_tag = None;
+        self.add_tag(tag);
 
         // Increment skip counter for Speed Tag
         self.blinds_skipped_count += 1;
@@ -1662,20 +2567,42 @@ impl Game {
             // After skipping Small, prepare for Big blind
             self.stage = Stage::PreBlind();
         }
+        self.roll_skip_tag();
 
         return Ok(());
     }
 
+    // Roll the Boss Blind of the current ante, shown from its first PreBlind
+    fn roll_boss(&mut self) {
+        let mut rng = crate::rng::rng_for("boss", "boss modifier");
+        self.upcoming_boss = Some(BossModifier::random(&mut rng));
+    }
+
+    /// Tag offered for skipping the upcoming blind, None if it is the Boss
+    pub fn skip_tag_offer(&self) -> Option<Tag> {
+        return self.pending_skip_tag;
+    }
+
+    // Roll the tag shown for the upcoming blind. Boss blinds cannot be
+    // skipped so they have no offer.
+    fn roll_skip_tag(&mut self) {
+        let next_blind = self.blind.map_or(Blind::Small, |b| b.next());
+        self.pending_skip_tag = match next_blind {
+            Blind::Boss => None,
+            _ => Some(self.select_random_tag()),
+        };
+    }
+
     /// Select an item from a pending tag pack
     fn select_from_tag_pack(&mut self, index: usize) -> Result<(), GameError> {
         // Must have a pending tag pack
-        let pack = self.pending_tag_pack.take().ok_or(GameError::InvalidAction)?;
+        let pack = self.pending_tag_pack.take().ok_or(GameError::InvalidAction(None))?;
 
         // Index must be valid
         if index >= pack.size() {
             // Put pack back if invalid selection
             self.pending_tag_pack = Some(pack);
-            return Err(GameError::InvalidAction);
+            return Err(GameError::InvalidAction(None));
         }
 
         // Process the selection based on pack type
@@ -1694,16 +2621,14 @@ impl Game {
             }
             TagPack::MegaBuffoon(ref jokers) => {
                 // Add joker if space available
-                if self.jokers.len() < self.max_joker_slots() {
-                    self.jokers.push(jokers[index].clone());
-                    // Re-register joker effects
-                    self.effect_registry
-                        .register_jokers(self.jokers.clone(), &self.clone());
+                if self.jokers.len() < self.joker_slots() {
+                    self.add_pack_joker(jokers[index].clone());
                 }
             }
             TagPack::MegaStandard(ref cards) => {
-                // Add card to deck
-                self.deck.extend(vec![cards[index].clone()]);
+                // Add card to deck, a copy so picking it twice keeps ids unique
+                let card = self.copy_card(&cards[index]);
+                self.add_card_with_origin(card, CardOrigin::Created("Standard Pack".to_string()));
             }
             TagPack::Spectral(ref spectrals) => {
                 // Add spectral to consumables if space available
@@ -1728,6 +2653,152 @@ impl Game {
         Ok(())
     }
 
+    /// Buy a booster pack from the shop and open it. The game stays in the
+    /// `OpenPack` stage until the pack's choices are used up or it is skipped.
+    pub(crate) fn buy_booster(&mut self, pack: BoosterPack) -> Result<(), GameError> {
+        if self.stage != Stage::Shop() {
+            return Err(GameError::InvalidStage);
+        }
+        let price = self.shop.pack_price(&pack);
+        if !self.can_afford(price) {
+            return Err(GameError::InvalidBalance);
+        }
+        self.shop.buy_pack(pack)?;
+        self.money -= price as i64;
+        self.stage = Stage::OpenPack();
+        self.apply_telescope();
+
+        for e in self.effect_registry.on_pack_open.clone() {
+            if let Effects::OnPackOpen(f) = e {
+                f(self);
+            }
+        }
+        return Ok(());
+    }
+
+    /// Buy the shop's voucher. Its effect lasts for the rest of the run and
+    /// shop prices, slots and rerolls are updated right away.
+    pub(crate) fn buy_voucher(&mut self, voucher: Vouchers) -> Result<(), GameError> {
+        if self.stage != Stage::Shop() {
+            return Err(GameError::InvalidStage);
+        }
+        if self.shop.voucher != Some(voucher) {
+            return Err(GameError::InvalidAction(None));
+        }
+        let price = self.shop.voucher_price(&voucher);
+        if !self.can_afford(price) {
+            return Err(GameError::InvalidBalance);
+        }
+        self.shop.buy_voucher()?;
+        self.money -= price as i64;
+        self.vouchers.push(voucher);
+        voucher.apply_effect(self);
+        self.shop.update_config(&self.vouchers);
+        return Ok(());
+    }
+
+    /// Rerolls left in this shop that cost nothing, one per Chaos the Clown
+    pub fn free_rerolls(&self) -> usize {
+        let chaos = self
+            .active_jokers()
+            .iter()
+            .filter(|j| matches!(j, Jokers::ChaosTheClown(_)))
+            .count();
+        let free_used = self.shop.rerolls_this_round - self.shop.reroll_increase;
+        return chaos.saturating_sub(free_used);
+    }
+
+    /// Cost of the next shop reroll, see `Shop::reroll_cost`
+    pub fn reroll_cost(&self) -> usize {
+        if self.free_rerolls() > 0 {
+            return 0;
+        }
+        return self.shop.reroll_cost();
+    }
+
+    // Free rerolls do not raise the cost of the next one
+    fn reroll_shop(&mut self) -> Result<(), GameError> {
+        if self.stage != Stage::Shop() {
+            return Err(GameError::InvalidStage);
+        }
+        let cost = self.reroll_cost();
+        if !self.can_afford(cost) {
+            return Err(GameError::InvalidBalance);
+        }
+        if self.free_rerolls() == 0 {
+            self.shop.reroll_increase += 1;
+        }
+        self.money -= cost as i64;
+        self.stats.rerolls += 1;
+        self.shop.reroll_with_jokers(&self.jokers, &self.vouchers);
+        self.events.push(Event::ShopRerolled { cost });
+        return Ok(());
+    }
+
+    fn select_from_pack(&mut self, index: usize) -> Result<(), GameError> {
+        let mut pack = self.shop.open_pack.take().ok_or(GameError::InvalidAction(None))?;
+        // Check for room before taking so a failed pick keeps the pack intact
+        let room = match pack.select(index) {
+            None => Err(GameError::InvalidAction(None)),
+            Some(PackSelection::Joker(_)) if self.jokers.len() >= self.joker_slots() => {
+                Err(GameError::NoAvailableSlot)
+            }
+            // Planets are used when taken, like in the game, and need no slot
+            Some(PackSelection::Card(_)) | Some(PackSelection::Joker(_)) => Ok(()),
+            Some(PackSelection::Planet(_)) => Ok(()),
+            Some(_) if self.consumables.len() >= self.config.consumable_slots => {
+                Err(GameError::NoAvailableSlot)
+            }
+            Some(_) => Ok(()),
+        };
+        if let Err(err) = room {
+            self.shop.open_pack = Some(pack);
+            return Err(err);
+        }
+
+        match pack.take(index).expect("selection checked above") {
+            PackSelection::Joker(joker) => {
+                self.add_pack_joker(joker);
+                self.update_modifiers();
+            }
+            PackSelection::Card(card) => {
+                let card = self.copy_card(&card);
+                let origin = CardOrigin::Created(pack.pack_type.name().to_string());
+                self.add_card_with_origin(card, origin);
+            }
+            PackSelection::Planet(planet) => {
+                self.apply_consumable(&Consumables::Planet(planet), None)?;
+            }
+            selection => {
+                let consumable = selection.to_consumable().expect("consumable selection");
+                self.consumables.push(consumable);
+            }
+        }
+
+        match pack.is_done() {
+            true => self.close_pack(),
+            false => self.shop.open_pack = Some(pack),
+        }
+        return Ok(());
+    }
+
+    fn close_pack(&mut self) {
+        self.shop.open_pack = None;
+        self.stage = Stage::Shop();
+    }
+
+    // Close the open pack without taking the rest of it (Red Card)
+    fn skip_pack(&mut self) {
+        let effects = self.effect_registry.on_pack_skip.clone();
+        let slots = self.effect_registry.on_pack_skip_slots.clone();
+        for (e, slot) in effects.into_iter().zip(slots) {
+            if let Effects::OnPackSkip(f) = e {
+                f(self, slot);
+            }
+        }
+        self.close_pack();
+    }
+
     fn select_blind(&mut self, blind: Blind) -> Result<(), GameError> {
         // can only set blind if stage is pre blind
         if self.stage != Stage::PreBlind() {
@@ -1747,59 +2818,29 @@ impl Game {
         }
         self.blind = Some(blind);
 
-        // Assign random boss modifier for Boss blinds
-        let boss_modifier = if blind == Blind::Boss {
-            Some(BossModifier::random(&mut rand::thread_rng()))
+        // Boss blinds use the boss rolled for the ante, Chicot disables it
+        let has_chicot = self.jokers.iter().any(|j| matches!(j, Jokers::Chicot(_)));
+        let boss_modifier = if blind == Blind::Boss && !has_chicot {
+            let mut rng = crate::rng::rng_for("boss", "boss modifier");
+            let modifier = self.upcoming_boss.unwrap_or_else(|| BossModifier::random(&mut rng));
+            // Boss Tag: re-roll into a different boss
+            let modifier = match self.should_reroll_boss() {
+                true => BossModifier::random_except(&mut rng, modifier),
+                false => modifier,
+            };
+            self.upcoming_boss = Some(modifier);
+            Some(modifier)
         } else {
             None
         };
+        return self.begin_blind(blind, boss_modifier);
+    }
 
+    // Set up and deal a selected blind
+    pub(crate) fn begin_blind(&mut self, blind: Blind, boss_modifier: Option<BossModifier>) -> Result<(), GameError> {
         // Reset Category D boss modifier state
         self.first_deal_this_blind = true;
 
-        // Apply boss modifier effects
-        if let Some(modifier) = boss_modifier {
-            // The Manacle: -1 hand size
-            let hand_size_mod = modifier.hand_size_modifier();
-            if hand_size_mod != 0 {
-                self.modify_hand_size(hand_size_mod);
-            }
-
-            // The Water: start with 0 discards
-            let discard_mod = modifier.discard_modifier();
-            if discard_mod == i32::MIN {
-                self.discards = 0;
-            } else if discard_mod != 0 {
-                self.discards = self.discards.saturating_sub(discard_mod.abs() as usize);
-            }
-
-            // The Needle: max 1 hand this blind
-            if let Some(max_hands) = modifier.max_hands() {
-                self.plays = self.plays.min(max_hands);
-            }
-
-            // The Mouth: randomly select one hand type that can be played
-            if modifier.restricts_to_one_hand_type() {
-                use rand::seq::SliceRandom;
-                let all_hand_ranks = vec![
-                    HandRank::HighCard,
-                    HandRank::OnePair,
-                    HandRank::TwoPair,
-                    HandRank::ThreeOfAKind,
-                    HandRank::Straight,
-                    HandRank::Flush,
-                    HandRank::FullHouse,
-                    HandRank::FourOfAKind,
-                    HandRank::StraightFlush,
-                    HandRank::RoyalFlush,
-                    HandRank::FiveOfAKind,
-                    HandRank::FlushHouse,
-                    HandRank::FlushFive,
-                ];
-                self.allowed_hand_rank = Some(*all_hand_ranks.choose(&mut rand::thread_rng()).unwrap());
-            }
-        }
-
         // Reset and randomize RoundState for jokers that need per-round state
         self.reset_round_state();
 
@@ -1817,7 +2858,15 @@ impl Game {
             self.discards = self.discards.saturating_sub(self.modifiers.discard_bonus.abs() as usize);
         }
 
+        // Boss modifiers apply on top of the blind's plays and discards
+        if let Some(rule) = self.boss_rule() {
+            rule.on_blind_start(self);
+        }
+
         // Trigger OnBlindSelect effects
+        if blind != Blind::Boss && self.jokers.iter().any(|j| matches!(j, Jokers::Madness(_))) {
+            self.trigger_madness();
+        }
         self.trigger_blind_select();
 
         // Process round start tags (Juggle)
@@ -1837,14 +2886,14 @@ impl Game {
             let effects = self.effect_registry.on_shop_end.clone();
             for effect in effects {
                 if let crate::effect::Effects::OnShopEnd(callback) = effect {
-                    let cb = callback.lock().unwrap();
-                    cb(self);
+                    callback(self);
                 }
             }
         }
 
         self.stage = Stage::PreBlind();
         self.round += 1;
+        self.roll_skip_tag();
         return Ok(());
     }
 
@@ -1855,38 +2904,69 @@ impl Game {
             return Err(GameError::InvalidStage);
         }
 
-        self.score += score;
+        self.score = self.score.saturating_add(score);
         let required = self.required_score();
 
         // blind not passed
         if self.score < required {
-            // no more hands to play -> lose
-            if self.plays == 0 {
-                self.stage = Stage::End(End::Lose);
+            // more hands to play, carry on
+            if self.plays > 0 {
                 return Ok(false);
-            } else {
-                // more hands to play, carry on
+            }
+            // no more hands to play -> lose, unless an effect saves the run
+            // and the blind counts as beaten
+            if !self.on_would_lose() {
+                self.stage = match self.ante_current > self.ante_end {
+                    true => Stage::End(End::Endless),
+                    false => Stage::End(End::Lose),
+                };
                 return Ok(false);
             }
         }
 
         let blind = self.blind.expect("stage is blind");
+        self.events.push(Event::BlindDefeated {
+            blind,
+            score: self.score,
+        });
         // score exceeds blind (blind passed).
         // handle reward then progress to next stage.
-        let reward = self.calc_reward(blind)?;
-        self.reward = reward;
+        let cash_out = self.cash_out_for(blind);
+        self.reward = cash_out.total();
+        self.events.push(Event::CashOut(cash_out.clone()));
+        self.pending_cash_out = Some(cash_out);
+
+        // Undo lasting boss effects (The Manacle) before leaving the blind
+        if let Some(rule) = self.boss_rule() {
+            rule.on_blind_end(self);
+        }
 
         // passed boss blind, either win or progress ante
         if blind == Blind::Boss {
             // Process boss defeated tags (Investment)
             self.process_boss_defeated_tags();
 
+            // Campfire starts over after each Boss Blind
+            for joker in &mut self.jokers {
+                if let Jokers::Campfire(j) = joker {
+                    j.reset_on_boss();
+                }
+            }
+
+            // Anaglyph Deck: a Double Tag for every Boss Blind defeated
+            if self.config.deck_type.is_some_and(|d| d.grants_double_tag()) {
+                self.add_tag(Tag::Double);
+            }
+
             if let Some(ante_next) = self.ante_current.next(self.ante_end) {
                 self.ante_current = ante_next;
+            } else if self.config.endless {
+                self.ante_current = self.ante_current.following();
             } else {
                 self.stage = Stage::End(End::Win);
                 return Ok(false);
             }
+            self.roll_boss();
         };
 
         // Trigger OnRoundEnd effects before finishing blind
@@ -1897,54 +2977,147 @@ impl Game {
         return Ok(true);
     }
 
+    /// Apply an action. Every `GameError::InvalidAction` (wrong stage, a
+    /// hand the boss forbids, a bad index...) comes back with a
+    /// `RejectedAction` listing the legal actions.
     pub fn handle_action(&mut self, action: Action) -> Result<(), GameError> {
+        let money = self.money;
+        let mut res = self.with_rng(|game| game.apply_action(action.clone()));
+        match self.money.cmp(&money) {
+            std::cmp::Ordering::Greater => {
+                let gained = (self.money - money) as usize;
+                self.stats.money_earned += gained;
+                self.events.push(Event::MoneyGained(gained));
+            }
+            std::cmp::Ordering::Less => {
+                let spent = (money - self.money) as usize;
+                self.stats.money_spent += spent;
+                self.events.push(Event::MoneySpent(spent));
+            }
+            std::cmp::Ordering::Equal => {}
+        }
+        if res == Err(GameError::InvalidAction(None)) {
+            let rejected = RejectedAction::new(action, self.gen_actions());
+            res = Err(GameError::InvalidAction(Some(Box::new(rejected))));
+        }
+        self.record_checkpoint();
+        self.trim_events();
+        return res;
+    }
+
+    // Drop the oldest events past MAX_PENDING_EVENTS
+    fn trim_events(&mut self) {
+        let overflow = self.events.len().saturating_sub(MAX_PENDING_EVENTS);
+        self.events.drain(..overflow);
+    }
+
+    fn apply_action(&mut self, action: Action) -> Result<(), GameError> {
         self.action_history.push(action.clone());
         return match action {
             Action::SelectCard(card) => match self.stage.is_blind() {
                 true => self.select_card(card),
-                false => Err(GameError::InvalidAction),
+                false => Err(GameError::InvalidAction(None)),
+            },
+            Action::SelectCardIndex(index) => match self.stage.is_blind() {
+                true => self.select_card_index(index),
+                false => Err(GameError::InvalidAction(None)),
+            },
+            Action::ToggleCardIndex(index) => match self.stage.is_blind() {
+                true => self.toggle_card_index(index),
+                false => Err(GameError::InvalidAction(None)),
+            },
+            Action::DeselectAll() => match self.stage.is_blind() {
+                true => {
+                    self.available.deselect_all();
+                    Ok(())
+                }
+                false => Err(GameError::InvalidAction(None)),
+            },
+            Action::SortHand(mode) => match self.stage.is_blind() {
+                true => {
+                    self.available.sort(mode);
+                    Ok(())
+                }
+                false => Err(GameError::InvalidAction(None)),
             },
             Action::Play() => match self.stage.is_blind() {
                 true => self.play_selected(),
-                false => Err(GameError::InvalidAction),
+                false => Err(GameError::InvalidAction(None)),
             },
             Action::Discard() => match self.stage.is_blind() {
                 true => self.discard_selected(),
-                false => Err(GameError::InvalidAction),
+                false => Err(GameError::InvalidAction(None)),
             },
             Action::MoveCard(dir, card) => match self.stage.is_blind() {
                 true => self.move_card(dir, card),
-                false => Err(GameError::InvalidAction),
+                false => Err(GameError::InvalidAction(None)),
             },
             Action::CashOut(_reward) => match self.stage {
                 Stage::PostBlind() => self.cashout(),
-                _ => Err(GameError::InvalidAction),
+                _ => Err(GameError::InvalidAction(None)),
             },
             Action::BuyJoker(joker) => match self.stage {
                 Stage::Shop() => self.buy_joker(joker),
-                _ => Err(GameError::InvalidAction),
+                _ => Err(GameError::InvalidAction(None)),
             },
-            Action::BuyConsumable(consumable) => match self.stage {
-                Stage::Shop() => self.buy_consumable(consumable),
-                _ => Err(GameError::InvalidAction),
+            Action::BuyConsumable(slot) => match self.stage {
+                Stage::Shop() => self.buy_consumable_at(slot),
+                _ => Err(GameError::InvalidAction(None)),
             },
-            Action::UseConsumable(consumable, targets) => self.use_consumable(consumable, targets),
+            Action::BuyAndUseConsumable(slot) => match self.stage {
+                Stage::Shop() => self.buy_and_use_consumable(slot),
+                _ => Err(GameError::InvalidAction(None)),
+            },
+            Action::UseConsumable(consumable, targets) => {
+                self.use_consumable_on(consumable, targets)
+            }
             Action::NextRound() => match self.stage {
                 Stage::Shop() => self.next_round(),
-                _ => Err(GameError::InvalidAction),
+                _ => Err(GameError::InvalidAction(None)),
             },
             Action::SelectBlind(blind) => match self.stage {
                 Stage::PreBlind() => self.select_blind(blind),
-                _ => Err(GameError::InvalidAction),
+                _ => Err(GameError::InvalidAction(None)),
             },
             Action::SkipBlind() => match self.stage {
                 Stage::PreBlind() => self.skip_blind(),
-                _ => Err(GameError::InvalidAction),
+                _ => Err(GameError::InvalidAction(None)),
             },
             Action::SelectFromTagPack(index) => self.select_from_tag_pack(index),
-            Action::SellJoker(joker) => match self.stage {
-                Stage::Shop() => self.sell_joker(joker),
-                _ => Err(GameError::InvalidAction),
+            Action::SellJoker(slot) => match self.can_manage_items() {
+                true => self.sell_joker_at(slot),
+                false => Err(GameError::InvalidAction(None)),
+            },
+            Action::MoveJoker(from, to) => match self.can_manage_items() {
+                true => self.move_joker(from, to),
+                false => Err(GameError::InvalidAction(None)),
+            },
+            Action::SellConsumable(slot) => match self.can_manage_items() {
+                true => self.sell_consumable(slot),
+                false => Err(GameError::InvalidAction(None)),
+            },
+            Action::BuyBooster(pack) => match self.stage {
+                Stage::Shop() => self.buy_booster(pack),
+                _ => Err(GameError::InvalidAction(None)),
+            },
+            Action::SelectFromPack(index) => match self.stage {
+                Stage::OpenPack() => self.select_from_pack(index),
+                _ => Err(GameError::InvalidAction(None)),
+            },
+            Action::SkipPack() => match self.stage {
+                Stage::OpenPack() => {
+                    self.skip_pack();
+                    Ok(())
+                }
+                _ => Err(GameError::InvalidAction(None)),
+            },
+            Action::BuyVoucher(voucher) => match self.stage {
+                Stage::Shop() => self.buy_voucher(voucher),
+                _ => Err(GameError::InvalidAction(None)),
+            },
+            Action::Reroll() => match self.stage {
+                Stage::Shop() => self.reroll_shop(),
+                _ => Err(GameError::InvalidAction(None)),
             },
         };
     }
@@ -1958,35 +3131,27 @@ impl Game {
 
 impl fmt::Display for Game {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "deck length: {}", self.deck.len())?;
-        writeln!(f, "available length: {}", self.available.cards().len())?;
-        writeln!(f, "selected length: {}", self.available.selected().len())?;
-        writeln!(f, "discard length: {}", self.discarded.len())?;
-        writeln!(f, "jokers: ")?;
-        for j in self.jokers.clone() {
-            writeln!(f, "{}", j)?
-        }
-        writeln!(f, "action history length: {}", self.action_history.len())?;
-        writeln!(f, "blind: {:?}", self.blind)?;
-        writeln!(f, "stage: {:?}", self.stage)?;
-        writeln!(f, "ante: {:?}", self.ante_current)?;
-        writeln!(f, "round: {}", self.round)?;
-        writeln!(f, "hands remaining: {}", self.plays)?;
-        writeln!(f, "discards remaining: {}", self.discards)?;
-        writeln!(f, "money: {}", self.money)?;
-        writeln!(f, "score: {}", self.score)
+        write!(f, "{}", self.render())
     }
 }
 
 impl Default for Game {
     fn default() -> Self {
-        return Self::new(Config::default());
+        return Self::new(Config::default()).expect("default config is valid");
     }
 }
 
+// Games are moved between threads by parallel simulators and shared
+// read-only during search, keep them Send + Sync.
+const _: () = {
+    fn assert_send_sync<T: Send + Sync>() {}
+    let _ = assert_send_sync::<Game>;
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::action::ActionKind;
     use crate::card::{Suit, Value};
     use crate::joker::Rarity;
 
@@ -1995,7 +3160,7 @@ mod tests {
         let g = Game::default();
         assert_eq!(g.available.cards().len(), 0);
         assert_eq!(g.deck.len(), 52);
-        assert_eq!(g.mult, 0);
+        assert_eq!(g.mult, 0.0);
     }
 
     #[test]
@@ -2018,6 +3183,163 @@ mod tests {
         assert_eq!(g.available.cards().len(), 4);
         assert_eq!(g.deck.len(), 52 - 4);
     }
+    #[test]
+    fn test_draw_emits_events() {
+        let mut g = Game::default();
+        g.deal();
+        let events = g.drain_events();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            Event::CardsDrawn(cards) => assert_eq!(cards, &g.available.cards()),
+            e => panic!("unexpected event {:?}", e),
+        }
+        assert!(g.events().is_empty());
+    }
+
+    #[test]
+    fn test_undrained_events_are_capped() {
+        let mut g = Game::default();
+        g.start();
+        for i in 0..MAX_PENDING_EVENTS + 10 {
+            g.events.push(Event::MoneyGained(i));
+        }
+        // Rejected actions still trim the queue, dropping the oldest
+        assert!(g.handle_action(Action::Play()).is_err());
+        assert_eq!(g.events().len(), MAX_PENDING_EVENTS);
+        assert_eq!(g.events()[0], Event::MoneyGained(10));
+    }
+
+    #[test]
+    fn test_refill_without_reshuffle_when_deck_exhausted() {
+        let mut g = Game::default();
+        g.stage = Stage::Blind(Blind::Small, None);
+        g.deal();
+        g.drain_events();
+
+        // Leave only two cards in the draw pile
+        let leftover = g.deck.len() - 2;
+        let mut burned = g.deck.draw_up_to(leftover);
+        g.discarded.append(&mut burned);
+
+        for c in g.available.cards()[0..3].to_vec() {
+            g.select_card(c).unwrap();
+        }
+        g.discard_selected().unwrap();
+
+        // Only two cards came back, and discards were not reshuffled in
+        assert_eq!(g.available.cards().len(), g.hand_size_target() - 1);
+        assert_eq!(g.deck.len(), 0);
+        let events = g.drain_events();
+        assert!(events.contains(&Event::DeckExhausted {
+            requested: 3,
+            drawn: 2
+        }));
+        assert!(matches!(&events[1], Event::CardsDrawn(cards) if cards.len() == 2));
+    }
+
+    // Discard until the hand is empty, returning every card that was drawn
+    fn discard_out_deck(g: &mut Game) -> Vec<Card> {
+        let mut drawn = g.available.cards();
+        g.discards = 100;
+        while !g.available.cards().is_empty() {
+            for c in g.available.cards().into_iter().take(5) {
+                g.select_card(c).unwrap();
+            }
+            g.discard_selected().unwrap();
+            for event in g.drain_events() {
+                if let Event::CardsDrawn(cards) = event {
+                    drawn.extend(cards);
+                }
+            }
+        }
+        return drawn;
+    }
+
+    #[test]
+    fn test_play_out_full_deck_no_refill() {
+        let mut g = Game::default();
+        g.stage = Stage::Blind(Blind::Small, None);
+        g.deal();
+        g.drain_events();
+
+        let drawn = discard_out_deck(&mut g);
+
+        // Every card in the deck was seen exactly once, nothing was reshuffled
+        assert_eq!(drawn.len(), 52);
+        assert_eq!(drawn.iter().map(|c| c.id).collect::<HashSet<_>>().len(), 52);
+        assert_eq!(g.deck.len(), 0);
+        assert_eq!(g.discarded.len(), 52);
+    }
+
+    #[test]
+    fn test_play_out_full_deck_reshuffle_discards() {
+        let mut config = Config::default();
+        config.deck_exhaustion = DeckExhaustion::ReshuffleDiscards;
+        let mut g = Game::new(config).unwrap();
+        g.stage = Stage::Blind(Blind::Small, None);
+        g.deal();
+        g.discards = 20;
+
+        for _ in 0..20 {
+            for c in g.available.cards().into_iter().take(5) {
+                g.select_card(c).unwrap();
+            }
+            g.discard_selected().unwrap();
+            // The hand never shrinks, discards are recycled
+            assert_eq!(g.available.cards().len(), g.hand_size_target());
+            assert_eq!(g.deck.len() + g.discarded.len() + g.available.cards().len(), 52);
+        }
+        assert!(g.events().iter().any(|e| matches!(e, Event::DiscardsReshuffled(_))));
+    }
+
+    #[test]
+    fn test_hand_levels_table() {
+        let mut g = Game::default();
+        g.upgrade_hand(HandRank::Flush);
+        g.stats.hands_played.insert(HandRank::Flush, 3);
+
+        let table = g.hand_levels();
+        assert_eq!(table.len(), 13);
+        assert_eq!(table[0].rank, HandRank::HighCard);
+        assert_eq!(table[0].planet_name.as_deref(), Some("Pluto"));
+
+        let flush = table.iter().find(|h| h.rank == HandRank::Flush).unwrap();
+        assert_eq!(flush.level, 2);
+        assert_eq!(flush.chips, 35 + 15);
+        assert_eq!(flush.mult, 4 + 2);
+        assert_eq!(flush.times_played, 3);
+        assert_eq!(flush.planet, Some(crate::planet::Planets::Venus));
+    }
+
+    #[test]
+    fn test_index_selection_actions() {
+        let mut g = Game::default();
+        g.stage = Stage::Blind(Blind::Small, None);
+        g.deal();
+
+        g.handle_action(Action::SelectCardIndex(0)).unwrap();
+        g.handle_action(Action::ToggleCardIndex(2)).unwrap();
+        assert_eq!(g.available.selected().len(), 2);
+
+        // Toggling a selected card deselects it
+        g.handle_action(Action::ToggleCardIndex(0)).unwrap();
+        assert_eq!(g.available.selected().len(), 1);
+
+        g.handle_action(Action::DeselectAll()).unwrap();
+        assert!(g.available.selected().is_empty());
+
+        assert_eq!(
+            g.handle_action(Action::SelectCardIndex(100)),
+            Err(GameError::NoCardMatch)
+        );
+
+        g.stage = Stage::Shop();
+        assert!(matches!(
+            g.handle_action(Action::DeselectAll()),
+            Err(GameError::InvalidAction(_))
+        ));
+    }
+
     #[test]
     fn test_discard() {
         let mut g = Game::default();
@@ -2164,7 +3486,7 @@ mod tests {
 
         let j1 = g.shop.joker_from_index(0).expect("is joker");
         g.buy_joker(j1.clone()).expect("buy joker");
-        assert_eq!(g.money, 10 - j1.cost());
+        assert_eq!(g.money, 10 - j1.cost() as i64);
         assert_eq!(g.jokers.len(), 1);
     }
 
@@ -2234,11 +3556,14 @@ mod tests {
         let club1 = Card::new(Value::Ace, Suit::Club);
         let club2 = Card::new(Value::Ace, Suit::Club);
         let heart = Card::new(Value::King, Suit::Heart);
+        g.available.extend(vec![club1, club2, heart]);
+        BossModifier::TheClub.rule().on_blind_start(&mut g);
 
         // Two club aces and a heart king
         // In this implementation, hand detection happens before debuffing is checked
         // So the pair is detected, but clubs don't contribute chips/mult
-        let cards = vec![club1, club2, heart];
+        let cards = g.available.cards();
+        assert_eq!(cards.iter().map(|c| c.debuffed).collect::<Vec<_>>(), [true, true, false]);
         let hand = SelectHand::new(cards).best_hand().unwrap();
         let score = g.calc_score(hand);
 
@@ -2390,10 +3715,14 @@ mod tests {
         g.available.select_card(ace).unwrap();
         g.available.select_card(ace2).unwrap();
         g.available.select_card(king).unwrap();
+        assert!(!g.gen_actions().any(|a| a == Action::Play()));
+        // Neither mask offers the Play the blind would reject
+        assert_eq!(g.gen_action_space().play, vec![0]);
+        assert_eq!(g.observe_with_mask().1, g.gen_action_space().to_vec());
 
         let result = g.play_selected();
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), GameError::InvalidAction);
+        assert_eq!(result.unwrap_err(), GameError::InvalidAction(None));
     }
 
     #[test]
@@ -2493,9 +3822,8 @@ mod tests {
         // - 1 card played (removed)
         // - 1 remaining card
         // - The Hook tries to discard 2, but can only discard 1
-        // - Drew 2 cards (1 played + 1 discarded)
-        // Final: 0 after play, then drew 2 = 2 cards
-        assert_eq!(g.available.cards().len(), 2);
+        // - Hand is refilled from the deck back up to full hand size
+        assert_eq!(g.available.cards().len(), g.hand_size_target());
     }
 
     #[test]
@@ -2566,7 +3894,7 @@ mod tests {
 
         let result = g.play_selected();
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), GameError::InvalidAction);
+        assert_eq!(result.unwrap_err(), GameError::InvalidAction(None));
     }
 
     #[test]
@@ -2899,15 +4227,51 @@ mod tests {
         let result = g.skip_blind();
         assert!(result.is_ok(), "Should be able to skip small blind");
 
-        // Should have received a tag
-        assert!(g.tags.len() > 0 || g.money > 10, "Should have received a tag or immediate money");
+        // Should have received a tag
+        assert!(g.tags.len() > 0 || g.money > 10, "Should have received a tag or immediate money");
+
+        // Should have incremented skip counter
+        assert_eq!(g.blinds_skipped_count, 1);
+
+        // Should be in PreBlind stage for next blind
+        assert_eq!(g.stage, Stage::PreBlind());
+        assert_eq!(g.blind, Some(Blind::Small));
+    }
+
+    #[test]
+    fn test_skip_tag_offer() {
+        let mut g = Game::with_seed(Config::default(), 4).unwrap();
+        g.start();
+        let offer = g.skip_tag_offer().expect("small blind has a tag offer");
+        assert!(g.gen_actions().any(|a| a == Action::SkipBlind()));
+
+        g.handle_action(Action::SkipBlind()).unwrap();
+        let received = g.tags.contains(&offer) || offer.trigger_type() == crate::tag::TagTrigger::Immediate;
+        assert!(received);
+        assert!(g.skip_tag_offer().is_some());
+
+        // The boss blind has no offer and cannot be skipped
+        g.handle_action(Action::SkipBlind()).unwrap();
+        assert_eq!(g.skip_tag_offer(), None);
+        assert!(!g.gen_actions().any(|a| a == Action::SkipBlind()));
+    }
 
-        // Should have incremented skip counter
-        assert_eq!(g.blinds_skipped_count, 1);
+    #[test]
+    fn test_boss_tag_rerolls_boss() {
+        use crate::tag::Tag;
 
-        // Should be in PreBlind stage for next blind
-        assert_eq!(g.stage, Stage::PreBlind());
-        assert_eq!(g.blind, Some(Blind::Small));
+        let boss_for = |tag: Option<Tag>| {
+            let mut g = Game::with_seed(Config::default(), 8).unwrap();
+            g.start();
+            g.blind = Some(Blind::Big);
+            if let Some(tag) = tag {
+                g.tags.push(tag);
+            }
+            g.handle_action(Action::SelectBlind(Blind::Boss)).unwrap();
+            assert!(g.tags.is_empty());
+            return g.stage.boss_modifier();
+        };
+        assert_ne!(boss_for(None), boss_for(Some(Tag::Boss)));
     }
 
     #[test]
@@ -2939,7 +4303,7 @@ mod tests {
         // Try to skip boss blind
         let result = g.skip_blind();
         assert!(result.is_err(), "Should not be able to skip boss blind");
-        assert_eq!(result.unwrap_err(), GameError::InvalidAction);
+        assert_eq!(result.unwrap_err(), GameError::InvalidAction(None));
     }
 
     #[test]
@@ -3694,10 +5058,11 @@ mod tests {
         // Reroll cost should be 0
         assert_eq!(g.shop.reroll_cost(), 0);
 
-        // Verify we can reroll for free
+        // The first reroll is free, later ones go up by $1 from there
         let money_before = g.money;
-        // Note: There's no direct reroll method exposed, but we can check the config
-        assert_eq!(g.shop.config.reroll_cost, 0);
+        g.handle_action(Action::Reroll()).unwrap();
+        assert_eq!(g.money, money_before);
+        assert_eq!(g.reroll_cost(), 1);
     }
 
     #[test]
@@ -3813,4 +5178,970 @@ mod tests {
         assert!(g.shop.joker_price(&g.shop.jokers[0]) > 0);
         assert!(g.shop.joker_price(&g.shop.jokers[1]) > 0);
     }
+
+    #[test]
+    fn test_calc_score_emits_phases_in_order() {
+        let mut g = Game::default();
+        g.stage = Stage::Blind(Blind::Small, None);
+        let hand = SelectHand::new(vec![
+            Card::new(Value::King, Suit::Heart),
+            Card::new(Value::King, Suit::Spade),
+        ])
+        .best_hand()
+        .unwrap();
+        g.drain_events();
+        let score = g.calc_score(hand);
+
+        let scored: Vec<(ScorePhase, f64, f64)> = g
+            .drain_events()
+            .into_iter()
+            .filter_map(|e| match e {
+                Event::Scored { phase, chips, mult } => Some((phase, chips, mult)),
+                _ => None,
+            })
+            .collect();
+        let phases: Vec<ScorePhase> = scored.iter().map(|(p, _, _)| *p).collect();
+        assert_eq!(
+            phases,
+            vec![
+                ScorePhase::BaseHand,
+                ScorePhase::PlayedCards,
+                ScorePhase::HeldInHand,
+                ScorePhase::Jokers,
+                ScorePhase::JokerEditions,
+            ]
+        );
+        // Pair base 10 x 2, then two kings add 10 chips each
+        assert_eq!((scored[0].1, scored[0].2), (10.0, 2.0));
+        assert_eq!((scored[1].1, scored[1].2), (30.0, 2.0));
+        let (_, chips, mult) = scored[4];
+        assert_eq!(score as f64, chips * mult);
+    }
+
+    #[test]
+    fn test_card_x_mult_applies_before_jokers() {
+        use crate::joker::TheJoker;
+
+        let mut g = Game::default();
+        g.stage = Stage::Blind(Blind::Small, None);
+        g.jokers.push(Jokers::TheJoker(TheJoker {}));
+        g.refresh_joker_effects();
+        let mut glass = Card::new(Value::King, Suit::Heart);
+        glass.set_enhancement(crate::card::Enhancement::Glass);
+        let hand = SelectHand::new(vec![glass, Card::new(Value::King, Suit::Spade)])
+            .best_hand()
+            .unwrap();
+        g.drain_events();
+        let score = g.calc_score(hand);
+
+        let mults: Vec<f64> = g
+            .drain_events()
+            .into_iter()
+            .filter_map(|e| match e {
+                Event::Scored { mult, .. } => Some(mult),
+                _ => None,
+            })
+            .collect();
+        // Pair 2 mult, Glass x2 when the card scores, then The Joker +4:
+        // 2 * 2 + 4, not (2 + 4) * 2
+        assert_eq!(mults[1], 4.0);
+        assert_eq!(mults[3], 8.0);
+        assert_eq!(score, 30 * 8);
+    }
+
+    #[test]
+    fn test_play_emits_typed_events() {
+        use crate::joker::TheJoker;
+
+        let mut g = Game::with_seed(Config::default(), 3).unwrap();
+        g.start();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        g.jokers.push(Jokers::TheJoker(TheJoker {}));
+        g.refresh_joker_effects();
+        g.score = g.required_score() - 1;
+        let card = g.available.cards()[0];
+        g.handle_action(Action::SelectCard(card)).unwrap();
+        g.drain_events();
+
+        g.handle_action(Action::Play()).unwrap();
+        let events = g.drain_events();
+        let position = |f: &dyn Fn(&Event) -> bool| events.iter().position(f).unwrap();
+        let scored = position(&|e| {
+            *e == Event::CardScored {
+                card,
+                chips: card.chips() as f64,
+                mult: 0.0,
+            }
+        });
+        let joker = position(&|e| {
+            *e == Event::JokerTriggered {
+                slot: Some(0),
+                chips: 0.0,
+                mult: 4.0,
+            }
+        });
+        let defeated = position(&|e| matches!(e, Event::BlindDefeated { blind: Blind::Small, .. }));
+        let cash_out = position(&|e| matches!(e, Event::CashOut(_)));
+        assert!(scored < joker && joker < defeated && defeated < cash_out);
+
+        let reward = g.reward;
+        g.handle_action(Action::CashOut(reward)).unwrap();
+        assert_eq!(g.drain_events().last(), Some(&Event::MoneyGained(reward)));
+
+        let card = g.deck.cards()[0];
+        g.destroy_card_by(card, "Immolate");
+        assert_eq!(
+            g.drain_events(),
+            vec![Event::CardDestroyed {
+                card,
+                by: "Immolate".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_modify_card_everywhere() {
+        use crate::card::Enhancement;
+
+        let mut g = Game::default();
+        g.start();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        let card = g.available.card_from_index(0).unwrap();
+        assert!(g.hand.contains(&card));
+
+        let modified = g.modify_card_everywhere(card.id, |c| c.set_enhancement(Enhancement::Steel));
+        assert!(modified);
+        let in_available = g.available.card_from_index(0).unwrap();
+        assert_eq!(in_available.enhancement, Some(Enhancement::Steel));
+        let in_hand: Vec<&Card> = g.hand.iter().filter(|c| c.id == card.id).collect();
+        assert_eq!(in_hand, vec![&in_available]);
+
+        // Playing the modified card removes it from the hand tracking too
+        g.handle_action(Action::SelectCard(in_available)).unwrap();
+        g.handle_action(Action::Play()).unwrap();
+        assert!(!g.hand.iter().any(|c| c.id == card.id));
+
+        assert!(!g.modify_card_everywhere(usize::MAX, |c| c.set_enhancement(Enhancement::Gold)));
+    }
+
+    #[test]
+    fn test_card_ids_stay_unique() {
+        use crate::consumable::Consumable;
+        use crate::spectral::Spectrals;
+        use crate::tag::TagPack;
+
+        let ids = |g: &Game| -> Vec<usize> {
+            let mut ids: Vec<usize> = g
+                .deck
+                .cards()
+                .iter()
+                .chain(g.available.cards().iter())
+                .chain(g.discarded.iter())
+                .map(|c| c.id)
+                .collect();
+            ids.sort();
+            ids
+        };
+        let assert_unique = |g: &Game| {
+            let mut unique = ids(g);
+            unique.dedup();
+            assert_eq!(unique, ids(g), "duplicate card ids");
+        };
+
+        let mut g = Game::default();
+        g.start();
+        let card = g.available.card_from_index(0).unwrap();
+
+        // Cryptid copies keep the look of the card but not its id
+        Spectrals::Cryptid.use_effect(&mut g, Some(vec![card])).unwrap();
+        let copies = g
+            .deck
+            .cards()
+            .into_iter()
+            .filter(|c| (c.value, c.suit) == (card.value, card.suit) && c.id != card.id)
+            .count();
+        assert_eq!(copies, 2);
+        assert_unique(&g);
+
+        // Picking the same standard pack card twice
+        g.pending_tag_pack = Some(TagPack::MegaStandard(vec![card; 5]));
+        g.handle_action(Action::SelectFromTagPack(0)).unwrap();
+        g.handle_action(Action::SelectFromTagPack(0)).unwrap();
+        let f = g.create_enhanced_face_card();
+        g.add_card_to_deck(f);
+        assert_eq!(ids(&g).len(), 52 + 5);
+        assert_unique(&g);
+
+        // Ids held by a restored game are never handed out again
+        let snap = g.snapshot();
+        let mut restored = Game::default();
+        restored.restore(&snap);
+        let highest = *ids(&restored).last().unwrap();
+        assert!(restored.new_card_id() > highest);
+        let copy = restored.copy_card(&card);
+        restored.add_card_to_deck(copy);
+        assert_unique(&restored);
+    }
+
+    #[test]
+    fn test_rejected_action_lists_legal_actions() {
+        let mut g = Game::default();
+        g.start();
+
+        let err = g.handle_action(Action::Play()).unwrap_err();
+        let GameError::InvalidAction(Some(rejected)) = err.clone() else {
+            panic!("expected rejected action, got {err:?}");
+        };
+        assert_eq!(rejected.action, Action::Play());
+        assert_eq!(rejected.legal_kinds, ActionKind::SelectBlind | ActionKind::SkipBlind);
+        assert_eq!(rejected.total, g.gen_actions().count());
+        assert!(err.to_string().contains("SelectBlind: Small"));
+
+        // Any InvalidAction is reported, not only a wrong stage
+        let err = g.handle_action(Action::SelectFromTagPack(0)).unwrap_err();
+        assert!(matches!(err, GameError::InvalidAction(Some(_))), "{err:?}");
+
+        // Other errors carry no legal actions
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        g.discards = 0;
+        let card = g.available.card_from_index(0).unwrap();
+        g.handle_action(Action::SelectCard(card)).unwrap();
+        assert_eq!(g.handle_action(Action::Discard()), Err(GameError::NoRemainingDiscards));
+    }
+
+    #[test]
+    fn test_game_across_threads() {
+        let mut g = Game::with_seed(Config::default(), 8).unwrap();
+        g.start();
+        let shared = std::sync::Arc::new(g.clone());
+
+        // Read from several threads at once (Sync)
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let shared = shared.clone();
+                std::thread::spawn(move || shared.gen_actions().count())
+            })
+            .collect();
+        let counts: Vec<usize> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert!(counts.iter().all(|c| *c == counts[0]));
+
+        // Step a moved game on another thread (Send)
+        let stepped = std::thread::spawn(move || {
+            g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+            g
+        })
+        .join()
+        .unwrap();
+        assert!(stepped.stage.is_blind());
+    }
+
+    #[test]
+    fn test_same_seed_replays_same_run() {
+        // Step two games with the same seed through the same (first legal) actions
+        let run = |seed: u64| {
+            let mut g = Game::with_seed(Config::default(), seed).unwrap();
+            g.start();
+            for _ in 0..60 {
+                if g.is_over() {
+                    break;
+                }
+                let action = g.gen_actions().next().expect("legal action");
+                g.handle_action(action).unwrap();
+            }
+            // Card ids come from a global counter, compare faces only
+            let faces = |cards: Vec<Card>| -> Vec<(Value, Suit)> {
+                cards.iter().map(|c| (c.value, c.suit)).collect()
+            };
+            (faces(g.deck.cards()), faces(g.available.cards()), g.shop.jokers.clone(), g.score)
+        };
+        assert_eq!(run(99), run(99));
+        assert_ne!(run(99).0, run(100).0);
+    }
+
+    #[test]
+    fn test_seals_trigger() {
+        use crate::card::Seal;
+        use crate::planet::Planets;
+
+        let mut g = Game::with_seed(Config::default(), 31).unwrap();
+        g.start();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        g.consumables.clear();
+
+        // Gold: $3 when played
+        let gold = g.available.card_from_index(0).unwrap();
+        g.modify_card_everywhere(gold.id, |c| c.set_seal(Seal::Gold));
+        let money = g.money;
+        g.handle_action(Action::SelectCardIndex(0)).unwrap();
+        g.handle_action(Action::Play()).unwrap();
+        assert_eq!(g.money, money + 3);
+
+        // Purple: a Tarot when discarded
+        let purple = g.available.card_from_index(0).unwrap();
+        g.modify_card_everywhere(purple.id, |c| c.set_seal(Seal::Purple));
+        g.handle_action(Action::SelectCardIndex(0)).unwrap();
+        g.handle_action(Action::Discard()).unwrap();
+        assert!(matches!(g.consumables.as_slice(), [Consumables::Tarot(_)]));
+
+        // Blue: Planet of the final hand played when held at round end
+        let blue = g.available.card_from_index(0).unwrap();
+        g.modify_card_everywhere(blue.id, |c| c.set_seal(Seal::Blue));
+        g.round_state.last_hand_rank = Some(HandRank::OnePair);
+        g.trigger_round_end();
+        let planet = Planets::planet_for(HandRank::OnePair).unwrap();
+        assert_eq!(g.consumables.last(), Some(&Consumables::Planet(planet)));
+
+        // Red: the card scores twice
+        let mut h = Game::default();
+        h.stage = Stage::Blind(Blind::Small, None);
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let base = h.calc_score(SelectHand::new(vec![ace]).best_hand().unwrap());
+        let mut red = Card::new(Value::Ace, Suit::Heart);
+        red.set_seal(Seal::Red);
+        let mut h = Game::default();
+        h.stage = Stage::Blind(Blind::Small, None);
+        let retriggered = h.calc_score(SelectHand::new(vec![red]).best_hand().unwrap());
+        assert_eq!(retriggered, base + ace.chips());
+    }
+
+    #[test]
+    fn test_huge_scores_saturate() {
+        let mut g = Game::default();
+        g.stage = Stage::Blind(Blind::Small, None);
+        g.blind = Some(Blind::Small);
+        // Stacked X mult far beyond usize
+        g.mult = 1e30;
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let score = g.calc_score(SelectHand::new(vec![ace]).best_hand().unwrap());
+        assert_eq!(score, usize::MAX);
+        assert_eq!(g.mult, g.config.base_mult as f64);
+
+        g.score = 1;
+        assert!(g.handle_score(score).unwrap());
+        assert_eq!(g.score, usize::MAX);
+    }
+
+    #[test]
+    fn test_joker_editions_scoring() {
+        let score_with = |edition: Edition| {
+            let mut g = Game::default();
+            g.stage = Stage::Blind(Blind::Small, None);
+            g.jokers.push(Jokers::TheJoker(crate::joker::TheJoker {}));
+            g.set_joker_edition(0, edition);
+            g.refresh_joker_effects();
+            let ace = Card::new(Value::Ace, Suit::Heart);
+            return g.calc_score(SelectHand::new(vec![ace]).best_hand().unwrap());
+        };
+        // High card ace with The Joker: (5 + 11) chips x (1 + 4) mult
+        assert_eq!(score_with(Edition::Base), 16 * 5);
+        assert_eq!(score_with(Edition::Foil), (16 + 50) * 5);
+        assert_eq!(score_with(Edition::Holographic), 16 * (5 + 10));
+        assert_eq!(score_with(Edition::Polychrome), 16 * 15 / 2);
+        assert_eq!(score_with(Edition::Negative), 16 * 5);
+    }
+
+    #[test]
+    fn test_endless_mode() {
+        // Beating the final boss wins the run by default
+        let mut g = Game::default();
+        g.ante_current = Ante::Eight;
+        g.blind = Some(Blind::Boss);
+        g.stage = Stage::Blind(Blind::Boss, None);
+        g.handle_score(g.required_score()).unwrap();
+        assert_eq!(g.result(), Some(End::Win));
+
+        // Endless mode carries on into ante 9 with a bigger requirement
+        let mut config = Config::default();
+        config.endless = true;
+        let mut g = Game::new(config).unwrap();
+        g.ante_current = Ante::Eight;
+        g.blind = Some(Blind::Boss);
+        g.stage = Stage::Blind(Blind::Boss, None);
+        g.handle_score(g.required_score()).unwrap();
+        assert_eq!(g.stage, Stage::PostBlind());
+        assert_eq!(g.ante_current, Ante::Endless(9));
+
+        g.blind = Some(Blind::Small);
+        g.stage = Stage::Blind(Blind::Small, None);
+        assert_eq!(g.required_score(), Ante::Endless(9).base());
+        g.plays = 0;
+        g.handle_score(0).unwrap();
+        assert_eq!(g.result(), Some(End::Endless));
+        assert!(g.run_summary().won);
+    }
+
+    #[test]
+    fn test_cash_out_breakdown() {
+        let mut g = Game::default();
+        g.blind = Some(Blind::Big);
+        g.stage = Stage::Blind(Blind::Big, None);
+        g.money = 12;
+        g.plays = 3;
+        g.handle_score(g.required_score()).unwrap();
+
+        let breakdown = g.cash_out().cloned().unwrap();
+        assert_eq!(breakdown.blind, Blind::Big);
+        assert_eq!(breakdown.hand_money, 3);
+        assert_eq!(breakdown.interest, 2);
+        assert_eq!(g.reward, breakdown.total());
+        assert!(g.events().contains(&Event::CashOut(breakdown.clone())));
+
+        g.handle_action(Action::CashOut(g.reward)).unwrap();
+        assert_eq!(g.money, 12 + breakdown.total() as i64);
+        assert!(g.cash_out().is_none());
+    }
+
+    #[test]
+    fn test_stake_rules() {
+        use crate::config::Stake;
+
+        let white = Game::new(Config::default()).unwrap();
+        let blue = Game::new(Config::with_stake(Stake::Blue)).unwrap();
+        assert_eq!(blue.config.discards, white.config.discards - 1);
+        assert_eq!(blue.shop.stake, Stake::Blue);
+
+        let mut red = Game::new(Config::with_stake(Stake::Red)).unwrap();
+        red.money = 0;
+        assert_eq!(red.cash_out_for(Blind::Small).total(), red.plays);
+        assert_eq!(red.cash_out_for(Blind::Big).total(), Blind::Big.reward() + red.plays);
+
+        let mut g = Game::new(Config::with_stake(Stake::Green)).unwrap();
+        g.ante_current = Ante::Two;
+        assert_eq!(g.required_score(), 900);
+        g.config.stake = Stake::Purple;
+        assert_eq!(g.required_score(), 1000);
+        g.ante_current = Ante::One;
+        assert_eq!(g.required_score(), Ante::One.base());
+    }
+
+    #[test]
+    fn test_deck_rules() {
+        use crate::alternative_deck::DeckType;
+        use crate::tag::Tag;
+
+        // Green Deck pays for remaining hands and discards, with no interest
+        let mut g = Game::new(Config::with_deck(DeckType::GreenDeck)).unwrap();
+        g.money = 25;
+        g.plays = 2;
+        g.discards = 3;
+        assert_eq!(g.cash_out_for(Blind::Big).total(), Blind::Big.reward() + 2 * 2 + 3);
+
+        // Plasma Deck doubles blinds and balances chips and mult
+        let mut g = Game::new(Config::with_deck(DeckType::PlasmaDeck)).unwrap();
+        g.blind = Some(Blind::Small);
+        assert_eq!(g.required_score(), Ante::One.base() * 2);
+        let king = Card::new(Value::King, Suit::Heart);
+        let king2 = Card::new(Value::King, Suit::Diamond);
+        g.available.extend(vec![king, king2]);
+        g.available.select_card(king).unwrap();
+        g.available.select_card(king2).unwrap();
+        // Pair: 10 + 2 * 10 chips and 2 mult -> ((30 + 2) / 2)^2
+        assert_eq!(g.calc_score_for_test(), 256);
+
+        // Anaglyph Deck gives a Double Tag for beating the boss
+        let mut g = Game::new(Config::with_deck(DeckType::AnaglyPhDeck)).unwrap();
+        g.blind = Some(Blind::Boss);
+        g.stage = Stage::Blind(Blind::Boss, None);
+        g.handle_score(g.required_score()).unwrap();
+        assert_eq!(g.tags, vec![Tag::Double]);
+
+        // Ghost Deck sells Spectral cards without a voucher
+        let mut g = Game::new(Config::with_deck(DeckType::GhostDeck)).unwrap();
+        let spectral = (0..50).any(|_| {
+            g.shop.refresh(&[]);
+            g.shop.consumables.iter().any(|c| matches!(c, Consumables::Spectral(_)))
+        });
+        assert!(spectral);
+    }
+
+    #[test]
+    fn test_joker_stickers() {
+        use crate::joker::TheJoker;
+
+        let mut g = Game::default();
+        g.stage = Stage::Shop();
+        g.money = 10;
+        let rental = Jokers::GreedyJoker(Default::default());
+        g.shop.jokers.clear();
+        g.shop.push_joker(rental.clone(), Edition::Base);
+        g.shop.joker_stickers[0].rental = true;
+        g.buy_joker(rental).unwrap();
+        assert_eq!(g.money, 9);
+        g.trigger_round_end();
+        assert_eq!(g.money, 6);
+
+        // Eternal jokers cannot be sold and survive Ankh-style destruction
+        g.jokers.push(Jokers::TheJoker(TheJoker {}));
+        g.jokers.push(Jokers::LustyJoker(Default::default()));
+        g.set_joker_stickers(1, Stickers { eternal: true, ..Default::default() });
+        assert_eq!(g.sell_joker(Jokers::TheJoker(TheJoker {})), Err(GameError::InvalidAction(None)));
+        assert_eq!(g.destroy_all_jokers_except(2), Some(1));
+        assert_eq!(
+            g.jokers,
+            vec![Jokers::TheJoker(TheJoker {}), Jokers::LustyJoker(Default::default())]
+        );
+        assert!(g.joker_stickers(0).eternal);
+        assert!(!g.joker_stickers(1).eternal);
+
+        // Perishable jokers stop working after 5 rounds
+        g.set_joker_stickers(1, Stickers::perishable());
+        for _ in 0..4 {
+            g.trigger_round_end();
+        }
+        assert_eq!(g.active_jokers().len(), 2);
+        g.trigger_round_end();
+        assert_eq!(g.active_jokers(), vec![Jokers::TheJoker(TheJoker {})]);
+    }
+
+    #[test]
+    fn test_buffoon_pack_joker_stickers() {
+        use crate::booster::PackType;
+        use crate::config::Stake;
+
+        let mut config = Config::default();
+        config.stake = Stake::Gold;
+        let taken: Vec<(Jokers, Stickers)> = (0..100)
+            .map(|seed| {
+                let mut g = Game::with_seed(config.clone(), seed).unwrap();
+                g.start();
+                g.stage = Stage::Shop();
+                g.money = 20;
+                g.shop.packs = vec![PackType::Buffoon.into()];
+                g.handle_action(Action::BuyBooster(PackType::Buffoon.into())).unwrap();
+                g.handle_action(Action::SelectFromPack(0)).unwrap();
+                (g.jokers[0].clone(), g.joker_stickers(0))
+            })
+            .collect();
+        assert!(taken.iter().any(|(_, s)| s.rental));
+        assert!(taken.iter().any(|(_, s)| s.eternal || s.perishable.is_some()));
+        assert!(taken.iter().all(|(j, s)| !s.eternal || j.eternal_compatible()));
+    }
+
+    #[test]
+    fn test_negative_joker_slot() {
+        use crate::joker::TheJoker;
+
+        let mut g = Game::default();
+        g.stage = Stage::Shop();
+        g.money = 100;
+        g.jokers = vec![Jokers::TheJoker(TheJoker {}); g.config.joker_slots];
+
+        // Full slots: a plain joker cannot be bought, a Negative one can
+        g.shop.jokers.clear();
+        g.shop.joker_editions.clear();
+        let plain = Jokers::GreedyJoker(Default::default());
+        let negative = Jokers::LustyJoker(Default::default());
+        g.shop.push_joker(plain.clone(), Edition::Base);
+        g.shop.push_joker(negative.clone(), Edition::Negative);
+        let buys: Vec<Action> = g.gen_actions().filter(|a| matches!(a, Action::BuyJoker(_))).collect();
+        assert_eq!(buys, vec![Action::BuyJoker(negative.clone())]);
+        assert_eq!(g.buy_joker(plain.clone()), Err(GameError::NoAvailableSlot));
+        assert!(!g.has_joker_room(Edition::Base));
+        assert!(g.has_joker_room(Edition::Negative));
+
+        // The action space masks the same buys
+        let space = g.gen_action_space();
+        let plain_index = space.action_to_index(&Action::BuyJoker(plain.clone()), &g).unwrap();
+        let negative_index = space.action_to_index(&Action::BuyJoker(negative.clone()), &g).unwrap();
+        assert!(!space.is_legal(plain_index));
+        assert!(space.is_legal(negative_index));
+
+        g.buy_joker(negative.clone()).unwrap();
+        assert_eq!(g.money, 100 - (negative.cost() + Edition::Negative.extra_cost()) as i64);
+        assert_eq!(g.jokers.len(), g.config.joker_slots + 1);
+        assert_eq!(g.joker_slots(), g.config.joker_slots + 1);
+        assert_eq!(g.joker_edition(g.jokers.len() - 1), Edition::Negative);
+        // Still full for anything but another Negative joker
+        assert!(!g.has_joker_room(Edition::Base));
+
+        // Selling it gives the slot back and keeps the other editions in place
+        g.set_joker_edition(0, Edition::Foil);
+        g.sell_joker(negative).unwrap();
+        assert_eq!(g.joker_slots(), g.config.joker_slots);
+        assert_eq!(g.joker_edition(0), Edition::Foil);
+    }
+
+    #[test]
+    fn test_sell_and_move_joker_by_slot() {
+        use crate::joker::TheJoker;
+
+        let mut g = Game::default();
+        g.stage = Stage::Shop();
+        let greedy = Jokers::GreedyJoker(Default::default());
+        let lusty = Jokers::LustyJoker(Default::default());
+        g.jokers = vec![Jokers::TheJoker(TheJoker {}), greedy.clone(), lusty.clone()];
+        g.set_joker_edition(0, Edition::Foil);
+        g.set_joker_stickers(1, Stickers { eternal: true, ..Default::default() });
+
+        // Editions and stickers move with their joker
+        g.handle_action(Action::MoveJoker(0, 2)).unwrap();
+        assert_eq!(g.jokers, vec![greedy.clone(), lusty.clone(), Jokers::TheJoker(TheJoker {})]);
+        assert_eq!(g.joker_edition(2), Edition::Foil);
+        assert!(g.joker_stickers(0).eternal);
+        g.handle_action(Action::MoveJoker(2, 1)).unwrap();
+        assert_eq!(g.jokers[1], Jokers::TheJoker(TheJoker {}));
+        assert_eq!(g.joker_edition(1), Edition::Foil);
+        assert_eq!(g.move_joker(1, 3), Err(GameError::InvalidMoveJoker));
+
+        // Sell by slot, the Eternal joker stays
+        let money = g.money;
+        assert!(g.handle_action(Action::SellJoker(0)).is_err());
+        g.handle_action(Action::SellJoker(1)).unwrap();
+        assert_eq!(g.money, money + (TheJoker {}.sell_value() + Edition::Foil.extra_cost() / 2) as i64);
+        assert_eq!(g.jokers, vec![greedy, lusty]);
+
+        g.stage = Stage::PreBlind();
+        assert!(g.handle_action(Action::SellJoker(0)).is_err());
+        assert!(g.handle_action(Action::MoveJoker(0, 1)).is_err());
+    }
+
+    #[test]
+    fn test_held_enhancements() {
+        use crate::card::Enhancement;
+
+        let score_with_held = |enhancement: Option<Enhancement>| {
+            let mut g = Game::with_seed(Config::default(), 7).unwrap();
+            g.start();
+            g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+            if let Some(enhancement) = enhancement {
+                let held = g.available.card_from_index(1).unwrap();
+                g.modify_card_everywhere(held.id, |c| c.set_enhancement(enhancement));
+            }
+            g.handle_action(Action::SelectCardIndex(0)).unwrap();
+            return g.calc_score_for_test();
+        };
+        // Steel: x1.5 mult while held, nothing for other enhancements
+        let base = score_with_held(None);
+        assert_eq!(score_with_held(Some(Enhancement::Steel)), (base as f32 * 1.5) as usize);
+        assert_eq!(score_with_held(Some(Enhancement::Bonus)), base);
+
+        // Gold: $3 when held at round end
+        let mut g = Game::with_seed(Config::default(), 7).unwrap();
+        g.start();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        let gold = g.available.card_from_index(0).unwrap();
+        g.modify_card_everywhere(gold.id, |c| c.set_enhancement(Enhancement::Gold));
+        let money = g.money;
+        g.trigger_round_end();
+        assert_eq!(g.money, money + 3);
+    }
+
+    #[test]
+    fn test_booster_pack_flow() {
+        use crate::booster::{PackSize, PackType};
+        use crate::provenance::CardOrigin;
+
+        let mut g = Game::with_seed(Config::default(), 12).unwrap();
+        g.start();
+        g.stage = Stage::Shop();
+        g.money = 20;
+        let mega = BoosterPack::new(PackType::Arcana, PackSize::Mega);
+        g.shop.packs = vec![mega];
+        assert!(g.gen_actions().any(|a| a == Action::BuyBooster(mega)));
+
+        g.handle_action(Action::BuyBooster(mega)).unwrap();
+        assert_eq!(g.money, 12);
+        assert_eq!(g.stage, Stage::OpenPack());
+        let actions: Vec<Action> = g.gen_actions().collect();
+        assert_eq!(actions.len(), 6);
+        assert_eq!(actions.last(), Some(&Action::SkipPack()));
+        assert!(g.handle_action(Action::NextRound()).is_err());
+
+        // Mega packs allow two picks, then the game returns to the shop
+        g.handle_action(Action::SelectFromPack(0)).unwrap();
+        assert_eq!(g.stage, Stage::OpenPack());
+        assert_eq!(g.shop.open_pack.as_ref().unwrap().len(), 4);
+        g.handle_action(Action::SelectFromPack(3)).unwrap();
+        assert_eq!(g.consumables.len(), 2);
+        assert_eq!(g.stage, Stage::Shop());
+        assert!(g.shop.open_pack.is_none());
+
+        // Standard pack cards go into the deck
+        g.shop.packs = vec![PackType::Standard.into()];
+        g.handle_action(Action::BuyBooster(PackType::Standard.into())).unwrap();
+        let card = g.shop.open_pack.as_ref().unwrap().get_cards().unwrap()[1];
+        let deck_size = g.deck.len();
+        g.handle_action(Action::SelectFromPack(1)).unwrap();
+        assert_eq!(g.deck.len(), deck_size + 1);
+        let added = g.deck.cards().into_iter().last().unwrap();
+        assert_eq!((added.value, added.suit), (card.value, card.suit));
+        assert_eq!(
+            g.card_history(added.id).unwrap().origin,
+            CardOrigin::Created("Standard Pack".to_string())
+        );
+
+        // No room for a joker: the pick is rejected and the pack stays open
+        g.money = 20;
+        g.jokers = vec![Jokers::TheJoker(crate::joker::TheJoker {}); g.joker_slots()];
+        g.shop.packs = vec![PackType::Buffoon.into()];
+        g.handle_action(Action::BuyBooster(PackType::Buffoon.into())).unwrap();
+        assert!(!g.gen_actions().any(|a| matches!(a, Action::SelectFromPack(_))));
+        assert!(g.handle_action(Action::SelectFromPack(0)).is_err());
+        assert_eq!(g.shop.open_pack.as_ref().unwrap().len(), 2);
+        g.handle_action(Action::SkipPack()).unwrap();
+        assert_eq!(g.stage, Stage::Shop());
+    }
+
+    #[test]
+    fn test_celestial_pack_planet_used_at_once() {
+        use crate::booster::PackType;
+        use crate::planet::Planets;
+
+        let mut g = Game::with_seed(Config::default(), 12).unwrap();
+        g.start();
+        g.stage = Stage::Shop();
+        g.money = 20;
+        g.vouchers = vec![Vouchers::Telescope, Vouchers::Observatory];
+        g.stats.hands_played.insert(HandRank::Flush, 3);
+        let planet = Consumables::Planet(Planets::planet_for(HandRank::Flush).unwrap());
+        g.consumables = vec![planet.clone(); g.config.consumable_slots];
+
+        // Telescope puts the most played hand's Planet in the pack, taking
+        // it levels the hand up without a free consumable slot
+        g.shop.packs = vec![PackType::Celestial.into()];
+        g.handle_action(Action::BuyBooster(PackType::Celestial.into())).unwrap();
+        let index = (0..g.shop.open_pack.as_ref().unwrap().len())
+            .find(|i| {
+                let selection = g.shop.open_pack.as_ref().unwrap().select(*i);
+                selection.and_then(|s| s.to_consumable()) == Some(planet.clone())
+            })
+            .expect("Telescope adds the Planet");
+        let level = g.get_hand_level(HandRank::Flush).level;
+        assert!(g.gen_actions().any(|a| a == Action::SelectFromPack(index)));
+        g.handle_action(Action::SelectFromPack(index)).unwrap();
+        assert_eq!(g.get_hand_level(HandRank::Flush).level, level + 1);
+        assert_eq!(g.last_consumable_used, Some(planet.clone()));
+        assert_eq!(g.stage, Stage::Shop());
+
+        // The held Planets still count for Observatory
+        assert_eq!(g.consumables.len(), g.config.consumable_slots);
+        assert_eq!(g.observatory_x_mult(HandRank::Flush), 1.5_f64.powi(g.consumables.len() as i32));
+    }
+
+    #[test]
+    fn test_buy_and_use_consumable() {
+        use crate::planet::Planets;
+        use crate::tarot::Tarots;
+
+        let mut g = Game::with_seed(Config::default(), 12).unwrap();
+        g.start();
+        g.stage = Stage::Shop();
+        g.money = 10;
+        let planet = Consumables::Planet(Planets::planet_for(HandRank::OnePair).unwrap());
+        g.consumables = vec![planet.clone(); g.config.consumable_slots];
+        g.shop.consumables = vec![planet.clone(), Consumables::Tarot(Tarots::TheMagician)];
+
+        // Consumables with targets cannot be used in the shop
+        let actions: Vec<Action> = g.gen_actions().collect();
+        assert!(actions.contains(&Action::BuyAndUseConsumable(0)));
+        assert!(!actions.contains(&Action::BuyAndUseConsumable(1)));
+        assert!(!actions.contains(&Action::BuyConsumable(0)));
+        assert!(g.handle_action(Action::BuyAndUseConsumable(1)).is_err());
+
+        let level = g.get_hand_level(HandRank::OnePair).level;
+        let cost = g.consumable_cost(&planet);
+        g.handle_action(Action::BuyAndUseConsumable(0)).unwrap();
+        assert_eq!(g.get_hand_level(HandRank::OnePair).level, level + 1);
+        assert_eq!(g.money, 10 - cost as i64);
+        assert_eq!(g.consumables.len(), g.config.consumable_slots);
+        assert_eq!(g.shop.consumables, vec![Consumables::Tarot(Tarots::TheMagician)]);
+    }
+
+    #[test]
+    fn test_sort_hand_keeps_order_across_draws() {
+        let mut g = Game::with_seed(Config::default(), 8).unwrap();
+        g.start();
+        assert!(g.sort_hand(SortMode::ByRank).is_err());
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        g.sort_hand(SortMode::ByRank).unwrap();
+        let keys = |g: &Game| -> Vec<_> {
+            g.available.iter_cards().map(|c| SortMode::ByRank.key(&c)).collect()
+        };
+        assert!(keys(&g).is_sorted());
+
+        // Drawn cards are sorted in, the hand stays sorted
+        g.handle_action(Action::SelectCardIndex(0)).unwrap();
+        g.handle_action(Action::SelectCardIndex(1)).unwrap();
+        g.handle_action(Action::Discard()).unwrap();
+        assert_eq!(g.available.cards().len(), g.hand_size_target());
+        assert!(keys(&g).is_sorted());
+
+        // Recorded, so a replay selects the same cards by position
+        assert!(g.action_history.to_vec().contains(&Action::SortHand(SortMode::ByRank)));
+        #[cfg(feature = "serde")]
+        {
+            let replayed = Game::replay(&g.to_replay()).unwrap();
+            assert_eq!(replayed.available.cards(), g.available.cards());
+        }
+    }
+
+    #[test]
+    fn test_buy_consumable_from_shop_slot() {
+        use crate::planet::Planets;
+        use crate::tarot::Tarots;
+
+        let mut g = Game::with_seed(Config::default(), 12).unwrap();
+        g.start();
+        g.stage = Stage::PostBlind();
+        g.handle_action(Action::CashOut(0)).unwrap();
+        assert_eq!(g.shop.consumables.len(), g.shop.config.consumable_slots);
+
+        let tarot = Consumables::Tarot(Tarots::TheHermit);
+        let planet = Consumables::Planet(Planets::Mercury);
+        g.shop.consumables = vec![tarot.clone(), planet.clone()];
+        g.shop.config.price_multiplier = 0.5;
+        g.money = 0;
+        assert!(!g.gen_actions().any(|a| matches!(a, Action::BuyConsumable(_))));
+        assert!(g.handle_action(Action::BuyConsumable(0)).is_err());
+        assert!(g.handle_action(Action::BuyConsumable(2)).is_err());
+
+        // Discounted to $1, the slot empties once bought
+        g.money = 3;
+        g.handle_action(Action::BuyConsumable(1)).unwrap();
+        assert_eq!(g.money, 2);
+        assert_eq!(g.consumables, vec![planet]);
+        assert_eq!(g.shop.consumables, vec![tarot.clone()]);
+        assert!(g.gen_actions().any(|a| a == Action::BuyConsumable(0)));
+        g.handle_action(Action::BuyConsumable(0)).unwrap();
+        assert_eq!(g.consumables.last(), Some(&tarot));
+        assert!(g.shop.consumables.is_empty());
+    }
+
+    #[test]
+    fn test_reroll_shop_cost() {
+        let mut g = Game::with_seed(Config::default(), 12).unwrap();
+        g.start();
+        g.stage = Stage::PostBlind();
+        g.money = 11;
+        g.handle_action(Action::CashOut(0)).unwrap();
+        let before = g.shop.jokers.clone();
+        g.drain_events();
+
+        // $5, then +$1 for every reroll
+        assert_eq!(g.reroll_cost(), 5);
+        g.handle_action(Action::Reroll()).unwrap();
+        assert_ne!(g.shop.jokers, before);
+        assert_eq!(g.drain_events(), vec![Event::ShopRerolled { cost: 5 }, Event::MoneySpent(5)]);
+        assert_eq!((g.money, g.reroll_cost()), (6, 6));
+        g.handle_action(Action::Reroll()).unwrap();
+        assert_eq!(g.money, 0);
+
+        // Masked out and rejected when unaffordable
+        assert!(!g.gen_actions().any(|a| a == Action::Reroll()));
+        assert!(!g.gen_action_space().is_legal(g.gen_action_space().reroll_min()));
+        assert_eq!(g.handle_action(Action::Reroll()), Err(GameError::InvalidBalance));
+
+        // The next shop starts over, Reroll Surplus takes $2 off
+        g.vouchers.push(Vouchers::Reroll);
+        g.stage = Stage::PostBlind();
+        g.money = 3;
+        g.handle_action(Action::CashOut(0)).unwrap();
+        assert_eq!(g.reroll_cost(), 3);
+        let space = g.gen_action_space();
+        assert_eq!(space.to_action(space.reroll_min(), &g).unwrap(), Action::Reroll());
+        g.handle_action_index(space.reroll_min()).unwrap();
+        assert_eq!((g.money, g.reroll_cost()), (0, 4));
+    }
+
+    #[test]
+    fn test_chaos_the_clown_free_reroll() {
+        use crate::joker::ChaosTheClown;
+        let mut g = Game::with_seed(Config::default(), 12).unwrap();
+        g.start();
+        g.jokers.push(Jokers::ChaosTheClown(ChaosTheClown {}));
+        g.stage = Stage::PostBlind();
+        g.money = 5;
+        g.handle_action(Action::CashOut(0)).unwrap();
+
+        // One free reroll per shop, it does not raise the cost
+        assert_eq!((g.free_rerolls(), g.reroll_cost()), (1, 0));
+        g.handle_action(Action::Reroll()).unwrap();
+        assert_eq!(g.money, 5);
+        assert_eq!((g.free_rerolls(), g.reroll_cost()), (0, 5));
+        g.handle_action(Action::Reroll()).unwrap();
+        assert_eq!((g.money, g.reroll_cost()), (0, 6));
+
+        g.stage = Stage::PostBlind();
+        g.handle_action(Action::CashOut(0)).unwrap();
+        assert_eq!(g.free_rerolls(), 1);
+    }
+
+    #[test]
+    fn test_buy_voucher() {
+        let mut g = Game::with_seed(Config::default(), 12).unwrap();
+        g.start();
+        g.stage = Stage::PostBlind();
+        g.money = 20;
+        g.handle_action(Action::CashOut(0)).unwrap();
+        let offered = g.shop.voucher.expect("ante 1 shop has a voucher");
+
+        // Rerolling keeps the voucher for the ante
+        g.shop.reroll_with_jokers(&g.jokers, &g.vouchers);
+        assert_eq!(g.shop.voucher, Some(offered));
+
+        g.shop.voucher = Some(Vouchers::Grabber);
+        g.money = 9;
+        assert!(!g.gen_actions().any(|a| matches!(a, Action::BuyVoucher(_))));
+        assert!(g.handle_action(Action::BuyVoucher(Vouchers::Grabber)).is_err());
+        assert!(g.handle_action(Action::BuyVoucher(Vouchers::Wasteful)).is_err());
+
+        g.money = 10;
+        assert!(g.gen_actions().any(|a| a == Action::BuyVoucher(Vouchers::Grabber)));
+        g.handle_action(Action::BuyVoucher(Vouchers::Grabber)).unwrap();
+        assert_eq!(g.money, 0);
+        assert_eq!(g.vouchers, vec![Vouchers::Grabber]);
+        assert_eq!(g.shop.voucher, None);
+        assert_eq!(g.config.plays, Config::default().plays + 1);
+
+        // The slot stays empty until the next ante
+        g.stage = Stage::PostBlind();
+        g.handle_action(Action::CashOut(0)).unwrap();
+        assert_eq!(g.shop.voucher, None);
+        g.stage = Stage::PostBlind();
+        g.ante_current = g.ante_current.next(g.ante_end).unwrap();
+        g.handle_action(Action::CashOut(0)).unwrap();
+        assert!(g.shop.voucher.is_some());
+        assert_ne!(g.shop.voucher, Some(Vouchers::Grabber));
+
+        g.stage = Stage::PreBlind();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        assert_eq!(g.plays, Config::default().plays + 1);
+    }
+
+    #[test]
+    fn test_seeded_entry_points_replay() {
+        // Rolls made outside `handle_action` still come from the game's seed
+        let run = |seed: u64| {
+            let mut g = Game::with_seed(Config::default(), seed).unwrap();
+            let mut lucky = Card::new(Value::Ace, Suit::Heart);
+            lucky.set_enhancement(crate::card::Enhancement::Lucky);
+            let hand = SelectHand::new(vec![lucky]).best_hand().unwrap();
+            let scores: Vec<usize> = (0..20).map(|_| g.calc_score(hand.clone())).collect();
+            for _ in 0..8 {
+                g.create_random_tarot();
+            }
+            let card = g.create_enhanced_number();
+            return (scores, g.consumables.clone(), (card.value, card.suit, card.enhancement), g.rng.clone());
+        };
+        assert_eq!(run(42), run(42));
+        assert_ne!(run(42).3, run(43).3);
+    }
+
+    #[cfg(feature = "rng-audit")]
+    #[test]
+    fn test_rng_log_records_deal() {
+        // `start` also rolls the boss and skip tag, deal on its own
+        let mut g = Game::with_seed(Config::default(), 5).unwrap();
+        g.clear_rng_log();
+        g.with_rng(|g| g.deal());
+        let log = g.rng_log();
+        assert!(!log.is_empty());
+        assert!(log.iter().all(|d| d.subsystem == "deck" && d.purpose == "shuffle"));
+
+        // Same seed draws the same values
+        let mut h = Game::with_seed(Config::default(), 5).unwrap();
+        h.clear_rng_log();
+        h.with_rng(|h| h.deal());
+        assert_eq!(g.rng_log(), h.rng_log());
+    }
 }
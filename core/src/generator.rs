@@ -1,26 +1,27 @@
-use crate::action::{Action, MoveDirection};
+use crate::action::{Action, ActionKind, MoveDirection};
+use crate::booster::PackSelection;
 use crate::consumable::Consumable;
 use crate::game::Game;
-use crate::joker::Joker;
 use crate::space::ActionSpace;
 use crate::stage::{Blind, Stage};
+use enumset::EnumSet;
+use itertools::Itertools;
+use std::iter;
 
 impl Game {
     // Get all legal SelectCard actions that can be executed given current state
-    fn gen_actions_select_card(&self) -> Option<impl Iterator<Item = Action>> {
+    fn gen_actions_select_card(&self) -> Option<impl Iterator<Item = Action> + use<'_>> {
         // Can only select card during blinds
         if !self.stage.is_blind() {
             return None;
         }
         // Cannot select more than max
-        if self.available.selected().len() >= self.config.selected_max {
+        if self.available.selected_count() >= self.config.selected_max {
             return None;
         }
         let combos = self
             .available
-            .not_selected()
-            .clone()
-            .into_iter()
+            .iter_not_selected()
             .map(|c| Action::SelectCard(c));
         return Some(combos);
     }
@@ -36,11 +37,14 @@ impl Game {
             return None;
         }
         // If no cards selected, return None
-        if self.available.selected().len() == 0 {
+        if self.available.selected_count() == 0 {
             return None;
         }
-        let combos = vec![Action::Play()].into_iter();
-        return Some(combos);
+        // The Mouth and The Eye reject some hand types
+        if !self.selected_hand_allowed() {
+            return None;
+        }
+        return Some(iter::once(Action::Play()));
     }
 
     // Get all legal Play actions that can be executed given current state
@@ -54,34 +58,28 @@ impl Game {
             return None;
         }
         // If no cards selected, return None
-        if self.available.selected().len() == 0 {
+        if self.available.selected_count() == 0 {
             return None;
         }
-        let combos = vec![Action::Discard()].into_iter();
-        return Some(combos);
+        return Some(iter::once(Action::Discard()));
     }
 
     // Get all legal move card actions
-    fn gen_actions_move_card(&self) -> Option<impl Iterator<Item = Action>> {
+    fn gen_actions_move_card(&self) -> Option<impl Iterator<Item = Action> + use<'_>> {
         // Can only move cards during blinds
         if !self.stage.is_blind() {
             return None;
         }
+        let last = self.available.iter_cards().len().saturating_sub(1);
         let left = self
             .available
-            .cards()
-            .clone()
-            .into_iter()
+            .iter_cards()
             .skip(1)
             .map(|c| Action::MoveCard(MoveDirection::Left, c));
         let right = self
             .available
-            .cards()
-            .clone()
-            .into_iter()
-            .rev()
-            .skip(1)
-            .rev()
+            .iter_cards()
+            .take(last)
             .map(|c| Action::MoveCard(MoveDirection::Right, c));
 
         let combos = left.chain(right);
@@ -94,7 +92,7 @@ impl Game {
         if self.stage != Stage::PostBlind() {
             return None;
         }
-        return Some(vec![Action::CashOut(self.reward)].into_iter());
+        return Some(iter::once(Action::CashOut(self.reward)));
     }
 
     // Get next round action
@@ -103,7 +101,7 @@ impl Game {
         if self.stage != Stage::Shop() {
             return None;
         }
-        return Some(vec![Action::NextRound()].into_iter());
+        return Some(iter::once(Action::NextRound()));
     }
 
     // Get select blind action
@@ -113,23 +111,35 @@ impl Game {
             return None;
         }
         if let Some(blind) = self.blind {
-            return Some(vec![Action::SelectBlind(blind.next())].into_iter());
+            return Some(iter::once(Action::SelectBlind(blind.next())));
         } else {
-            return Some(vec![Action::SelectBlind(Blind::Small)].into_iter());
+            return Some(iter::once(Action::SelectBlind(Blind::Small)));
+        }
+    }
+
+    // Get skip blind action, only Small and Big blinds can be skipped
+    fn gen_actions_skip_blind(&self) -> Option<impl Iterator<Item = Action>> {
+        if self.stage != Stage::PreBlind() {
+            return None;
         }
+        if self.blind.map_or(Blind::Small, |b| b.next()) == Blind::Boss {
+            return None;
+        }
+        return Some(iter::once(Action::SkipBlind()));
     }
 
     // Get buy joker actions
-    fn gen_actions_buy_joker(&self) -> Option<impl Iterator<Item = Action>> {
+    fn gen_actions_buy_joker(&self) -> Option<impl Iterator<Item = Action> + use<'_>> {
         // If stage is not shop, cannot buy
         if self.stage != Stage::Shop() {
             return None;
         }
-        // Cannot buy if all joker slots full
-        if self.jokers.len() >= self.config.joker_slots {
-            return None;
-        }
-        return self.shop.gen_moves_buy_joker(self.money);
+        // With all joker slots full only Negative jokers can be bought
+        let buys = self.shop.gen_moves_buy_joker(self.spendable_money())?;
+        return Some(buys.filter(move |action| match action {
+            Action::BuyJoker(joker) => self.has_joker_room(self.shop.joker_edition(joker)),
+            _ => true,
+        }));
     }
 
     // Get buy consumable actions
@@ -142,14 +152,29 @@ impl Game {
         if self.consumables.len() >= self.config.consumable_slots {
             return None;
         }
-        let money = self.money;
         let buys = self
             .shop
             .consumables
-            .clone()
-            .into_iter()
-            .filter(move |c| c.cost() <= money)
-            .map(|c| Action::BuyConsumable(c));
+            .iter()
+            .enumerate()
+            .filter(move |(_, c)| self.can_afford(self.consumable_cost(c)))
+            .map(|(slot, _)| Action::BuyConsumable(slot));
+        return Some(buys);
+    }
+
+    // Get buy and use actions, for shop consumables that need no targets.
+    // They never take a consumable slot.
+    fn gen_actions_buy_and_use_consumable(&self) -> Option<impl Iterator<Item = Action> + use<'_>> {
+        if self.stage != Stage::Shop() {
+            return None;
+        }
+        let buys = self
+            .shop
+            .consumables
+            .iter()
+            .enumerate()
+            .filter(move |(_, c)| !c.requires_target() && self.can_afford(self.consumable_cost(c)))
+            .map(|(slot, _)| Action::BuyAndUseConsumable(slot));
         return Some(buys);
     }
 
@@ -159,15 +184,35 @@ impl Game {
         if !self.stage.is_blind() && self.stage != Stage::Shop() {
             return None;
         }
-        // For now, only generate actions for consumables that don't require targets
-        // TODO: Handle targeted consumables
-        let uses = self
-            .consumables
-            .clone()
-            .into_iter()
-            .filter(|c| !c.requires_target())
-            .map(|c| Action::UseConsumable(c, None));
-        return Some(uses);
+        // Targeted consumables act on cards in hand, so only during blinds.
+        // Every combination of min..=max targets over the available cards is
+        // offered, at most C(available, 3) per consumable.
+        let ids: Vec<usize> = self.available.iter_cards().map(|c| c.id).collect();
+        let mut uses = Vec::new();
+        for c in &self.consumables {
+            let spec = c.target_spec();
+            if !spec.requires_target() {
+                uses.push(Action::UseConsumable(c.clone(), None));
+                continue;
+            }
+            if !self.stage.is_blind() {
+                continue;
+            }
+            for n in spec.min..=spec.max.min(ids.len()) {
+                for targets in ids.iter().copied().combinations(n) {
+                    uses.push(Action::UseConsumable(c.clone(), Some(targets)));
+                }
+            }
+        }
+        return Some(uses.into_iter());
+    }
+
+    // Get sell consumable actions
+    fn gen_actions_sell_consumable(&self) -> Option<impl Iterator<Item = Action>> {
+        if !self.can_manage_items() {
+            return None;
+        }
+        return Some((0..self.consumables.len()).map(Action::SellConsumable));
     }
 
     // Get select from tag pack actions
@@ -182,6 +227,75 @@ impl Game {
         None
     }
 
+    // Get buy booster pack actions
+    fn gen_actions_buy_booster(&self) -> Option<impl Iterator<Item = Action> + use<'_>> {
+        // If stage is not shop, cannot buy
+        if self.stage != Stage::Shop() {
+            return None;
+        }
+        return self.shop.gen_moves_buy_booster(self.spendable_money());
+    }
+
+    // Get buy voucher action
+    fn gen_actions_buy_voucher(&self) -> Option<impl Iterator<Item = Action>> {
+        // If stage is not shop, cannot buy
+        if self.stage != Stage::Shop() {
+            return None;
+        }
+        return self.shop.gen_moves_buy_voucher(self.spendable_money());
+    }
+
+    // Get reroll action, if the shop can be rerolled for what the player has
+    fn gen_actions_reroll(&self) -> Option<impl Iterator<Item = Action>> {
+        if self.stage != Stage::Shop() || !self.can_afford(self.reroll_cost()) {
+            return None;
+        }
+        return Some(iter::once(Action::Reroll()));
+    }
+
+    // Get select from / skip actions for the open booster pack
+    fn gen_actions_open_pack(&self) -> Option<impl Iterator<Item = Action> + use<>> {
+        if self.stage != Stage::OpenPack() {
+            return None;
+        }
+        let pack = self.shop.open_pack.as_ref()?;
+        let joker_room = self.jokers.len() < self.joker_slots();
+        let consumable_room = self.consumables.len() < self.config.consumable_slots;
+        // Only offer items there is room for, skipping is always legal
+        let selects: Vec<Action> = (0..pack.len())
+            .filter(|i| match pack.select(*i) {
+                Some(PackSelection::Joker(_)) => joker_room,
+                Some(PackSelection::Card(_)) | Some(PackSelection::Planet(_)) => true,
+                Some(_) => consumable_room,
+                None => false,
+            })
+            .map(Action::SelectFromPack)
+            .collect();
+        return Some(selects.into_iter().chain(iter::once(Action::SkipPack())));
+    }
+
+    // Get sell joker actions, Eternal jokers cannot be sold
+    fn gen_actions_sell_joker(&self) -> Option<impl Iterator<Item = Action> + use<'_>> {
+        if !self.can_manage_items() {
+            return None;
+        }
+        let sells = (0..self.jokers.len())
+            .filter(|i| !self.joker_stickers(*i).eternal)
+            .map(Action::SellJoker);
+        return Some(sells);
+    }
+
+    // Get move joker actions, one slot left or right at a time
+    fn gen_actions_move_joker(&self) -> Option<impl Iterator<Item = Action>> {
+        if !self.can_manage_items() {
+            return None;
+        }
+        let count = self.jokers.len();
+        let left = (1..count).map(|i| Action::MoveJoker(i, i - 1));
+        let right = (0..count.saturating_sub(1)).map(|i| Action::MoveJoker(i, i + 1));
+        return Some(left.chain(right));
+    }
+
     // Get all legal actions that can be executed given current state
     pub fn gen_actions(&self) -> impl Iterator<Item = Action> + use<'_> {
         let select_cards = self.gen_actions_select_card();
@@ -191,10 +305,19 @@ impl Game {
         let cash_outs = self.gen_actions_cash_out();
         let next_rounds = self.gen_actions_next_round();
         let select_blinds = self.gen_actions_select_blind();
+        let skip_blinds = self.gen_actions_skip_blind();
         let buy_jokers = self.gen_actions_buy_joker();
         let buy_consumables = self.gen_actions_buy_consumable();
+        let buy_and_use_consumables = self.gen_actions_buy_and_use_consumable();
         let use_consumables = self.gen_actions_use_consumable();
         let select_from_tag_pack = self.gen_actions_select_from_tag_pack();
+        let buy_boosters = self.gen_actions_buy_booster();
+        let buy_vouchers = self.gen_actions_buy_voucher();
+        let rerolls = self.gen_actions_reroll();
+        let open_pack = self.gen_actions_open_pack();
+        let sell_jokers = self.gen_actions_sell_joker();
+        let move_jokers = self.gen_actions_move_joker();
+        let sell_consumables = self.gen_actions_sell_consumable();
 
         return select_cards
             .into_iter()
@@ -205,10 +328,67 @@ impl Game {
             .chain(cash_outs.into_iter().flatten())
             .chain(next_rounds.into_iter().flatten())
             .chain(select_blinds.into_iter().flatten())
+            .chain(skip_blinds.into_iter().flatten())
             .chain(buy_jokers.into_iter().flatten())
             .chain(buy_consumables.into_iter().flatten())
+            .chain(buy_and_use_consumables.into_iter().flatten())
             .chain(use_consumables.into_iter().flatten())
-            .chain(select_from_tag_pack.into_iter().flatten());
+            .chain(select_from_tag_pack.into_iter().flatten())
+            .chain(buy_boosters.into_iter().flatten())
+            .chain(buy_vouchers.into_iter().flatten())
+            .chain(rerolls.into_iter().flatten())
+            .chain(open_pack.into_iter().flatten())
+            .chain(sell_jokers.into_iter().flatten())
+            .chain(move_jokers.into_iter().flatten())
+            .chain(sell_consumables.into_iter().flatten());
+    }
+
+    /// Kinds of the actions `gen_actions` would give, without generating
+    /// them all, for frontends enabling whole sections of their interface
+    pub fn legal_action_kinds(&self) -> EnumSet<ActionKind> {
+        let mut kinds = EnumSet::new();
+        let mut add = |kind: ActionKind, legal: bool| {
+            if legal {
+                kinds.insert(kind);
+            }
+        };
+        add(ActionKind::SelectCard, any(self.gen_actions_select_card()));
+        add(ActionKind::Play, any(self.gen_actions_play()));
+        add(ActionKind::Discard, any(self.gen_actions_discard()));
+        add(ActionKind::MoveCard, any(self.gen_actions_move_card()));
+        add(ActionKind::CashOut, any(self.gen_actions_cash_out()));
+        add(ActionKind::NextRound, any(self.gen_actions_next_round()));
+        add(ActionKind::SelectBlind, any(self.gen_actions_select_blind()));
+        add(ActionKind::SkipBlind, any(self.gen_actions_skip_blind()));
+        add(ActionKind::BuyJoker, any(self.gen_actions_buy_joker()));
+        add(ActionKind::BuyConsumable, any(self.gen_actions_buy_consumable()));
+        add(ActionKind::BuyAndUseConsumable, any(self.gen_actions_buy_and_use_consumable()));
+        add(ActionKind::UseConsumable, self.can_use_consumable());
+        add(ActionKind::SelectFromTagPack, any(self.gen_actions_select_from_tag_pack()));
+        add(ActionKind::BuyBooster, any(self.gen_actions_buy_booster()));
+        add(ActionKind::BuyVoucher, any(self.gen_actions_buy_voucher()));
+        add(ActionKind::Reroll, any(self.gen_actions_reroll()));
+        add(ActionKind::SellJoker, any(self.gen_actions_sell_joker()));
+        add(ActionKind::MoveJoker, any(self.gen_actions_move_joker()));
+        add(ActionKind::SellConsumable, any(self.gen_actions_sell_consumable()));
+        // Only the pack's own items, a handful at most
+        for action in self.gen_actions_open_pack().into_iter().flatten() {
+            kinds.insert(action.kind());
+        }
+        return kinds;
+    }
+
+    // Whether `gen_actions_use_consumable` gives anything, without listing
+    // every combination of targets
+    fn can_use_consumable(&self) -> bool {
+        if !self.stage.is_blind() && self.stage != Stage::Shop() {
+            return false;
+        }
+        let cards = self.available.iter_cards().len();
+        return self.consumables.iter().any(|c| {
+            let spec = c.target_spec();
+            return !spec.requires_target() || (self.stage.is_blind() && spec.min <= spec.max.min(cards));
+        });
     }
 
     fn unmask_action_space_select_cards(&self, space: &mut ActionSpace) {
@@ -216,15 +396,12 @@ impl Game {
             return;
         }
         // Cannot select more if max already selected
-        if self.available.selected().len() >= self.config.selected_max {
+        if self.available.selected_count() >= self.config.selected_max {
             return;
         }
-        self.available
-            .cards_and_selected()
-            .iter()
-            .enumerate()
-            .filter(|(_, (_, a))| !*a)
-            .for_each(|(i, _)| {
+        (0..self.available.iter_cards().len())
+            .filter(|i| !self.available.is_selected_index(*i))
+            .for_each(|i| {
                 space
                     .unmask_select_card(i)
                     .expect("valid index for selecting");
@@ -236,11 +413,12 @@ impl Game {
             return;
         }
         // Cannot play/discard if no cards selected
-        if self.available.selected().len() == 0 {
+        if self.available.selected_count() == 0 {
             return;
         }
-        // Can only play/discard is have remaining
-        if self.plays != 0 {
+        // Can only play/discard is have remaining. The Mouth and The Eye
+        // reject some hand types
+        if self.plays != 0 && self.selected_hand_allowed() {
             space.unmask_play();
         }
         if self.discards != 0 {
@@ -254,30 +432,19 @@ impl Game {
         }
         // move left
         // every available card except the first can move left
-        self.available
-            .cards()
-            .iter()
-            .skip(1)
-            .enumerate()
-            .for_each(|(i, _)| {
-                space
-                    .unmask_move_card_left(i)
-                    .expect("valid index for move left")
-            });
+        let movable = self.available.iter_cards().len().saturating_sub(1);
+        (0..movable).for_each(|i| {
+            space
+                .unmask_move_card_left(i)
+                .expect("valid index for move left")
+        });
         // move right
         // every available card except the last can move right
-        self.available
-            .cards()
-            .iter()
-            .rev()
-            .skip(1)
-            .rev()
-            .enumerate()
-            .for_each(|(i, _)| {
-                space
-                    .unmask_move_card_right(i)
-                    .expect("valid index for move right")
-            });
+        (0..movable).for_each(|i| {
+            space
+                .unmask_move_card_right(i)
+                .expect("valid index for move right")
+        });
     }
 
     fn unmask_action_space_cash_out(&self, space: &mut ActionSpace) {
@@ -294,6 +461,13 @@ impl Game {
         space.unmask_next_round();
     }
 
+    fn unmask_action_space_reroll(&self, space: &mut ActionSpace) {
+        if self.stage != Stage::Shop() || !self.can_afford(self.reroll_cost()) {
+            return;
+        }
+        space.unmask_reroll();
+    }
+
     fn unmask_action_space_select_blind(&self, space: &mut ActionSpace) {
         if self.stage != Stage::PreBlind() {
             return;
@@ -305,20 +479,18 @@ impl Game {
         if self.stage != Stage::Shop() {
             return;
         }
-        // Cannot buy if all joker slots full
-        if self.jokers.len() >= self.config.joker_slots {
-            return;
-        }
-        self.shop
-            .jokers
-            .iter()
-            .enumerate()
-            .filter(|(_i, j)| j.cost() <= self.money)
-            .for_each(|(i, _j)| {
+        // The shop slots of the jokers `gen_actions` offers, so slots,
+        // Negative editions and prices are checked the same way
+        for action in self.gen_actions_buy_joker().into_iter().flatten() {
+            let Action::BuyJoker(joker) = action else {
+                continue;
+            };
+            if let Some(i) = self.shop.jokers.iter().position(|j| j == &joker) {
                 space
                     .unmask_buy_joker(i)
-                    .expect("valid index for buy joker")
-            });
+                    .expect("valid index for buy joker");
+            }
+        }
     }
 
     fn unmask_action_space_buy_consumable(&self, space: &mut ActionSpace) {
@@ -333,7 +505,7 @@ impl Game {
             .consumables
             .iter()
             .enumerate()
-            .filter(|(_i, c)| c.cost() <= self.money)
+            .filter(|(_i, c)| self.can_afford(self.consumable_cost(c)))
             .for_each(|(i, _c)| {
                 space
                     .unmask_buy_consumable(i)
@@ -346,10 +518,16 @@ impl Game {
         if !self.stage.is_blind() && self.stage != Stage::Shop() {
             return;
         }
+        // Targeted consumables use the selected cards as their targets
+        let selected = self.available.selected_count();
         self.consumables
             .iter()
             .enumerate()
-            .filter(|(_i, c)| !c.requires_target())
+            .filter(|(_i, c)| {
+                !c.requires_target()
+                    || (self.stage.is_blind()
+                        && (c.min_targets()..=c.max_targets()).contains(&selected))
+            })
             .for_each(|(i, _c)| {
                 space
                     .unmask_use_consumable(i)
@@ -357,20 +535,67 @@ impl Game {
             });
     }
 
+    fn unmask_action_space_sell_consumable(&self, space: &mut ActionSpace) {
+        if !self.can_manage_items() {
+            return;
+        }
+        (0..self.consumables.len().min(space.sell_consumable.len())).for_each(|i| {
+            space
+                .unmask_sell_consumable(i)
+                .expect("valid index for sell consumable")
+        });
+    }
+
+    fn unmask_action_space_jokers(&self, space: &mut ActionSpace) {
+        if !self.can_manage_items() {
+            return;
+        }
+        // Negative jokers can take the count past the space's slots
+        let count = self.jokers.len().min(space.sell_joker.len());
+        (0..count)
+            .filter(|i| !self.joker_stickers(*i).eternal)
+            .for_each(|i| space.unmask_sell_joker(i).expect("valid index for sell joker"));
+        // every joker but the first can move left, every joker but the last right
+        let movable = count.saturating_sub(1);
+        (0..movable).for_each(|i| {
+            space
+                .unmask_move_joker_left(i)
+                .expect("valid index for move joker left");
+            space
+                .unmask_move_joker_right(i)
+                .expect("valid index for move joker right");
+        });
+    }
+
     // Get an action space, masked for legal actions only
     pub fn gen_action_space(&self) -> ActionSpace {
         let mut space = ActionSpace::from(self.config.clone());
-        self.unmask_action_space_select_cards(&mut space);
-        self.unmask_action_space_play_and_discard(&mut space);
-        self.unmask_action_space_move_cards(&mut space);
-        self.unmask_action_space_cash_out(&mut space);
-        self.unmask_action_space_next_round(&mut space);
-        self.unmask_action_space_select_blind(&mut space);
-        self.unmask_action_space_buy_joker(&mut space);
-        self.unmask_action_space_buy_consumable(&mut space);
-        self.unmask_action_space_use_consumable(&mut space);
+        self.gen_action_space_into(&mut space);
         return space;
     }
+
+    // Re-mask an existing action space for the current state, reusing its
+    // allocation. The space must have been built from this game's config.
+    pub fn gen_action_space_into(&self, space: &mut ActionSpace) {
+        space.clear();
+        self.unmask_action_space_select_cards(space);
+        self.unmask_action_space_play_and_discard(space);
+        self.unmask_action_space_move_cards(space);
+        self.unmask_action_space_cash_out(space);
+        self.unmask_action_space_next_round(space);
+        self.unmask_action_space_select_blind(space);
+        self.unmask_action_space_buy_joker(space);
+        self.unmask_action_space_buy_consumable(space);
+        self.unmask_action_space_use_consumable(space);
+        self.unmask_action_space_jokers(space);
+        self.unmask_action_space_sell_consumable(space);
+        self.unmask_action_space_reroll(space);
+    }
+}
+
+// Whether a generator gives at least one action
+fn any(actions: Option<impl Iterator<Item = Action>>) -> bool {
+    return actions.is_some_and(|mut actions| actions.next().is_some());
 }
 
 #[cfg(test)]
@@ -419,6 +644,33 @@ mod tests {
         assert_eq!(moves.len(), 1);
     }
 
+    #[test]
+    fn test_gen_moves_select_and_move_card() {
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        let king = Card::new(Value::King, Suit::Diamond);
+        let queen = Card::new(Value::Queen, Suit::Club);
+
+        let mut g = Game::default();
+        g.stage = Stage::Blind(Blind::Small, None);
+        g.available.extend(vec![ace, king, queen]);
+        g.select_card(king).unwrap();
+
+        let selects: Vec<Action> = g.gen_actions_select_card().expect("are selects").collect();
+        assert_eq!(selects, vec![Action::SelectCard(ace), Action::SelectCard(queen)]);
+
+        // Every card but the first can move left, every card but the last can move right
+        let moves: Vec<Action> = g.gen_actions_move_card().expect("are moves").collect();
+        assert_eq!(
+            moves,
+            vec![
+                Action::MoveCard(MoveDirection::Left, king),
+                Action::MoveCard(MoveDirection::Left, queen),
+                Action::MoveCard(MoveDirection::Right, ace),
+                Action::MoveCard(MoveDirection::Right, king),
+            ]
+        );
+    }
+
     #[test]
     fn test_unmask_action_space_select_cards() {
         let mut g = Game::default();
@@ -547,4 +799,147 @@ mod tests {
             assert!(space.move_card_right[i] == 1);
         }
     }
+
+    #[test]
+    fn test_joker_sell_and_move_actions() {
+        use crate::joker::Jokers;
+        use crate::sticker::Stickers;
+
+        let mut g = Game::default();
+        g.stage = Stage::Shop();
+        g.jokers = vec![Jokers::GreedyJoker(Default::default()); 3];
+        g.set_joker_stickers(2, Stickers { eternal: true, ..Default::default() });
+
+        let actions: Vec<Action> = g
+            .gen_actions()
+            .filter(|a| matches!(a, Action::SellJoker(_) | Action::MoveJoker(_, _)))
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                Action::SellJoker(0),
+                Action::SellJoker(1),
+                Action::MoveJoker(1, 0),
+                Action::MoveJoker(2, 1),
+                Action::MoveJoker(0, 1),
+                Action::MoveJoker(1, 2),
+            ]
+        );
+
+        let space = g.gen_action_space();
+        assert_eq!(space.sell_joker[..3], [1, 1, 0]);
+        assert_eq!(space.move_joker_left[..3], [1, 1, 0]);
+        assert_eq!(space.move_joker_right[..3], [1, 1, 0]);
+        let legal: Vec<Action> = space
+            .to_vec()
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| **v == 1)
+            .map(|(i, _)| space.to_action(i, &g).expect("to action"))
+            .filter(|a| matches!(a, Action::SellJoker(_) | Action::MoveJoker(_, _)))
+            .collect();
+        assert_eq!(legal.len(), 6);
+        assert!(actions.iter().all(|a| legal.contains(a)));
+
+        // Not while choosing a blind
+        g.stage = Stage::PreBlind();
+        assert!(g.gen_action_space().sell_joker.iter().all(|v| *v == 0));
+        assert!(!g.gen_actions().any(|a| matches!(a, Action::SellJoker(_))));
+    }
+
+    #[test]
+    fn test_consumable_use_with_targets_and_sell() {
+        use crate::consumable::Consumables;
+        use crate::planet::Planets;
+        use crate::tarot::Tarots;
+
+        let mut g = Game::default();
+        g.stage = Stage::Blind(Blind::Small, None);
+        let cards: Vec<Card> = [Value::Two, Value::Three, Value::Four]
+            .iter()
+            .map(|v| Card::new(*v, Suit::Heart))
+            .collect();
+        g.available.extend(cards.clone());
+        let strength = Consumables::Tarot(Tarots::Strength);
+        let pluto = Consumables::Planet(Planets::Pluto);
+        g.consumables = vec![strength.clone(), pluto.clone()];
+
+        // Strength takes 1 or 2 targets: 3 singles and 3 pairs
+        let uses: Vec<Action> = g
+            .gen_actions()
+            .filter(|a| matches!(a, Action::UseConsumable(_, _)))
+            .collect();
+        assert_eq!(uses.len(), 7);
+        assert!(uses.contains(&Action::UseConsumable(pluto.clone(), None)));
+        assert!(uses.contains(&Action::UseConsumable(
+            strength.clone(),
+            Some(vec![cards[0].id, cards[2].id])
+        )));
+        let sells: Vec<Action> = g
+            .gen_actions()
+            .filter(|a| matches!(a, Action::SellConsumable(_)))
+            .collect();
+        assert_eq!(sells, vec![Action::SellConsumable(0), Action::SellConsumable(1)]);
+
+        // The space uses the selected cards as targets
+        let space = g.gen_action_space();
+        assert_eq!(space.use_consumable[..2], [0, 1]);
+        assert_eq!(space.sell_consumable[..3], [1, 1, 0]);
+        g.select_card(cards[1]).unwrap();
+        let space = g.gen_action_space();
+        assert_eq!(space.use_consumable[..2], [1, 1]);
+        let index = space.use_consumable_min();
+        assert_eq!(
+            space.to_action(index, &g).unwrap(),
+            Action::UseConsumable(strength, Some(vec![cards[1].id]))
+        );
+
+        // Selling pays half the cost
+        let money = g.money;
+        let index = space.sell_consumable_min() + 1;
+        g.handle_action(space.to_action(index, &g).unwrap()).unwrap();
+        assert_eq!(g.money, money + pluto.sell_value() as i64);
+        assert_eq!(g.consumables.len(), 1);
+
+        // Outside blinds targeted consumables cannot be used
+        g.stage = Stage::Shop();
+        assert!(!g.gen_actions().any(|a| matches!(a, Action::UseConsumable(_, _))));
+        assert_eq!(g.gen_action_space().use_consumable[0], 0);
+    }
+
+    #[test]
+    fn test_legal_action_kinds_match_gen_actions() {
+        use crate::rng::GameRng;
+        use rand::seq::IteratorRandom;
+
+        // Random walks through a few runs. Blinds are cleared by any hand and
+        // there is money for the shop's buys and packs.
+        for seed in 0..4 {
+            let mut g = Game::with_seed(crate::config::Config::default(), seed).unwrap();
+            let mut rng = GameRng::new(seed);
+            g.start();
+            g.money = 100;
+            for _ in 0..300 {
+                if g.stage.is_blind() {
+                    g.score = g.required_score();
+                }
+                let kinds: EnumSet<ActionKind> = g.gen_actions().map(|a| a.kind()).collect();
+                assert_eq!(g.legal_action_kinds(), kinds, "stage {:?}", g.stage);
+                // Moving cards around leads nowhere
+                let actions = g.gen_actions().filter(|a| a.kind() != ActionKind::MoveCard);
+                let Some(action) = actions.choose(&mut rng) else {
+                    break;
+                };
+                if g.handle_action(action).is_err() || g.is_over() {
+                    break;
+                }
+            }
+        }
+
+        let mut g = Game::default();
+        g.start();
+        let kinds = g.legal_action_kinds();
+        assert!(kinds.contains(ActionKind::SelectBlind));
+        assert!(!kinds.contains(ActionKind::Play));
+    }
 }
@@ -1,5 +1,5 @@
-use indexmap::IndexMap;
 use itertools::Itertools;
+#[cfg(feature = "python")]
 use pyo3::pyclass;
 use std::fmt;
 
@@ -73,57 +73,29 @@ impl SelectHand {
     pub(crate) fn len(&self) -> usize {
         self.0.len()
     }
-    // Get all values in a hand. Sorted lowest to highest.
-    fn values(&self) -> Vec<Value> {
-        self.0.iter().map(|x| x.value).sorted().collect()
-    }
-    pub(crate) fn cards(&self) -> Vec<Card> {
+    pub fn cards(&self) -> Vec<Card> {
         return self.0.clone();
     }
-
-    // Get map of each value with corresponding cards.
-    // For example, Ks, Ah, Jh, Jc, Jd -> {A: [Ah], K: [Ks], J: [Jh, Jc: Jd]}
-    fn values_freq(&self) -> IndexMap<Value, Vec<Card>> {
-        let mut counts: IndexMap<Value, Vec<Card>> = IndexMap::new();
-        for card in self.0.clone() {
-            if let Some(cards) = counts.get(&card.value) {
-                let mut copy = cards.clone();
-                copy.push(card);
-                counts.insert(card.value, copy);
-            } else {
-                counts.insert(card.value, vec![card]);
-            }
-        }
-        // Return sorted by value
-        return counts
-            .into_iter()
-            .sorted_by(|a, b| Ord::cmp(&b.0, &a.0))
-            .collect();
+    // Borrow the cards without cloning
+    pub(crate) fn as_slice(&self) -> &[Card] {
+        return &self.0;
+    }
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [Card] {
+        return &mut self.0;
     }
 
-    // Get all suits in a hand
-    pub(crate) fn suits(&self) -> Vec<Suit> {
-        self.0.iter().map(|x| x.suit).sorted().collect()
+    // Cards of the given rank, in hand order
+    fn cards_of_rank(&self, rank: usize) -> impl Iterator<Item = Card> + '_ {
+        return self.0.iter().filter(move |c| c.value as usize == rank).copied();
     }
 
-    // Get map of each suit with corresponding cards.
-    // For example, Ks, Ah, Jh, Jc, Jd -> {h: [Jh, Ah], s: [Ks], c: [Jc], d: [Jd]}
-    pub(crate) fn suits_freq(&self) -> IndexMap<Suit, Vec<Card>> {
-        let mut counts: IndexMap<Suit, Vec<Card>> = IndexMap::new();
-        for card in self.0.clone() {
-            if let Some(cards) = counts.get(&card.suit) {
-                let mut copy = cards.clone();
-                copy.push(card);
-                counts.insert(card.suit, copy);
-            } else {
-                counts.insert(card.suit, vec![card]);
-            }
-        }
-        // Return sorted by suit
-        return counts
-            .into_iter()
-            .sorted_by(|a, b| Ord::cmp(&b.0, &a.0))
+    // First card of every rank in `ranks`, lowest first (Ace last)
+    fn first_of_ranks(&self, ranks: u16) -> SelectHand {
+        let cards = (0..RANK_COUNT)
+            .filter(|r| ranks & (1 << r) != 0)
+            .filter_map(|r| self.cards_of_rank(r).next())
             .collect();
+        return SelectHand::new(cards);
     }
 
     /// Can play any number of cards, it is our responsibility
@@ -152,6 +124,27 @@ impl SelectHand {
         &self,
         context: &HandContext,
     ) -> Result<MadeHand, PlayHandError> {
+        // Stone cards have no rank or suit: the hand is made from the other
+        // cards and stones always score on top of it.
+        let (stones, ranked): (Vec<Card>, Vec<Card>) =
+            self.0.iter().copied().partition(|c| c.is_stone());
+        if stones.is_empty() || self.len() > 5 {
+            return self.best_ranked_hand(context);
+        }
+        let mut made = match ranked.is_empty() {
+            true => MadeHand {
+                hand: SelectHand::default(),
+                rank: HandRank::HighCard,
+                all: Vec::new(),
+            },
+            false => SelectHand::new(ranked).best_ranked_hand(context)?,
+        };
+        made.hand.0.extend(stones);
+        made.all = self.cards();
+        return Ok(made);
+    }
+
+    fn best_ranked_hand(&self, context: &HandContext) -> Result<MadeHand, PlayHandError> {
         if self.len() == 0 {
             return Err(PlayHandError::NoCards);
         }
@@ -159,524 +152,378 @@ impl SelectHand {
             return Err(PlayHandError::TooManyCards);
         }
 
-        // We start trying to evaluate best hands first, so we
-        // can return best hand right when we find it.
-        if let Some(hand) = self.is_flush_five(context) {
-            return Ok(MadeHand {
-                hand,
-                rank: HandRank::FlushFive,
-                all: self.cards(),
-            });
-        }
-        if let Some(hand) = self.is_flush_house(context) {
-            return Ok(MadeHand {
-                hand,
-                rank: HandRank::FlushHouse,
-                all: self.cards(),
-            });
-        }
-        if let Some(hand) = self.is_five_of_kind() {
-            return Ok(MadeHand {
-                hand,
-                rank: HandRank::FiveOfAKind,
-                all: self.cards(),
-            });
-        }
-        if let Some(hand) = self.is_royal_flush(context) {
-            return Ok(MadeHand {
-                hand,
-                rank: HandRank::RoyalFlush,
-                all: self.cards(),
-            });
-        }
-        if let Some(hand) = self.is_straight_flush(context) {
-            return Ok(MadeHand {
-                hand,
-                rank: HandRank::StraightFlush,
-                all: self.cards(),
-            });
-        }
-        if let Some(hand) = self.is_four_of_kind() {
-            return Ok(MadeHand {
-                hand,
-                rank: HandRank::FourOfAKind,
-                all: self.cards(),
-            });
-        }
-        if let Some(hand) = self.is_fullhouse() {
-            return Ok(MadeHand {
-                hand,
-                rank: HandRank::FullHouse,
-                all: self.cards(),
-            });
-        }
-        if let Some(hand) = self.is_flush(context) {
-            return Ok(MadeHand {
-                hand,
-                rank: HandRank::Flush,
-                all: self.cards(),
-            });
-        }
-        if let Some(hand) = self.is_straight(context) {
-            return Ok(MadeHand {
-                hand,
-                rank: HandRank::Straight,
-                all: self.cards(),
-            });
-        }
-        if let Some(hand) = self.is_three_of_kind() {
-            return Ok(MadeHand {
-                hand,
-                rank: HandRank::ThreeOfAKind,
-                all: self.cards(),
-            });
-        }
-        if let Some(hand) = self.is_two_pair() {
-            return Ok(MadeHand {
-                hand,
-                rank: HandRank::TwoPair,
-                all: self.cards(),
-            });
-        }
-        if let Some(hand) = self.is_pair() {
-            return Ok(MadeHand {
-                hand,
-                rank: HandRank::OnePair,
-                all: self.cards(),
-            });
-        }
-        if let Some(hand) = self.is_highcard() {
-            return Ok(MadeHand {
+        // Every category is decided from one pass over the cards, only the
+        // cards of the best one are then picked out
+        let shape = HandShape::new(&self.0);
+        let rank = shape.rank(context);
+        let hand = match rank {
+            HandRank::FlushFive
+            | HandRank::FlushHouse
+            | HandRank::RoyalFlush
+            | HandRank::StraightFlush => Some(self.clone()),
+            HandRank::FiveOfAKind => self.of_a_kind(&shape, 5),
+            HandRank::FourOfAKind => self.of_a_kind(&shape, 4),
+            HandRank::FullHouse => self.two_groups(&shape, 3),
+            HandRank::Flush => self.flush(&shape, context),
+            HandRank::Straight => self.straight(&shape, context),
+            HandRank::ThreeOfAKind => self.of_a_kind(&shape, 3),
+            HandRank::TwoPair => self.two_groups(&shape, 2),
+            HandRank::OnePair => self.of_a_kind(&shape, 2),
+            HandRank::HighCard => self.of_a_kind(&shape, 1),
+        };
+        return match hand {
+            Some(hand) => Ok(MadeHand {
                 hand,
-                rank: HandRank::HighCard,
+                rank,
                 all: self.cards(),
-            });
-        }
-        // We didn't match any known hand, oops...
-        return Err(PlayHandError::UnknownHand);
+            }),
+            // We didn't match any known hand, oops...
+            None => Err(PlayHandError::UnknownHand),
+        };
     }
 
     /// Backward-compatible wrapper that uses default context
-    pub(crate) fn best_hand(&self) -> Result<MadeHand, PlayHandError> {
+    pub fn best_hand(&self) -> Result<MadeHand, PlayHandError> {
         self.best_hand_with_context(&HandContext::default_context())
     }
 
-    pub(crate) fn is_highcard(&self) -> Option<SelectHand> {
-        if self.len() < 1 {
-            return None;
-        }
-        if let Some((_value, cards)) = self
-            .values_freq()
-            .into_iter()
-            .find(|(_key, val)| val.len() >= 1)
-        {
-            return Some(SelectHand::new(cards));
-        } else {
-            return None;
-        }
+    // Cards of the highest rank held at least `n` times
+    fn of_a_kind(&self, shape: &HandShape, n: u8) -> Option<SelectHand> {
+        let rank = shape.rank_with(n, None)?;
+        return Some(SelectHand::new(self.cards_of_rank(rank).collect()));
     }
 
-    pub(crate) fn is_pair(&self) -> Option<SelectHand> {
-        if self.len() < 2 {
-            return None;
-        }
-        if let Some((_value, cards)) = self
-            .values_freq()
-            .into_iter()
-            .find(|(_key, val)| val.len() >= 2)
-        {
-            return Some(SelectHand::new(cards));
-        } else {
-            return None;
-        }
+    // Highest rank held `n` times followed by the highest other pair, for
+    // a full house (3) or two pair (2)
+    fn two_groups(&self, shape: &HandShape, n: u8) -> Option<SelectHand> {
+        let first = shape.rank_with(n, None)?;
+        let second = shape.rank_with(2, Some(first))?;
+        let cards = self.cards_of_rank(first).chain(self.cards_of_rank(second)).collect();
+        return Some(SelectHand::new(cards));
     }
 
-    pub(crate) fn is_two_pair(&self) -> Option<SelectHand> {
-        if self.len() < 4 {
-            return None;
-        }
+    fn straight(&self, shape: &HandShape, context: &HandContext) -> Option<SelectHand> {
+        let ranks = shape.straight(context)?;
+        return Some(self.first_of_ranks(ranks));
+    }
 
-        // First find first pair
-        let first = self
-            .values_freq()
-            .into_iter()
-            .find(|(_key, val)| val.len() >= 2);
-        if first.is_none() {
-            return None;
+    fn flush(&self, shape: &HandShape, context: &HandContext) -> Option<SelectHand> {
+        let min_cards = min_flush_cards(context);
+        let best_4 = |cards: Vec<Card>| -> Vec<Card> {
+            return cards
+                .iter()
+                .sorted_by_key(|c| c.value)
+                .rev()
+                .take(4)
+                .copied()
+                .collect();
+        };
+
+        // Check normal suit matching, Wild cards count towards every suit
+        if let Some(suit) = shape.flush_suit(min_cards) {
+            let cards: Vec<Card> = self
+                .0
+                .iter()
+                .filter(|c| c.suit == suit || c.is_wild())
+                .copied()
+                .collect();
+            // If 4-card flush, take only the best 4 cards
+            if context.modifiers.four_card_flushes && cards.len() > 4 {
+                return Some(SelectHand::new(best_4(cards)));
+            }
+            return Some(SelectHand::new(cards));
         }
-        let first_val = first
-            .as_ref()
-            .unwrap()
-            .1
-            .first()
-            .expect("values freq has empty Vec<card>")
-            .value;
-
-        // Next find second pair that isn't same value as first pair
-        let second = self
-            .values_freq()
-            .into_iter()
-            .find(|(key, val)| *key != first_val && val.len() >= 2);
-        if second.is_none() {
-            return None;
+
+        // Check smeared suits (Hearts/Diamonds count as same, Spades/Clubs count as same)
+        if context.modifiers.smeared_suits {
+            for red in [true, false] {
+                if shape.smeared_count(red) < min_cards {
+                    continue;
+                }
+                let cards: Vec<Card> = self
+                    .0
+                    .iter()
+                    .filter(|c| c.suit.is_red() == red || c.is_wild())
+                    .copied()
+                    .collect();
+                let flush_cards = match context.modifiers.four_card_flushes && cards.len() > 4 {
+                    true => best_4(cards),
+                    false => cards.into_iter().take(min_cards).collect(),
+                };
+                return Some(SelectHand::new(flush_cards));
+            }
         }
 
-        // Combine first and second pair
-        let mut cards: Vec<Card> = Vec::new();
-        cards.extend(first.unwrap().1);
-        cards.extend(second.unwrap().1);
-        return Some(SelectHand::new(cards));
+        None
     }
 
-    pub(crate) fn is_three_of_kind(&self) -> Option<SelectHand> {
-        if self.len() < 3 {
-            return None;
-        }
-        if let Some((_value, cards)) = self
-            .values_freq()
-            .into_iter()
-            .find(|(_key, val)| val.len() >= 3)
-        {
-            return Some(SelectHand::new(cards));
-        } else {
-            return None;
-        }
+    // Whether the cards contain each category, and the cards making it.
+    // Each check takes its own `HandShape`, `best_hand` shares one.
+    pub fn is_highcard(&self) -> Option<SelectHand> {
+        return self.of_a_kind(&HandShape::new(&self.0), 1);
     }
 
-    pub(crate) fn is_straight(&self, context: &HandContext) -> Option<SelectHand> {
-        let min_cards = if context.modifiers.four_card_straights {
-            4
-        } else {
-            5
-        };
+    pub fn is_pair(&self) -> Option<SelectHand> {
+        return self.of_a_kind(&HandShape::new(&self.0), 2);
+    }
 
-        if self.len() < min_cards {
-            return None;
-        }
+    pub fn is_two_pair(&self) -> Option<SelectHand> {
+        return self.two_groups(&HandShape::new(&self.0), 2);
+    }
 
-        let values = self.values();
-
-        // Check for consecutive sequences of the required length
-        // Try from longest to shortest (5 down to min_cards)
-        for window_size in (min_cards..=self.len().min(5)).rev() {
-            // Check all possible windows of this size
-            for i in 0..=(values.len().saturating_sub(window_size)) {
-                let window = &values[i..i + window_size];
-
-                // Check if this window is consecutive
-                if window.windows(2).all(|v| (v[1] as u16 - v[0] as u16) == 1) {
-                    // Build the straight from these values
-                    let straight_cards: Vec<Card> = window
-                        .iter()
-                        .filter_map(|v| self.0.iter().find(|c| c.value == *v))
-                        .copied()
-                        .collect();
-                    return Some(SelectHand::new(straight_cards));
-                }
+    pub fn is_three_of_kind(&self) -> Option<SelectHand> {
+        return self.of_a_kind(&HandShape::new(&self.0), 3);
+    }
 
-                // Check for gap straights (Shortcut joker) - allows one gap in the sequence
-                if context.modifiers.gap_straights {
-                    let mut gap_count = 0;
-                    let mut is_gap_straight = true;
-
-                    for pair in window.windows(2) {
-                        let diff = pair[1] as u16 - pair[0] as u16;
-                        if diff == 1 {
-                            // Consecutive - good
-                            continue;
-                        } else if diff == 2 && gap_count == 0 {
-                            // One gap allowed
-                            gap_count += 1;
-                        } else {
-                            // Too many gaps or gap too large
-                            is_gap_straight = false;
-                            break;
-                        }
-                    }
-
-                    if is_gap_straight && gap_count == 1 {
-                        // Valid gap straight - build the straight from these values
-                        let straight_cards: Vec<Card> = window
-                            .iter()
-                            .filter_map(|v| self.0.iter().find(|c| c.value == *v))
-                            .copied()
-                            .collect();
-                        return Some(SelectHand::new(straight_cards));
-                    }
-                }
-            }
-        }
+    pub fn is_straight(&self, context: &HandContext) -> Option<SelectHand> {
+        return self.straight(&HandShape::new(&self.0), context);
+    }
 
-        // Special case for low ace straights (A, 2, 3, 4, 5) or (A, 2, 3, 4) with four_card modifier
-        if values.contains(&Value::Ace) && values.contains(&Value::Two) {
-            // Check if we have the required consecutive values starting from 2
-            let needed_values: Vec<Value> = if min_cards == 4 {
-                vec![Value::Two, Value::Three, Value::Four]
-            } else {
-                vec![Value::Two, Value::Three, Value::Four, Value::Five]
-            };
+    pub fn is_flush(&self, context: &HandContext) -> Option<SelectHand> {
+        return self.flush(&HandShape::new(&self.0), context);
+    }
 
-            if needed_values.iter().all(|v| values.contains(v)) {
-                let mut straight_cards: Vec<Card> = needed_values
-                    .iter()
-                    .filter_map(|v| self.0.iter().find(|c| c.value == *v))
-                    .copied()
-                    .collect();
+    pub fn is_fullhouse(&self) -> Option<SelectHand> {
+        return self.two_groups(&HandShape::new(&self.0), 3);
+    }
 
-                // Add the ace
-                if let Some(ace) = self.0.iter().find(|c| c.value == Value::Ace) {
-                    straight_cards.push(*ace);
-                }
+    pub fn is_four_of_kind(&self) -> Option<SelectHand> {
+        return self.of_a_kind(&HandShape::new(&self.0), 4);
+    }
 
-                return Some(SelectHand::new(straight_cards));
-            }
+    pub fn is_straight_flush(&self, context: &HandContext) -> Option<SelectHand> {
+        let shape = HandShape::new(&self.0);
+        return (shape.is_flush(context) && shape.straight(context).is_some()).then(|| self.clone());
+    }
 
-            // Gap straight with low ace (A, 2, 3, 5) or (A, 2, 4, 5)
-            if context.modifiers.gap_straights {
-                if min_cards == 4 {
-                    // Check for A,2,3,5 (missing 4) or A,2,4,5 (missing 3)
-                    if values.contains(&Value::Five) {
-                        if values.contains(&Value::Three) && !values.contains(&Value::Four) {
-                            // A,2,3,5 gap straight
-                            let gap_values = vec![Value::Two, Value::Three, Value::Five];
-                            let mut straight_cards: Vec<Card> = gap_values
-                                .iter()
-                                .filter_map(|v| self.0.iter().find(|c| c.value == *v))
-                                .copied()
-                                .collect();
-                            if let Some(ace) = self.0.iter().find(|c| c.value == Value::Ace) {
-                                straight_cards.push(*ace);
-                            }
-                            return Some(SelectHand::new(straight_cards));
-                        } else if values.contains(&Value::Four) && !values.contains(&Value::Three) {
-                            // A,2,4,5 gap straight
-                            let gap_values = vec![Value::Two, Value::Four, Value::Five];
-                            let mut straight_cards: Vec<Card> = gap_values
-                                .iter()
-                                .filter_map(|v| self.0.iter().find(|c| c.value == *v))
-                                .copied()
-                                .collect();
-                            if let Some(ace) = self.0.iter().find(|c| c.value == Value::Ace) {
-                                straight_cards.push(*ace);
-                            }
-                            return Some(SelectHand::new(straight_cards));
-                        }
-                    }
-                } else {
-                    // 5-card with gap: A,2,3,4,6 or A,2,3,5,6 or A,2,4,5,6
-                    if values.contains(&Value::Six) {
-                        // Check various gap combinations
-                        let gap_combos = vec![
-                            vec![Value::Two, Value::Three, Value::Four, Value::Six], // Missing 5
-                            vec![Value::Two, Value::Three, Value::Five, Value::Six], // Missing 4
-                            vec![Value::Two, Value::Four, Value::Five, Value::Six],  // Missing 3
-                        ];
-
-                        for combo in gap_combos {
-                            if combo.iter().all(|v| values.contains(v)) {
-                                let mut straight_cards: Vec<Card> = combo
-                                    .iter()
-                                    .filter_map(|v| self.0.iter().find(|c| c.value == *v))
-                                    .copied()
-                                    .collect();
-                                if let Some(ace) = self.0.iter().find(|c| c.value == Value::Ace) {
-                                    straight_cards.push(*ace);
-                                }
-                                return Some(SelectHand::new(straight_cards));
-                            }
-                        }
-                    }
-                }
-            }
-        }
+    pub fn is_royal_flush(&self, context: &HandContext) -> Option<SelectHand> {
+        let shape = HandShape::new(&self.0);
+        return shape.is_royal_flush(context).then(|| self.clone());
+    }
 
-        None
+    pub fn is_five_of_kind(&self) -> Option<SelectHand> {
+        return self.of_a_kind(&HandShape::new(&self.0), 5);
     }
 
-    pub(crate) fn is_flush(&self, context: &HandContext) -> Option<SelectHand> {
-        let min_cards = if context.modifiers.four_card_flushes {
-            4
-        } else {
-            5
-        };
+    pub fn is_flush_house(&self, context: &HandContext) -> Option<SelectHand> {
+        let shape = HandShape::new(&self.0);
+        return (shape.is_flush(context) && shape.is_full_house()).then(|| self.clone());
+    }
 
-        if self.len() < min_cards {
-            return None;
-        }
+    pub fn is_flush_five(&self, context: &HandContext) -> Option<SelectHand> {
+        let shape = HandShape::new(&self.0);
+        return (shape.is_flush(context) && shape.rank_with(5, None).is_some()).then(|| self.clone());
+    }
+}
 
-        // Check normal suit matching
-        if let Some((_suit, cards)) = self
-            .suits_freq()
-            .into_iter()
-            .find(|(_key, val)| val.len() >= min_cards)
-        {
-            // If 4-card flush, take only the best 4 cards
-            if context.modifiers.four_card_flushes && cards.len() > 4 {
-                let best_4: Vec<Card> = cards
-                    .iter()
-                    .sorted_by_key(|c| c.value)
-                    .rev()
-                    .take(4)
-                    .copied()
-                    .collect();
-                return Some(SelectHand::new(best_4));
-            }
-            return Some(SelectHand::new(cards));
-        }
+const RANK_COUNT: usize = 13;
+// Ten to Ace
+const ROYAL_RANKS: u16 = 0b1_1111_0000_0000;
 
-        // Check smeared suits (Hearts/Diamonds count as same, Spades/Clubs count as same)
-        if context.modifiers.smeared_suits {
-            // Hearts + Diamonds count as same suit
-            let red_cards: Vec<Card> = self
-                .0
-                .iter()
-                .filter(|c| c.suit == Suit::Heart || c.suit == Suit::Diamond)
-                .copied()
-                .collect();
+fn min_flush_cards(context: &HandContext) -> usize {
+    return match context.modifiers.four_card_flushes {
+        true => 4,
+        false => 5,
+    };
+}
 
-            if red_cards.len() >= min_cards {
-                let flush_cards: Vec<Card> = if context.modifiers.four_card_flushes && red_cards.len() > 4 {
-                    red_cards
-                        .iter()
-                        .sorted_by_key(|c| c.value)
-                        .rev()
-                        .take(4)
-                        .copied()
-                        .collect()
-                } else {
-                    red_cards.into_iter().take(min_cards).collect()
-                };
-                return Some(SelectHand::new(flush_cards));
-            }
+fn min_straight_cards(context: &HandContext) -> usize {
+    return match context.modifiers.four_card_straights {
+        true => 4,
+        false => 5,
+    };
+}
 
-            // Spades + Clubs count as same suit
-            let black_cards: Vec<Card> = self
-                .0
-                .iter()
-                .filter(|c| c.suit == Suit::Spade || c.suit == Suit::Club)
-                .copied()
-                .collect();
+// Rank counts and suit counts of up to five cards, taken in one pass so
+// every hand category is checked from them instead of rescanning the cards
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct HandShape {
+    len: usize,
+    // Cards of each rank, by `Value as usize`
+    counts: [u8; RANK_COUNT],
+    // Bit `Value as usize` set for every rank held
+    ranks: u16,
+    // Cards towards each suit, by `Suit as usize`. Wild cards count
+    // towards all four.
+    suits: [u8; 4],
+    wild: u8,
+}
 
-            if black_cards.len() >= min_cards {
-                let flush_cards: Vec<Card> = if context.modifiers.four_card_flushes && black_cards.len() > 4 {
-                    black_cards
-                        .iter()
-                        .sorted_by_key(|c| c.value)
-                        .rev()
-                        .take(4)
-                        .copied()
-                        .collect()
-                } else {
-                    black_cards.into_iter().take(min_cards).collect()
-                };
-                return Some(SelectHand::new(flush_cards));
+impl HandShape {
+    fn new(cards: &[Card]) -> Self {
+        let mut shape = Self {
+            len: cards.len(),
+            ..Self::default()
+        };
+        for card in cards {
+            shape.counts[card.value as usize] += 1;
+            shape.ranks |= 1 << card.value as usize;
+            if card.is_wild() {
+                shape.wild += 1;
+                shape.suits.iter_mut().for_each(|n| *n += 1);
+            } else {
+                shape.suits[card.suit as usize] += 1;
             }
         }
-
-        None
+        return shape;
     }
 
-    pub(crate) fn is_fullhouse(&self) -> Option<SelectHand> {
-        if self.len() < 5 {
-            return None;
+    // Best category the cards make, see `best_hand_with_context`
+    fn rank(&self, context: &HandContext) -> HandRank {
+        let flush = self.is_flush(context);
+        let straight = self.straight(context).is_some();
+        let most = self.counts.iter().copied().max().unwrap_or(0);
+        let full_house = self.is_full_house();
+        let two_pair = self
+            .rank_with(2, None)
+            .and_then(|pair| self.rank_with(2, Some(pair)))
+            .is_some();
+        if flush && most >= 5 {
+            return HandRank::FlushFive;
         }
-
-        // First find 3ok
-        let three = self
-            .values_freq()
-            .into_iter()
-            .find(|(_key, val)| val.len() >= 3);
-        if three.is_none() {
-            return None;
+        if flush && full_house {
+            return HandRank::FlushHouse;
         }
-        let three_val = three
-            .as_ref()
-            .unwrap()
-            .1
-            .first()
-            .expect("values freq has empty Vec<card>")
-            .value;
-
-        // Next find 2ok that isn't same value as 3ok
-        let two = self
-            .values_freq()
-            .into_iter()
-            .find(|(key, val)| *key != three_val && val.len() >= 2);
-        if two.is_none() {
-            return None;
+        if most >= 5 {
+            return HandRank::FiveOfAKind;
+        }
+        if flush && straight && self.is_royal() {
+            return HandRank::RoyalFlush;
+        }
+        if flush && straight {
+            return HandRank::StraightFlush;
+        }
+        if most >= 4 {
+            return HandRank::FourOfAKind;
         }
+        if full_house {
+            return HandRank::FullHouse;
+        }
+        if flush {
+            return HandRank::Flush;
+        }
+        if straight {
+            return HandRank::Straight;
+        }
+        if most >= 3 {
+            return HandRank::ThreeOfAKind;
+        }
+        if two_pair {
+            return HandRank::TwoPair;
+        }
+        if most >= 2 {
+            return HandRank::OnePair;
+        }
+        return HandRank::HighCard;
+    }
 
-        // Combine 3ok and 2ok
-        let mut cards: Vec<Card> = Vec::new();
-        cards.extend(three.unwrap().1);
-        cards.extend(two.unwrap().1);
-        return Some(SelectHand::new(cards));
+    // Highest rank held at least `n` times, other than `except`
+    fn rank_with(&self, n: u8, except: Option<usize>) -> Option<usize> {
+        return (0..RANK_COUNT)
+            .rev()
+            .find(|r| self.counts[*r] >= n && Some(*r) != except);
     }
 
-    pub(crate) fn is_four_of_kind(&self) -> Option<SelectHand> {
-        if self.len() < 4 {
-            return None;
-        }
-        if let Some((_value, cards)) = self
-            .values_freq()
+    fn is_full_house(&self) -> bool {
+        return self
+            .rank_with(3, None)
+            .and_then(|three| self.rank_with(2, Some(three)))
+            .is_some();
+    }
+
+    fn is_royal(&self) -> bool {
+        return self.len == 5 && self.ranks == ROYAL_RANKS;
+    }
+
+    fn is_royal_flush(&self, context: &HandContext) -> bool {
+        return self.is_royal() && self.is_flush(context) && self.straight(context).is_some();
+    }
+
+    // Suit with at least `min` cards, Diamonds first
+    fn flush_suit(&self, min: usize) -> Option<Suit> {
+        return Suit::suits()
             .into_iter()
-            .find(|(_key, val)| val.len() >= 4)
-        {
-            return Some(SelectHand::new(cards));
-        } else {
-            return None;
+            .rev()
+            .find(|s| self.suits[*s as usize] as usize >= min);
+    }
+
+    // Red (Hearts and Diamonds) or black cards with Smeared Joker, Wild
+    // cards once
+    fn smeared_count(&self, red: bool) -> usize {
+        let (a, b) = match red {
+            true => (Suit::Heart, Suit::Diamond),
+            false => (Suit::Spade, Suit::Club),
+        };
+        return (self.suits[a as usize] + self.suits[b as usize] - self.wild) as usize;
+    }
+
+    fn is_flush(&self, context: &HandContext) -> bool {
+        let min_cards = min_flush_cards(context);
+        if self.len < min_cards {
+            return false;
         }
+        return self.flush_suit(min_cards).is_some()
+            || (context.modifiers.smeared_suits
+                && (self.smeared_count(true) >= min_cards || self.smeared_count(false) >= min_cards));
     }
 
-    pub(crate) fn is_straight_flush(&self, context: &HandContext) -> Option<SelectHand> {
-        if self.is_flush(context).is_some() && self.is_straight(context).is_some() {
-            return Some(self.clone());
+    // Ranks of the straight, longest first and then lowest. Four Fingers
+    // allows four ranks, Shortcut one missing rank inside the run.
+    fn straight(&self, context: &HandContext) -> Option<u16> {
+        let min_cards = min_straight_cards(context);
+        if self.len < min_cards {
+            return None;
         }
-        return None;
-    }
-
-    pub(crate) fn is_royal_flush(&self, context: &HandContext) -> Option<SelectHand> {
-        if self.is_straight_flush(context).is_some()
-            && self.values().into_iter().eq(vec![
-                Value::Ten,
-                Value::Jack,
-                Value::Queen,
-                Value::King,
-                Value::Ace,
-            ])
-        {
-            return Some(self.clone());
+        let gaps = context.modifiers.gap_straights;
+        let held: Vec<usize> = (0..RANK_COUNT).filter(|r| self.ranks & (1 << r) != 0).collect();
+        for size in (min_cards..=held.len().min(5)).rev() {
+            for run in held.windows(size) {
+                let span = run[size - 1] - run[0];
+                if span == size - 1 || (gaps && span == size) {
+                    return Some(run.iter().fold(0, |mask, r| mask | 1 << r));
+                }
+            }
         }
-        return None;
+        return self.ace_low_straight(min_cards, gaps);
     }
 
-    pub(crate) fn is_five_of_kind(&self) -> Option<SelectHand> {
-        if self.len() < 5 {
+    // A-2-3-4(-5), the Ace played low. With Shortcut one of the ranks
+    // between the Two and the top may be missing.
+    fn ace_low_straight(&self, min_cards: usize, gaps: bool) -> Option<u16> {
+        let has = |v: Value| self.ranks & (1 << v as usize) != 0;
+        if !has(Value::Ace) || !has(Value::Two) {
             return None;
         }
-        if let Some((_value, cards)) = self
-            .values_freq()
-            .into_iter()
-            .find(|(_key, val)| val.len() >= 5)
-        {
-            return Some(SelectHand::new(cards));
-        } else {
+        // Ranks after the Two, up to the top of the run
+        let above: &[Value] = match min_cards {
+            4 => &[Value::Three, Value::Four],
+            _ => &[Value::Three, Value::Four, Value::Five],
+        };
+        let ace_and_two = 1 << Value::Ace as usize | 1 << Value::Two as usize;
+        if above.iter().all(|v| has(*v)) {
+            return Some(above.iter().fold(ace_and_two, |mask, v| mask | 1 << *v as usize));
+        }
+        if !gaps {
             return None;
         }
-    }
-
-    pub(crate) fn is_flush_house(&self, context: &HandContext) -> Option<SelectHand> {
-        if self.is_flush(context).is_some() && self.is_fullhouse().is_some() {
-            return Some(self.clone());
+        // One rank missing: the run reaches one rank higher
+        let top = match min_cards {
+            4 => Value::Five,
+            _ => Value::Six,
+        };
+        if !has(top) {
+            return None;
         }
-        return None;
-    }
-
-    pub(crate) fn is_flush_five(&self, context: &HandContext) -> Option<SelectHand> {
-        if self.is_flush(context).is_some() && self.is_five_of_kind().is_some() {
-            return Some(self.clone());
+        let run: Vec<Value> = above.iter().copied().chain([top]).collect();
+        let missing: Vec<&Value> = run.iter().filter(|v| !has(**v)).collect();
+        if missing.len() != 1 {
+            return None;
         }
-        return None;
+        return Some(
+            run.iter()
+                .filter(|v| has(**v))
+                .fold(ace_and_two, |mask, v| mask | 1 << *v as usize),
+        );
     }
 }
 
@@ -698,9 +545,100 @@ impl fmt::Display for SelectHand {
     }
 }
 
+/// Breakdown of a made hand from `MadeHand::explain`: the cards forming the
+/// hand, the kickers, and the joker rule modifiers the hand depends on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandExplanation {
+    pub rank: HandRank,
+    pub cards: Vec<Card>,
+    pub kickers: Vec<Card>,
+    pub modifiers: Vec<&'static str>,
+}
+
+impl MadeHand {
+    /// Explain how this hand was formed. Modifiers are inferred from the
+    /// cards: a 4 card straight or flush needs Four Fingers, a straight with
+    /// a gap needs Shortcut and a flush of mixed suits needs Smeared Joker.
+    pub fn explain(&self) -> HandExplanation {
+        let cards = self.hand.cards();
+        let kickers: Vec<Card> = self
+            .all
+            .iter()
+            .filter(|c| !cards.contains(c))
+            .copied()
+            .collect();
+
+        let straight = matches!(
+            self.rank,
+            HandRank::Straight | HandRank::StraightFlush | HandRank::RoyalFlush
+        );
+        let flush = matches!(
+            self.rank,
+            HandRank::Flush
+                | HandRank::StraightFlush
+                | HandRank::RoyalFlush
+                | HandRank::FlushHouse
+                | HandRank::FlushFive
+        );
+
+        // Stone cards score with the hand but take no part in forming it
+        let ranked: Vec<Card> = cards.iter().filter(|c| !c.is_stone()).copied().collect();
+        let suited: Vec<&Card> = ranked.iter().filter(|c| !c.is_wild()).collect();
+
+        let mut modifiers = Vec::new();
+        if (straight || flush) && ranked.len() == 4 {
+            modifiers.push("Four Fingers");
+        }
+        if straight && !is_consecutive(&ranked) {
+            modifiers.push("Shortcut");
+        }
+        if flush && suited.iter().any(|c| c.suit != suited[0].suit) {
+            modifiers.push("Smeared Joker");
+        }
+
+        return HandExplanation {
+            rank: self.rank,
+            cards,
+            kickers,
+            modifiers,
+        };
+    }
+}
+
+// Whether the card values form a run, with ace high or low
+fn is_consecutive(cards: &[Card]) -> bool {
+    let run = |ace: i32| {
+        let mut values: Vec<i32> = cards
+            .iter()
+            .map(|c| match c.value {
+                Value::Ace => ace,
+                v => v as i32,
+            })
+            .collect();
+        values.sort();
+        values.windows(2).all(|w| w[1] - w[0] == 1)
+    };
+    return run(Value::Ace as i32) || run(-1);
+}
+
+impl fmt::Display for HandExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let join = |cards: &[Card]| cards.iter().map(|c| c.to_string()).join(" ");
+        write!(f, "{:?} from [{}]", self.rank, join(&self.cards))?;
+        if !self.kickers.is_empty() {
+            write!(f, ", kickers [{}]", join(&self.kickers))?;
+        }
+        if !self.modifiers.is_empty() {
+            write!(f, ", using {}", self.modifiers.join(", "))?;
+        }
+        return Ok(());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::card::Enhancement;
 
     // Helper to create default context for tests
     fn ctx() -> HandContext<'static> {
@@ -708,90 +646,68 @@ mod tests {
     }
 
     #[test]
-    fn test_values() {
+    fn test_hand_shape_ranks() {
         let c3 = Card::new(Value::Two, Suit::Heart);
         let c4 = Card::new(Value::Three, Suit::Diamond);
         let c5 = Card::new(Value::Jack, Suit::Heart);
         let c1 = Card::new(Value::King, Suit::Heart);
         let c2 = Card::new(Value::Ace, Suit::Spade);
 
-        let hand = SelectHand::new(vec![c1, c2, c3, c4, c5]);
-        let values = hand.values();
-
-        // Should have 5 values
-        assert_eq!(values.len(), 5);
+        let shape = HandShape::new(&[c1, c2, c3, c4, c5]);
+        assert_eq!(shape.len, 5);
 
-        // Expect sorted (2, 3, J, K, A)
-        assert_eq!(values[0], Value::Two);
-        assert_eq!(values[1], Value::Three);
-        assert_eq!(values[2], Value::Jack);
-        assert_eq!(values[3], Value::King);
-        assert_eq!(values[4], Value::Ace);
+        // One bit per rank held (2, 3, J, K, A)
+        let expected = [Value::Two, Value::Three, Value::Jack, Value::King, Value::Ace]
+            .iter()
+            .fold(0, |mask, v| mask | 1 << *v as usize);
+        assert_eq!(shape.ranks, expected);
+        assert_eq!(shape.rank_with(1, None), Some(Value::Ace as usize));
     }
 
     #[test]
-    fn test_values_freq() {
+    fn test_hand_shape_counts() {
         let c1 = Card::new(Value::Two, Suit::Heart);
         let c2 = Card::new(Value::Three, Suit::Diamond);
         let c3 = Card::new(Value::Four, Suit::Heart);
         let c4 = Card::new(Value::King, Suit::Heart);
         let c5 = Card::new(Value::King, Suit::Spade);
 
-        let hand = SelectHand::new(vec![c1, c2, c3, c4, c5]);
-        let freq = hand.values_freq();
-
-        // Should have 4 values (K, 2, 3, 4)
-        assert_eq!(freq.len(), 4);
+        let shape = HandShape::new(&[c1, c2, c3, c4, c5]);
 
         // Expect 2 kings and 1 each of 2, 3, 4
-        assert_eq!(freq.get(&Value::King).unwrap().len(), 2);
-        assert_eq!(freq.get(&Value::Two).unwrap().len(), 1);
-        assert_eq!(freq.get(&Value::Three).unwrap().len(), 1);
-        assert_eq!(freq.get(&Value::Four).unwrap().len(), 1);
-
-        // No extra cards
-        assert_eq!(freq.get(&Value::Five), None);
-        assert_eq!(freq.get(&Value::Nine), None);
-
-        // Can also check the cards in the vec are as expected
-        assert_eq!(freq.get(&Value::King).unwrap()[0].value, Value::King);
-        assert_eq!(freq.get(&Value::King).unwrap()[1].value, Value::King);
-        assert_eq!(freq.get(&Value::Two).unwrap()[0].value, Value::Two);
-        assert_eq!(freq.get(&Value::Three).unwrap()[0].value, Value::Three);
-        assert_eq!(freq.get(&Value::Four).unwrap()[0].value, Value::Four);
-
-        // Check ordered by value
-        assert_eq!(freq.into_iter().nth(0).unwrap().0, Value::King)
+        assert_eq!(shape.counts[Value::King as usize], 2);
+        assert_eq!(shape.counts[Value::Two as usize], 1);
+        assert_eq!(shape.counts[Value::Three as usize], 1);
+        assert_eq!(shape.counts[Value::Four as usize], 1);
+        assert_eq!(shape.counts.iter().sum::<u8>(), 5);
+
+        // Highest pair is the kings, there is no second pair
+        assert_eq!(shape.rank_with(2, None), Some(Value::King as usize));
+        assert_eq!(shape.rank_with(2, Some(Value::King as usize)), None);
     }
 
     #[test]
-    fn test_suits_freq() {
+    fn test_hand_shape_suits() {
         let c1 = Card::new(Value::King, Suit::Heart);
         let c2 = Card::new(Value::King, Suit::Spade);
         let c3 = Card::new(Value::Two, Suit::Heart);
         let c4 = Card::new(Value::Three, Suit::Diamond);
-        let c5 = Card::new(Value::Four, Suit::Heart);
-
-        let hand = SelectHand::new(vec![c1, c2, c3, c4, c5]);
-        let freq = hand.suits_freq();
-
-        // Should have 3 values (heart, spade, diamond)
-        assert_eq!(freq.len(), 3);
-
-        // Expect 3 hearts and 1 each of spade and diamond
-        assert_eq!(freq.get(&Suit::Heart).unwrap().len(), 3);
-        assert_eq!(freq.get(&Suit::Spade).unwrap().len(), 1);
-        assert_eq!(freq.get(&Suit::Diamond).unwrap().len(), 1);
-
-        // No clubs to be found
-        assert_eq!(freq.get(&Suit::Club), None);
-
-        // Can also check the cards in the vec are as expected
-        assert_eq!(freq.get(&Suit::Heart).unwrap()[0].suit, Suit::Heart);
-        assert_eq!(freq.get(&Suit::Heart).unwrap()[1].suit, Suit::Heart);
-        assert_eq!(freq.get(&Suit::Heart).unwrap()[2].suit, Suit::Heart);
-        assert_eq!(freq.get(&Suit::Spade).unwrap()[0].suit, Suit::Spade);
-        assert_eq!(freq.get(&Suit::Diamond).unwrap()[0].suit, Suit::Diamond);
+        let mut c5 = Card::new(Value::Four, Suit::Heart);
+
+        // Expect 3 hearts and 1 each of spade and diamond, no clubs
+        let shape = HandShape::new(&[c1, c2, c3, c4, c5]);
+        assert_eq!(shape.suits[Suit::Heart as usize], 3);
+        assert_eq!(shape.suits[Suit::Spade as usize], 1);
+        assert_eq!(shape.suits[Suit::Diamond as usize], 1);
+        assert_eq!(shape.suits[Suit::Club as usize], 0);
+        assert_eq!(shape.smeared_count(true), 4);
+
+        // A Wild card counts towards every suit, but once when smeared
+        c5.enhancement = Some(crate::card::Enhancement::Wild);
+        let shape = HandShape::new(&[c1, c2, c3, c4, c5]);
+        assert_eq!(shape.suits, [2, 1, 3, 2]);
+        assert_eq!(shape.smeared_count(true), 4);
+        assert_eq!(shape.smeared_count(false), 2);
     }
 
     #[test]
@@ -1340,6 +1256,104 @@ mod tests {
         assert_eq!(straight.unwrap().len(), 5);
     }
 
+    #[test]
+    fn test_explain() {
+        let ah = Card::new(Value::Ace, Suit::Heart);
+        let ks = Card::new(Value::King, Suit::Spade);
+        let kd = Card::new(Value::King, Suit::Diamond);
+        let two = Card::new(Value::Two, Suit::Club);
+        let made = SelectHand::new(vec![ah, ks, kd, two]).best_hand().unwrap();
+        let explained = made.explain();
+        assert_eq!(explained.rank, HandRank::OnePair);
+        assert_eq!(explained.cards.len(), 2);
+        assert_eq!(explained.kickers, vec![ah, two]);
+        assert!(explained.modifiers.is_empty());
+        assert!(explained.to_string().starts_with("OnePair from ["));
+
+        // A, 2, 3, 5 straight needs both Four Fingers and Shortcut
+        let c3 = Card::new(Value::Three, Suit::Diamond);
+        let c5 = Card::new(Value::Five, Suit::Club);
+        let mods = GameModifiers {
+            gap_straights: true,
+            four_card_straights: true,
+            ..Default::default()
+        };
+        let ctx = HandContext { modifiers: &mods };
+        let made = SelectHand::new(vec![ah, two, c3, c5, ks])
+            .best_hand_with_context(&ctx)
+            .unwrap();
+        let explained = made.explain();
+        assert_eq!(explained.rank, HandRank::Straight);
+        assert_eq!(explained.modifiers, vec!["Four Fingers", "Shortcut"]);
+        assert_eq!(explained.kickers, vec![ks]);
+
+        // Hearts and diamonds flush needs Smeared Joker
+        let red: Vec<Card> = [Value::Two, Value::Five, Value::Seven, Value::Nine, Value::Jack]
+            .iter()
+            .enumerate()
+            .map(|(i, v)| Card::new(*v, if i % 2 == 0 { Suit::Heart } else { Suit::Diamond }))
+            .collect();
+        let mods = GameModifiers {
+            smeared_suits: true,
+            ..Default::default()
+        };
+        let ctx = HandContext { modifiers: &mods };
+        let made = SelectHand::new(red).best_hand_with_context(&ctx).unwrap();
+        assert_eq!(made.rank, HandRank::Flush);
+        assert_eq!(made.explain().modifiers, vec!["Smeared Joker"]);
+        assert!(made.explain().to_string().ends_with("using Smeared Joker"));
+    }
+
+    #[test]
+    fn test_wild_cards_any_suit() {
+        let mut wild = Card::new(Value::King, Suit::Spade);
+        wild.enhancement = Some(Enhancement::Wild);
+        let hearts: Vec<Card> = [Value::Two, Value::Five, Value::Seven, Value::Nine]
+            .iter()
+            .map(|v| Card::new(*v, Suit::Heart))
+            .collect();
+        let mut cards = hearts.clone();
+        cards.push(wild);
+        let made = SelectHand::new(cards).best_hand().unwrap();
+        assert_eq!(made.rank, HandRank::Flush);
+        assert_eq!(made.hand.len(), 5);
+        // A wild card alone is not a mixed suit flush
+        assert!(made.explain().modifiers.is_empty());
+
+        // Without the wild card there is no flush
+        let mut cards = hearts;
+        cards.push(Card::new(Value::King, Suit::Spade));
+        let made = SelectHand::new(cards).best_hand().unwrap();
+        assert_eq!(made.rank, HandRank::HighCard);
+    }
+
+    #[test]
+    fn test_stone_cards_no_rank_or_suit() {
+        let mut stone = Card::new(Value::Ace, Suit::Heart);
+        stone.enhancement = Some(Enhancement::Stone);
+        let k1 = Card::new(Value::King, Suit::Spade);
+        let k2 = Card::new(Value::King, Suit::Club);
+        let three = Card::new(Value::Three, Suit::Heart);
+
+        // Stone scores alongside the pair, the kicker does not
+        let made = SelectHand::new(vec![k1, stone, k2, three]).best_hand().unwrap();
+        assert_eq!(made.rank, HandRank::OnePair);
+        assert_eq!(made.hand.len(), 3);
+        assert!(made.hand.cards().contains(&stone));
+        assert_eq!(made.all.len(), 4);
+
+        // A stone never pairs with a card of its old rank
+        let ace = Card::new(Value::Ace, Suit::Spade);
+        let made = SelectHand::new(vec![ace, stone]).best_hand().unwrap();
+        assert_eq!(made.rank, HandRank::HighCard);
+        assert_eq!(made.hand.cards(), vec![ace, stone]);
+
+        // Only stones played is a high card that scores every stone
+        let made = SelectHand::new(vec![stone, stone]).best_hand().unwrap();
+        assert_eq!(made.rank, HandRank::HighCard);
+        assert_eq!(made.hand.len(), 2);
+    }
+
     #[test]
     fn test_gap_straight_ace_low() {
         // Test gap straight with low ace: A, 2, 3, 5 (missing 4)
@@ -1388,6 +1402,28 @@ mod tests {
         assert_eq!(straight, None);
     }
 
+    #[test]
+    fn test_four_card_straight_with_pair() {
+        // 5 6 6 7 8: the paired six doesn't break a Four Fingers straight
+        let hand = SelectHand::new(vec![
+            Card::new(Value::Five, Suit::Heart),
+            Card::new(Value::Six, Suit::Spade),
+            Card::new(Value::Six, Suit::Heart),
+            Card::new(Value::Seven, Suit::Diamond),
+            Card::new(Value::Eight, Suit::Club),
+        ]);
+        assert_eq!(hand.is_straight(&HandContext::default_context()), None);
+
+        let mods = GameModifiers {
+            four_card_straights: true,
+            ..Default::default()
+        };
+        let ctx = HandContext { modifiers: &mods };
+        let best = hand.best_hand_with_context(&ctx).unwrap();
+        assert_eq!(best.rank, HandRank::Straight);
+        assert_eq!(best.hand.len(), 4);
+    }
+
     #[test]
     fn test_four_card_flush_modifier() {
         // Test 4-card flush with Four Fingers joker
@@ -0,0 +1,128 @@
+use crate::action::Action;
+use std::sync::Arc;
+
+// Actions per sealed chunk, cloning a history copies at most this many
+// actions plus one pointer per earlier chunk
+const CHUNK_LEN: usize = 64;
+
+/// Actions handled by a game, in order. Full chunks are shared between
+/// clones so snapshots of long games stay cheap; it otherwise behaves like
+/// a `Vec<Action>` and is saved as one.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "Vec<Action>", into = "Vec<Action>"))]
+#[derive(Clone, Default)]
+pub struct ActionHistory {
+    chunks: Vec<Arc<[Action]>>,
+    tail: Vec<Action>,
+}
+
+impl ActionHistory {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    pub fn len(&self) -> usize {
+        return self.chunks.len() * CHUNK_LEN + self.tail.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
+    pub fn push(&mut self, action: Action) {
+        self.tail.push(action);
+        if self.tail.len() == CHUNK_LEN {
+            let chunk = std::mem::replace(&mut self.tail, Vec::with_capacity(CHUNK_LEN));
+            self.chunks.push(chunk.into());
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Action> {
+        let chunk = index / CHUNK_LEN;
+        return match self.chunks.get(chunk) {
+            Some(actions) => actions.get(index % CHUNK_LEN),
+            None if chunk == self.chunks.len() => self.tail.get(index % CHUNK_LEN),
+            None => None,
+        };
+    }
+
+    pub fn last(&self) -> Option<&Action> {
+        return match self.tail.last() {
+            Some(action) => Some(action),
+            None => self.chunks.last().and_then(|chunk| chunk.last()),
+        };
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Action> + '_ {
+        return self.chunks.iter().flat_map(|chunk| chunk.iter()).chain(self.tail.iter());
+    }
+
+    pub fn to_vec(&self) -> Vec<Action> {
+        return self.iter().cloned().collect();
+    }
+}
+
+impl std::ops::Index<usize> for ActionHistory {
+    type Output = Action;
+
+    fn index(&self, index: usize) -> &Action {
+        return self.get(index).expect("action history index out of range");
+    }
+}
+
+impl std::fmt::Debug for ActionHistory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f.debug_list().entries(self.iter()).finish();
+    }
+}
+
+impl PartialEq for ActionHistory {
+    fn eq(&self, other: &Self) -> bool {
+        return self.len() == other.len() && self.iter().eq(other.iter());
+    }
+}
+
+impl Eq for ActionHistory {}
+
+impl From<Vec<Action>> for ActionHistory {
+    fn from(actions: Vec<Action>) -> Self {
+        let mut history = ActionHistory::new();
+        for action in actions {
+            history.push(action);
+        }
+        return history;
+    }
+}
+
+impl From<ActionHistory> for Vec<Action> {
+    fn from(history: ActionHistory) -> Self {
+        return history.to_vec();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_history_chunks() {
+        let actions: Vec<Action> = (0..CHUNK_LEN * 2 + 5).map(Action::SelectCardIndex).collect();
+        let mut history = ActionHistory::new();
+        for action in actions.iter().cloned() {
+            history.push(action);
+        }
+        assert_eq!(history.len(), actions.len());
+        assert_eq!(history.to_vec(), actions);
+        assert_eq!(history[CHUNK_LEN], Action::SelectCardIndex(CHUNK_LEN));
+        assert_eq!(history.last(), actions.last());
+        assert_eq!(history.get(actions.len()), None);
+
+        // Clones share full chunks and diverge afterwards
+        let mut branch = history.clone();
+        assert!(Arc::ptr_eq(&branch.chunks[0], &history.chunks[0]));
+        branch.push(Action::Play());
+        assert_eq!(branch.len(), history.len() + 1);
+        assert_eq!(history.to_vec(), actions);
+        assert_eq!(ActionHistory::from(actions), history);
+    }
+}
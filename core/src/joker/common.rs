@@ -1,10 +1,11 @@
-// Common Rarity Jokers - 67 total
+// Common Rarity Jokers - 66 total
 // These are the most basic and frequently available jokers
 
 use super::*;
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct TheJoker {}
 
@@ -26,15 +27,16 @@ impl Joker for TheJoker {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, _hand: MadeHand) {
-            g.mult += 4;
+            g.mult += 4.0;
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct GreedyJoker {}
 
@@ -56,21 +58,17 @@ impl Joker for GreedyJoker {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
-            let diamonds = hand
-                .hand
-                .suits()
-                .iter()
-                .filter(|s| **s == Suit::Diamond)
-                .count();
-            g.mult += diamonds * 3
+            let diamonds = g.scored_triggers(&hand, |c| g.modifiers.is_suit(c, Suit::Diamond));
+            g.mult += (diamonds * 3) as f64
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct LustyJoker {}
 
@@ -92,21 +90,17 @@ impl Joker for LustyJoker {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
-            let hearts = hand
-                .hand
-                .suits()
-                .iter()
-                .filter(|s| **s == Suit::Heart)
-                .count();
-            g.mult += hearts * 3
+            let hearts = g.scored_triggers(&hand, |c| g.modifiers.is_suit(c, Suit::Heart));
+            g.mult += (hearts * 3) as f64
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct WrathfulJoker {}
 
@@ -128,21 +122,17 @@ impl Joker for WrathfulJoker {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
-            let spades = hand
-                .hand
-                .suits()
-                .iter()
-                .filter(|s| **s == Suit::Spade)
-                .count();
-            g.mult += spades * 3
+            let spades = g.scored_triggers(&hand, |c| g.modifiers.is_suit(c, Suit::Spade));
+            g.mult += (spades * 3) as f64
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct GluttonousJoker {}
 
@@ -164,21 +154,17 @@ impl Joker for GluttonousJoker {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
-            let clubs = hand
-                .hand
-                .suits()
-                .iter()
-                .filter(|s| **s == Suit::Club)
-                .count();
-            g.mult += clubs * 3
+            let clubs = g.scored_triggers(&hand, |c| g.modifiers.is_suit(c, Suit::Club));
+            g.mult += (clubs * 3) as f64
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct JollyJoker {}
 
@@ -201,16 +187,17 @@ impl Joker for JollyJoker {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
             if hand.hand.is_pair().is_some() {
-                g.mult += 8
+                g.mult += 8.0
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct ZanyJoker {}
 
@@ -233,16 +220,17 @@ impl Joker for ZanyJoker {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
             if hand.hand.is_three_of_kind().is_some() {
-                g.mult += 12
+                g.mult += 12.0
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct MadJoker {}
 
@@ -265,16 +253,17 @@ impl Joker for MadJoker {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
             if hand.hand.is_two_pair().is_some() {
-                g.mult += 10
+                g.mult += 10.0
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct CrazyJoker {}
 
@@ -299,16 +288,17 @@ impl Joker for CrazyJoker {
             use crate::hand::HandContext;
             let ctx = HandContext::default_context();
             if hand.hand.is_straight(&ctx).is_some() {
-                g.mult += 12
+                g.mult += 12.0
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct DrollJoker {}
 
@@ -333,16 +323,17 @@ impl Joker for DrollJoker {
             use crate::hand::HandContext;
             let ctx = HandContext::default_context();
             if hand.hand.is_flush(&ctx).is_some() {
-                g.mult += 10
+                g.mult += 10.0
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct SlyJoker {}
 
@@ -365,16 +356,17 @@ impl Joker for SlyJoker {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
             if hand.hand.is_pair().is_some() {
-                g.chips += 50
+                g.chips += 50.0
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct WilyJoker {}
 
@@ -397,16 +389,17 @@ impl Joker for WilyJoker {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
             if hand.hand.is_three_of_kind().is_some() {
-                g.chips += 100
+                g.chips += 100.0
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct CleverJoker {}
 
@@ -429,16 +422,17 @@ impl Joker for CleverJoker {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
             if hand.hand.is_two_pair().is_some() {
-                g.chips += 80
+                g.chips += 80.0
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct DeviousJoker {}
 
@@ -463,16 +457,17 @@ impl Joker for DeviousJoker {
             use crate::hand::HandContext;
             let ctx = HandContext::default_context();
             if hand.hand.is_straight(&ctx).is_some() {
-                g.chips += 100
+                g.chips += 100.0
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct CraftyJoker {}
 
@@ -497,17 +492,18 @@ impl Joker for CraftyJoker {
             use crate::hand::HandContext;
             let ctx = HandContext::default_context();
             if hand.hand.is_flush(&ctx).is_some() {
-                g.chips += 80
+                g.chips += 80.0
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
 // Joker #16: Half Joker
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct HalfJoker {}
 
@@ -530,10 +526,10 @@ impl Joker for HalfJoker {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
             if hand.hand.cards().len() <= 3 {
-                g.mult += 20;
+                g.mult += 20.0;
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
@@ -541,7 +537,8 @@ impl Joker for HalfJoker {
 
 // Joker #17: Credit Card - allows going into debt
 // Note: This is an Economy joker with passive effect
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct CreditCard {}
 
@@ -570,7 +567,8 @@ impl Joker for CreditCard {
 
 
 // Joker #18: Banner
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Banner {}
 
@@ -590,22 +588,19 @@ impl Joker for Banner {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::Chips]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let discards_remaining = game.discards;
-        fn apply(g: &mut Game, _hand: MadeHand, discards: usize) {
-            g.chips += discards * 30;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, discards_remaining);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.chips += (game.discards * 30) as f64;
     }
 }
 
 
 
 // Joker #19: Mystic Summit
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct MysticSummit {}
 
@@ -625,24 +620,21 @@ impl Joker for MysticSummit {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultPlus]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let discards_remaining = game.discards;
-        fn apply(g: &mut Game, _hand: MadeHand, discards: usize) {
-            if discards == 0 {
-                g.mult += 15;
-            }
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        if game.discards == 0 {
+            game.mult += 15.0;
         }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, discards_remaining);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
     }
 }
 
 
 
 // Joker #20: Raised Fist
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct RaisedFist {}
 
@@ -683,18 +675,19 @@ impl Joker for RaisedFist {
                 Value::Ace => 11,
             }).min().unwrap_or(0);
             let mult_bonus = lowest_rank_value * 2;
-            g.mult += mult_bonus;
+            g.mult += mult_bonus as f64;
         }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
 // Joker #21: Chaos the Clown - 1 free reroll per shop
-// Note: Passive effect, handled in shop logic
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+// Note: Passive effect, counted by Game::free_rerolls
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct ChaosTheClown {}
 
@@ -715,7 +708,7 @@ impl Joker for ChaosTheClown {
         vec![Categories::Effect]
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
-        // Passive effect - handled in shop logic
+        // Passive effect - see Game::free_rerolls
         vec![]
     }
 }
@@ -723,7 +716,8 @@ impl Joker for ChaosTheClown {
 
 
 // Joker #22: Scary Face
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct ScaryFace {}
 
@@ -745,17 +739,18 @@ impl Joker for ScaryFace {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
-            let face_count = hand.hand.cards().iter().filter(|c| c.is_face()).count();
-            g.chips += face_count * 30;
+            let face_count = g.scored_triggers(&hand, |c| g.modifiers.is_face(c));
+            g.chips += (face_count * 30) as f64;
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
 // Joker #23: Abstract Joker
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct AbstractJoker {}
 
@@ -775,15 +770,11 @@ impl Joker for AbstractJoker {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultPlus]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let joker_count = game.jokers.len();
-        fn apply(g: &mut Game, _hand: MadeHand, count: usize) {
-            g.mult += count * 3;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, joker_count);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult += (game.jokers.len() * 3) as f64;
     }
 }
 
@@ -791,7 +782,8 @@ impl Joker for AbstractJoker {
 
 // Joker #24: Delayed Gratification - Economy joker
 // Note: Effect handled at end of round
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct DelayedGratification {}
 
@@ -813,16 +805,16 @@ impl Joker for DelayedGratification {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnRoundEnd: Earn $2 per discard if no discards used
         fn on_round_end(g: &mut Game) {
             if g.discards_used == 0 {
-                g.money += g.discards_total * 2;
+                g.money += (g.discards_total * 2) as i64;
             }
         }
 
-        vec![Effects::OnRoundEnd(Arc::new(Mutex::new(on_round_end)))]
+        vec![Effects::OnRoundEnd(Arc::new(on_round_end))]
     }
 }
 
@@ -830,7 +822,8 @@ impl Joker for DelayedGratification {
 
 // Joker #25: Gros Michel - +15 Mult with destruction chance
 // Note: Destruction handled separately
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct GrosMichel {}
 
@@ -852,16 +845,17 @@ impl Joker for GrosMichel {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, _hand: MadeHand) {
-            g.mult += 15;
+            g.mult += 15.0;
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
 // Joker #26: Even Steven
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct EvenSteven {}
 
@@ -884,19 +878,20 @@ impl Joker for EvenSteven {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::card::Value;
         fn apply(g: &mut Game, hand: MadeHand) {
-            let even_count = hand.hand.cards().iter()
-                .filter(|c| matches!(c.value, Value::Two | Value::Four | Value::Six | Value::Eight | Value::Ten))
-                .count();
-            g.mult += even_count * 4;
+            let even_count = g.scored_triggers(&hand, |c| {
+                matches!(c.value, Value::Two | Value::Four | Value::Six | Value::Eight | Value::Ten)
+            });
+            g.mult += (even_count * 4) as f64;
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
 // Joker #27: Odd Todd
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct OddTodd {}
 
@@ -922,17 +917,18 @@ impl Joker for OddTodd {
             let has_odd = hand.hand.cards().iter()
                 .any(|c| matches!(c.value, Value::Ace | Value::Three | Value::Five | Value::Seven | Value::Nine));
             if has_odd {
-                g.chips += 31;
+                g.chips += 31.0;
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
 // Joker #28: Scholar
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Scholar {}
 
@@ -955,13 +951,11 @@ impl Joker for Scholar {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::card::Value;
         fn apply(g: &mut Game, hand: MadeHand) {
-            let ace_count = hand.hand.cards().iter()
-                .filter(|c| c.value == Value::Ace)
-                .count();
-            g.chips += ace_count * 20;
-            g.mult += ace_count * 4;
+            let ace_count = g.scored_triggers(&hand, |c| c.value == Value::Ace);
+            g.chips += (ace_count * 20) as f64;
+            g.mult += (ace_count * 4) as f64;
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
@@ -969,7 +963,8 @@ impl Joker for Scholar {
 
 // Joker #29: Business Card - Economy joker
 // Note: Random chance effect handled during scoring
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct BusinessCard {}
 
@@ -991,28 +986,30 @@ impl Joker for BusinessCard {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnScore: Played face cards have 1 in 2 chance to give $2
         fn on_score(g: &mut Game, hand: MadeHand) {
-            let cards = hand.hand.cards();
-            let face_count = cards.iter().filter(|c| c.is_face()).count();
+            use rand::Rng;
+
+            let face_count = g.scored_triggers(&hand, |c| g.modifiers.is_face(c));
 
             for _ in 0..face_count {
-                if rand::random::<f32>() < 0.5 {
+                if crate::rng::rng_for("joker", "business card money").gen_bool(0.5) {
                     g.money += 2;
                 }
             }
         }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(on_score)))]
+        vec![Effects::OnScore(Arc::new(on_score))]
     }
 }
 
 
 
 // Joker #30: Supernova
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Supernova {}
 
@@ -1032,28 +1029,36 @@ impl Joker for Supernova {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultPlus]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        // Clone the play counts HashMap for the closure
-        let play_counts = game.hand_rank_play_counts.clone();
-
-        fn apply(g: &mut Game, hand: MadeHand, counts: HashMap<HandRank, usize>) {
-            let times_played = counts.get(&hand.rank).copied().unwrap_or(0);
-            g.mult += times_played;
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        // Read at scoring time, the hand being played already counts
+        fn apply(g: &mut Game, hand: MadeHand) {
+            g.mult += g.stats.times_played(hand.rank) as f64;
         }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, play_counts.clone());
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
 // Joker #31: Ride the Bus - Stateful joker
-// Note: State tracking needed - simplified implementation
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
-pub struct RideTheBus {}
+pub struct RideTheBus {
+    // Consecutive hands scored without a face card
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bonus_mult: usize,
+}
+
+impl RideTheBus {
+    pub fn on_hand_played(&mut self, scored_face: bool) {
+        if scored_face {
+            self.bonus_mult = 0;
+        } else {
+            self.bonus_mult += 1;
+        }
+    }
+}
 
 impl Joker for RideTheBus {
     fn name(&self) -> String {
@@ -1071,23 +1076,26 @@ impl Joker for RideTheBus {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultPlus]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let mult_bonus = game.round_state.consecutive_hands_without_faces;
-
-        fn apply(g: &mut Game, _hand: MadeHand, bonus: usize) {
-            g.mult += bonus;
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        // Scales before scoring, so a hand without faces counts itself
+        fn on_play(g: &mut Game, hand: MadeHand, slot: usize) {
+            let scored_face = hand.hand.cards().iter().any(|c| g.modifiers.is_face(c));
+            if let Some(Jokers::RideTheBus(j)) = g.jokers.get_mut(slot) {
+                j.on_hand_played(scored_face);
+            }
         }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, mult_bonus);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        vec![Effects::OnPlay(Arc::new(on_play))]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult += self.bonus_mult as f64;
     }
 }
 
 
 
 // Joker #32: Runner
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Runner {}
 
@@ -1112,27 +1120,38 @@ impl Joker for Runner {
             use crate::hand::HandContext;
             let ctx = HandContext::default_context();
             if hand.hand.is_straight(&ctx).is_some() {
-                g.chips += 15;
+                g.chips += 15.0;
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
 // Joker #33: Ice Cream - Stateful joker
-// Note: Full implementation would track hands played
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
-pub struct IceCream {}
+pub struct IceCream {
+    // Hands played since the joker was bought
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hands_played: usize,
+}
+
+impl IceCream {
+    /// Chips the next hand gets, +100 less 5 per hand played
+    pub fn chips(&self) -> usize {
+        return 100_usize.saturating_sub(self.hands_played * 5);
+    }
+}
 
 impl Joker for IceCream {
     fn name(&self) -> String {
         "Ice Cream".to_string()
     }
     fn desc(&self) -> String {
-        "+100 Chips (-5 Chips for each hand played)".to_string()
+        format!("+{} Chips (-5 Chips for each hand played)", self.chips())
     }
     fn cost(&self) -> usize {
         5
@@ -1143,24 +1162,25 @@ impl Joker for IceCream {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::Chips]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let chips_bonus = 100_isize - (game.hands_played_this_blind as isize * 5);
-        let chips_bonus = chips_bonus.max(0) as usize; // Don't go negative
-
-        fn apply(g: &mut Game, _hand: MadeHand, bonus: usize) {
-            g.chips += bonus;
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        // Melts after the hand scored, copies keep the chips they gave
+        fn on_hand_end(g: &mut Game, _hand: MadeHand, _score: usize, slot: usize) {
+            if let Some(Jokers::IceCream(j)) = g.jokers.get_mut(slot) {
+                j.hands_played += 1;
+            }
         }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, chips_bonus);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        vec![Effects::OnHandEnd(Arc::new(on_hand_end))]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.chips += self.chips() as f64;
     }
 }
 
 
 
 // Joker #34: Splash
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Splash {}
 
@@ -1190,7 +1210,8 @@ impl Joker for Splash {
 
 
 // Joker #35: Blue Joker
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct BlueJoker {}
 
@@ -1210,15 +1231,11 @@ impl Joker for BlueJoker {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::Chips]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let cards_in_deck = game.deck.cards().len();
-        fn apply(g: &mut Game, _hand: MadeHand, deck_size: usize) {
-            g.chips += deck_size * 2;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, cards_in_deck);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.chips += (game.deck.cards().len() * 2) as f64;
     }
 }
 
@@ -1230,7 +1247,8 @@ impl Joker for BlueJoker {
 // and stubs for the complex ones.
 
 // Joker #36: Sixth Sense - Complex (random chance, spectral card generation)
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct SixthSense {}
 
@@ -1259,7 +1277,8 @@ impl Joker for SixthSense {
 
 
 // Joker #37: Constellation - Stateful
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "python", pyclass)]
 pub struct Constellation {
     pub planet_cards_used: usize,
@@ -1308,22 +1327,18 @@ impl Joker for Constellation {
         vec![Categories::MultMult]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let mult_multiplier = self.bonus_mult;
-
-        fn apply(g: &mut Game, _hand: MadeHand, multiplier: f32) {
-            g.mult = (g.mult as f32 * multiplier) as usize;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, mult_multiplier);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult *= self.bonus_mult as f64;
     }
 }
 
 
 
 // Joker #38: Hiker - Modifies cards permanently
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Hiker {}
 
@@ -1352,7 +1367,8 @@ impl Joker for Hiker {
 
 
 // Joker #39: Green Joker - Stateful
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct GreenJoker {
     pub bonus_mult: isize,  // Accumulated mult bonus (can be negative)
@@ -1391,26 +1407,23 @@ impl Joker for GreenJoker {
         vec![Categories::MultPlus]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let mult_bonus = self.bonus_mult;
-
-        fn apply(g: &mut Game, _hand: MadeHand, bonus: isize) {
-            if bonus >= 0 {
-                g.mult += bonus as usize;
-            } else {
-                g.mult = g.mult.saturating_sub((-bonus) as usize);
+        fn on_play(g: &mut Game, _hand: MadeHand, slot: usize) {
+            if let Some(Jokers::GreenJoker(j)) = g.jokers.get_mut(slot) {
+                j.on_hand_played();
             }
         }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, mult_bonus);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        vec![Effects::OnPlay(Arc::new(on_play))]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult = (game.mult + self.bonus_mult as f64).max(0.0);
     }
 }
 
 
 
 // Joker #40: Superposition - Creates tarot cards
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Superposition {}
 
@@ -1432,7 +1445,7 @@ impl Joker for Superposition {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnScore: Create Tarot if hand contains Straight and Ace
         fn on_score(g: &mut Game, hand: MadeHand) {
@@ -1455,23 +1468,31 @@ impl Joker for Superposition {
             }
         }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(on_score)))]
+        vec![Effects::OnScore(Arc::new(on_score))]
     }
 }
 
 
 
 // Joker #41: To Do List - Changes per round
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
-pub struct ToDoList {}
+pub struct ToDoList {
+    // This round's poker hand, copied from the round state for desc()
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hand: Option<HandRank>,
+}
 
 impl Joker for ToDoList {
     fn name(&self) -> String {
         "To Do List".to_string()
     }
     fn desc(&self) -> String {
-        "$5 if poker hand is listed type (hand changes each round)".to_string()
+        return match self.hand {
+            Some(hand) => format!("$5 if poker hand is a {} (hand changes each round)", hand),
+            None => "$5 if poker hand is listed type (hand changes each round)".to_string(),
+        };
     }
     fn cost(&self) -> usize {
         4
@@ -1482,27 +1503,50 @@ impl Joker for ToDoList {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::Economy]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let todo_hand = game.round_state.todo_hand;
-
-        fn apply(g: &mut Game, hand: MadeHand, target: Option<HandRank>) {
-            if let Some(target_rank) = target {
-                if hand.rank == target_rank {
-                    g.money += 5;
+    fn effects(&self, _in: &Game) -> Vec<Effects> {
+        // OnRoundBegin: roll a hand other than last round's, unless another
+        // To Do List already rolled it this round. Secret hands never come up
+        fn on_round_begin(g: &mut Game) {
+            use rand::seq::SliceRandom;
+            if g.round_state.todo_hand.is_none() {
+                let previous = g.jokers.iter().find_map(|j| match j {
+                    Jokers::ToDoList(t) => t.hand,
+                    _ => None,
+                });
+                let hands: Vec<HandRank> = HandRank::all()[..=HandRank::StraightFlush as usize]
+                    .iter()
+                    .copied()
+                    .filter(|h| Some(*h) != previous)
+                    .collect();
+                let mut rng = crate::rng::rng_for("joker", "to do list hand");
+                g.round_state.todo_hand = hands.choose(&mut rng).copied();
+            }
+            let target = g.round_state.todo_hand;
+            for j in &mut g.jokers {
+                if let Jokers::ToDoList(t) = j {
+                    t.hand = target;
                 }
             }
         }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, todo_hand);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+
+        // OnScore: $5 when the played hand is this round's hand
+        fn apply(g: &mut Game, hand: MadeHand) {
+            if g.round_state.todo_hand == Some(hand.rank) {
+                g.money += 5;
+            }
+        }
+        vec![
+            Effects::OnRoundBegin(Arc::new(on_round_begin)),
+            Effects::OnScore(Arc::new(apply)),
+        ]
     }
 }
 
 
 
 // Joker #42: Cavendish - Very rare destruction
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Cavendish {}
 
@@ -1524,14 +1568,14 @@ impl Joker for Cavendish {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnScore: X3 Mult
         fn on_score(g: &mut Game, _hand: MadeHand) {
-            g.mult *= 3;
+            g.mult *= 3.0;
         }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(on_score)))]
+        vec![Effects::OnScore(Arc::new(on_score))]
         // TODO: OnRoundEnd effect with 1 in 1000 chance to destroy this joker
     }
 }
@@ -1539,7 +1583,8 @@ impl Joker for Cavendish {
 
 
 // Joker #43: Red Card - Triggers on pack skip
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct RedCard {
     pub bonus_mult: usize,
@@ -1568,31 +1613,36 @@ impl Joker for RedCard {
         vec![Categories::MultPlus]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let mult_bonus = self.bonus_mult;
-
-        fn apply(g: &mut Game, _hand: MadeHand, bonus: usize) {
-            g.mult += bonus;
+        fn on_pack_skip(g: &mut Game, slot: usize) {
+            if let Some(Jokers::RedCard(j)) = g.jokers.get_mut(slot) {
+                j.on_booster_skipped();
+            }
         }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, mult_bonus);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        vec![Effects::OnPackSkip(Arc::new(on_pack_skip))]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult += self.bonus_mult as f64;
     }
 }
 
 
 
 // Joker #44: Square Joker
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
-pub struct SquareJoker {}
+pub struct SquareJoker {
+    // Chips gained from hands of exactly 4 cards
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bonus_chips: usize,
+}
 
 impl Joker for SquareJoker {
     fn name(&self) -> String {
         "Square Joker".to_string()
     }
     fn desc(&self) -> String {
-        "Gains +4 Chips if hand has exactly 4 cards".to_string()
+        format!("+{} Chips (gains +4 Chips if played hand has exactly 4 cards)", self.bonus_chips)
     }
     fn cost(&self) -> usize {
         5
@@ -1604,12 +1654,18 @@ impl Joker for SquareJoker {
         vec![Categories::Chips]
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
-        fn apply(g: &mut Game, hand: MadeHand) {
-            if hand.hand.cards().len() == 4 {
-                g.chips += 4;
+        fn on_play(g: &mut Game, hand: MadeHand, slot: usize) {
+            if hand.all.len() != 4 {
+                return;
+            }
+            if let Some(Jokers::SquareJoker(j)) = g.jokers.get_mut(slot) {
+                j.bonus_chips += 4;
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnPlay(Arc::new(on_play))]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.chips += self.bonus_chips as f64;
     }
 }
 
@@ -1619,7 +1675,8 @@ impl Joker for SquareJoker {
 // I'll add simplified implementations for completeness
 
 // Joker #45: Riff-Raff
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct RiffRaff {}
 
@@ -1641,35 +1698,35 @@ impl Joker for RiffRaff {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         fn on_blind_select(g: &mut Game) {
             use rand::seq::SliceRandom;
 
             // Determine how many jokers we can add
-            let slots_available = g.max_joker_slots().saturating_sub(g.jokers.len());
+            let slots_available = g.joker_slots().saturating_sub(g.jokers.len());
             let to_create = slots_available.min(2);
 
             // Generate 2 common jokers
             for _ in 0..to_create {
                 let all_common = crate::joker::Jokers::all_common();
-                let joker = all_common.choose(&mut rand::thread_rng()).unwrap().clone();
+                let joker = all_common.choose(&mut crate::rng::rng_for("joker", "riff-raff joker")).unwrap().clone();
                 g.jokers.push(joker);
             }
 
             // Re-register joker effects after adding new ones
-            g.effect_registry = crate::effect::EffectRegistry::new();
-            g.effect_registry.register_jokers(g.jokers.clone(), &g.clone());
+            g.refresh_joker_effects();
         }
 
-        vec![Effects::OnBlindSelect(Arc::new(Mutex::new(on_blind_select)))]
+        vec![Effects::OnBlindSelect(Arc::new(on_blind_select))]
     }
 }
 
 
 
 // Joker #46: Golden Ticket
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct GoldenTicket {}
 
@@ -1691,15 +1748,14 @@ impl Joker for GoldenTicket {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
-        let effect = Effects::OnPlay(Arc::new(Mutex::new(|g: &mut Game, hand: MadeHand| {
-            // Count Gold enhancement cards in played hand
-            let gold_count = hand.all.iter().filter(|c| c.enhancement == Some(crate::card::Enhancement::Gold)).count();
-            if gold_count > 0 {
-                g.money += gold_count * 3;
+        // $3 each time a Gold card scores, including retriggers
+        let effect = Effects::OnCardScored(Arc::new(|g: &mut Game, card: Card, _slot: usize| {
+            if card.enhancement == Some(crate::card::Enhancement::Gold) {
+                g.money += 3;
             }
-        })));
+        }));
 
         vec![effect]
     }
@@ -1708,7 +1764,8 @@ impl Joker for GoldenTicket {
 
 
 // Joker #47: Swashbuckler
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Swashbuckler {}
 
@@ -1728,24 +1785,22 @@ impl Joker for Swashbuckler {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultPlus]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
         let total_sell_value: usize = game.jokers.iter()
             .map(|j| j.sell_value())
             .sum();
-        fn apply(g: &mut Game, _hand: MadeHand, sell_value: usize) {
-            g.mult += sell_value;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, total_sell_value);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        game.mult += total_sell_value as f64;
     }
 }
 
 
 
 // Joker #48: Smiley Face
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct SmileyFace {}
 
@@ -1767,17 +1822,18 @@ impl Joker for SmileyFace {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
-            let face_count = hand.hand.cards().iter().filter(|c| c.is_face()).count();
-            g.mult += face_count * 4;
+            let face_count = g.scored_triggers(&hand, |c| g.modifiers.is_face(c));
+            g.mult += (face_count * 4) as f64;
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
 // Joker #49: Golden Joker
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct GoldenJoker {}
 
@@ -1799,21 +1855,22 @@ impl Joker for GoldenJoker {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnRoundEnd: Earn $3
         fn on_round_end(g: &mut Game) {
             g.money += 3;
         }
 
-        vec![Effects::OnRoundEnd(Arc::new(Mutex::new(on_round_end)))]
+        vec![Effects::OnRoundEnd(Arc::new(on_round_end))]
     }
 }
 
 
 
 // Joker #50: Drunkard - Passive effect
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Drunkard {}
 
@@ -1842,7 +1899,8 @@ impl Joker for Drunkard {
 
 
 // Joker #51: Faceless Joker
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct FacelessJoker {}
 
@@ -1863,27 +1921,23 @@ impl Joker for FacelessJoker {
         vec![Categories::Economy]
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
-        fn on_discard(g: &mut Game, hand: MadeHand) {
-            let face_count = hand
-                .hand
-                .cards()
-                .iter()
-                .filter(|c| c.is_face())
-                .count();
+        fn on_discard(g: &mut Game, hand: MadeHand, _slot: usize) {
+            let face_count = hand.all.iter().filter(|c| g.modifiers.is_face(c)).count();
 
             if face_count >= 3 {
                 g.money += 5;
             }
         }
 
-        vec![Effects::OnDiscard(Arc::new(Mutex::new(on_discard)))]
+        vec![Effects::OnDiscard(Arc::new(on_discard))]
     }
 }
 
 
 
 // Joker #52: Hanging Chad - Retrigger effect
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct HangingChad {}
 
@@ -1906,12 +1960,20 @@ impl Joker for HangingChad {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         vec![]
     }
+    fn retriggers(&self, _game: &Game, _card: &Card, index: usize) -> usize {
+        if index == 0 {
+            2
+        } else {
+            0
+        }
+    }
 }
 
 
 
 // Joker #53: Popcorn - Stateful
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Popcorn {}
 
@@ -1931,24 +1993,20 @@ impl Joker for Popcorn {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultPlus]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
         let mult_bonus = 20_isize - (game.round as isize * 4);
-        let mult_bonus = mult_bonus.max(0) as usize; // Don't go negative
-
-        fn apply(g: &mut Game, _hand: MadeHand, bonus: usize) {
-            g.mult += bonus;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, mult_bonus);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        game.mult += mult_bonus.max(0) as f64; // Don't go negative
     }
 }
 
 
 
 // Joker #54: Walkie Talkie
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct WalkieTalkie {}
 
@@ -1971,20 +2029,19 @@ impl Joker for WalkieTalkie {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::card::Value;
         fn apply(g: &mut Game, hand: MadeHand) {
-            let count = hand.hand.cards().iter()
-                .filter(|c| matches!(c.value, Value::Ten | Value::Four))
-                .count();
-            g.chips += count * 10;
-            g.mult += count * 4;
+            let count = g.scored_triggers(&hand, |c| matches!(c.value, Value::Ten | Value::Four));
+            g.chips += (count * 10) as f64;
+            g.mult += (count * 4) as f64;
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
 // Joker #55: Shoot the Moon
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct ShootTheMoon {}
 
@@ -2007,21 +2064,22 @@ impl Joker for ShootTheMoon {
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::card::Value;
 
-        fn apply(g: &mut Game, _hand: MadeHand) {
+        fn apply(g: &mut Game, hand: MadeHand) {
             // Calculate at score time, not registration time!
-            let queen_count = g.hand.iter().filter(|c| c.value == Value::Queen).count();
+            let queen_count = g.held_triggers(&hand, |c| c.value == Value::Queen);
             let mult_bonus = queen_count * 13;
-            g.mult += mult_bonus;
+            g.mult += mult_bonus as f64;
         }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
 // Joker #56: Fortune Teller - Stateful
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct FortuneTeller {
     pub tarot_cards_used: usize,
@@ -2050,22 +2108,18 @@ impl Joker for FortuneTeller {
         vec![Categories::MultPlus]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let mult_bonus = self.tarot_cards_used;
-
-        fn apply(g: &mut Game, _hand: MadeHand, bonus: usize) {
-            g.mult += bonus;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, mult_bonus);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult += self.tarot_cards_used as f64;
     }
 }
 
 
 
 // Joker #57: Juggler - Passive effect
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Juggler {}
 
@@ -2093,7 +2147,8 @@ impl Joker for Juggler {
 
 
 // Joker #58: Photograph
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Photograph {}
 
@@ -2115,27 +2170,28 @@ impl Joker for Photograph {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnScore: First played face card gives X2 Mult
         fn on_score(g: &mut Game, hand: MadeHand) {
             let cards = hand.hand.cards();
             // Find first face card
-            let has_face_card = cards.iter().any(|c| c.is_face());
+            let has_face_card = cards.iter().any(|c| g.modifiers.is_face(c));
 
             if has_face_card {
-                g.mult *= 2;
+                g.mult *= 2.0;
             }
         }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(on_score)))]
+        vec![Effects::OnScore(Arc::new(on_score))]
     }
 }
 
 
 
 // Joker #59: Reserved Parking
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct ReservedParking {}
 
@@ -2157,13 +2213,15 @@ impl Joker for ReservedParking {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, _hand: MadeHand) {
+            use rand::Rng;
+
             // Calculate at score time, not registration time!
-            let face_cards: Vec<_> = g.hand.iter().filter(|c| c.is_face()).collect();
+            let face_cards: Vec<_> = g.hand.iter().filter(|c| g.modifiers.is_face(c)).collect();
             let mut money_bonus = 0;
 
             // Each face card has 1 in 3 chance to give $1
             for _ in &face_cards {
-                if rand::random::<f32>() < 1.0 / 3.0 {
+                if crate::rng::rng_for("joker", "reserved parking money").gen_bool(1.0 / 3.0) {
                     money_bonus += 1;
                 }
             }
@@ -2171,14 +2229,15 @@ impl Joker for ReservedParking {
             g.money += money_bonus;
         }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 
 
 // Joker #60: Mail-In Rebate - Changes per round
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct MailInRebate {}
 
@@ -2199,16 +2258,22 @@ impl Joker for MailInRebate {
         vec![Categories::Economy]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        // TODO: Needs OnDiscard effect with access to discarded cards
-        // For now, this joker's effect is handled directly in Game::discard_selected()
-        vec![]
+        // OnDiscard: $3 per discarded card of this round's rank
+        fn on_discard(g: &mut Game, hand: MadeHand, _slot: usize) {
+            if let Some(rank) = g.round_state.mail_rebate_rank {
+                g.money += (hand.all.iter().filter(|c| c.value == rank).count() * 3) as i64;
+            }
+        }
+
+        vec![Effects::OnDiscard(Arc::new(on_discard))]
     }
 }
 
 
 
 // Joker #61: 8 Ball - Complex (random chance, tarot generation)
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct EightBall {}
 
@@ -2230,32 +2295,33 @@ impl Joker for EightBall {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnScore: 1 in 5 chance per 8 played to create Tarot
         fn on_score(g: &mut Game, hand: MadeHand) {
+            use rand::Rng;
             use crate::card::Value;
 
             // Count 8s in played hand
-            let cards = hand.hand.cards();
-            let eights_played = cards.iter().filter(|c| c.value == Value::Eight).count();
+            let eights_played = g.scored_triggers(&hand, |c| c.value == Value::Eight);
 
             // For each 8, 1 in 5 chance to create Tarot
             for _ in 0..eights_played {
-                if rand::random::<f32>() < 0.2 {
+                if crate::rng::rng_for("joker", "8 ball tarot").gen_bool(0.2) {
                     g.create_random_tarot();
                 }
             }
         }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(on_score)))]
+        vec![Effects::OnScore(Arc::new(on_score))]
     }
 }
 
 
 
 // Joker #62: Misprint - Random mult
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Misprint {}
 
@@ -2277,24 +2343,29 @@ impl Joker for Misprint {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnScore: Add random mult between 0 and 23
         fn on_score(g: &mut Game, _hand: MadeHand) {
             use rand::Rng;
-            let mut rng = rand::thread_rng();
+            if g.expected_rolls {
+                g.mult += 23.0 / 2.0;
+                return;
+            }
+            let mut rng = crate::rng::rng_for("joker", "misprint mult");
             let bonus = rng.gen_range(0..=23);
-            g.mult += bonus;
+            g.mult += bonus as f64;
         }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(on_score)))]
+        vec![Effects::OnScore(Arc::new(on_score))]
     }
 }
 
 
 
 // Joker #63: Egg - Gains sell value over time
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Egg {
     pub sell_value_bonus: usize,
@@ -2338,48 +2409,9 @@ impl Joker for Egg {
 
 
 
-// Joker #64: Hit the Road - X0.5 Mult per Jack discarded this round
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
-#[cfg_attr(feature = "python", pyclass(eq))]
-pub struct HitTheRoad {}
-
-impl Joker for HitTheRoad {
-    fn name(&self) -> String {
-        "Hit the Road".to_string()
-    }
-    fn desc(&self) -> String {
-        "X0.5 Mult for every Jack discarded this round".to_string()
-    }
-    fn cost(&self) -> usize {
-        6
-    }
-    fn rarity(&self) -> Rarity {
-        Rarity::Common
-    }
-    fn categories(&self) -> Vec<Categories> {
-        vec![Categories::MultMult]
-    }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let jacks_discarded = game.round_state.jacks_discarded_this_round;
-
-        fn apply(g: &mut Game, _hand: MadeHand, jack_count: usize) {
-            // X0.5 for each jack: 0.5^jack_count
-            let multiplier = 0.5_f32.powi(jack_count as i32);
-            g.mult = (g.mult as f32 * multiplier) as usize;
-        }
-
-        let closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, jacks_discarded);
-        };
-
-        vec![Effects::OnScore(Arc::new(Mutex::new(closure)))]
-    }
-}
-
-
-
 // Joker #65: Satellite - $1 at end of round per unique Planet card used
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Satellite {}
 
@@ -2401,18 +2433,19 @@ impl Joker for Satellite {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         fn on_round_end(g: &mut Game) {
-            let unique_count = g.unique_planets_used.len();
-            g.money += unique_count;
+            let unique_count = g.stats.unique_planets_used.len();
+            g.money += unique_count as i64;
         }
 
-        vec![Effects::OnRoundEnd(Arc::new(Mutex::new(on_round_end)))]
+        vec![Effects::OnRoundEnd(Arc::new(on_round_end))]
     }
 }
 
 
 
 // Joker: Hallucination - 1 in 2 chance to create a Tarot card when any Booster Pack is opened
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Hallucination {}
 
@@ -2433,9 +2466,20 @@ impl Joker for Hallucination {
         vec![Categories::Effect]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        // TODO: Need OnPackOpen effect type (unique - only joker that triggers on pack opening)
-        // TODO: Need Tarot card creation system
-        // TODO: Need consumable slot availability checking
-        vec![]
+        use std::sync::Arc;
+
+        // OnPackOpen: 1 in 2 chance to create a Tarot if there is room
+        fn on_pack_open(g: &mut Game) {
+            use rand::Rng;
+
+            if g.consumables.len() >= g.config.consumable_slots {
+                return;
+            }
+            if crate::rng::rng_for("joker", "hallucination tarot").gen_bool(0.5) {
+                g.create_random_tarot();
+            }
+        }
+
+        vec![Effects::OnPackOpen(Arc::new(on_pack_open))]
     }
 }
@@ -4,7 +4,8 @@
 use super::*;
 
 // Joker: Triboulet - Played Kings and Queens each give X2 Mult
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Triboulet {}
 
@@ -35,15 +36,16 @@ impl Joker for Triboulet {
                 .count();
 
             for _ in 0..royal_count {
-                g.mult = g.mult * 2;
+                g.mult *= 2.0;
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: Canio - X Mult (gains X1 Mult when a face card is destroyed)
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "python", pyclass)]
 pub struct Canio {
     pub bonus_mult: f32,  // Accumulated X mult multiplier (starts at 1.0)
@@ -94,20 +96,16 @@ impl Joker for Canio {
         vec![Categories::MultMult]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let mult_multiplier = self.bonus_mult;
-
-        fn apply(g: &mut Game, _hand: MadeHand, multiplier: f32) {
-            g.mult = (g.mult as f32 * multiplier) as usize;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, mult_multiplier);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult *= self.bonus_mult as f64;
     }
 }
 
 // Joker: Yorick - Gains X1 Mult every 23 cards discarded
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "python", pyclass)]
 pub struct Yorick {
     pub cards_discarded: usize,  // Total cards discarded
@@ -165,20 +163,16 @@ impl Joker for Yorick {
         vec![Categories::MultMult]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let mult_multiplier = self.bonus_mult;
-
-        fn apply(g: &mut Game, _hand: MadeHand, multiplier: f32) {
-            g.mult = (g.mult as f32 * multiplier) as usize;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, mult_multiplier);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult *= self.bonus_mult as f64;
     }
 }
 
 // Joker: Chicot - Disables effect of every Boss Blind
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Chicot {}
 
@@ -205,7 +199,8 @@ impl Joker for Chicot {
 }
 
 // Joker: Perkeo - Creates Negative copy of 1 random consumable at end of shop
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Perkeo {}
 
@@ -226,7 +221,7 @@ impl Joker for Perkeo {
         vec![Categories::Effect]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        vec![Effects::OnShopEnd(Arc::new(Mutex::new(|game: &mut Game| {
+        vec![Effects::OnShopEnd(Arc::new(|game: &mut Game| {
             // Only duplicate if we have consumables and space for more
             if game.consumables.is_empty() {
                 return;
@@ -237,12 +232,12 @@ impl Joker for Perkeo {
 
             // Pick a random consumable to duplicate
             use rand::seq::SliceRandom;
-            let mut rng = rand::thread_rng();
+            let mut rng = crate::rng::rng_for("joker", "perkeo consumable");
             if let Some(consumable) = game.consumables.choose(&mut rng) {
                 // TODO: Full implementation should create a "Negative" edition consumable
                 // which provides +1 consumable slot. For now, just duplicate if space available.
                 game.consumables.push(consumable.clone());
             }
-        })))]
+        }))]
     }
 }
@@ -3,10 +3,10 @@ use crate::effect::Effects;
 use crate::game::Game;
 use crate::hand::MadeHand;
 use crate::rank::HandRank;
+#[cfg(feature = "python")]
 use pyo3::pyclass;
-use std::collections::HashMap;
 use std::fmt;
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use strum::{EnumIter, IntoEnumIterator};
 
 pub trait Joker: std::fmt::Debug + Clone {
@@ -21,6 +21,25 @@ pub trait Joker: std::fmt::Debug + Clone {
     fn sell_value(&self) -> usize {
         self.cost() / 2
     }
+
+    /// Extra triggers this joker gives a scored card, `index` is the card's
+    /// position among the scored cards. Summed by `Game::card_trigger_count`.
+    fn retriggers(&self, _game: &Game, _card: &Card, _index: usize) -> usize {
+        0
+    }
+
+    /// Extra triggers this joker gives a card held in hand. Summed by
+    /// `Game::held_trigger_count`.
+    fn held_retriggers(&self, _game: &Game, _card: &Card) -> usize {
+        0
+    }
+
+    /// Scoring hook run in the Jokers phase right after this joker's
+    /// `OnScore` effects. Effects are registered once per lineup, this gets
+    /// the joker in its slot as it is now, with `&mut` access to its own
+    /// state, so stateful jokers score here. Blueprint and Brainstorm run it on a clone of
+    /// the joker they copy, so a copy never changes the original.
+    fn on_score(&mut self, _game: &mut Game, _hand: &MadeHand) {}
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -128,6 +147,27 @@ macro_rules! make_jokers {
                     )*
                 }
             }
+            fn retriggers(&self, game: &Game, card: &Card, index: usize) -> usize {
+                match self {
+                    $(
+                        Jokers::$x(joker) => joker.retriggers(game, card, index),
+                    )*
+                }
+            }
+            fn held_retriggers(&self, game: &Game, card: &Card) -> usize {
+                match self {
+                    $(
+                        Jokers::$x(joker) => joker.held_retriggers(game, card),
+                    )*
+                }
+            }
+            fn on_score(&mut self, game: &mut Game, hand: &MadeHand) {
+                match self {
+                    $(
+                        Jokers::$x(joker) => joker.on_score(game, hand),
+                    )*
+                }
+            }
         }
     }
 }
@@ -307,6 +347,127 @@ impl Jokers {
     pub fn all_common() -> Vec<Self> {
         Self::by_rarity(Rarity::Common)
     }
+
+    /// Stable position of this joker kind in declaration order (used by
+    /// observations)
+    pub fn index(&self) -> usize {
+        let kind = std::mem::discriminant(self);
+        return Self::iter()
+            .position(|j| std::mem::discriminant(&j) == kind)
+            .expect("every joker kind is iterable");
+    }
+
+    /// Whether Blueprint and Brainstorm can copy this joker. Rule changes
+    /// and end of round payouts are not copied.
+    pub fn is_copyable(&self) -> bool {
+        return !matches!(
+            self,
+            Jokers::FourFingers(_)
+                | Jokers::CreditCard(_)
+                | Jokers::ChaosTheClown(_)
+                | Jokers::DelayedGratification(_)
+                | Jokers::Egg(_)
+                | Jokers::Splash(_)
+                | Jokers::Pareidolia(_)
+                | Jokers::Juggler(_)
+                | Jokers::Drunkard(_)
+                | Jokers::GoldenJoker(_)
+                | Jokers::Troubadour(_)
+                | Jokers::MerryAndy(_)
+                | Jokers::OopsAll6s(_)
+                | Jokers::Showman(_)
+                | Jokers::SmearedJoker(_)
+                | Jokers::Shortcut(_)
+                | Jokers::Astronomer(_)
+                | Jokers::Cloud9(_)
+                | Jokers::Rocket(_)
+                | Jokers::ToTheMoon(_)
+                | Jokers::GiftCard(_)
+                | Jokers::TurtleBean(_)
+                | Jokers::MidasMask(_)
+                | Jokers::Satellite(_)
+                | Jokers::MrBones(_)
+                | Jokers::Chicot(_)
+                | Jokers::InvisibleJoker(_)
+        );
+    }
+
+    /// Whether the joker can be Eternal. Jokers that destroy themselves or
+    /// work by being sold cannot.
+    pub fn eternal_compatible(&self) -> bool {
+        return !matches!(
+            self,
+            Jokers::GrosMichel(_)
+                | Jokers::IceCream(_)
+                | Jokers::Cavendish(_)
+                | Jokers::Luchador(_)
+                | Jokers::TurtleBean(_)
+                | Jokers::DietCola(_)
+                | Jokers::Popcorn(_)
+                | Jokers::Ramen(_)
+                | Jokers::Seltzer(_)
+                | Jokers::MrBones(_)
+                | Jokers::InvisibleJoker(_)
+        );
+    }
+
+    /// Whether the joker can be Perishable. Jokers that grow over the run
+    /// cannot.
+    pub fn perishable_compatible(&self) -> bool {
+        return !matches!(
+            self,
+            Jokers::CeremonialDagger(_)
+                | Jokers::RideTheBus(_)
+                | Jokers::Runner(_)
+                | Jokers::Constellation(_)
+                | Jokers::GreenJoker(_)
+                | Jokers::RedCard(_)
+                | Jokers::Madness(_)
+                | Jokers::SquareJoker(_)
+                | Jokers::Vampire(_)
+                | Jokers::Hologram(_)
+                | Jokers::Rocket(_)
+                | Jokers::Obelisk(_)
+                | Jokers::LuckyCat(_)
+                | Jokers::FlashCard(_)
+                | Jokers::SpareTrousers(_)
+                | Jokers::WeeJoker(_)
+                | Jokers::HitTheRoad(_)
+                | Jokers::GlassJoker(_)
+                | Jokers::Campfire(_)
+                | Jokers::Castle(_)
+                | Jokers::Yorick(_)
+                | Jokers::Canio(_)
+        );
+    }
+}
+
+/// Slot of the joker whose ability the joker in slot `index` uses: its own,
+/// or for Blueprint (joker to the right) and Brainstorm (leftmost joker, the
+/// second one when Brainstorm is leftmost) the copied joker, following
+/// chains of copiers. None when there is nothing to copy, the chain loops,
+/// or it ends on a joker that cannot be copied or is `disabled`.
+pub(crate) fn ability_source<F>(jokers: &[Jokers], index: usize, disabled: F) -> Option<usize>
+where
+    F: Fn(usize) -> bool,
+{
+    // Copiers only point at one joker each, so a chain visiting more slots
+    // than there are jokers has looped. Scoring runs this per card, keep
+    // it free of allocations.
+    let mut i = index;
+    for _ in 0..=jokers.len() {
+        if disabled(i) {
+            return None;
+        }
+        i = match jokers.get(i)? {
+            Jokers::Blueprint(_) => i + 1,
+            Jokers::Brainstorm(_) if i == 0 => 1,
+            Jokers::Brainstorm(_) => 0,
+            joker if i == index || joker.is_copyable() => return Some(i),
+            _ => return None,
+        };
+    }
+    return None;
 }
 
 impl fmt::Display for Jokers {
@@ -1,10 +1,11 @@
-// Rare Rarity Jokers - 17 total
+// Rare Rarity Jokers - 20 total
 // These are rare jokers with powerful and unique effects
 
 use super::*;
 
 // Joker: The Duo - X2 Mult if played hand contains a Pair
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct TheDuo {}
 
@@ -27,15 +28,16 @@ impl Joker for TheDuo {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
             if hand.hand.is_pair().is_some() {
-                g.mult = g.mult * 2;
+                g.mult *= 2.0;
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: The Trio - X3 Mult if played hand contains Three of a Kind
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct TheTrio {}
 
@@ -58,15 +60,16 @@ impl Joker for TheTrio {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
             if hand.hand.is_three_of_kind().is_some() {
-                g.mult = g.mult * 3;
+                g.mult *= 3.0;
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: The Family - X4 Mult if played hand contains Four of a Kind
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct TheFamily {}
 
@@ -89,15 +92,16 @@ impl Joker for TheFamily {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
             if hand.hand.is_four_of_kind().is_some() {
-                g.mult = g.mult * 4;
+                g.mult *= 4.0;
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: The Order - X3 Mult if played hand contains Straight
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct TheOrder {}
 
@@ -122,15 +126,16 @@ impl Joker for TheOrder {
             use crate::hand::HandContext;
             let ctx = HandContext::default_context();
             if hand.hand.is_straight(&ctx).is_some() {
-                g.mult = g.mult * 3;
+                g.mult *= 3.0;
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: The Tribe - X2 Mult if played hand contains Flush
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct TheTribe {}
 
@@ -155,15 +160,16 @@ impl Joker for TheTribe {
             use crate::hand::HandContext;
             let ctx = HandContext::default_context();
             if hand.hand.is_flush(&ctx).is_some() {
-                g.mult = g.mult * 2;
+                g.mult *= 2.0;
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: Baron - Each King held in hand gives X1.5 Mult
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Baron {}
 impl Joker for Baron {
@@ -185,19 +191,20 @@ impl Joker for Baron {
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::card::Value;
 
-        fn apply(g: &mut Game, _hand: MadeHand) {
+        fn apply(g: &mut Game, hand: MadeHand) {
             // Calculate at score time, not registration time!
-            let king_count = g.hand.iter().filter(|c| c.value == Value::King).count();
-            let mult_multiplier = 1.5_f32.powi(king_count as i32);
-            g.mult = (g.mult as f32 * mult_multiplier) as usize;
+            let king_count = g.held_triggers(&hand, |c| c.value == Value::King);
+            let mult_multiplier = 1.5_f64.powi(king_count as i32);
+            g.mult *= mult_multiplier;
         }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: Blueprint - Copies ability of Joker to the right
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Blueprint {}
 impl Joker for Blueprint {
@@ -217,13 +224,14 @@ impl Joker for Blueprint {
         vec![Categories::Effect]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        // Complex - would need to dynamically copy another joker's effects
+        // The copied joker's effects are registered instead, see `ability_source`
         vec![]
     }
 }
 
 // Joker: WeeJoker - Gains +8 Chips when each played 2 is scored
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct WeeJoker {}
 impl Joker for WeeJoker {
@@ -251,14 +259,15 @@ impl Joker for WeeJoker {
                 .iter()
                 .filter(|c| c.value == Value::Two)
                 .count();
-            g.chips += twos * 8;
+            g.chips += (twos * 8) as f64;
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: BaseballCard - Uncommon Jokers each give X1.5 Mult
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct BaseballCard {}
 impl Joker for BaseballCard {
@@ -277,34 +286,36 @@ impl Joker for BaseballCard {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultMult]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
         let uncommon_count = game
             .jokers
             .iter()
             .filter(|j| j.rarity() == Rarity::Uncommon)
             .count();
-        fn apply(g: &mut Game, _hand: MadeHand, count: usize) {
-            // X1.5 per uncommon = multiply by (1.5 ^ count)
-            let multiplier = 1.5_f32.powi(count as i32);
-            g.mult = (g.mult as f32 * multiplier) as usize;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, uncommon_count);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        // X1.5 per uncommon = multiply by (1.5 ^ count)
+        game.mult *= 1.5_f64.powi(uncommon_count as i32);
     }
 }
 
 // Joker: AncientJoker - Each played card with [suit] gives X1.5 Mult when scored
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
-pub struct AncientJoker {}
+pub struct AncientJoker {
+    // This round's suit, copied from the round state for desc()
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub suit: Option<Suit>,
+}
 impl Joker for AncientJoker {
     fn name(&self) -> String {
         "Ancient Joker".to_string()
     }
     fn desc(&self) -> String {
-        "Each played card with [suit] gives X1.5 Mult when scored; suit changes at end of round".to_string()
+        let suit = self.suit.map_or("[suit]", |s| s.name());
+        format!("Each played card with {} suit gives X1.5 Mult when scored; suit changes at end of round", suit)
     }
     fn cost(&self) -> usize {
         8
@@ -315,27 +326,45 @@ impl Joker for AncientJoker {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultMult]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let ancient_suit = game.round_state.ancient_suit;
-
-        fn apply(g: &mut Game, hand: MadeHand, suit: Option<Suit>) {
-            if let Some(target_suit) = suit {
-                let matching_cards = hand.all.iter().filter(|c| c.suit == target_suit).count();
-                if matching_cards > 0 {
-                    let multiplier = 1.5_f32.powi(matching_cards as i32);
-                    g.mult = (g.mult as f32 * multiplier) as usize;
+    fn effects(&self, _in: &Game) -> Vec<Effects> {
+        // OnRoundBegin: roll a suit other than last round's, unless another
+        // Ancient Joker already rolled it this round
+        fn on_round_begin(g: &mut Game) {
+            use rand::seq::SliceRandom;
+            if g.round_state.ancient_suit.is_none() {
+                let previous = g.jokers.iter().find_map(|j| match j {
+                    Jokers::AncientJoker(a) => a.suit,
+                    _ => None,
+                });
+                let suits: Vec<Suit> = Suit::suits().into_iter().filter(|s| Some(*s) != previous).collect();
+                let mut rng = crate::rng::rng_for("joker", "ancient joker suit");
+                g.round_state.ancient_suit = suits.choose(&mut rng).copied();
+            }
+            let suit = g.round_state.ancient_suit;
+            for j in &mut g.jokers {
+                if let Jokers::AncientJoker(a) = j {
+                    a.suit = suit;
                 }
             }
         }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, ancient_suit);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+
+        // OnScore: X1.5 per scored trigger of this round's suit
+        fn apply(g: &mut Game, hand: MadeHand) {
+            if let Some(suit) = g.round_state.ancient_suit {
+                let matching = g.scored_triggers(&hand, |c| g.modifiers.is_suit(c, suit));
+                g.mult *= 1.5_f64.powi(matching as i32);
+            }
+        }
+        vec![
+            Effects::OnRoundBegin(Arc::new(on_round_begin)),
+            Effects::OnScore(Arc::new(apply)),
+        ]
     }
 }
 
 // Joker: Stuntman - +250 Chips; +3 hand size
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Stuntman {}
 impl Joker for Stuntman {
@@ -356,14 +385,15 @@ impl Joker for Stuntman {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, _hand: MadeHand) {
-            g.chips += 250;
+            g.chips += 250.0;
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: Vagabond - Create Tarot card if hand played with $4 or less
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Vagabond {}
 impl Joker for Vagabond {
@@ -384,7 +414,7 @@ impl Joker for Vagabond {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnScore: Create Tarot if hand played with $4 or less
         fn on_score(g: &mut Game, _hand: MadeHand) {
@@ -393,12 +423,13 @@ impl Joker for Vagabond {
             }
         }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(on_score)))]
+        vec![Effects::OnScore(Arc::new(on_score))]
     }
 }
 
 // Joker: Driver's License - X3 Mult if full deck has at least 16 Enhanced cards
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct DriverLicense {}
 impl Joker for DriverLicense {
@@ -417,30 +448,25 @@ impl Joker for DriverLicense {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultMult]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
-
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
         // Count enhanced cards in full deck (deck + available + discarded)
         let enhanced_count = game.deck.cards().iter()
             .chain(game.available.cards().iter())
             .chain(game.discarded.iter())
             .filter(|c| c.enhancement.is_some())
             .count();
-
         if enhanced_count >= 16 {
-            let effect = Effects::OnScore(Arc::new(Mutex::new(|g: &mut Game, _hand: MadeHand| {
-                g.mult *= 3;
-            })));
-            vec![effect]
-        } else {
-            vec![]
+            game.mult *= 3.0;
         }
     }
 }
 
 // Joker: Burnt Joker - Upgrade level of first discarded poker hand each round
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct BurntJoker {}
 impl Joker for BurntJoker {
@@ -460,14 +486,22 @@ impl Joker for BurntJoker {
         vec![Categories::Effect]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        // TODO: Need OnDiscard effect to track first discard of round
-        // TODO: Need hand level upgrade system
-        vec![]
+        use std::sync::Arc;
+
+        // OnDiscard: first discard of the blind levels up its poker hand
+        fn on_discard(g: &mut Game, hand: crate::hand::MadeHand, _slot: usize) {
+            if g.discards_this_blind == 1 {
+                g.upgrade_hand(hand.rank);
+            }
+        }
+
+        vec![Effects::OnDiscard(Arc::new(on_discard))]
     }
 }
 
 // Joker: Invisible Joker - After 2 rounds, sell this to duplicate random Joker
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct InvisibleJoker {
     pub rounds_remaining: usize,
@@ -495,7 +529,7 @@ impl Joker for InvisibleJoker {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnRoundEnd: Decrement rounds_remaining
         fn on_round_end(g: &mut Game) {
@@ -531,28 +565,28 @@ impl Joker for InvisibleJoker {
 
                 if !other_jokers.is_empty() {
                     // Pick a random joker to duplicate
-                    let to_duplicate = other_jokers.choose(&mut rand::thread_rng()).unwrap().clone();
+                    let to_duplicate = other_jokers.choose(&mut crate::rng::rng_for("joker", "invisible joker copy")).unwrap().clone();
 
                     // Add it if there's space
-                    if g.jokers.len() < g.max_joker_slots() {
+                    if g.jokers.len() < g.joker_slots() {
                         g.jokers.push(to_duplicate);
                         // Re-register joker effects
-                        g.effect_registry = crate::effect::EffectRegistry::new();
-                        g.effect_registry.register_jokers(g.jokers.clone(), &g.clone());
+                        g.refresh_joker_effects();
                     }
                 }
             }
         }
 
         vec![
-            Effects::OnRoundEnd(Arc::new(Mutex::new(on_round_end))),
-            Effects::OnSell(Arc::new(Mutex::new(on_sell)))
+            Effects::OnRoundEnd(Arc::new(on_round_end)),
+            Effects::OnSell(Arc::new(on_sell))
         ]
     }
 }
 
 // Joker: Brainstorm - Copies ability of leftmost Joker
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Brainstorm {}
 impl Joker for Brainstorm {
@@ -572,14 +606,14 @@ impl Joker for Brainstorm {
         vec![Categories::Effect]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        // TODO: Need dynamic effect copying system (similar to Blueprint which copies rightmost)
-        // TODO: Find leftmost joker and copy its effects
+        // The copied joker's effects are registered instead, see `ability_source`
         vec![]
     }
 }
 
 // Joker: DNA - If first hand of round has only 1 card, add permanent copy to deck and draw it to hand
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct DNA {}
 impl Joker for DNA {
@@ -599,9 +633,150 @@ impl Joker for DNA {
         vec![Categories::Effect]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        // TODO: Need OnPlay effect to check if first hand of round
-        // TODO: Need deck modification system (add card permanently)
-        // TODO: Need card duplication and draw system
+        fn on_play(g: &mut Game, hand: MadeHand, _slot: usize) {
+            // The hand being played is already counted
+            if g.round_state.hands_played != 1 || hand.all.len() != 1 {
+                return;
+            }
+            g.copy_card_to_hand(&hand.all[0], "DNA");
+        }
+        vec![Effects::OnPlay(Arc::new(on_play))]
+    }
+}
+
+// Joker: Campfire - Gains X0.25 Mult for each card sold, resets when Boss Blind is defeated
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "python", pyclass(eq))]
+pub struct Campfire {
+    pub cards_sold: usize,
+}
+
+impl Joker for Campfire {
+    fn name(&self) -> String {
+        "Campfire".to_string()
+    }
+    fn desc(&self) -> String {
+        let mult = 1.0 + (0.25 * self.cards_sold as f32);
+        format!("X{:.2} Mult (X0.25 per card sold, resets on boss)", mult)
+    }
+    fn cost(&self) -> usize {
+        5
+    }
+    fn rarity(&self) -> Rarity {
+        Rarity::Rare
+    }
+    fn categories(&self) -> Vec<Categories> {
+        vec![Categories::MultMult]
+    }
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
         vec![]
     }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult *= 1.0 + (0.25 * self.cards_sold as f64);
+    }
+}
+
+impl Campfire {
+    pub fn on_card_sold(&mut self) {
+        self.cards_sold += 1;
+    }
+
+    pub fn reset_on_boss(&mut self) {
+        self.cards_sold = 0;
+    }
+}
+
+// Joker: Obelisk - Gains X0.2 Mult per consecutive hand played without playing your most played hand
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "python", pyclass(eq))]
+pub struct Obelisk {
+    pub consecutive_count: usize,
+}
+
+impl Joker for Obelisk {
+    fn name(&self) -> String {
+        "Obelisk".to_string()
+    }
+    fn desc(&self) -> String {
+        let mult = 1.0 + (0.2 * self.consecutive_count as f32);
+        format!(
+            "X{:.1} Mult (X0.2 per consecutive hand without most-played hand)",
+            mult
+        )
+    }
+    fn cost(&self) -> usize {
+        5
+    }
+    fn rarity(&self) -> Rarity {
+        Rarity::Rare
+    }
+    fn categories(&self) -> Vec<Categories> {
+        vec![Categories::MultMult]
+    }
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        // Scales before scoring. The hand's rank is already counted in the
+        // run stats, so it is the most played when every other rank trails.
+        fn on_play(g: &mut Game, hand: MadeHand, slot: usize) {
+            let times_played = g.stats.times_played(hand.rank);
+            let most_played = g
+                .stats
+                .hands_played
+                .iter()
+                .all(|(rank, count)| *rank == hand.rank || *count < times_played);
+            if let Some(Jokers::Obelisk(j)) = g.jokers.get_mut(slot) {
+                j.on_hand_played(most_played);
+            }
+        }
+
+        vec![Effects::OnPlay(Arc::new(on_play))]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult *= 1.0 + (0.2 * self.consecutive_count as f64);
+    }
+}
+
+impl Obelisk {
+    /// `most_played` is true when the hand's rank, counting this hand, has
+    /// been played more than any other rank
+    pub fn on_hand_played(&mut self, most_played: bool) {
+        if most_played {
+            self.consecutive_count = 0;
+        } else {
+            self.consecutive_count += 1;
+        }
+    }
+}
+
+// Joker: Hit the Road - X0.5 Mult per Jack discarded this round
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "python", pyclass(eq))]
+pub struct HitTheRoad {}
+
+impl Joker for HitTheRoad {
+    fn name(&self) -> String {
+        "Hit the Road".to_string()
+    }
+    fn desc(&self) -> String {
+        "X0.5 Mult for every Jack discarded this round".to_string()
+    }
+    fn cost(&self) -> usize {
+        6
+    }
+    fn rarity(&self) -> Rarity {
+        Rarity::Rare
+    }
+    fn categories(&self) -> Vec<Categories> {
+        vec![Categories::MultMult]
+    }
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        // Gains X0.5 for each Jack: 1 + 0.5 * jack_count
+        let jacks = game.round_state.jacks_discarded_this_round;
+        game.mult *= 1.0 + 0.5 * jacks as f64;
+    }
 }
@@ -681,7 +681,7 @@ fn test_square_joker() {
     // (5 + 4) * (1) = 9
     let after = 9;
 
-    let j = Jokers::SquareJoker(SquareJoker {});
+    let j = Jokers::SquareJoker(SquareJoker::default());
     score_before_after_joker(j, hand, before, after);
 }
 
@@ -1076,7 +1076,7 @@ fn test_bull() {
     g.shop.jokers.push(j.clone());
     g.buy_joker(j).unwrap();
     g.stage = Stage::Blind(Blind::Small, None);
-    let current_money = g.money;
+    let current_money = g.money_held();
 
     // Score pair with Bull (+2 chips per $1)
     // pair (level 1) -> 10 chips, 2 mult
@@ -1461,10 +1461,9 @@ fn test_baseball_card() {
     // pair (level 1) -> 10 chips, 2 mult
     // Played cards (2 aces) -> 22 chips
     // Baseball Card: X2.25 mult (1.5 ^ 2 uncommons)
-    // (10 + 22) * (2 * 2.25) = 144, but truncation gives us (2 * 2) = 4
-    // So actual result: (10 + 22) * 4 = 128
+    // (10 + 22) * (2 * 2.25) = 144
     let score = g.calc_score(hand.best_hand().unwrap());
-    assert_eq!(score, 128);
+    assert_eq!(score, 144);
 }
 
 #[test]
@@ -1579,14 +1578,14 @@ fn test_mail_in_rebate() {
         Card::new(Value::Three, Suit::Diamond),
     ];
 
-    // Apply Mail-In Rebate logic manually (as done in discard_selected)
-    if let Some(rebate_rank) = g.round_state.mail_rebate_rank {
-        let has_mail_rebate = g.jokers.iter().any(|j| matches!(j, Jokers::MailInRebate(_)));
-        if has_mail_rebate {
-            let matching_cards = discarded_cards.iter().filter(|c| c.value == rebate_rank).count();
-            g.money += matching_cards * 3;
-        }
+    // Discard them through the game so the OnDiscard effect fires
+    g.refresh_joker_effects();
+    g.stage = Stage::Blind(Blind::Small, None);
+    g.available.extend(discarded_cards.clone());
+    for card in discarded_cards {
+        g.select_card(card).unwrap();
     }
+    g.handle_action(Action::Discard()).unwrap();
 
     // Should earn $3 per Two discarded: 3 * 3 = $9
     // Money: 10 + 9 = 19
@@ -1797,11 +1796,13 @@ fn test_reserved_parking() {
 
     // Play a hand multiple times to trigger probability
     let mut triggered_count = 0;
-    for _ in 0..100 {
+    for seed in 0..100 {
         let c1 = Card::new(Value::Ace, Suit::Heart);
         let c2 = Card::new(Value::Ace, Suit::Diamond);
 
+        // Reseed, a clone would replay the same rolls
         let mut g_temp = g.clone();
+        g_temp.rng = crate::rng::GameRng::new(seed);
         g_temp.calc_score(SelectHand::new(vec![c1, c2]).best_hand().unwrap());
 
         // Money should eventually increase (probabilistic)
@@ -1826,15 +1827,13 @@ fn test_ice_cream() {
     // Test WITHOUT joker
     let mut g = Game::default();
     g.stage = Stage::Blind(Blind::Small, None);
-    g.hands_played_this_blind = 3;
     let score_without = g.calc_score(SelectHand::new(vec![c1, c2]).best_hand().unwrap());
 
     // Test WITH joker (after 3 hands: 100 - 15 = 85 chips)
     let mut g2 = Game::default();
-    g2.hands_played_this_blind = 3;
     g2.money += 1000;
     g2.stage = Stage::Shop();
-    let joker = Jokers::IceCream(IceCream {});
+    let joker = Jokers::IceCream(IceCream { hands_played: 3 });
     g2.shop.jokers.push(joker.clone());
     g2.buy_joker(joker).unwrap();
     g2.stage = Stage::Blind(Blind::Small, None);
@@ -1908,6 +1907,50 @@ fn test_constellation() {
     assert!(score_with > score_without, "Constellation should multiply score. Without: {}, With: {}", score_without, score_with);
 }
 
+#[test]
+fn test_consumable_use_updates_stateful_jokers() {
+    use crate::consumable::Consumables;
+    use crate::planet::Planets;
+    use crate::tarot::Tarots;
+
+    let mut g = Game::default();
+    g.money += 1000;
+    g.stage = Stage::Shop();
+    for joker in [
+        Jokers::Constellation(Constellation::default()),
+        Jokers::FortuneTeller(FortuneTeller::default()),
+        Jokers::Satellite(Satellite::default()),
+    ] {
+        g.shop.jokers.push(joker.clone());
+        g.buy_joker(joker).unwrap();
+    }
+
+    g.consumables = vec![
+        Consumables::Planet(Planets::Mercury),
+        Consumables::Planet(Planets::Mercury),
+        Consumables::Planet(Planets::Venus),
+        Consumables::Tarot(Tarots::TheHermit),
+    ];
+    for c in g.consumables.clone() {
+        g.use_consumable(c, None).unwrap();
+    }
+
+    // Satellite counts unique planets, Constellation counts every planet
+    assert_eq!(g.stats.unique_planets_used.len(), 2);
+    match &g.jokers[0] {
+        Jokers::Constellation(j) => assert_eq!(j.planet_cards_used, 3),
+        j => panic!("unexpected joker {:?}", j),
+    }
+    match &g.jokers[1] {
+        Jokers::FortuneTeller(j) => assert_eq!(j.tarot_cards_used, 1),
+        j => panic!("unexpected joker {:?}", j),
+    }
+
+    // Upgrading a hand some other way is not a planet use
+    g.upgrade_hand(HandRank::HighCard);
+    assert_eq!(g.stats.unique_planets_used.len(), 2);
+}
+
 #[test]
 fn test_fortune_teller() {
     // Fortune Teller: +1 Mult per Tarot card used this run
@@ -2228,7 +2271,6 @@ fn test_cartomancer() {
 }
 
 #[test]
-#[ignore = "Needs death prevention system"]
 fn test_mr_bones() {
     // Mr. Bones: Prevents death if chips scored >= 25% of required chips; self-destructs
     let mut g = Game::default();
@@ -2240,9 +2282,35 @@ fn test_mr_bones() {
     g.shop.jokers.push(joker.clone());
     g.buy_joker(joker).unwrap();
 
-    // TODO: Set up scenario where player would die but has >= 25% chips
-    // TODO: Verify game continues and Mr. Bones is destroyed
-    // assert!(g.jokers.iter().find(|j| matches!(j, Jokers::MrBones(_))).is_none(), "Mr. Bones should be destroyed");
+    // Last hand of the blind, a quarter of the required score already made
+    let mut saved = g.clone();
+    saved.stage = Stage::Blind(Blind::Small, None);
+    saved.blind = Some(Blind::Small);
+    saved.plays = 1;
+    saved.score = saved.required_score() / 4;
+    saved.deal();
+    let card = saved.available.cards()[0];
+    saved.select_card(card).unwrap();
+    saved.handle_action(Action::Play()).unwrap();
+    assert!(saved.score < saved.required_score());
+    assert_eq!(saved.stage, Stage::PostBlind(), "Mr. Bones should save the run");
+    assert!(saved.jokers.iter().find(|j| matches!(j, Jokers::MrBones(_))).is_none(), "Mr. Bones should be destroyed");
+    assert!(saved.pending_cash_out.is_some(), "The blind pays out as if beaten");
+    assert_eq!(saved.saved_by, vec!["Mr. Bones".to_string()]);
+    assert!(saved.events().contains(&crate::event::Event::RunSaved { by: "Mr. Bones".to_string() }));
+
+    // Below 25% the run is lost and Mr. Bones stays
+    let mut lost = g.clone();
+    lost.stage = Stage::Blind(Blind::Small, None);
+    lost.blind = Some(Blind::Small);
+    lost.plays = 1;
+    lost.deal();
+    let card = lost.available.cards()[0];
+    lost.select_card(card).unwrap();
+    lost.handle_action(Action::Play()).unwrap();
+    assert_eq!(lost.stage, Stage::End(crate::stage::End::Lose));
+    assert_eq!(lost.jokers.len(), 1);
+    assert!(lost.saved_by.is_empty());
 }
 
 #[test]
@@ -2350,6 +2418,51 @@ fn test_dusk() {
     assert!(increase_ratio >= 1.2, "Dusk should increase score by at least 20% on final hand. Ratio: {:.2}, Not final: {}, Final: {}", increase_ratio, score_not_final, score_final);
 }
 
+#[test]
+fn test_retriggers_repeat_per_card_jokers() {
+    // Scary Face gives +30 chips per scored face card, again on every retrigger
+    let k1 = Card::new(Value::King, Suit::Heart);
+    let k2 = Card::new(Value::King, Suit::Diamond);
+    let score_with = |jokers: Vec<Jokers>| {
+        let mut g = Game::default();
+        g.stage = Stage::Blind(Blind::Small, None);
+        g.jokers = jokers;
+        g.refresh_joker_effects();
+        g.calc_score(SelectHand::new(vec![k1, k2]).best_hand().unwrap())
+    };
+    // Pair: (10 + 2 * 10 + 2 * 30) chips x 2 mult
+    assert_eq!(score_with(vec![Jokers::ScaryFace(ScaryFace {})]), 180);
+    // Both kings trigger twice: (10 + 4 * 10 + 4 * 30) x 2
+    assert_eq!(
+        score_with(vec![Jokers::SockAndBuskin(SockAndBuskin {}), Jokers::ScaryFace(ScaryFace {})]),
+        340
+    );
+}
+
+#[test]
+fn test_mime() {
+    // Mime: held in hand abilities trigger again, Steel and Baron alike
+    let a1 = Card::new(Value::Ace, Suit::Heart);
+    let a2 = Card::new(Value::Ace, Suit::Diamond);
+    let mut king = Card::new(Value::King, Suit::Spade);
+    king.set_enhancement(Enhancement::Steel);
+    let score_with = |jokers: Vec<Jokers>| {
+        let mut g = Game::default();
+        g.stage = Stage::Blind(Blind::Small, None);
+        g.available.extend(vec![a1, a2, king]);
+        g.available.select_card(a1).unwrap();
+        g.available.select_card(a2).unwrap();
+        g.hand = vec![a1, a2, king];
+        g.jokers = jokers;
+        g.refresh_joker_effects();
+        g.calc_score(SelectHand::new(vec![a1, a2]).best_hand().unwrap())
+    };
+    // Pair of aces: 32 chips x (2 mult x1.5 Baron) x1.5 Steel
+    assert_eq!(score_with(vec![Jokers::Baron(Baron {})]), 144);
+    // Both abilities trigger twice: 32 x (2 x 2.25) x 2.25
+    assert_eq!(score_with(vec![Jokers::Mime(Mime {}), Jokers::Baron(Baron {})]), 324);
+}
+
 #[test]
 fn test_sock_and_buskin() {
     // Sock and Buskin: Retrigger all played face cards
@@ -2427,6 +2540,65 @@ fn test_seltzer() {
     assert!(score > score_without, "Seltzer should increase score. Without: {}, With: {}", score_without, score);
 }
 
+#[test]
+fn test_card_trigger_count() {
+    // Red seal, jokers and Blueprint copies all add to one trigger count
+    use crate::card::Seal;
+
+    let mut g = Game::default();
+    let mut two = Card::new(Value::Two, Suit::Heart);
+    let king = Card::new(Value::King, Suit::Spade);
+    assert_eq!(g.card_trigger_count(&two, 0), 1);
+
+    two.set_seal(Seal::Red);
+    assert_eq!(g.card_trigger_count(&two, 0), 2);
+
+    g.jokers = vec![
+        Jokers::Blueprint(Blueprint::default()),
+        Jokers::Hack(Hack::default()),
+        Jokers::SockAndBuskin(SockAndBuskin::default()),
+        Jokers::HangingChad(HangingChad::default()),
+    ];
+    // seal + Blueprint(Hack) + Hack + Hanging Chad on the first card
+    assert_eq!(g.card_trigger_count(&two, 0), 1 + 1 + 1 + 1 + 2);
+    assert_eq!(g.card_trigger_count(&two, 1), 1 + 1 + 1 + 1);
+    // Sock and Buskin only for faces
+    assert_eq!(g.card_trigger_count(&king, 1), 2);
+
+    // Brainstorm copies the leftmost joker, Blueprint, which copies Hack
+    g.jokers.push(Jokers::Brainstorm(Brainstorm::default()));
+    assert_eq!(g.card_trigger_count(&king, 1), 2);
+    assert_eq!(g.card_trigger_count(&two, 1), 1 + 1 + 1 + 1 + 1);
+}
+
+#[test]
+fn test_copy_chains() {
+    let sources = |jokers: &[Jokers]| -> Vec<Option<usize>> {
+        (0..jokers.len()).map(|i| ability_source(jokers, i, |_| false)).collect()
+    };
+    let blueprint = Jokers::Blueprint(Blueprint::default());
+    let brainstorm = Jokers::Brainstorm(Brainstorm::default());
+    let joker = Jokers::TheJoker(TheJoker::default());
+
+    // Blueprint -> Blueprint -> The Joker
+    assert_eq!(sources(&[blueprint.clone(), blueprint.clone(), joker.clone()]), vec![Some(2), Some(2), Some(2)]);
+    // Nothing to the right of the last Blueprint
+    assert_eq!(sources(&[joker.clone(), blueprint.clone()]), vec![Some(0), None]);
+    // Brainstorm -> Blueprint -> The Joker
+    assert_eq!(
+        sources(&[blueprint.clone(), joker.clone(), brainstorm.clone()]),
+        vec![Some(1), Some(1), Some(1)]
+    );
+    // Blueprint and Brainstorm copying each other copy nothing
+    assert_eq!(sources(&[blueprint.clone(), brainstorm.clone()]), vec![None, None]);
+    // Rule changing jokers keep their own ability but cannot be copied
+    let splash = Jokers::Splash(Splash::default());
+    assert_eq!(sources(&[blueprint.clone(), splash]), vec![None, Some(1)]);
+    // A debuffed joker is not copied
+    let debuffed = ability_source(&[blueprint, joker], 0, |slot| slot == 1);
+    assert_eq!(debuffed, None);
+}
+
 #[test]
 fn test_shortcut() {
     // Shortcut: Allows Straights to be made with gaps of 1 rank
@@ -2507,8 +2679,7 @@ fn test_certificate() {
     // Manually trigger OnRoundBegin effects to simulate round start
     for effect in &g.effect_registry.on_round_begin.clone() {
         if let crate::effect::Effects::OnRoundBegin(callback) = effect {
-            let func = callback.lock().unwrap();
-            func(&mut g);
+            callback(&mut g);
         }
     }
 
@@ -2572,10 +2743,13 @@ fn test_gift_card() {
 }
 
 #[test]
-#[ignore = "Needs OnPackOpen hook"]
 fn test_hallucination() {
     // Hallucination: 1 in 2 chance to create Tarot when opening Booster Pack
-    let mut g = Game::default();
+    use crate::booster::PackType;
+    use crate::config::Config;
+    use crate::consumable::Consumables;
+
+    let mut g = Game::with_seed(Config::default(), 8).unwrap();
     g.start();
 
     g.money += 1000;
@@ -2584,9 +2758,63 @@ fn test_hallucination() {
     g.shop.jokers.push(joker.clone());
     g.buy_joker(joker).unwrap();
 
-    let initial_consumables = g.consumables.len();
-    // TODO: Open a booster pack
-    // TODO: Verify Tarot card may be created (probabilistic)
+    let mut created = 0;
+    for _ in 0..20 {
+        g.consumables.clear();
+        g.shop.packs = vec![PackType::Buffoon.into()];
+        g.handle_action(Action::BuyBooster(PackType::Buffoon.into())).unwrap();
+        assert_eq!(g.stage, Stage::OpenPack());
+        assert!(g.consumables.iter().all(|c| matches!(c, Consumables::Tarot(_))));
+        created += g.consumables.len();
+        g.handle_action(Action::SkipPack()).unwrap();
+    }
+    assert!(created > 0 && created < 20);
+
+    // Must have room
+    g.consumables = vec![Consumables::Tarot(crate::tarot::Tarots::TheFool); g.config.consumable_slots];
+    for _ in 0..5 {
+        g.shop.packs = vec![PackType::Buffoon.into()];
+        g.handle_action(Action::BuyBooster(PackType::Buffoon.into())).unwrap();
+        assert_eq!(g.consumables.len(), g.config.consumable_slots);
+        g.handle_action(Action::SkipPack()).unwrap();
+    }
+}
+
+#[test]
+fn test_red_card_pack_skip() {
+    // Red Card: +3 Mult for every Booster Pack skipped, not for packs
+    // closed by taking all their choices
+    use crate::booster::PackType;
+    use crate::config::Config;
+
+    let mut g = Game::with_seed(Config::default(), 8).unwrap();
+    g.start();
+    g.money += 1000;
+    g.stage = Stage::Shop();
+    let joker = Jokers::RedCard(RedCard::default());
+    g.shop.jokers.push(joker.clone());
+    g.buy_joker(joker).unwrap();
+
+    for _ in 0..2 {
+        g.shop.packs = vec![PackType::Buffoon.into()];
+        g.handle_action(Action::BuyBooster(PackType::Buffoon.into())).unwrap();
+        g.handle_action(Action::SkipPack()).unwrap();
+    }
+    assert_eq!(g.jokers[0], Jokers::RedCard(RedCard { bonus_mult: 6 }));
+
+    g.shop.packs = vec![PackType::Buffoon.into()];
+    g.handle_action(Action::BuyBooster(PackType::Buffoon.into())).unwrap();
+    g.handle_action(Action::SelectFromPack(0)).unwrap();
+    assert_eq!(g.stage, Stage::Shop());
+    assert_eq!(g.jokers[0], Jokers::RedCard(RedCard { bonus_mult: 6 }));
+
+    // The new mult scores right away, without the joker taken from the
+    // pack: (5 + 11) * (1 + 6)
+    g.jokers.truncate(1);
+    g.refresh_joker_effects();
+    g.stage = Stage::Blind(Blind::Small, None);
+    let hand = SelectHand::new(vec![Card::new(Value::Ace, Suit::Heart)]);
+    assert_eq!(g.calc_score(hand.best_hand().unwrap()), 112);
 }
 
 #[test]
@@ -2677,7 +2905,7 @@ fn test_cloud9() {
     // Trigger round end
     g.trigger_round_end();
 
-    assert_eq!(g.money, initial_money + nine_count, "Should earn $1 per 9 in deck. Initial: ${}, After: ${}, Nines: {}", initial_money, g.money, nine_count);
+    assert_eq!(g.money, initial_money + nine_count as i64, "Should earn $1 per 9 in deck. Initial: ${}, After: ${}, Nines: {}", initial_money, g.money, nine_count);
 }
 
 #[test]
@@ -2705,7 +2933,7 @@ fn test_delayed_gratification() {
     // Trigger round end
     g.trigger_round_end();
 
-    assert_eq!(g.money, initial_money + (initial_discards * 2), "Should earn $2 per unused discard. Initial: ${}, After: ${}, Discards: {}", initial_money, g.money, initial_discards);
+    assert_eq!(g.money, initial_money + (initial_discards * 2) as i64, "Should earn $2 per unused discard. Initial: ${}, After: ${}, Discards: {}", initial_money, g.money, initial_discards);
 
     // Test that it doesn't trigger if discards were used
     let money_after_first = g.money;
@@ -2819,9 +3047,9 @@ fn test_misprint() {
     let mut found_variation = false;
     let mut previous_score = None;
 
+    // Score on the same game, a clone would replay the same roll
     for _ in 0..10 {
-        let mut g_test = g.clone();
-        let score = g_test.calc_score(hand.clone());
+        let score = g.calc_score(hand.clone());
 
         if let Some(prev) = previous_score {
             if score != prev {
@@ -2867,8 +3095,10 @@ fn test_eight_ball() {
     // Over 50 trials, we should see at least one Tarot created
     let mut tarot_created = false;
 
-    for _ in 0..50 {
+    for seed in 0..50 {
+        // Reseed, a clone would replay the same rolls
         let mut g_test = g.clone();
+        g_test.rng = crate::rng::GameRng::new(seed);
         let initial_consumables = g_test.consumables.len();
         g_test.calc_score(hand.clone());
 
@@ -3025,7 +3255,35 @@ fn test_drunkard() {
 }
 
 #[test]
-#[ignore = "OnDiscard effect not triggering - needs investigation"]
+fn test_burnt_joker() {
+    // Burnt Joker: first discard of the blind levels up the discarded hand
+    use crate::rank::HandRank;
+
+    let mut g = Game::default();
+    g.stage = Stage::Blind(Blind::Small, None);
+    g.discards = 2;
+    g.jokers.push(Jokers::BurntJoker(BurntJoker::default()));
+    g.refresh_joker_effects();
+
+    let pair = vec![Card::new(Value::Nine, Suit::Heart), Card::new(Value::Nine, Suit::Club)];
+    g.available.extend(pair.clone());
+    for card in pair {
+        g.select_card(card).unwrap();
+    }
+    g.handle_action(Action::Discard()).unwrap();
+    assert_eq!(g.get_hand_level(HandRank::OnePair).level, 2);
+
+    // Only the first discard counts
+    let pair = vec![Card::new(Value::Four, Suit::Heart), Card::new(Value::Four, Suit::Club)];
+    g.available.extend(pair.clone());
+    for card in pair {
+        g.select_card(card).unwrap();
+    }
+    g.handle_action(Action::Discard()).unwrap();
+    assert_eq!(g.get_hand_level(HandRank::OnePair).level, 2);
+}
+
+#[test]
 fn test_faceless_joker() {
     use crate::effect::Effects;
 
@@ -3061,7 +3319,7 @@ fn test_faceless_joker() {
     let effects_clone = g.effect_registry.on_discard.clone();
     for effect in &effects_clone {
         if let Effects::OnDiscard(f) = effect {
-            f.lock().unwrap()(&mut g, hand_3_faces.clone());
+            f(&mut g, hand_3_faces.clone(), 0);
         }
     }
 
@@ -3078,7 +3336,7 @@ fn test_faceless_joker() {
     let effects_clone = g.effect_registry.on_discard.clone();
     for effect in &effects_clone {
         if let Effects::OnDiscard(f) = effect {
-            f.lock().unwrap()(&mut g, hand_2_faces.clone());
+            f(&mut g, hand_2_faces.clone(), 0);
         }
     }
 
@@ -3097,7 +3355,7 @@ fn test_faceless_joker() {
     let effects_clone = g.effect_registry.on_discard.clone();
     for effect in &effects_clone {
         if let Effects::OnDiscard(f) = effect {
-            f.lock().unwrap()(&mut g, hand_4_faces.clone());
+            f(&mut g, hand_4_faces.clone(), 0);
         }
     }
 
@@ -3132,9 +3390,9 @@ fn test_satellite() {
     let base_reward = g.money - money_before_1;
 
     // Test 2: Add 3 unique planets to the set
-    g.unique_planets_used.insert(HandRank::OnePair);
-    g.unique_planets_used.insert(HandRank::TwoPair);
-    g.unique_planets_used.insert(HandRank::ThreeOfAKind);
+    g.stats.unique_planets_used.insert(HandRank::OnePair);
+    g.stats.unique_planets_used.insert(HandRank::TwoPair);
+    g.stats.unique_planets_used.insert(HandRank::ThreeOfAKind);
 
     // Trigger round end - should earn base reward + $3 from Satellite
     let money_before = g.money;
@@ -3142,8 +3400,8 @@ fn test_satellite() {
     assert_eq!(g.money - money_before, base_reward + 3, "Should earn base reward + $3 when 3 unique planets used");
 
     // Test 3: Add 2 more unique planets (total 5)
-    g.unique_planets_used.insert(HandRank::Straight);
-    g.unique_planets_used.insert(HandRank::Flush);
+    g.stats.unique_planets_used.insert(HandRank::Straight);
+    g.stats.unique_planets_used.insert(HandRank::Flush);
 
     // Trigger round end - should earn base reward + $5 from Satellite
     let money_before = g.money;
@@ -3345,6 +3603,35 @@ fn test_credit_card() {
     assert_eq!(g.modifiers.min_money, -20, "Credit Card should allow going $20 into debt");
 }
 
+#[test]
+fn test_credit_card_allows_debt() {
+    let mut g = Game::default();
+    g.stage = Stage::Shop();
+    g.jokers.push(Jokers::CreditCard(CreditCard {}));
+    g.refresh_joker_effects();
+    g.update_modifiers();
+    g.money = 0;
+
+    // Broke but with Credit Card, a joker is still on the table
+    let joker = Jokers::TheJoker(TheJoker {});
+    g.shop.jokers.push(joker.clone());
+    let cost = g.shop.joker_cost(&joker) as i64;
+    assert!(g.gen_actions().any(|a| matches!(a, Action::BuyJoker(_))));
+    g.buy_joker(joker).unwrap();
+    assert_eq!(g.money, -cost);
+
+    // In debt there is nothing held and no interest
+    assert_eq!(g.money_held(), 0);
+    assert_eq!(g.interest(), 0);
+
+    // Spending stops at -$20
+    g.money = -19;
+    assert!(g.can_afford(1));
+    assert!(!g.can_afford(2));
+    g.lose_money(5);
+    assert_eq!(g.money, -20);
+}
+
 #[test]
 fn test_pareidolia() {
     let mut g = Game::default();
@@ -3359,8 +3646,43 @@ fn test_pareidolia() {
     // Verify modifier is set
     assert!(g.modifiers.all_cards_are_faces, "Pareidolia should set all_cards_are_faces modifier");
 
-    // TODO: Full implementation requires card.is_face() to check this modifier
-    // Currently the modifier is set but not used in scoring logic
+    // Scary Face now counts both twos of a pair as face cards
+    // pair (level 1) -> 10 chips, 2 mult
+    // Played cards (2 twos) -> 2 chips
+    // Scary Face: +30 chips per face card -> 60 chips
+    // (10 + 2 + 60) * (2) = 144
+    let tc = Card::new(Value::Two, Suit::Club);
+    let ts = Card::new(Value::Two, Suit::Spade);
+    let hand = SelectHand::new(vec![tc, ts]);
+    g.jokers.push(Jokers::ScaryFace(ScaryFace {}));
+    g.refresh_joker_effects();
+    assert_eq!(g.calc_score(hand.best_hand().unwrap()), 144);
+}
+
+#[test]
+fn test_smeared_joker() {
+    let ah = Card::new(Value::Ace, Suit::Heart);
+    let ad = Card::new(Value::Ace, Suit::Diamond);
+    let asp = Card::new(Value::Ace, Suit::Spade);
+    let mut stone = Card::new(Value::Ace, Suit::Heart);
+    stone.set_enhancement(Enhancement::Stone);
+    let mut g = Game::default();
+    g.stage = Stage::Blind(Blind::Small, None);
+    g.jokers = vec![Jokers::SmearedJoker(SmearedJoker {})];
+    g.update_modifiers();
+    assert!(g.modifiers.is_suit(&ah, Suit::Diamond));
+    assert!(!g.modifiers.is_suit(&asp, Suit::Diamond));
+    assert!(!g.modifiers.is_suit(&stone, Suit::Heart));
+
+    // Greedy Joker counts the hearts as diamonds
+    // 3ok (level 1) -> 30 chips, 3 mult
+    // Played cards (3 aces) -> 33 chips
+    // Greedy Joker: +3 mult per diamond, 2 smeared diamonds -> +6 mult
+    // (30 + 33) * (3 + 6) = 567
+    g.jokers.push(Jokers::GreedyJoker(GreedyJoker {}));
+    g.refresh_joker_effects();
+    let hand = SelectHand::new(vec![ah, ad, asp]);
+    assert_eq!(g.calc_score(hand.best_hand().unwrap()), 567);
 }
 
 #[test]
@@ -3411,10 +3733,6 @@ fn test_golden_ticket() {
 
     // Verify joker is registered
     assert!(g.jokers.contains(&Jokers::GoldenTicket(GoldenTicket {})), "Golden Ticket should be in jokers list");
-
-    // Note: Full functionality test would require creating Gold enhancement cards
-    // which requires tarot card system. Implementation uses OnPlay to check for
-    // Gold enhancement and award $3 per Gold card.
 }
 
 #[test]
@@ -3431,11 +3749,28 @@ fn test_midas_mask() {
     // Verify joker is registered
     assert!(g.jokers.contains(&Jokers::MidasMask(MidasMask {})), "Midas Mask should be in jokers list");
 
-    // Note: Full functionality test would require integration test that:
-    // 1. Adds face cards to game state (available pile)
-    // 2. Actually plays a hand (not just calc_score)
-    // 3. Verifies face cards get Gold enhancement after being scored
-    // Implementation: OnScore effect modifies all face cards in the hand to Gold enhancement
+    let kh = Card::new(Value::King, Suit::Heart);
+    let kd = Card::new(Value::King, Suit::Diamond);
+    let two = Card::new(Value::Two, Suit::Club);
+    g.stage = Stage::PreBlind();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.available.empty();
+    g.available.extend(vec![kh, kd, two]);
+
+    // Scoring a hand without playing it changes nothing
+    g.clone().calc_score(pair_of_kings().best_hand().unwrap());
+    assert!(g.available.cards().iter().all(|c| c.enhancement.is_none()));
+
+    // The scored Kings turn Gold, the kicker doesn't score and stays as is
+    for card in [kh, kd, two] {
+        g.handle_action(Action::SelectCard(card)).unwrap();
+    }
+    g.handle_action(Action::Play()).unwrap();
+    let played: Vec<Card> = g.discarded.iter().filter(|c| c.value == Value::King).copied().collect();
+    assert_eq!(played.len(), 2);
+    assert!(played.iter().all(|c| c.enhancement == Some(Enhancement::Gold)));
+    let kicker = g.discarded.iter().find(|c| c.id == two.id).unwrap();
+    assert_eq!(kicker.enhancement, None);
 }
 
 #[test]
@@ -3628,7 +3963,7 @@ fn test_riff_raff() {
     assert!(result.is_ok(), "SelectBlind action should succeed");
 
     // Check that 2 more jokers were added (if space available)
-    let max_slots = g.max_joker_slots();
+    let max_slots = g.joker_slots();
     let expected_jokers = if max_slots >= 3 {
         3  // RiffRaff + 2 new common jokers
     } else {
@@ -3685,7 +4020,7 @@ fn test_invisible_joker() {
 
     // Should have: -1 (sold InvisibleJoker) +1 (duplicated TheJoker) = same count
     // But only if there's space
-    let max_slots = g.max_joker_slots();
+    let max_slots = g.joker_slots();
     if max_slots > jokers_before_sell - 1 {
         // There was space to add the duplicate
         assert_eq!(jokers_after_sell, jokers_before_sell,
@@ -3733,14 +4068,6 @@ fn test_trading_card() {
     let destroyed_before = g.destroyed.len();
     g.handle_action(Action::Discard()).unwrap();
 
-    // Manually trigger OnDiscard effects
-    let discarded_hand = crate::hand::SelectHand::new(vec![card_to_discard]).best_hand().unwrap();
-    let effects_clone = g.effect_registry.on_discard.clone();
-    for effect in &effects_clone {
-        if let Effects::OnDiscard(f) = effect {
-            f.lock().unwrap()(&mut g, discarded_hand.clone());
-        }
-    }
 
     assert_eq!(g.discards_this_blind, 1, "Should have 1 discard this blind");
     assert_eq!(g.destroyed.len(), destroyed_before + 1,
@@ -3758,14 +4085,6 @@ fn test_trading_card() {
     g.select_card(card_to_discard_2).unwrap();
     g.handle_action(Action::Discard()).unwrap();
 
-    // Manually trigger OnDiscard effects
-    let discarded_hand_2 = crate::hand::SelectHand::new(vec![card_to_discard_2]).best_hand().unwrap();
-    let effects_clone = g.effect_registry.on_discard.clone();
-    for effect in &effects_clone {
-        if let Effects::OnDiscard(f) = effect {
-            f.lock().unwrap()(&mut g, discarded_hand_2.clone());
-        }
-    }
 
     assert_eq!(g.discards_this_blind, 2, "Should have 2 discards this blind");
     assert_eq!(g.destroyed.len(), destroyed_before_second,
@@ -3795,14 +4114,6 @@ fn test_trading_card() {
     }
     g2.handle_action(Action::Discard()).unwrap();
 
-    // Manually trigger OnDiscard effects
-    let discarded_hand_multi = crate::hand::SelectHand::new(cards.clone()).best_hand().unwrap();
-    let effects_clone = g2.effect_registry.on_discard.clone();
-    for effect in &effects_clone {
-        if let Effects::OnDiscard(f) = effect {
-            f.lock().unwrap()(&mut g2, discarded_hand_multi.clone());
-        }
-    }
 
     assert_eq!(g2.discards_this_blind, 1, "Should be first discard of new blind");
     assert_eq!(g2.destroyed.len(), destroyed_before_multi,
@@ -4129,7 +4440,7 @@ fn test_supernova() {
     g.stage = Stage::Blind(Blind::Small, None);
 
     // Manually set play count for OnePair to 5
-    g.hand_rank_play_counts.insert(HandRank::OnePair, 5);
+    g.stats.hands_played.insert(HandRank::OnePair, 5);
 
     // Create a pair hand
     let pair_hand = SelectHand::new(vec![
@@ -4162,17 +4473,33 @@ fn test_supernova() {
 }
 
 #[test]
-#[ignore = "RideTheBus captures game state at buy time - cannot test without redesigning effect system or playing actual hands"]
 fn test_ride_the_bus() {
-    // RideTheBus captures `game.round_state.consecutive_hands_without_faces` when effects() is called.
-    // This happens during buy_joker(), so the captured value is whatever the state is at buy time.
-    // To properly test this joker, we would need to either:
-    // 1. Actually play hands to build up the consecutive count (complex)
-    // 2. Redesign the effect system to not capture state in closures (major refactor)
-    // 3. Add an internal state field to RideTheBus and test that instead
-    //
-    // For now, marking as ignored since the implementation exists and follows the same pattern
-    // as other working jokers.
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.jokers = vec![Jokers::RideTheBus(RideTheBus::default())];
+    g.refresh_joker_effects();
+    g.plays = 5;
+
+    let two = Card::new(Value::Two, Suit::Heart);
+    let three = Card::new(Value::Three, Suit::Spade);
+    let king = Card::new(Value::King, Suit::Club);
+    g.available.empty();
+    g.available.extend(vec![two, three, king]);
+
+    // Hands without a scoring face card build up mult
+    for (card, bonus) in [(two, 1), (three, 2)] {
+        g.handle_action(Action::SelectCard(card)).unwrap();
+        g.handle_action(Action::Play()).unwrap();
+        assert_eq!(g.jokers[0], Jokers::RideTheBus(RideTheBus { bonus_mult: bonus }));
+    }
+    // (10 + 20) * (2 + 2)
+    assert_eq!(g.clone().calc_score(pair_of_kings().best_hand().unwrap()), 120);
+
+    // A scoring face card resets it
+    g.handle_action(Action::SelectCard(king)).unwrap();
+    g.handle_action(Action::Play()).unwrap();
+    assert_eq!(g.jokers[0], Jokers::RideTheBus(RideTheBus { bonus_mult: 0 }));
 }
 
 #[test]
@@ -4212,19 +4539,140 @@ fn test_red_card() {
 }
 
 #[test]
-#[ignore = "Throwback captures game state at buy time - cannot test without redesigning effect system"]
 fn test_throwback() {
-    // Throwback captures `game.blinds_skipped_count` when effects() is called during buy_joker().
-    // Same closure capture issue as RideTheBus - the joker works correctly in actual gameplay
-    // but cannot be easily tested without playing actual game rounds to build up state.
+    let mut g = Game::default();
+    g.start();
+    g.jokers.push(Jokers::Throwback(Throwback {}));
+    g.refresh_joker_effects();
+
+    // High card Ace: (5 + 11) * 1 = 16 with no skips yet
+    let hand = SelectHand::new(vec![Card::new(Value::Ace, Suit::Heart)]);
+    assert_eq!(g.calc_score(hand.best_hand().unwrap()), 16);
+
+    // Skipping after the joker was bought still counts, X1.25
+    g.handle_action(Action::SkipBlind()).unwrap();
+    assert_eq!(g.blinds_skipped_count, 1);
+    // Drop anything the skip tag handed out, and compare against the
+    // plain score in case the tag levelled up High Card
+    g.jokers.truncate(1);
+    g.refresh_joker_effects();
+    g.stage = Stage::Blind(Blind::Big, None);
+    let mut plain = g.clone();
+    plain.jokers.clear();
+    plain.refresh_joker_effects();
+    let base = plain.calc_score(hand.best_hand().unwrap()) as f64;
+    assert_eq!(g.calc_score(hand.best_hand().unwrap()), (base * 1.25) as usize);
+
+    g.blinds_skipped_count = 4;
+    assert_eq!(g.calc_score(hand.best_hand().unwrap()), (base * 2.0) as usize);
 }
 
 #[test]
-#[ignore = "HitTheRoad captures game state at buy time - cannot test without redesigning effect system"]
 fn test_hit_the_road() {
-    // HitTheRoad captures `game.round_state.jacks_discarded_this_round` when effects() is called during buy_joker().
-    // Same closure capture issue as RideTheBus - the joker works correctly in actual gameplay
-    // but cannot be easily tested without playing actual game rounds to build up state.
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.jokers = vec![Jokers::HitTheRoad(HitTheRoad {})];
+    g.refresh_joker_effects();
+
+    // Pair of Kings: (10 + 20) * 2 = 60 before any Jack is discarded
+    assert_eq!(g.clone().calc_score(pair_of_kings().best_hand().unwrap()), 60);
+
+    // Jacks discarded after the effects were registered count, X0.5 each
+    let jacks = [Card::new(Value::Jack, Suit::Heart), Card::new(Value::Jack, Suit::Club)];
+    g.available.extend(jacks.to_vec());
+    for jack in jacks {
+        g.handle_action(Action::SelectCard(jack)).unwrap();
+    }
+    g.handle_action(Action::Discard()).unwrap();
+    assert_eq!(g.clone().calc_score(pair_of_kings().best_hand().unwrap()), 120);
+}
+
+#[test]
+fn test_on_score_reads_live_joker_state() {
+    let mut g = Game::default();
+    g.stage = Stage::Blind(Blind::Small, None);
+    g.jokers = vec![Jokers::GreenJoker(GreenJoker { bonus_mult: 2 })];
+    g.refresh_joker_effects();
+
+    // State changed without re-registering effects still scores:
+    // (10 + 20) * (2 + 4) = 180
+    g.jokers[0] = Jokers::GreenJoker(GreenJoker { bonus_mult: 4 });
+    assert_eq!(g.calc_score(pair_of_kings().best_hand().unwrap()), 180);
+    assert!(g.events().contains(&crate::event::Event::JokerTriggered {
+        slot: Some(0),
+        chips: 0.0,
+        mult: 4.0,
+    }));
+
+    // Debuffed jokers don't score
+    let debuffed = crate::sticker::Stickers { debuffed: true, ..Default::default() };
+    g.set_joker_stickers(0, debuffed);
+    assert_eq!(g.calc_score(pair_of_kings().best_hand().unwrap()), 60);
+}
+
+// Play a pair of kings and return the score of the hand
+fn play_pair_of_kings(g: &mut Game) -> Option<usize> {
+    let kings = pair_of_kings().cards();
+    g.available.extend(kings.clone());
+    for king in kings {
+        g.handle_action(Action::SelectCard(king)).unwrap();
+    }
+    g.handle_action(Action::Play()).unwrap();
+    return g.drain_events().into_iter().find_map(|e| match e {
+        crate::event::Event::HandPlayed { score, .. } => Some(score),
+        _ => None,
+    });
+}
+
+#[test]
+fn test_card_sharp_and_acrobat_in_play() {
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.jokers = vec![
+        Jokers::CardSharp(CardSharp {}),
+        Jokers::Acrobat(Acrobat {}),
+    ];
+    g.refresh_joker_effects();
+    g.plays = 2;
+
+    // First Pair of the round: (10 + 20) * 2 = 60
+    assert_eq!(play_pair_of_kings(&mut g), Some(60));
+    // Card Sharp sees the earlier Pair and Acrobat the final hand: 60 * 3 * 3
+    assert_eq!(play_pair_of_kings(&mut g), Some(540));
+}
+
+#[test]
+fn test_on_score_copies_use_a_clone() {
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.jokers = vec![
+        Jokers::Blueprint(Blueprint {}),
+        Jokers::IceCream(IceCream { hands_played: 2 }),
+    ];
+    g.refresh_joker_effects();
+    g.plays = 5;
+
+    // Blueprint gives the same +90 Chips, only Ice Cream itself melts
+    let card = g.available.cards()[0];
+    g.handle_action(Action::SelectCard(card)).unwrap();
+    g.handle_action(Action::Play()).unwrap();
+    let triggered: Vec<crate::event::Event> = g
+        .drain_events()
+        .into_iter()
+        .filter(|e| matches!(e, crate::event::Event::JokerTriggered { .. }))
+        .collect();
+    assert_eq!(
+        triggered,
+        vec![
+            crate::event::Event::JokerTriggered { slot: Some(0), chips: 90.0, mult: 0.0 },
+            crate::event::Event::JokerTriggered { slot: Some(1), chips: 90.0, mult: 0.0 },
+        ]
+    );
+    assert_eq!(g.jokers[1], Jokers::IceCream(IceCream { hands_played: 3 }));
+    assert_eq!(g.jokers[1].desc(), "+85 Chips (-5 Chips for each hand played)");
 }
 
 #[test]
@@ -4266,8 +4714,34 @@ fn test_space_joker() {
     let effects = joker.effects(&g);
     assert_eq!(effects.len(), 1, "SpaceJoker should have 1 effect");
 
-    // Verify it's an OnScore effect
-    matches!(effects[0], Effects::OnScore(_));
+    // Verify it's an OnPlay effect
+    assert!(matches!(effects[0], Effects::OnPlay(_)));
+}
+
+#[test]
+fn test_space_joker_upgrades_before_scoring() {
+    let mut g = Game::default();
+    g.start();
+    g.jokers.push(Jokers::SpaceJoker(SpaceJoker {}));
+    g.refresh_joker_effects();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.plays = 40;
+
+    // Over 40 high cards some roll the upgrade, and each played hand scores
+    // at the level it had after its own roll, until one clears the blind
+    while matches!(g.stage, Stage::Blind(..)) && g.plays > 0 {
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        g.available.extend(vec![ace]);
+        g.handle_action(Action::SelectCard(ace)).unwrap();
+        g.handle_action(Action::Play()).unwrap();
+        let level = g.get_hand_level(HandRank::HighCard);
+        let scored = g.drain_events().into_iter().find_map(|e| match e {
+            crate::event::Event::HandPlayed { score, .. } => Some(score),
+            _ => None,
+        });
+        assert_eq!(scored, Some((level.chips + 11) * level.mult));
+    }
+    assert!(g.get_hand_level(HandRank::HighCard).level > 1);
 }
 
 // ============================================================================
@@ -4320,6 +4794,35 @@ fn test_loyalty_card() {
         "LoyaltyCard should not apply mult when hands_until_bonus != 0. Expected: 16, Got: {}", score_no_trigger);
 }
 
+#[test]
+fn test_loyalty_card_every_sixth_hand() {
+    let mut g = Game::default();
+    g.start();
+    g.jokers.push(Jokers::LoyaltyCard(LoyaltyCard::default()));
+    g.refresh_joker_effects();
+    assert!(g.jokers[0].desc().contains("5 remaining"));
+
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.plays = 7;
+    let mut scores = Vec::new();
+    for _ in 0..7 {
+        // High card Ace: (5 + 11) * 1 = 16, X4 on the loyal hand
+        let ace = Card::new(Value::Ace, Suit::Heart);
+        g.available.extend(vec![ace]);
+        g.handle_action(Action::SelectCard(ace)).unwrap();
+        g.handle_action(Action::Play()).unwrap();
+        scores.extend(g.drain_events().into_iter().filter_map(|e| match e {
+            crate::event::Event::HandPlayed { score, .. } => Some(score),
+            _ => None,
+        }));
+        if scores.len() == 5 {
+            assert!(g.jokers[0].desc().contains("active!"));
+        }
+    }
+    assert_eq!(scores, vec![16, 16, 16, 16, 16, 64, 16]);
+    assert_eq!(g.jokers[0], Jokers::LoyaltyCard(LoyaltyCard { hands_until_bonus: 4 }));
+}
+
 #[test]
 fn test_hologram() {
     use crate::card::{Card, Suit, Value};
@@ -4414,6 +4917,40 @@ fn test_ramen() {
         "Ramen with 0 cards discarded should apply X2.0 mult. Expected: 120, Got: {}", score_full);
 }
 
+#[test]
+fn test_ramen_counts_discarded_cards() {
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.jokers = vec![
+        Jokers::Ramen(Ramen::default()),
+        Jokers::Ramen(Ramen { cards_discarded: 40 }),
+    ];
+    g.refresh_joker_effects();
+
+    // Each Ramen counts all five discarded cards on itself
+    for card in g.available.cards().into_iter().take(5) {
+        g.handle_action(Action::SelectCard(card)).unwrap();
+    }
+    g.handle_action(Action::Discard()).unwrap();
+    assert_eq!(
+        g.jokers,
+        vec![
+            Jokers::Ramen(Ramen { cards_discarded: 5 }),
+            Jokers::Ramen(Ramen { cards_discarded: 45 }),
+        ]
+    );
+    assert!(g.jokers[0].desc().starts_with("X1.95"));
+
+    // The next hand sees the new counts: pair of Kings (10 + 20) * 2 = 60,
+    // X1.95 and X1.55
+    let hand = SelectHand::new(vec![
+        Card::new(Value::King, Suit::Heart),
+        Card::new(Value::King, Suit::Diamond),
+    ]);
+    assert_eq!(g.calc_score(hand.best_hand().unwrap()), 181);
+}
+
 #[test]
 fn test_glass_joker() {
     use crate::card::{Card, Suit, Value};
@@ -4544,7 +5081,7 @@ fn test_the_idol() {
     // Buy TheIdol
     g.money += 1000;
     g.stage = Stage::Shop();
-    let joker = Jokers::TheIdol(TheIdol {});
+    let joker = Jokers::TheIdol(TheIdol::default());
     g.shop.jokers.push(joker.clone());
     g.buy_joker(joker).unwrap();
     g.stage = Stage::Blind(Blind::Small, None);
@@ -4565,7 +5102,7 @@ fn test_the_idol() {
     ];
     g2.stage = Stage::Shop();
     g2.money += 1000;
-    let joker2 = Jokers::TheIdol(TheIdol {});
+    let joker2 = Jokers::TheIdol(TheIdol::default());
     g2.shop.jokers.push(joker2.clone());
     g2.buy_joker(joker2).unwrap();
     g2.stage = Stage::Blind(Blind::Small, None);
@@ -4576,6 +5113,103 @@ fn test_the_idol() {
         "TheIdol with no matching cards should have no effect. Expected: 60, Got: {}", score_no_match);
 }
 
+#[test]
+fn test_round_targets_roll_at_round_begin() {
+    use crate::action::Action;
+
+    let mut g = Game::default();
+    g.start();
+    g.jokers.push(Jokers::TheIdol(TheIdol::default()));
+    g.jokers.push(Jokers::TheIdol(TheIdol::default()));
+    g.jokers.push(Jokers::AncientJoker(AncientJoker::default()));
+    g.jokers.push(Jokers::ToDoList(ToDoList::default()));
+    g.refresh_joker_effects();
+    assert!(g.jokers[0].desc().contains("[rank] of [suit]"));
+
+    g.stage = Stage::PreBlind();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+
+    // The Idol's card comes from the full deck and both copies agree
+    let rank = g.round_state.idol_rank.unwrap();
+    let suit = g.round_state.idol_suit.unwrap();
+    assert!(g.deck.cards().into_iter().chain(g.available.cards())
+        .any(|c| c.value == rank && c.suit == suit));
+    for joker in &g.jokers[..2] {
+        assert_eq!(*joker, Jokers::TheIdol(TheIdol { rank: Some(rank), suit: Some(suit) }));
+    }
+    assert!(g.jokers[0].desc().contains(&format!("{:?} of {}", rank, suit.name())));
+
+    let ancient = g.round_state.ancient_suit.unwrap();
+    assert_eq!(g.jokers[2], Jokers::AncientJoker(AncientJoker { suit: Some(ancient) }));
+    assert!(g.jokers[2].desc().contains(ancient.name()));
+
+    let todo = g.round_state.todo_hand.unwrap();
+    assert!(todo <= HandRank::StraightFlush, "secret hands are never listed");
+    assert_eq!(g.jokers[3], Jokers::ToDoList(ToDoList { hand: Some(todo) }));
+    assert!(g.jokers[3].desc().contains(&todo.to_string()));
+
+    // Ancient Joker and To Do List never repeat last round's target
+    for _ in 0..10 {
+        let (suit, hand) = (g.round_state.ancient_suit, g.round_state.todo_hand);
+        g.begin_blind(Blind::Big, None).unwrap();
+        assert_ne!(g.round_state.ancient_suit, suit);
+        assert_ne!(g.round_state.todo_hand, hand);
+    }
+}
+
+#[test]
+fn test_ancient_joker() {
+    use crate::card::{Card, Suit, Value};
+    use crate::hand::SelectHand;
+
+    let mut g = Game::default();
+    g.stage = Stage::Blind(Blind::Small, None);
+    g.round_state.ancient_suit = Some(Suit::Heart);
+    g.jokers.push(Jokers::AncientJoker(AncientJoker::default()));
+    g.refresh_joker_effects();
+
+    // Pair of Kings: (10 + 20) * 2 = 60, X1.5 per scored Heart
+    let hearts = SelectHand::new(vec![
+        Card::new(Value::King, Suit::Heart),
+        Card::new(Value::King, Suit::Heart),
+    ]);
+    assert_eq!(g.calc_score(hearts.best_hand().unwrap()), 135);
+
+    let mixed = SelectHand::new(vec![
+        Card::new(Value::King, Suit::Heart),
+        Card::new(Value::King, Suit::Spade),
+    ]);
+    assert_eq!(g.calc_score(mixed.best_hand().unwrap()), 90);
+
+    // No suit rolled outside a round
+    g.round_state.ancient_suit = None;
+    assert_eq!(g.calc_score(mixed.best_hand().unwrap()), 60);
+}
+
+#[test]
+fn test_to_do_list() {
+    use crate::card::{Card, Suit, Value};
+    use crate::hand::SelectHand;
+
+    let mut g = Game::default();
+    g.stage = Stage::Blind(Blind::Small, None);
+    g.money = 0;
+    g.round_state.todo_hand = Some(HandRank::OnePair);
+    g.jokers.push(Jokers::ToDoList(ToDoList::default()));
+    g.refresh_joker_effects();
+
+    let pair = SelectHand::new(vec![
+        Card::new(Value::King, Suit::Heart),
+        Card::new(Value::King, Suit::Spade),
+    ]);
+    g.calc_score(pair.best_hand().unwrap());
+    assert_eq!(g.money, 5);
+
+    let high_card = SelectHand::new(vec![Card::new(Value::King, Suit::Heart)]);
+    g.calc_score(high_card.best_hand().unwrap());
+    assert_eq!(g.money, 5);
+}
+
 #[test]
 fn test_campfire() {
     use crate::card::{Card, Suit, Value};
@@ -4622,3 +5256,373 @@ fn test_campfire() {
     assert_eq!(score_no_effect, 60,
         "Campfire with 0 cards sold should have no effect. Expected: 60, Got: {}", score_no_effect);
 }
+
+fn pair_of_kings() -> SelectHand {
+    return SelectHand::new(vec![
+        Card::new(Value::King, Suit::Heart),
+        Card::new(Value::King, Suit::Diamond),
+    ]);
+}
+
+#[test]
+fn test_stateful_joker_scores_without_refresh() {
+    let mut g = Game::default();
+    g.stage = Stage::Blind(Blind::Small, None);
+    g.jokers = vec![Jokers::Campfire(Campfire::default()), Jokers::Brainstorm(Brainstorm {})];
+    g.refresh_joker_effects();
+
+    // State changed after registration is read when the hand scores, by
+    // the joker and by Brainstorm copying it: (10 + 20) * 2 * 2.0 * 2.0
+    if let Jokers::Campfire(j) = &mut g.jokers[0] {
+        j.cards_sold = 4;
+    }
+    assert_eq!(g.calc_score(pair_of_kings().best_hand().unwrap()), 240);
+}
+
+#[test]
+fn test_campfire_scales_on_sell_and_resets_on_boss() {
+    use crate::consumable::Consumables;
+    use crate::planet::Planets;
+
+    let mut g = Game::default();
+    g.start();
+    g.stage = Stage::Shop();
+    g.jokers = vec![Jokers::Campfire(Campfire::default()), Jokers::TheJoker(TheJoker {})];
+    g.consumables = vec![Consumables::Planet(Planets::Pluto)];
+    g.refresh_joker_effects();
+
+    // A joker and a consumable sold: X1.5
+    g.handle_action(Action::SellJoker(1)).unwrap();
+    g.handle_action(Action::SellConsumable(0)).unwrap();
+    assert_eq!(g.jokers[0], Jokers::Campfire(Campfire { cards_sold: 2 }));
+    // (10 + 20) * 2 * 1.5
+    assert_eq!(g.clone().calc_score(pair_of_kings().best_hand().unwrap()), 90);
+
+    // Beating the Boss Blind starts it over
+    g.stage = Stage::Blind(Blind::Boss, None);
+    g.blind = Some(Blind::Boss);
+    g.score = g.required_score();
+    g.plays = 1;
+    let card = g.available.cards()[0];
+    g.handle_action(Action::SelectCard(card)).unwrap();
+    g.handle_action(Action::Play()).unwrap();
+    assert_eq!(g.stage, Stage::PostBlind());
+    assert_eq!(g.jokers[0], Jokers::Campfire(Campfire { cards_sold: 0 }));
+    assert_eq!(g.clone().calc_score(pair_of_kings().best_hand().unwrap()), 60);
+}
+
+#[test]
+fn test_hologram_scales_on_card_added() {
+    let mut g = Game::default();
+    g.jokers = vec![Jokers::Hologram(Hologram::default())];
+    g.refresh_joker_effects();
+
+    let card = g.new_card(Value::Two, Suit::Club);
+    g.add_card_to_deck(card);
+    assert_eq!(g.jokers[0], Jokers::Hologram(Hologram { cards_added: 1 }));
+    // (10 + 20) * 2 * 1.25
+    assert_eq!(g.calc_score(pair_of_kings().best_hand().unwrap()), 75);
+}
+
+#[test]
+fn test_glass_joker_scales_on_glass_destroyed() {
+    let mut g = Game::default();
+    g.jokers = vec![Jokers::GlassJoker(GlassJoker::default())];
+    g.refresh_joker_effects();
+
+    let mut glass = g.deck.cards()[0];
+    glass.enhancement = Some(Enhancement::Glass);
+    g.destroy_card(glass);
+    // Other cards do not count
+    g.destroy_card(g.deck.cards()[0]);
+    assert_eq!(g.jokers[0], Jokers::GlassJoker(GlassJoker { glass_destroyed: 1 }));
+    // (10 + 20) * 2 * 1.75
+    assert_eq!(g.calc_score(pair_of_kings().best_hand().unwrap()), 105);
+}
+
+#[test]
+fn test_obelisk_scales_until_most_played_hand() {
+    use crate::rank::HandRank;
+
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.jokers = vec![Jokers::Obelisk(Obelisk::default())];
+    g.refresh_joker_effects();
+    g.plays = 5;
+    g.stats.hands_played.insert(HandRank::OnePair, 3);
+
+    // A single card is a High Card, played less than One Pair
+    let card = g.available.cards()[0];
+    g.handle_action(Action::SelectCard(card)).unwrap();
+    g.handle_action(Action::Play()).unwrap();
+    assert_eq!(g.jokers[0], Jokers::Obelisk(Obelisk { consecutive_count: 1 }));
+    // (10 + 20) * 2 * 1.2
+    assert_eq!(g.clone().calc_score(pair_of_kings().best_hand().unwrap()), 72);
+
+    // Now High Card is the most played hand
+    g.stats.hands_played.insert(HandRank::HighCard, 10);
+    let card = g.available.cards()[0];
+    g.handle_action(Action::SelectCard(card)).unwrap();
+    g.handle_action(Action::Play()).unwrap();
+    assert_eq!(g.jokers[0], Jokers::Obelisk(Obelisk { consecutive_count: 0 }));
+}
+
+#[test]
+fn test_castle_scales_on_suit_discard() {
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.jokers = vec![Jokers::Castle(Castle::default())];
+    g.refresh_joker_effects();
+
+    let suit = g.available.cards()[0].suit;
+    g.round_state.castle_suit = Some(suit);
+    let cards = g.available.cards();
+    let selected: Vec<Card> = cards.iter().take(5).copied().collect();
+    let matching = selected.iter().filter(|c| c.suit == suit).count();
+    for card in &selected {
+        g.handle_action(Action::SelectCard(*card)).unwrap();
+    }
+    g.handle_action(Action::Discard()).unwrap();
+    assert_eq!(g.jokers[0], Jokers::Castle(Castle { bonus_chips: 3 * matching }));
+}
+
+#[test]
+fn test_madness_destroys_joker_on_blind_select() {
+    use crate::sticker::Stickers;
+
+    let mut g = Game::default();
+    g.start();
+    g.jokers = vec![
+        Jokers::TheJoker(TheJoker {}),
+        Jokers::Madness(Madness::default()),
+        Jokers::Misprint(Misprint {}),
+    ];
+    g.set_joker_stickers(
+        2,
+        Stickers {
+            eternal: true,
+            ..Stickers::default()
+        },
+    );
+    g.refresh_joker_effects();
+
+    // The Eternal Misprint is safe, The Joker is destroyed
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    assert_eq!(
+        g.jokers,
+        vec![Jokers::Madness(Madness { blinds_selected: 1 }), Jokers::Misprint(Misprint {})]
+    );
+    assert!(g.joker_stickers(1).eternal);
+
+    // Nothing left to destroy, Madness still scales
+    g.jokers.remove(1);
+    g.set_joker_stickers(0, Stickers::default());
+    g.refresh_joker_effects();
+    g.blind = Some(Blind::Small);
+    g.stage = Stage::PreBlind();
+    g.handle_action(Action::SelectBlind(Blind::Big)).unwrap();
+    assert_eq!(g.jokers, vec![Jokers::Madness(Madness { blinds_selected: 2 })]);
+    // (10 + 20) * 2 * 2.0
+    assert_eq!(g.calc_score(pair_of_kings().best_hand().unwrap()), 120);
+
+    // No trigger on the Boss Blind
+    g.stage = Stage::PreBlind();
+    g.handle_action(Action::SelectBlind(Blind::Boss)).unwrap();
+    assert_eq!(g.jokers, vec![Jokers::Madness(Madness { blinds_selected: 2 })]);
+}
+
+#[test]
+fn test_green_joker_scales_on_play() {
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.jokers = vec![Jokers::GreenJoker(GreenJoker::default())];
+    g.refresh_joker_effects();
+    g.plays = 5;
+
+    let card = g.available.cards()[0];
+    g.handle_action(Action::SelectCard(card)).unwrap();
+    g.handle_action(Action::Play()).unwrap();
+    assert_eq!(g.jokers[0], Jokers::GreenJoker(GreenJoker { bonus_mult: 1 }));
+
+    // The joker scaled before scoring, so the hand got the new +1 Mult
+    assert!(g.events().contains(&crate::event::Event::JokerTriggered {
+        slot: Some(0),
+        chips: 0.0,
+        mult: 1.0,
+    }));
+}
+
+#[test]
+fn test_square_joker_scales_on_four_card_hand() {
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.jokers = vec![Jokers::SquareJoker(SquareJoker::default())];
+    g.refresh_joker_effects();
+    g.plays = 5;
+
+    let cards = g.available.cards();
+    for card in cards.iter().take(4) {
+        g.handle_action(Action::SelectCard(*card)).unwrap();
+    }
+    g.handle_action(Action::Play()).unwrap();
+    assert_eq!(g.jokers[0], Jokers::SquareJoker(SquareJoker { bonus_chips: 4 }));
+
+    // Fewer cards leave it alone
+    let card = g.available.cards()[0];
+    g.handle_action(Action::SelectCard(card)).unwrap();
+    g.handle_action(Action::Play()).unwrap();
+    assert_eq!(g.jokers[0], Jokers::SquareJoker(SquareJoker { bonus_chips: 4 }));
+    // (10 + 20 + 4) * 2
+    assert_eq!(g.calc_score(pair_of_kings().best_hand().unwrap()), 68);
+}
+
+#[test]
+fn test_golden_ticket_pays_per_gold_card_scored() {
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.jokers = vec![Jokers::GoldenTicket(GoldenTicket {})];
+    g.refresh_joker_effects();
+
+    let mut gold = Card::new(Value::Ace, Suit::Heart);
+    gold.set_enhancement(Enhancement::Gold);
+    g.available.extend(vec![gold]);
+    let money = g.money;
+    g.handle_action(Action::SelectCard(gold)).unwrap();
+    g.handle_action(Action::Play()).unwrap();
+    assert_eq!(g.money, money + 3);
+}
+
+#[test]
+fn test_seltzer_counts_down_on_hand_end() {
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.jokers = vec![
+        Jokers::Seltzer(Seltzer::default()),
+        Jokers::Seltzer(Seltzer { hands_remaining: 1 }),
+    ];
+    g.refresh_joker_effects();
+
+    // Each Seltzer only counts its own hands down
+    let card = g.available.cards()[0];
+    g.handle_action(Action::SelectCard(card)).unwrap();
+    g.handle_action(Action::Play()).unwrap();
+    assert_eq!(
+        g.jokers,
+        vec![
+            Jokers::Seltzer(Seltzer { hands_remaining: 9 }),
+            Jokers::Seltzer(Seltzer { hands_remaining: 0 }),
+        ]
+    );
+}
+
+#[test]
+fn test_dna_copies_single_card_first_hand() {
+    use crate::provenance::CardOrigin;
+
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.jokers = vec![Jokers::DNA(DNA {})];
+    g.refresh_joker_effects();
+    g.plays = 5;
+
+    let mut card = g.available.cards()[0];
+    g.available.modify_card(card.id, |c| c.set_enhancement(Enhancement::Steel));
+    card.set_enhancement(Enhancement::Steel);
+    let in_hand = g.available.cards().len();
+    g.handle_action(Action::SelectCard(card)).unwrap();
+    g.handle_action(Action::Play()).unwrap();
+
+    // The copy keeps the enhancement under a new id and lands in the hand
+    let copies: Vec<Card> = g
+        .available
+        .cards()
+        .into_iter()
+        .filter(|c| c.value == card.value && c.suit == card.suit && c.id != card.id)
+        .collect();
+    assert_eq!(copies.len(), 1);
+    assert_eq!(copies[0].enhancement, Some(Enhancement::Steel));
+    assert_eq!(g.available.cards().len(), in_hand);
+    assert_eq!(
+        g.card_history(copies[0].id).unwrap().origin,
+        CardOrigin::Copied {
+            from: card.id,
+            by: "DNA".to_string()
+        }
+    );
+
+    // Only the first hand of the round is copied
+    let ledger = g.card_ledger().len();
+    let card = g.available.cards()[0];
+    g.handle_action(Action::SelectCard(card)).unwrap();
+    g.handle_action(Action::Play()).unwrap();
+    assert_eq!(g.card_ledger().len(), ledger);
+}
+
+#[test]
+fn test_vampire_consumes_scored_enhancements() {
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.jokers = vec![Jokers::Vampire(Vampire::default())];
+    g.refresh_joker_effects();
+
+    let mut kh = Card::new(Value::King, Suit::Heart);
+    kh.set_enhancement(Enhancement::Mult);
+    let kd = Card::new(Value::King, Suit::Diamond);
+    let mut two = Card::new(Value::Two, Suit::Club);
+    two.set_enhancement(Enhancement::Bonus);
+    g.available.empty();
+    g.available.extend(vec![kh, kd, two]);
+    for card in [kh, kd, two] {
+        g.handle_action(Action::SelectCard(card)).unwrap();
+    }
+    g.handle_action(Action::Play()).unwrap();
+
+    // Only the scored Mult King is drained
+    assert_eq!(g.jokers[0], Jokers::Vampire(Vampire { bonus_mult: 1.2 }));
+    let king = g.discarded.iter().find(|c| c.id == kh.id).unwrap();
+    assert_eq!(king.enhancement, None);
+    let kicker = g.discarded.iter().find(|c| c.id == two.id).unwrap();
+    assert_eq!(kicker.enhancement, Some(Enhancement::Bonus));
+
+    // The hand scored without the +4 Mult it lost, at the new X1.2:
+    // (10 + 20) * 2 * 1.2
+    assert!(g.events().contains(&crate::event::Event::HandPlayed {
+        rank: crate::rank::HandRank::OnePair,
+        score: 72,
+    }));
+}
+
+#[test]
+fn test_vampire_drains_midas_mask_gold() {
+    let mut g = Game::default();
+    g.start();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+    g.jokers = vec![
+        Jokers::MidasMask(MidasMask {}),
+        Jokers::Vampire(Vampire::default()),
+    ];
+    g.refresh_joker_effects();
+
+    let kh = Card::new(Value::King, Suit::Heart);
+    let kd = Card::new(Value::King, Suit::Diamond);
+    g.available.empty();
+    g.available.extend(vec![kh, kd]);
+    g.handle_action(Action::SelectCard(kh)).unwrap();
+    g.handle_action(Action::SelectCard(kd)).unwrap();
+    g.handle_action(Action::Play()).unwrap();
+
+    // Midas Mask runs first, Vampire sees the Gold Kings and takes it back
+    assert_eq!(g.jokers[1], Jokers::Vampire(Vampire { bonus_mult: 1.4 }));
+    for id in [kh.id, kd.id] {
+        let card = g.discarded.iter().find(|c| c.id == id).unwrap();
+        assert_eq!(card.enhancement, None);
+    }
+}
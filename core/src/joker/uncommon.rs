@@ -1,9 +1,10 @@
-// Uncommon Rarity Jokers - 62 total
+// Uncommon Rarity Jokers - 60 total
 // These are moderately rare jokers with more complex effects
 
 use super::*;
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Throwback {}
 
@@ -23,43 +24,47 @@ impl Joker for Throwback {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultMult]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let skips = game.blinds_skipped_count;
-
-        fn apply(g: &mut Game, _hand: MadeHand, skip_count: usize) {
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        // Reads the run's skip counter when the hand scores, so skips made
+        // after the joker was bought count too
+        fn apply(g: &mut Game, _hand: MadeHand) {
             // X0.25 for each skip: 1.0 + (0.25 * skip_count)
-            let multiplier = 1.0 + (0.25 * skip_count as f32);
-            g.mult = (g.mult as f32 * multiplier) as usize;
+            let multiplier = 1.0 + (0.25 * g.blinds_skipped_count as f64);
+            g.mult *= multiplier;
         }
 
-        let closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, skips);
-        };
-
-        vec![Effects::OnScore(Arc::new(Mutex::new(closure)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct LoyaltyCard {
+    // Hands left to play before the X4 hand; 0 means the next hand gets it
     pub hands_until_bonus: usize,
 }
 
 impl Default for LoyaltyCard {
     fn default() -> Self {
         Self {
-            hands_until_bonus: 6,
+            hands_until_bonus: LOYALTY_CARD_EVERY - 1,
         }
     }
 }
 
+// Loyalty Card gives X4 on every 6th hand played
+const LOYALTY_CARD_EVERY: usize = 6;
+
 impl Joker for LoyaltyCard {
     fn name(&self) -> String {
         "Loyalty Card".to_string()
     }
     fn desc(&self) -> String {
-        format!("X4 Mult every 6 hands played ({}/6)", 6 - self.hands_until_bonus)
+        return match self.hands_until_bonus {
+            0 => format!("X4 Mult every {} hands played (active!)", LOYALTY_CARD_EVERY),
+            n => format!("X4 Mult every {} hands played ({} remaining)", LOYALTY_CARD_EVERY, n),
+        };
     }
     fn cost(&self) -> usize {
         5
@@ -71,87 +76,27 @@ impl Joker for LoyaltyCard {
         vec![Categories::MultMult]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let should_trigger = self.hands_until_bonus == 0;
-
-        fn apply(g: &mut Game, _hand: MadeHand, trigger: bool) {
-            if trigger {
-                g.mult = (g.mult as f32 * 4.0) as usize;
-            }
-        }
-
-        let closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, should_trigger);
-        };
-
-        vec![Effects::OnScore(Arc::new(Mutex::new(closure)))]
+        vec![]
     }
-}
-
-impl LoyaltyCard {
-    pub fn on_hand_played(&mut self) {
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
         if self.hands_until_bonus == 0 {
-            self.hands_until_bonus = 6;
+            game.mult *= 4.0;
         }
-        self.hands_until_bonus -= 1;
-    }
-}
-
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
-#[cfg_attr(feature = "python", pyclass(eq))]
-pub struct Campfire {
-    pub cards_sold: usize,
-}
-
-impl Default for Campfire {
-    fn default() -> Self {
-        Self { cards_sold: 0 }
     }
 }
 
-impl Joker for Campfire {
-    fn name(&self) -> String {
-        "Campfire".to_string()
-    }
-    fn desc(&self) -> String {
-        let mult = 1.0 + (0.25 * self.cards_sold as f32);
-        format!("X{:.2} Mult (X0.25 per card sold, resets on boss)", mult)
-    }
-    fn cost(&self) -> usize {
-        5
-    }
-    fn rarity(&self) -> Rarity {
-        Rarity::Uncommon
-    }
-    fn categories(&self) -> Vec<Categories> {
-        vec![Categories::MultMult]
-    }
-    fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let cards_sold = self.cards_sold;
-
-        fn apply(g: &mut Game, _hand: MadeHand, sold_count: usize) {
-            let multiplier = 1.0 + (0.25 * sold_count as f32);
-            g.mult = (g.mult as f32 * multiplier) as usize;
-        }
-
-        let closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, cards_sold);
+impl LoyaltyCard {
+    /// Count a played hand, starting the next cycle after the X4 hand
+    pub fn on_hand_played(&mut self) {
+        self.hands_until_bonus = match self.hands_until_bonus {
+            0 => LOYALTY_CARD_EVERY - 1,
+            n => n - 1,
         };
-
-        vec![Effects::OnScore(Arc::new(Mutex::new(closure)))]
     }
 }
 
-impl Campfire {
-    pub fn on_card_sold(&mut self) {
-        self.cards_sold += 1;
-    }
-
-    pub fn reset_on_boss(&mut self) {
-        self.cards_sold = 0;
-    }
-}
-
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Hologram {
     pub cards_added: usize,
@@ -181,18 +126,10 @@ impl Joker for Hologram {
         vec![Categories::MultMult]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let cards_added = self.cards_added;
-
-        fn apply(g: &mut Game, _hand: MadeHand, added_count: usize) {
-            let multiplier = 1.0 + (0.25 * added_count as f32);
-            g.mult = (g.mult as f32 * multiplier) as usize;
-        }
-
-        let closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, cards_added);
-        };
-
-        vec![Effects::OnScore(Arc::new(Mutex::new(closure)))]
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult *= 1.0 + (0.25 * self.cards_added as f64);
     }
 }
 
@@ -202,81 +139,30 @@ impl Hologram {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
-pub struct Obelisk {
-    pub consecutive_count: usize,
-}
-
-impl Default for Obelisk {
-    fn default() -> Self {
-        Self {
-            consecutive_count: 0,
-        }
-    }
+pub struct TheIdol {
+    // This round's card, copied from the round state for desc()
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rank: Option<Value>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub suit: Option<Suit>,
 }
 
-impl Joker for Obelisk {
-    fn name(&self) -> String {
-        "Obelisk".to_string()
-    }
-    fn desc(&self) -> String {
-        let mult = 1.0 + (0.2 * self.consecutive_count as f32);
-        format!(
-            "X{:.1} Mult (X0.2 per consecutive hand without most-played hand)",
-            mult
-        )
-    }
-    fn cost(&self) -> usize {
-        5
-    }
-    fn rarity(&self) -> Rarity {
-        Rarity::Uncommon
-    }
-    fn categories(&self) -> Vec<Categories> {
-        vec![Categories::MultMult]
-    }
-    fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let consecutive = self.consecutive_count;
-
-        fn apply(g: &mut Game, _hand: MadeHand, count: usize) {
-            let multiplier = 1.0 + (0.2 * count as f32);
-            g.mult = (g.mult as f32 * multiplier) as usize;
-        }
-
-        let closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, consecutive);
-        };
-
-        vec![Effects::OnScore(Arc::new(Mutex::new(closure)))]
-    }
-}
-
-impl Obelisk {
-    pub fn on_hand_played(&mut self, rank: crate::rank::HandRank, most_played: Option<crate::rank::HandRank>) {
-        if let Some(most) = most_played {
-            if rank == most {
-                self.consecutive_count = 0;
-            } else {
-                self.consecutive_count += 1;
-            }
-        } else {
-            // No most-played hand yet (first hand of run)
-            self.consecutive_count = 0;
-        }
-    }
-}
-
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
-#[cfg_attr(feature = "python", pyclass(eq))]
-pub struct TheIdol {}
-
 impl Joker for TheIdol {
     fn name(&self) -> String {
         "The Idol".to_string()
     }
     fn desc(&self) -> String {
-        "X2 Mult for each [rank] of [suit] in hand (changes per round)".to_string()
+        return match (self.rank, self.suit) {
+            (Some(rank), Some(suit)) => format!(
+                "Each played {:?} of {} gives X2 Mult when scored (card changes every round)",
+                rank,
+                suit.name()
+            ),
+            _ => "Each played [rank] of [suit] gives X2 Mult when scored (card changes every round)".to_string(),
+        };
     }
     fn cost(&self) -> usize {
         6
@@ -287,33 +173,50 @@ impl Joker for TheIdol {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultMult]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let idol_rank = game.round_state.idol_rank;
-        let idol_suit = game.round_state.idol_suit;
-        let hand_cards = game.hand.clone();
-
-        fn apply(g: &mut Game, _hand: MadeHand, rank: Option<Value>, suit: Option<Suit>, hand: Vec<Card>) {
-            if let (Some(r), Some(s)) = (rank, suit) {
-                let matching_count = hand.iter()
-                    .filter(|c| c.value == r && c.suit == s)
-                    .count();
-
-                if matching_count > 0 {
-                    let multiplier = 2.0_f32.powi(matching_count as i32);
-                    g.mult = (g.mult as f32 * multiplier) as usize;
+    fn effects(&self, _in: &Game) -> Vec<Effects> {
+        // OnRoundBegin: roll the card from the full deck, Stone cards
+        // excluded, unless another Idol already rolled it this round
+        fn on_round_begin(g: &mut Game) {
+            use rand::seq::SliceRandom;
+            if g.round_state.idol_rank.is_none() {
+                let mut rng = crate::rng::rng_for("joker", "the idol card");
+                let cards: Vec<Card> = g.deck.cards().into_iter()
+                    .chain(g.available.cards())
+                    .filter(|c| c.enhancement != Some(crate::card::Enhancement::Stone))
+                    .collect();
+                if let Some(card) = cards.choose(&mut rng) {
+                    g.round_state.idol_rank = Some(card.value);
+                    g.round_state.idol_suit = Some(card.suit);
+                }
+            }
+            let (rank, suit) = (g.round_state.idol_rank, g.round_state.idol_suit);
+            for j in &mut g.jokers {
+                if let Jokers::TheIdol(idol) = j {
+                    idol.rank = rank;
+                    idol.suit = suit;
                 }
             }
         }
 
-        let closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, idol_rank, idol_suit, hand_cards.clone());
-        };
+        // OnScore: X2 per scored trigger of this round's card
+        fn apply(g: &mut Game, hand: MadeHand) {
+            if let (Some(rank), Some(suit)) = (g.round_state.idol_rank, g.round_state.idol_suit) {
+                let matching = g.scored_triggers(&hand, |c| {
+                    c.value == rank && g.modifiers.is_suit(c, suit)
+                });
+                g.mult *= 2.0_f64.powi(matching as i32);
+            }
+        }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(closure)))]
+        vec![
+            Effects::OnRoundBegin(Arc::new(on_round_begin)),
+            Effects::OnScore(Arc::new(apply)),
+        ]
     }
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct SpaceJoker {}
 
@@ -335,17 +238,19 @@ impl Joker for SpaceJoker {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use rand::Rng;
-        fn apply(g: &mut Game, hand: MadeHand) {
-            if rand::thread_rng().gen_bool(0.25) {
-                // Upgrade the hand rank that was just played
+        // OnPlay: the upgrade lands before the hand scores, so the played
+        // hand already counts at its new level
+        fn on_play(g: &mut Game, hand: MadeHand, _slot: usize) {
+            if crate::rng::rng_for("joker", "space joker roll").gen_bool(0.25) {
                 g.upgrade_hand(hand.rank);
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnPlay(Arc::new(on_play))]
     }
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Burglar {}
 
@@ -371,11 +276,12 @@ impl Joker for Burglar {
             g.discards = 0;
         }
 
-        vec![Effects::OnBlindSelect(Arc::new(Mutex::new(on_blind_select)))]
+        vec![Effects::OnBlindSelect(Arc::new(on_blind_select))]
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Rocket {
     pub payout: usize,
@@ -405,20 +311,20 @@ impl Joker for Rocket {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnRoundEnd: Earn payout amount (read live from game state)
         fn on_round_end(g: &mut Game) {
             // Find the Rocket joker and read its current payout value
             for joker in &g.jokers {
                 if let Jokers::Rocket(rocket) = joker {
-                    g.money += rocket.payout;
+                    g.money += rocket.payout as i64;
                     break;
                 }
             }
         }
 
-        vec![Effects::OnRoundEnd(Arc::new(Mutex::new(on_round_end)))]
+        vec![Effects::OnRoundEnd(Arc::new(on_round_end))]
         // TODO: Need OnBossDefeat effect to increment payout
     }
 }
@@ -429,7 +335,8 @@ impl Rocket {
     }
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct MerryAndy {}
 
@@ -455,7 +362,8 @@ impl Joker for MerryAndy {
     }
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct OopsAll6s {}
 
@@ -483,7 +391,8 @@ impl Joker for OopsAll6s {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Ramen {
     pub cards_discarded: usize,
@@ -515,15 +424,17 @@ impl Joker for Ramen {
         vec![Categories::MultMult]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let discarded = self.cards_discarded;
-        fn apply(g: &mut Game, _hand: MadeHand, disc: usize) {
-            let multiplier = (2.0 - (0.01 * disc as f32)).max(0.0);
-            g.mult = (g.mult as f32 * multiplier) as usize;
+        // OnDiscard: count the discarded cards on this Ramen
+        fn on_discard(g: &mut Game, hand: MadeHand, slot: usize) {
+            if let Some(Jokers::Ramen(ramen)) = g.jokers.get_mut(slot) {
+                ramen.on_cards_discarded(hand.all.len());
+            }
         }
-        let closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, discarded);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(closure)))]
+
+        vec![Effects::OnDiscard(Arc::new(on_discard))]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult *= (2.0 - (0.01 * self.cards_discarded as f64)).max(0.0);
     }
 }
 
@@ -533,7 +444,8 @@ impl Ramen {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Castle {
     pub bonus_chips: usize,
@@ -565,14 +477,10 @@ impl Joker for Castle {
         vec![Categories::Chips]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let chips = self.bonus_chips;
-        fn apply(g: &mut Game, _hand: MadeHand, bonus: usize) {
-            g.chips += bonus;
-        }
-        let closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, chips);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(closure)))]
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.chips += self.bonus_chips as f64;
     }
 }
 
@@ -582,7 +490,8 @@ impl Castle {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct GlassJoker {
     pub glass_destroyed: usize,
@@ -614,15 +523,10 @@ impl Joker for GlassJoker {
         vec![Categories::MultMult]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let destroyed = self.glass_destroyed;
-        fn apply(g: &mut Game, _hand: MadeHand, count: usize) {
-            let multiplier = 1.0 + (0.75 * count as f32);
-            g.mult = (g.mult as f32 * multiplier) as usize;
-        }
-        let closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, destroyed);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(closure)))]
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult *= 1.0 + (0.75 * self.glass_destroyed as f64);
     }
 }
 
@@ -632,7 +536,8 @@ impl GlassJoker {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct LuckyCat {
     pub lucky_triggers: usize,
@@ -664,15 +569,10 @@ impl Joker for LuckyCat {
         vec![Categories::MultMult]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let triggers = self.lucky_triggers;
-        fn apply(g: &mut Game, _hand: MadeHand, count: usize) {
-            let multiplier = 1.0 + (0.25 * count as f32);
-            g.mult = (g.mult as f32 * multiplier) as usize;
-        }
-        let closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, triggers);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(closure)))]
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult *= 1.0 + (0.25 * self.lucky_triggers as f64);
     }
 }
 
@@ -686,7 +586,8 @@ impl LuckyCat {
 
 // Joker: Fibonacci - Each played Ace, 2, 3, 5, or 8 gives +8 Mult when scored
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Fibonacci {}
 
@@ -709,18 +610,19 @@ impl Joker for Fibonacci {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::card::Value;
         fn apply(g: &mut Game, hand: MadeHand) {
-            let fib_count = hand.hand.cards().iter()
-                .filter(|c| matches!(c.value, Value::Ace | Value::Two | Value::Three | Value::Five | Value::Eight))
-                .count();
-            g.mult += fib_count * 8;
+            let fib_count = g.scored_triggers(&hand, |c| {
+                matches!(c.value, Value::Ace | Value::Two | Value::Three | Value::Five | Value::Eight)
+            });
+            g.mult += (fib_count * 8) as f64;
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: Spare Trousers - Gains +2 Mult if played hand contains Two Pair
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct SpareTrousers {}
 
@@ -743,17 +645,18 @@ impl Joker for SpareTrousers {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
             if hand.hand.is_two_pair().is_some() {
-                g.mult += 2;
+                g.mult += 2.0;
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: Acrobat - X3 Mult on final hand of round
 // Note: Requires tracking if this is the final hand
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Acrobat {}
 
@@ -773,23 +676,21 @@ impl Joker for Acrobat {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultMult]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let is_final_hand = game.plays == 1;
-        fn apply(g: &mut Game, _hand: MadeHand, final_hand: bool) {
-            if final_hand {
-                g.mult = g.mult * 3;
-            }
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        // Final hand of the round
+        if game.plays == 1 {
+            game.mult *= 3.0;
         }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, is_final_hand);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
     }
 }
 
 // Joker: Onyx Agate - +7 Mult for each Club card played
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct OnyxAgate {}
 
@@ -811,21 +712,17 @@ impl Joker for OnyxAgate {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
-            let clubs = hand
-                .hand
-                .suits()
-                .iter()
-                .filter(|s| **s == Suit::Club)
-                .count();
-            g.mult += clubs * 7;
+            let clubs = g.scored_triggers(&hand, |c| g.modifiers.is_suit(c, Suit::Club));
+            g.mult += (clubs * 7) as f64;
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: Arrowhead - Played Spade cards give +50 Chips when scored
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Arrowhead {}
 
@@ -847,15 +744,10 @@ impl Joker for Arrowhead {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
-            let spades = hand
-                .hand
-                .suits()
-                .iter()
-                .filter(|s| **s == Suit::Spade)
-                .count();
-            g.chips += spades * 50;
+            let spades = g.scored_triggers(&hand, |c| g.modifiers.is_suit(c, Suit::Spade));
+            g.chips += (spades * 50) as f64;
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
@@ -863,7 +755,8 @@ impl Joker for Arrowhead {
 
 // Joker: The Duo - X2 Mult if played hand contains a Pair
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Bloodstone {}
 
@@ -886,26 +779,25 @@ impl Joker for Bloodstone {
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use rand::Rng;
         fn apply(g: &mut Game, hand: MadeHand) {
-            let hearts_count = hand
-                .hand
-                .suits()
-                .iter()
-                .filter(|s| **s == Suit::Heart)
-                .count();
+            let hearts_count = g.scored_triggers(&hand, |c| g.modifiers.is_suit(c, Suit::Heart));
 
             for _ in 0..hearts_count {
-                if rand::thread_rng().gen_bool(0.5) {
-                    g.mult = (g.mult as f32 * 1.5) as usize;
+                if g.expected_rolls {
+                    // X1.5 half of the time
+                    g.mult *= 1.25;
+                } else if crate::rng::rng_for("joker", "bloodstone roll").gen_bool(0.5) {
+                    g.mult *= 1.5;
                 }
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: Rough Gem - Played Diamond cards earn $1 when scored
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct RoughGem {}
 
@@ -927,22 +819,18 @@ impl Joker for RoughGem {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, hand: MadeHand) {
-            let diamonds = hand
-                .hand
-                .suits()
-                .iter()
-                .filter(|s| **s == Suit::Diamond)
-                .count();
-            g.money += diamonds;
+            let diamonds = g.scored_triggers(&hand, |c| g.modifiers.is_suit(c, Suit::Diamond));
+            g.money += diamonds as i64;
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: Flash Card - Gains +2 Mult per reroll in shop
 // Note: Requires tracking shop rerolls
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct FlashCard {}
 
@@ -962,22 +850,19 @@ impl Joker for FlashCard {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultPlus]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
         // Count rerolls from shop
-        let reroll_count = game.shop.rerolls_this_round;
-        fn apply(g: &mut Game, _hand: MadeHand, rerolls: usize) {
-            g.mult += rerolls * 2;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, reroll_count);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        game.mult += (game.shop.rerolls_this_round * 2) as f64;
     }
 }
 
 // Joker: Stone Joker - Gains +25 Chips for each Stone Card in full deck
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct StoneJoker {}
 
@@ -997,7 +882,10 @@ impl Joker for StoneJoker {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::Chips]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
         use crate::card::Enhancement;
         let stone_count = game
             .deck
@@ -1005,19 +893,14 @@ impl Joker for StoneJoker {
             .iter()
             .filter(|c| c.enhancement == Some(Enhancement::Stone))
             .count();
-        fn apply(g: &mut Game, _hand: MadeHand, stones: usize) {
-            g.chips += stones * 25;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, stone_count);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        game.chips += (stone_count * 25) as f64;
     }
 }
 
 // Joker: Bull - +2 Chips for each $1 you have
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Bull {}
 
@@ -1037,21 +920,18 @@ impl Joker for Bull {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::Chips]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let money = game.money;
-        fn apply(g: &mut Game, _hand: MadeHand, money: usize) {
-            g.chips += money * 2;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, money);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.chips += (game.money_held() * 2) as f64;
     }
 }
 
 // Joker: Erosion - +4 Mult for each card below 52 in full deck
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Erosion {}
 
@@ -1071,21 +951,19 @@ impl Joker for Erosion {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultPlus]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
         let cards_below = 52_usize.saturating_sub(game.deck.cards().len());
-        fn apply(g: &mut Game, _hand: MadeHand, missing: usize) {
-            g.mult += missing * 4;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, cards_below);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        game.mult += (cards_below * 4) as f64;
     }
 }
 
 // Joker: The Family - X4 Mult if played hand contains Four of a Kind
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct FourFingers {}
 impl Joker for FourFingers {
@@ -1112,7 +990,8 @@ impl Joker for FourFingers {
 
 // Joker: Mime - Retrigger all card held in hand abilities
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Mime {}
 impl Joker for Mime {
@@ -1132,14 +1011,17 @@ impl Joker for Mime {
         vec![Categories::Retrigger]
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
-        // Complex - would need to retrigger cards in hand (not played cards)
         vec![]
     }
+    fn held_retriggers(&self, _game: &Game, _card: &Card) -> usize {
+        1
+    }
 }
 
 // Joker: Marble Joker - Adds one Stone card to deck when Blind selected
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct MarbleJoker {}
 impl Joker for MarbleJoker {
@@ -1160,12 +1042,12 @@ impl Joker for MarbleJoker {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnBlindSelect: Add one Stone card to deck (modify random card to Stone enhancement)
         fn on_blind_select(g: &mut Game) {
             use rand::seq::SliceRandom;
-            let mut rng = rand::thread_rng();
+            let mut rng = crate::rng::rng_for("joker", "marble joker card");
 
             // Get all cards in deck without Stone enhancement
             let non_stone_cards: Vec<usize> = g.deck.cards().iter()
@@ -1181,13 +1063,14 @@ impl Joker for MarbleJoker {
             }
         }
 
-        vec![Effects::OnBlindSelect(Arc::new(Mutex::new(on_blind_select)))]
+        vec![Effects::OnBlindSelect(Arc::new(on_blind_select))]
     }
 }
 
 // Joker: Steel Joker - Gains X0.2 Mult for each Steel Card in full deck
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct SteelJoker {}
 impl Joker for SteelJoker {
@@ -1206,7 +1089,10 @@ impl Joker for SteelJoker {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultMult]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
         use crate::card::Edition;
         let steel_count = game
             .deck
@@ -1214,21 +1100,15 @@ impl Joker for SteelJoker {
             .iter()
             .filter(|c| c.edition == Edition::Foil)
             .count();
-        fn apply(g: &mut Game, _hand: MadeHand, count: usize) {
-            // X0.2 per steel card = multiply by (1.0 + 0.2 * count)
-            let multiplier = 1.0 + (0.2 * count as f32);
-            g.mult = (g.mult as f32 * multiplier) as usize;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, steel_count);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        // X0.2 per steel card = multiply by (1.0 + 0.2 * count)
+        game.mult *= 1.0 + (0.2 * steel_count as f64);
     }
 }
 
 // Joker: Pareidolia - All cards considered face cards
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Pareidolia {}
 impl Joker for Pareidolia {
@@ -1248,14 +1128,15 @@ impl Joker for Pareidolia {
         vec![Categories::Effect]
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
-        // Passive effect - would need to be handled in card.is_face() logic
+        // Passive effect - GameModifiers::is_face, set in update_modifiers
         vec![]
     }
 }
 
 // Joker: Blackboard - X3 Mult if all cards held in hand are Spades or Clubs
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Blackboard {}
 impl Joker for Blackboard {
@@ -1277,18 +1158,22 @@ impl Joker for Blackboard {
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         fn apply(g: &mut Game, _hand: MadeHand) {
             // Calculate at score time, not registration time!
-            let all_black = g.hand.iter().all(|c| c.suit == Suit::Spade || c.suit == Suit::Club);
-            let mult_multiplier = if all_black { 3 } else { 1 };
-            g.mult = g.mult * mult_multiplier;
+            let all_black = g
+                .hand
+                .iter()
+                .all(|c| g.modifiers.is_suit(c, Suit::Spade) || g.modifiers.is_suit(c, Suit::Club));
+            let mult_multiplier = if all_black { 3.0 } else { 1.0 };
+            g.mult *= mult_multiplier;
         }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: Smeared Joker - Hearts and Diamonds count as same suit; Spades and Clubs count as same suit
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct SmearedJoker {}
 impl Joker for SmearedJoker {
@@ -1308,14 +1193,15 @@ impl Joker for SmearedJoker {
         vec![Categories::Effect]
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
-        // Passive effect - would need to be handled in flush detection logic
+        // Passive effect - flush detection and GameModifiers::is_suit
         vec![]
     }
 }
 
 // Joker: Flower Pot - X3 Mult if hand contains Diamond, Club, Heart, and Spade cards
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct FlowerPot {}
 impl Joker for FlowerPot {
@@ -1338,22 +1224,22 @@ impl Joker for FlowerPot {
         use crate::card::Suit;
         fn apply(g: &mut Game, hand: MadeHand) {
             // Check all played cards, not just the made hand
-            let has_diamond = hand.all.iter().any(|c| c.suit == Suit::Diamond);
-            let has_club = hand.all.iter().any(|c| c.suit == Suit::Club);
-            let has_heart = hand.all.iter().any(|c| c.suit == Suit::Heart);
-            let has_spade = hand.all.iter().any(|c| c.suit == Suit::Spade);
+            let has = |suit: Suit| hand.all.iter().any(|c| g.modifiers.is_suit(c, suit));
+            let (has_diamond, has_club) = (has(Suit::Diamond), has(Suit::Club));
+            let (has_heart, has_spade) = (has(Suit::Heart), has(Suit::Spade));
 
             if has_diamond && has_club && has_heart && has_spade {
-                g.mult = g.mult * 3;
+                g.mult *= 3.0;
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: Seeing Double - X2 Mult if played hand has Club card and any other suit card
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct SeeingDouble {}
 impl Joker for SeeingDouble {
@@ -1376,20 +1262,21 @@ impl Joker for SeeingDouble {
         use crate::card::Suit;
         fn apply(g: &mut Game, hand: MadeHand) {
             // Check all played cards, not just the made hand
-            let has_club = hand.all.iter().any(|c| c.suit == Suit::Club);
-            let has_other = hand.all.iter().any(|c| c.suit != Suit::Club);
+            let has_club = hand.all.iter().any(|c| g.modifiers.is_suit(c, Suit::Club));
+            let has_other = hand.all.iter().any(|c| !c.is_stone() && c.suit != Suit::Club);
 
             if has_club && has_other {
-                g.mult = g.mult * 2;
+                g.mult *= 2.0;
             }
         }
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply)))]
+        vec![Effects::OnScore(Arc::new(apply))]
     }
 }
 
 // Joker: Baron - Each King held in hand gives X1.5 Mult
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct JokerStencil {}
 impl Joker for JokerStencil {
@@ -1408,25 +1295,22 @@ impl Joker for JokerStencil {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultMult]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
         // Max joker slots is typically 5, count empty slots
         let max_slots: usize = 5;
-        let current_jokers = game.jokers.len();
-        let empty_slots = max_slots.saturating_sub(current_jokers).saturating_add(1); // +1 because it counts itself as empty
-        fn apply(g: &mut Game, _hand: MadeHand, slots: usize) {
-            // X1 per slot means multiply by (1 * slots), which is just slots
-            g.mult = g.mult * slots;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, empty_slots);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+        let empty_slots = max_slots.saturating_sub(game.jokers.len()).saturating_add(1); // +1 because it counts itself as empty
+        // X1 per slot means multiply by (1 * slots), which is just slots
+        game.mult *= empty_slots as f64;
     }
 }
 
 // Joker: Showman - +4 Mult for Joker, Tarot, Planet, or Spectral cards remaining in consumable slots
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Showman {}
 impl Joker for Showman {
@@ -1445,21 +1329,18 @@ impl Joker for Showman {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultPlus]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let consumable_count = game.consumables.len();
-        fn apply(g: &mut Game, _hand: MadeHand, count: usize) {
-            g.mult += count * 4;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, consumable_count);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult += (game.consumables.len() * 4) as f64;
     }
 }
 
 // Joker: Bootstraps - Gains +2 Mult for every $5 you have
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Bootstraps {}
 impl Joker for Bootstraps {
@@ -1478,21 +1359,18 @@ impl Joker for Bootstraps {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultPlus]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let mult_bonus = (game.money / 5) * 2;
-        fn apply(g: &mut Game, _hand: MadeHand, bonus: usize) {
-            g.mult += bonus;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, mult_bonus);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult += ((game.money_held() / 5) * 2) as f64;
     }
 }
 
 // Joker: Cloud9 - Earn $1 for each 9 in full deck at end of round
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Cloud9 {}
 impl Joker for Cloud9 {
@@ -1513,22 +1391,23 @@ impl Joker for Cloud9 {
     }
     fn effects(&self, _in: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnRoundEnd: Earn $1 for each 9 in full deck
         fn on_round_end(g: &mut Game) {
             use crate::card::Value;
             let nine_count = g.deck.cards().iter().filter(|c| c.value == Value::Nine).count();
-            g.money += nine_count;
+            g.money += nine_count as i64;
         }
 
-        vec![Effects::OnRoundEnd(Arc::new(Mutex::new(on_round_end)))]
+        vec![Effects::OnRoundEnd(Arc::new(on_round_end))]
     }
 }
 
 // Joker: WeeJoker - Gains +8 Chips when each played 2 is scored
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct CardSharp {}
 
@@ -1548,24 +1427,20 @@ impl Joker for CardSharp {
     fn categories(&self) -> Vec<Categories> {
         vec![Categories::MultMult]
     }
-    fn effects(&self, game: &Game) -> Vec<Effects> {
-        let hands_played = game.round_state.hands_played_this_round.clone();
-
-        fn apply(g: &mut Game, hand: MadeHand, played: std::collections::HashSet<HandRank>) {
-            if played.contains(&hand.rank) {
-                g.mult = g.mult * 3;
-            }
+    fn effects(&self, _game: &Game) -> Vec<Effects> {
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, hand: &MadeHand) {
+        if game.round_state.hands_played_this_round.contains(&hand.rank) {
+            game.mult *= 3.0;
         }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, hands_played.clone());
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
     }
 }
 
 // Joker: Chicot - Disables effect of every Boss Blind
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Shortcut {}
 impl Joker for Shortcut {
@@ -1594,7 +1469,8 @@ impl Joker for Shortcut {
 
 // Joker: Troubadour - +2 hand size; -1 hand per round
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Troubadour {
     pub hands_remaining: i32,
@@ -1622,7 +1498,7 @@ impl Joker for Troubadour {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnRoundBegin: Calculate and apply hand_size bonus, then decrement counter
         fn on_round_begin(g: &mut Game) {
@@ -1642,13 +1518,14 @@ impl Joker for Troubadour {
             }
         }
 
-        vec![Effects::OnRoundBegin(Arc::new(Mutex::new(on_round_begin)))]
+        vec![Effects::OnRoundBegin(Arc::new(on_round_begin))]
     }
 }
 
 // Joker: Turtle Bean - Gains +5 hand size; decreases by 1 per round
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct TurtleBean {
     pub hand_size_bonus: i32,
@@ -1676,7 +1553,7 @@ impl Joker for TurtleBean {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnRoundBegin: Calculate and apply hand_size bonus, then decrement counter
         fn on_round_begin(g: &mut Game) {
@@ -1696,13 +1573,14 @@ impl Joker for TurtleBean {
             }
         }
 
-        vec![Effects::OnRoundBegin(Arc::new(Mutex::new(on_round_begin)))]
+        vec![Effects::OnRoundBegin(Arc::new(on_round_begin))]
     }
 }
 
 // Joker: Trading Card - If first discard contains 1 card, destroy it and earn $3
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct TradingCard {}
 impl Joker for TradingCard {
@@ -1723,9 +1601,9 @@ impl Joker for TradingCard {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
-        fn on_discard(g: &mut Game, hand: crate::hand::MadeHand) {
+        fn on_discard(g: &mut Game, hand: crate::hand::MadeHand, _slot: usize) {
             // Check if this is the first discard of the blind and exactly 1 card was discarded
             // Use hand.all which contains ALL selected cards, not just the ones used in best hand
             if g.discards_this_blind == 1 && hand.all.len() == 1 {
@@ -1735,19 +1613,20 @@ impl Joker for TradingCard {
                 // Remove from discarded pile and add to destroyed pile
                 if let Some(pos) = g.discarded.iter().rposition(|c| c == &card) {
                     g.discarded.remove(pos);
-                    g.destroyed.push(card);
+                    g.destroy_card_by(card, "Trading Card");
                     g.money += 3;
                 }
             }
         }
 
-        vec![Effects::OnDiscard(Arc::new(Mutex::new(on_discard)))]
+        vec![Effects::OnDiscard(Arc::new(on_discard))]
     }
 }
 
 // Joker: Matador - Earn $8 if played hand triggers Boss Blind ability
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Matador {}
 impl Joker for Matador {
@@ -1767,15 +1646,16 @@ impl Joker for Matador {
         vec![Categories::Economy]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        vec![Effects::OnBossBlindTrigger(Arc::new(Mutex::new(|game: &mut Game| {
+        vec![Effects::OnBossBlindTrigger(Arc::new(|game: &mut Game| {
             game.money += 8;
-        })))]
+        }))]
     }
 }
 
 // Joker: To the Moon - Earn $1 per $5 in excess of $20; excess lowers by $5 after round
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct ToTheMoon {
     pub excess_money: usize,
@@ -1803,32 +1683,24 @@ impl Joker for ToTheMoon {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnScore: Earn money based on excess over $20
         fn on_score(g: &mut Game, _hand: MadeHand) {
-            let current_excess = if g.money > 20 {
-                g.money.saturating_sub(20)
-            } else {
-                0
-            };
+            let current_excess = g.money_held().saturating_sub(20);
 
             // Earn $1 per $5 of excess
             let money_earned = current_excess / 5;
-            g.money += money_earned;
+            g.money += money_earned as i64;
         }
 
         // OnRoundEnd: Decrease the internal excess tracker
         fn on_round_end(g: &mut Game) {
+            // Calculate current excess
+            let current_excess = g.money_held().saturating_sub(20);
             // Find this joker and update its state
             for joker in g.jokers.iter_mut() {
                 if let Jokers::ToTheMoon(ttm) = joker {
-                    // Calculate current excess
-                    let current_excess = if g.money > 20 {
-                        g.money.saturating_sub(20)
-                    } else {
-                        0
-                    };
 
                     // Update internal excess tracker and decrease by $5
                     ttm.excess_money = current_excess.saturating_sub(5);
@@ -1838,15 +1710,16 @@ impl Joker for ToTheMoon {
         }
 
         vec![
-            Effects::OnScore(Arc::new(Mutex::new(on_score))),
-            Effects::OnRoundEnd(Arc::new(Mutex::new(on_round_end)))
+            Effects::OnScore(Arc::new(on_score)),
+            Effects::OnRoundEnd(Arc::new(on_round_end))
         ]
     }
 }
 
 // Joker: Vagabond - Create Tarot card if hand played with $4 or less
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Seance {}
 impl Joker for Seance {
@@ -1867,7 +1740,7 @@ impl Joker for Seance {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnScore: Create Planet if hand is Straight Flush
         fn on_score(g: &mut Game, hand: MadeHand) {
@@ -1876,13 +1749,14 @@ impl Joker for Seance {
             }
         }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(on_score)))]
+        vec![Effects::OnScore(Arc::new(on_score))]
     }
 }
 
 // Joker: Mr. Bones - Prevents death if chips scored >= 25% of required chips; self-destructs
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct MrBones {}
 impl Joker for MrBones {
@@ -1902,16 +1776,23 @@ impl Joker for MrBones {
         vec![Categories::Effect]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        // TODO: Need death prevention system
-        // TODO: Need OnBlindEnd effect to check score vs requirement
-        // TODO: Need joker self-destruction system
-        vec![]
+        fn on_would_lose(g: &mut Game) -> bool {
+            if g.score * 4 < g.required_score() {
+                return false;
+            }
+            if let Some(index) = g.jokers.iter().position(|j| matches!(j, Jokers::MrBones(_))) {
+                g.remove_joker_at(index);
+            }
+            return true;
+        }
+        vec![Effects::OnWouldLose(Arc::new(on_would_lose))]
     }
 }
 
 // Joker: Luchador - Sell this to disable current Boss Blind
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Luchador {}
 impl Joker for Luchador {
@@ -1931,17 +1812,10 @@ impl Joker for Luchador {
         vec![Categories::Effect]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        use crate::stage::{Blind, Stage};
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnSell: Disable the current Boss Blind modifier
-        let on_sell = Arc::new(Mutex::new(|g: &mut Game| {
-            // Check if we're in a Boss Blind
-            if let Stage::Blind(Blind::Boss, ref mut modifier) = g.stage {
-                // Disable the boss modifier
-                *modifier = None;
-            }
-        }));
+        let on_sell = Arc::new(|g: &mut Game| g.disable_boss_blind());
 
         vec![Effects::OnSell(on_sell)]
     }
@@ -1949,7 +1823,8 @@ impl Joker for Luchador {
 
 // Joker: Diet Cola - Sell this to create free Double Tag
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct DietCola {}
 impl Joker for DietCola {
@@ -1970,12 +1845,12 @@ impl Joker for DietCola {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::tag::Tag;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnSell: Create a free Double Tag
-        let on_sell = Arc::new(Mutex::new(|g: &mut Game| {
+        let on_sell = Arc::new(|g: &mut Game| {
             g.add_tag(Tag::Double);
-        }));
+        });
 
         vec![Effects::OnSell(on_sell)]
     }
@@ -1983,7 +1858,8 @@ impl Joker for DietCola {
 
 // Joker: Ceremonial Dagger - When Blind selected, destroys Joker to the right; adds double sell value to Mult
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct CeremonialDagger {
     pub bonus_mult: usize,
@@ -2010,22 +1886,19 @@ impl Joker for CeremonialDagger {
         vec![Categories::MultPlus]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        let mult_bonus = self.bonus_mult;
-        fn apply(g: &mut Game, _hand: MadeHand, bonus: usize) {
-            g.mult += bonus;
-        }
-        let apply_closure = move |g: &mut Game, hand: MadeHand| {
-            apply(g, hand, mult_bonus);
-        };
-        vec![Effects::OnScore(Arc::new(Mutex::new(apply_closure)))]
         // TODO: Need OnBlindSelect effect to destroy joker to the right
         // TODO: Need joker destruction system
+        vec![]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult += self.bonus_mult as f64;
     }
 }
 
 // Joker: Cartomancer - Create Tarot card when Blind selected; requires empty consumable slot
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Cartomancer {}
 impl Joker for Cartomancer {
@@ -2046,7 +1919,7 @@ impl Joker for Cartomancer {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         // OnBlindSelect: Create Tarot if there's room
         fn on_blind_select(g: &mut Game) {
@@ -2056,13 +1929,14 @@ impl Joker for Cartomancer {
             }
         }
 
-        vec![Effects::OnBlindSelect(Arc::new(Mutex::new(on_blind_select)))]
+        vec![Effects::OnBlindSelect(Arc::new(on_blind_select))]
     }
 }
 
 // Joker: Astronomer - All Planet cards and Celestial Packs in shop are free
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Astronomer {}
 impl Joker for Astronomer {
@@ -2090,7 +1964,8 @@ impl Joker for Astronomer {
 
 // Joker: Vampire - Gains X0.2 Mult per Enhanced card played; removes enhancement
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Vampire {
     pub bonus_mult: f32,
@@ -2125,69 +2000,40 @@ impl Joker for Vampire {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
-
-        let multiplier = self.bonus_mult;
-
-        // OnScore: Apply X mult multiplier
-        fn on_score(g: &mut Game, _hand: MadeHand, mult: f32) {
-            g.mult = (g.mult as f32 * mult) as usize;
-        }
-        let on_score_closure = move |g: &mut Game, hand: MadeHand| {
-            on_score(g, hand, multiplier);
-        };
+        use std::sync::Arc;
 
-        // OnPlay: Detect enhanced cards, increment bonus, remove enhancements
-        fn on_play(g: &mut Game, hand: MadeHand) {
-            // Get IDs of enhanced cards in the hand
-            let enhanced_card_ids: Vec<usize> = hand.all.iter()
-                .filter(|c| c.enhancement.is_some())
+        // OnPlay: Scored enhanced cards lose their enhancement before the
+        // hand scores, each one adding X0.2
+        fn on_play(g: &mut Game, hand: MadeHand, slot: usize) {
+            let enhanced_card_ids: Vec<usize> = hand.hand.as_slice().iter()
+                .filter(|c| !c.debuffed && c.enhancement.is_some())
                 .map(|c| c.id)
                 .collect();
+            if enhanced_card_ids.is_empty() {
+                return;
+            }
 
-            let enhanced_count = enhanced_card_ids.len();
-
-            // Increment Vampire's bonus_mult
-            if enhanced_count > 0 {
-                for joker in g.jokers.iter_mut() {
-                    if let Jokers::Vampire(vampire) = joker {
-                        vampire.bonus_mult += enhanced_count as f32 * 0.2;
-                        break;
-                    }
-                }
-
-                // Remove enhancements from these cards
-                for card_id in enhanced_card_ids {
-                    // Try deck
-                    if g.deck.modify_card(card_id, |c| {
-                        c.enhancement = None;
-                    }) {
-                        continue;
-                    }
-                    // Try available
-                    if g.available.modify_card(card_id, |c| {
-                        c.enhancement = None;
-                    }) {
-                        continue;
-                    }
-                    // Try discarded
-                    if let Some(card) = g.discarded.iter_mut().find(|c| c.id == card_id) {
-                        card.enhancement = None;
-                    }
-                }
+            if let Some(Jokers::Vampire(vampire)) = g.jokers.get_mut(slot) {
+                vampire.bonus_mult += enhanced_card_ids.len() as f32 * 0.2;
+            }
+            for card_id in enhanced_card_ids {
+                g.modify_card_everywhere(card_id, |c| {
+                    c.enhancement = None;
+                });
             }
         }
 
-        vec![
-            Effects::OnScore(Arc::new(Mutex::new(on_score_closure))),
-            Effects::OnPlay(Arc::new(Mutex::new(on_play)))
-        ]
+        vec![Effects::OnPlay(Arc::new(on_play))]
+    }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult *= self.bonus_mult as f64;
     }
 }
 
 // Joker: Driver's License - X3 Mult if full deck has at least 16 Enhanced cards
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Hack {}
 impl Joker for Hack {
@@ -2207,15 +2053,21 @@ impl Joker for Hack {
         vec![Categories::Effect]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        // TODO: Need RETRIGGER SYSTEM
-        // TODO: This is a major feature needed by multiple jokers
         vec![]
     }
+    fn retriggers(&self, _game: &Game, card: &Card, _index: usize) -> usize {
+        if matches!(card.value, Value::Two | Value::Three | Value::Four | Value::Five) {
+            1
+        } else {
+            0
+        }
+    }
 }
 
 // Joker: Dusk - Retrigger all played cards in final hand of round
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Dusk {}
 impl Joker for Dusk {
@@ -2235,15 +2087,22 @@ impl Joker for Dusk {
         vec![Categories::Effect]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        // TODO: Need RETRIGGER SYSTEM
-        // TODO: Need to detect final hand of round
         vec![]
     }
+    fn retriggers(&self, game: &Game, _card: &Card, _index: usize) -> usize {
+        // Final hand of the round
+        if game.plays == 1 {
+            1
+        } else {
+            0
+        }
+    }
 }
 
 // Joker: Sock and Buskin - Retrigger all played face cards
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct SockAndBuskin {}
 impl Joker for SockAndBuskin {
@@ -2263,14 +2122,21 @@ impl Joker for SockAndBuskin {
         vec![Categories::Effect]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        // TODO: Need RETRIGGER SYSTEM
         vec![]
     }
+    fn retriggers(&self, game: &Game, card: &Card, _index: usize) -> usize {
+        if game.modifiers.is_face(card) {
+            1
+        } else {
+            0
+        }
+    }
 }
 
 // Joker: Seltzer - Retrigger all played cards for next 10 hands
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Seltzer {
     pub hands_remaining: usize,
@@ -2298,26 +2164,30 @@ impl Joker for Seltzer {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
-        // OnPlay: Decrement hands_remaining counter after each hand played
-        fn on_play(g: &mut Game, _hand: MadeHand) {
-            for joker in g.jokers.iter_mut() {
-                if let Jokers::Seltzer(seltzer) = joker {
-                    if seltzer.hands_remaining > 0 {
-                        seltzer.hands_remaining -= 1;
-                    }
-                }
+        // OnHandEnd: Decrement hands_remaining counter after each hand played
+        fn on_hand_end(g: &mut Game, _hand: MadeHand, _score: usize, slot: usize) {
+            if let Some(Jokers::Seltzer(seltzer)) = g.jokers.get_mut(slot) {
+                seltzer.hands_remaining = seltzer.hands_remaining.saturating_sub(1);
             }
         }
 
-        vec![Effects::OnPlay(Arc::new(Mutex::new(on_play)))]
+        vec![Effects::OnHandEnd(Arc::new(on_hand_end))]
+    }
+    fn retriggers(&self, _game: &Game, _card: &Card, _index: usize) -> usize {
+        if self.hands_remaining > 0 {
+            1
+        } else {
+            0
+        }
     }
 }
 
 // Joker: Midas Mask - All face cards become Gold cards when scored
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct MidasMask {}
 impl Joker for MidasMask {
@@ -2338,52 +2208,44 @@ impl Joker for MidasMask {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
-        // OnScore: Convert all face cards to Gold enhancement
-        fn on_score(g: &mut Game, hand: MadeHand) {
-            // Get face card IDs from the hand
-            let face_card_ids: Vec<usize> = hand.all.iter()
-                .filter(|c| c.is_face())
+        // OnPlay: Scored face cards become Gold before the hand scores
+        fn on_play(g: &mut Game, hand: MadeHand, _slot: usize) {
+            let face_card_ids: Vec<usize> = hand.hand.as_slice().iter()
+                .filter(|c| !c.debuffed && g.modifiers.is_face(c))
                 .map(|c| c.id)
                 .collect();
-
-            // Modify each face card to have Gold enhancement
             for card_id in face_card_ids {
-                // Try deck first
-                if g.deck.modify_card(card_id, |c| {
-                    c.enhancement = Some(crate::card::Enhancement::Gold);
-                }) {
-                    continue;
-                }
-                // Try available
-                if g.available.modify_card(card_id, |c| {
-                    c.enhancement = Some(crate::card::Enhancement::Gold);
-                }) {
-                    continue;
-                }
-                // Try discarded
-                if let Some(card) = g.discarded.iter_mut().find(|c| c.id == card_id) {
-                    card.enhancement = Some(crate::card::Enhancement::Gold);
-                }
+                g.modify_card_everywhere(card_id, |c| {
+                    c.set_enhancement(crate::card::Enhancement::Gold);
+                });
             }
         }
 
-        vec![Effects::OnScore(Arc::new(Mutex::new(on_score)))]
+        vec![Effects::OnPlay(Arc::new(on_play))]
     }
 }
 
-// Joker: Madness - When Small or Big Blind selected, destroy random Joker and create 2 free Jokers
+// Joker: Madness - When Small or Big Blind selected, gain X0.5 Mult and destroy a random Joker
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
-pub struct Madness {}
+pub struct Madness {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub blinds_selected: usize,
+}
 impl Joker for Madness {
     fn name(&self) -> String {
         "Madness".to_string()
     }
     fn desc(&self) -> String {
-        "When Small or Big Blind selected, destroy random Joker and create 2 free Jokers".to_string()
+        let mult = 1.0 + (0.5 * self.blinds_selected as f32);
+        format!(
+            "X{:.1} Mult (X0.5 when Small or Big Blind selected, destroys a random Joker)",
+            mult
+        )
     }
     fn cost(&self) -> usize {
         7
@@ -2392,14 +2254,20 @@ impl Joker for Madness {
         Rarity::Uncommon
     }
     fn categories(&self) -> Vec<Categories> {
-        vec![Categories::Effect]
+        vec![Categories::MultMult]
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
-        // TODO: Need OnBlindSelect effect type
-        // TODO: Need joker destruction system
-        // TODO: Need joker creation system
         vec![]
     }
+    fn on_score(&mut self, game: &mut Game, _hand: &MadeHand) {
+        game.mult *= 1.0 + (0.5 * self.blinds_selected as f64);
+    }
+}
+
+impl Madness {
+    pub fn on_blind_selected(&mut self) {
+        self.blinds_selected += 1;
+    }
 }
 
 // ============================================================================
@@ -2408,7 +2276,8 @@ impl Joker for Madness {
 
 // Joker: Certificate - When round begins, add random playing card with random seal to hand
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct Certificate {}
 impl Joker for Certificate {
@@ -2429,7 +2298,7 @@ impl Joker for Certificate {
     }
     fn effects(&self, _game: &Game) -> Vec<Effects> {
         use crate::effect::Effects;
-        use std::sync::{Arc, Mutex};
+        use std::sync::Arc;
 
         fn on_round_begin(g: &mut Game) {
             use crate::card::{Card, Seal, Suit, Value};
@@ -2444,9 +2313,9 @@ impl Joker for Certificate {
             let all_suits = [Suit::Heart, Suit::Diamond, Suit::Club, Suit::Spade];
             let all_seals = [Seal::Gold, Seal::Red, Seal::Blue, Seal::Purple];
 
-            let value = all_values.choose(&mut rand::thread_rng()).unwrap();
-            let suit = all_suits.choose(&mut rand::thread_rng()).unwrap();
-            let seal = all_seals.choose(&mut rand::thread_rng()).unwrap();
+            let value = all_values.choose(&mut crate::rng::rng_for("joker", "certificate value")).unwrap();
+            let suit = all_suits.choose(&mut crate::rng::rng_for("joker", "certificate suit")).unwrap();
+            let seal = all_seals.choose(&mut crate::rng::rng_for("joker", "certificate seal")).unwrap();
 
             let mut card = Card::new(*value, *suit);
             card.seal = Some(*seal);
@@ -2455,13 +2324,14 @@ impl Joker for Certificate {
             g.hand.push(card);
         }
 
-        vec![Effects::OnRoundBegin(Arc::new(Mutex::new(on_round_begin)))]
+        vec![Effects::OnRoundBegin(Arc::new(on_round_begin))]
     }
 }
 
 // Joker: Gift Card - Add $1 of sell value to every Joker and Consumable card at end of round
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "python", pyclass(eq))]
 pub struct GiftCard {}
 impl Joker for GiftCard {
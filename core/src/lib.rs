@@ -1,28 +1,60 @@
 pub mod action;
+pub mod analysis;
 pub mod alternative_deck;
 pub mod ante;
+pub mod availability;
 pub mod available;
 pub mod booster;
 pub mod boss_modifier;
+pub mod boss_rule;
+pub mod breakdown;
 pub mod card;
+pub mod cashout;
+pub mod composition;
 pub mod config;
 pub mod consumable;
 pub mod deck;
 pub mod effect;
 pub mod error;
+pub mod event;
+#[cfg(feature = "serde")]
+pub mod export;
 pub mod game;
 pub mod generator;
 pub mod hand;
+pub mod history;
 pub mod joker;
+pub mod observation;
 pub mod planet;
+pub mod provenance;
 pub mod rank;
+pub mod render;
+#[cfg(feature = "serde")]
+pub mod replay;
+pub mod reward;
+pub mod rng;
+#[cfg(feature = "serde")]
+pub mod save;
+pub mod schedule;
+pub mod seed;
+// Sessions expire by `std::time::Instant`, which panics on wasm32
+#[cfg(not(target_arch = "wasm32"))]
+pub mod session;
+#[cfg(feature = "simulation")]
+pub mod simulation;
 pub mod shop;
+pub mod snapshot;
 pub mod space;
 pub mod spectral;
 pub mod stage;
+pub mod stats;
+pub mod sticker;
+pub mod summary;
 pub mod tag;
 pub mod tarot;
 pub mod voucher;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 #[cfg(test)]
 mod tests {
@@ -106,7 +138,7 @@ mod tests {
         use crate::planet::Planets;
         use crate::tarot::Tarots;
 
-        let mut g = Game::new(Config::default());
+        let mut g = Game::new(Config::default()).unwrap();
         g.money = 100;
         g.stage = Stage::Shop();
 
@@ -137,7 +169,7 @@ mod tests {
         use crate::config::Config;
         use crate::tarot::Tarots;
 
-        let mut g = Game::new(Config::default());
+        let mut g = Game::new(Config::default()).unwrap();
         g.money = 2; // Not enough for a tarot (cost 3)
         g.stage = Stage::Shop();
 
@@ -153,7 +185,7 @@ mod tests {
         use crate::config::Config;
         use crate::tarot::Tarots;
 
-        let mut g = Game::new(Config::default());
+        let mut g = Game::new(Config::default()).unwrap();
         g.money = 100;
         g.stage = Stage::PreBlind(); // Not shop stage
 
@@ -168,7 +200,7 @@ mod tests {
         use crate::config::Config;
         use crate::planet::Planets;
 
-        let mut g = Game::new(Config::default());
+        let mut g = Game::new(Config::default()).unwrap();
         let planet = Consumables::Planet(Planets::Mercury);
         g.consumables.push(planet.clone());
 
@@ -185,7 +217,7 @@ mod tests {
         use crate::config::Config;
         use crate::tarot::Tarots;
 
-        let mut g = Game::new(Config::default());
+        let mut g = Game::new(Config::default()).unwrap();
         let tarot = Consumables::Tarot(Tarots::TheHermit);
 
         // Don't add the tarot to consumables
@@ -199,7 +231,7 @@ mod tests {
         use crate::config::Config;
         use crate::tarot::Tarots;
 
-        let mut g = Game::new(Config::default());
+        let mut g = Game::new(Config::default()).unwrap();
         let tarot = Consumables::Tarot(Tarots::TheMagician);
         g.consumables.push(tarot.clone());
 
@@ -215,12 +247,13 @@ mod tests {
         use crate::config::Config;
         use crate::tarot::Tarots;
 
-        let mut g = Game::new(Config::default());
+        let mut g = Game::new(Config::default()).unwrap();
         let tarot = Consumables::Tarot(Tarots::TheMagician);
         g.consumables.push(tarot.clone());
 
         let card1 = Card::new(Value::Five, Suit::Heart);
         let card2 = Card::new(Value::Six, Suit::Diamond);
+        g.available.extend(vec![card1, card2]);
 
         // TheMagician requires up to 2 targets
         assert!(g.use_consumable(tarot.clone(), Some(vec![card1, card2])).is_ok());
@@ -228,6 +261,49 @@ mod tests {
         assert_eq!(g.last_consumable_used, Some(tarot));
     }
 
+    #[test]
+    fn test_use_consumable_targets_by_id() {
+        use crate::action::Action;
+        use crate::card::{Card, Suit, Value};
+        use crate::consumable::Consumables;
+        use crate::error::GameError;
+        use crate::stage::{Blind, Stage};
+        use crate::tarot::Tarots;
+
+        let mut g = Game::default();
+        g.stage = Stage::Blind(Blind::Small, None);
+        let five = Card::new(Value::Five, Suit::Heart);
+        let king = Card::new(Value::King, Suit::Spade);
+        g.available.extend(vec![five, king]);
+        let death = Consumables::Tarot(Tarots::Death);
+        let star = Consumables::Tarot(Tarots::TheStar);
+        g.consumables = vec![death.clone(), star.clone()];
+
+        assert_eq!(
+            g.use_consumable_on(death.clone(), Some(vec![five.id, 9999])),
+            Err(GameError::MissingTarget(9999))
+        );
+        let missing = Action::UseConsumable(death.clone(), Some(vec![9999]));
+        assert!(g.handle_action(missing).is_err());
+        assert_eq!(
+            g.use_consumable_on(star.clone(), Some(vec![king.id, king.id])),
+            Err(GameError::DuplicateTarget(king.id))
+        );
+        assert_eq!(
+            g.use_consumable_on(death.clone(), Some(vec![])),
+            Err(GameError::InvalidTargetCount)
+        );
+        assert_eq!(g.consumables.len(), 2);
+
+        // The King became an Ace after the snapshots were taken, Death copies
+        // it as it is now
+        g.modify_card_everywhere(king.id, |c| c.set_rank(Value::Ace));
+        g.use_consumable(death, Some(vec![five, king])).unwrap();
+        let converted = g.resolve_targets(&[five.id]).unwrap()[0];
+        assert_eq!((converted.value, converted.suit), (Value::Ace, Suit::Spade));
+        assert_eq!(g.consumables, vec![star]);
+    }
+
     #[test]
     fn test_last_consumable_tracking() {
         use crate::consumable::Consumables;
@@ -235,7 +311,7 @@ mod tests {
         use crate::planet::Planets;
         use crate::tarot::Tarots;
 
-        let mut g = Game::new(Config::default());
+        let mut g = Game::new(Config::default()).unwrap();
         assert!(g.last_consumable_used.is_none());
 
         let planet = Consumables::Planet(Planets::Venus);
@@ -280,26 +356,34 @@ mod tests {
         assert_eq!(initial.chips, 10);
         assert_eq!(initial.mult, 2);
 
-        // Upgrade once: Level 1→2 should add +30 chips, +3 mult
+        // Every Pair level adds +15 chips, +1 mult
         g.upgrade_hand(HandRank::OnePair);
         let upgraded = g.get_hand_level(HandRank::OnePair);
         assert_eq!(upgraded.level, 2);
-        assert_eq!(upgraded.chips, 40); // 10 + 30
-        assert_eq!(upgraded.mult, 5); // 2 + 3
+        assert_eq!(upgraded.chips, 25); // 10 + 15
+        assert_eq!(upgraded.mult, 3); // 2 + 1
 
-        // Upgrade again: Level 2→3 should add +25 chips, +2 mult
         g.upgrade_hand(HandRank::OnePair);
-        let upgraded2 = g.get_hand_level(HandRank::OnePair);
-        assert_eq!(upgraded2.level, 3);
-        assert_eq!(upgraded2.chips, 65); // 40 + 25
-        assert_eq!(upgraded2.mult, 7); // 5 + 2
-
-        // Upgrade a third time: Level 3→4 should add +20 chips, +2 mult
         g.upgrade_hand(HandRank::OnePair);
         let upgraded3 = g.get_hand_level(HandRank::OnePair);
         assert_eq!(upgraded3.level, 4);
-        assert_eq!(upgraded3.chips, 85); // 65 + 20
-        assert_eq!(upgraded3.mult, 9); // 7 + 2
+        assert_eq!(upgraded3.chips, 55); // 10 + 3 * 15
+        assert_eq!(upgraded3.mult, 5); // 2 + 3 * 1
+    }
+
+    #[test]
+    fn test_custom_hand_scaling() {
+        use crate::config::Config;
+        use crate::rank::{HandRank, Level};
+
+        let mut config = Config::default();
+        config.hand_scaling.set(HandRank::OnePair, 30, 3);
+        let mut g = Game::new(config).unwrap();
+        g.upgrade_hand(HandRank::OnePair);
+        assert_eq!(g.get_hand_level(HandRank::OnePair), Level::new(2, 40, 5));
+        // Other ranks keep the default table
+        g.upgrade_hand(HandRank::Flush);
+        assert_eq!(g.get_hand_level(HandRank::Flush), Level::new(2, 50, 6));
     }
 
     #[test]
@@ -312,8 +396,8 @@ mod tests {
         g.upgrade_hand(HandRank::Flush);
         let flush = g.get_hand_level(HandRank::Flush);
         assert_eq!(flush.level, 2);
-        assert_eq!(flush.chips, 65); // 35 + 30
-        assert_eq!(flush.mult, 7); // 4 + 3
+        assert_eq!(flush.chips, 50); // 35 + 15
+        assert_eq!(flush.mult, 6); // 4 + 2
 
         // Upgrade Straight
         g.upgrade_hand(HandRank::Straight);
@@ -350,8 +434,8 @@ mod tests {
         g.upgrade_hand(HandRank::OnePair);
 
         // Reset scoring state and play same hand at level 2
-        g.chips = g.config.base_chips;
-        g.mult = g.config.base_mult;
+        g.chips = g.config.base_chips as f64;
+        g.mult = g.config.base_mult as f64;
         let level2_score = g.calc_score_for_test();
 
         // Level 2 should score higher due to +30 chips, +3 mult
@@ -359,14 +443,14 @@ mod tests {
 
         // Calculate expected difference
         // Level 1 Pair: 10 chips, 2 mult
-        // Level 2 Pair: 40 chips, 5 mult
+        // Level 2 Pair: 25 chips, 3 mult
         // Card chips: 5 + 5 = 10
         // Base chips/mult from config are also added
         // But the key is that level 2 should score significantly higher
         assert!(level2_score > level1_score);
 
-        // Verify the difference is substantial (at least 5x higher for this example)
-        assert!(level2_score >= level1_score * 5);
+        // Verify the difference is substantial (over 2x higher for this example)
+        assert!(level2_score > level1_score * 2);
     }
 
     #[test]
@@ -461,13 +545,10 @@ mod tests {
 
         // Verify upgrade formula progression
         // Level 1: 35 chips, 4 mult
-        // Level 2: 65 chips, 7 mult (+30, +3)
-        // Level 3: 90 chips, 9 mult (+25, +2)
-        // Level 4: 110 chips, 11 mult (+20, +2)
-        // Level 5: 130 chips, 13 mult (+20, +2)
-        // Level 6: 150 chips, 15 mult (+20, +2)
-        assert_eq!(final_level.chips, 150);
-        assert_eq!(final_level.mult, 15);
+        // Every level: +15 chips, +2 mult
+        // Level 6: 110 chips, 14 mult
+        assert_eq!(final_level.chips, 110);
+        assert_eq!(final_level.mult, 14);
     }
 
     #[test]
@@ -658,6 +739,37 @@ mod tests {
         assert!(joker.cost() > 0);
     }
 
+    #[test]
+    fn test_generate_random_joker_uses_rarity_weights() {
+        use crate::joker::{Joker, Rarity};
+
+        let g = Game::default();
+        let mut seen_non_common = false;
+        for _ in 0..500 {
+            let joker = g.generate_random_joker();
+            // Legendary jokers only come from The Soul
+            assert_ne!(joker.rarity(), Rarity::Legendary);
+            if joker.rarity() != Rarity::Common {
+                seen_non_common = true;
+            }
+        }
+        // 30% of rolls are uncommon or rare, so 500 commons in a row is effectively impossible
+        assert!(seen_non_common);
+    }
+
+    #[test]
+    fn test_rare_pool_classification() {
+        use crate::joker::{Joker, Jokers, Rarity};
+
+        let rares: Vec<String> = Jokers::by_rarity(Rarity::Rare)
+            .iter()
+            .map(|j| j.name())
+            .collect();
+        for name in ["Campfire", "Obelisk", "Hit the Road", "Blueprint", "The Duo"] {
+            assert!(rares.contains(&name.to_string()), "{} should be Rare", name);
+        }
+    }
+
     // ===== Category A Tarot Tests (No Targets) =====
 
     #[test]
@@ -1282,7 +1394,7 @@ mod tests {
     fn test_create_enhanced_face_card() {
         use crate::card::Value;
 
-        let g = Game::default();
+        let mut g = Game::default();
         let card = g.create_enhanced_face_card();
 
         // Should be J, Q, or K
@@ -1296,7 +1408,7 @@ mod tests {
     fn test_create_enhanced_ace() {
         use crate::card::Value;
 
-        let g = Game::default();
+        let mut g = Game::default();
         let card = g.create_enhanced_ace();
 
         // Should be an Ace
@@ -1310,7 +1422,7 @@ mod tests {
     fn test_create_enhanced_number() {
         use crate::card::Value;
 
-        let g = Game::default();
+        let mut g = Game::default();
         let card = g.create_enhanced_number();
 
         // Should be 2-10
@@ -1331,9 +1443,8 @@ mod tests {
         let g = Game::default();
         let joker = g.generate_rare_joker();
 
-        // Should be rare rarity, or common if no rare jokers exist yet
-        // (rare jokers not implemented yet, so will fallback to common)
-        assert!(matches!(joker.rarity(), Rarity::Rare | Rarity::Common));
+        // Should be sampled from the rare pool
+        assert_eq!(joker.rarity(), Rarity::Rare);
     }
 
     #[test]
@@ -1343,9 +1454,8 @@ mod tests {
         let g = Game::default();
         let joker = g.generate_legendary_joker();
 
-        // Should be legendary rarity, or fallback if no legendary jokers exist yet
-        // (legendary jokers not implemented yet, so will fallback)
-        assert!(matches!(joker.rarity(), Rarity::Legendary | Rarity::Rare | Rarity::Common));
+        // Should be sampled from the legendary pool
+        assert_eq!(joker.rarity(), Rarity::Legendary);
     }
 
     #[test]
@@ -1761,7 +1871,7 @@ mod tests {
         // All hand levels should increase by 1
         assert_eq!(
             g.hand_levels[&HandRank::HighCard],
-            initial_level.upgrade()
+            initial_level.upgrade(10, 1)
         );
     }
 
@@ -1780,40 +1890,68 @@ mod tests {
 
         g.consumables.push(Consumables::Spectral(Spectrals::Ankh));
 
-        // Use Ankh (copy 1 joker, destroy others)
-        // Note: Current implementation just keeps first joker
+        g.set_joker_edition(0, crate::card::Edition::Negative);
+        g.set_joker_edition(1, crate::card::Edition::Negative);
+        g.set_joker_edition(2, crate::card::Edition::Negative);
+        let before = g.jokers.clone();
+
+        // Use Ankh (copy a random joker, destroy others)
         g.use_consumable(Consumables::Spectral(Spectrals::Ankh), None)
             .unwrap();
 
-        // Should only have 1 joker left
-        assert_eq!(g.jokers.len(), 1);
-        // Should be JollyJoker (the first one)
-        assert!(matches!(g.jokers[0], Jokers::JollyJoker(_)));
+        // The kept joker and its copy, which loses Negative
+        assert_eq!(g.jokers.len(), 2);
+        assert_eq!(g.jokers[0], g.jokers[1]);
+        assert!(before.contains(&g.jokers[0]));
+        assert_eq!(g.joker_edition(0), crate::card::Edition::Negative);
+        assert_eq!(g.joker_edition(1), crate::card::Edition::Base);
     }
 
     #[test]
-    fn test_spectral_hex() {
+    fn test_spectral_ankh_picks_any_joker() {
         use crate::consumable::Consumables;
         use crate::joker::{GreedyJoker, JollyJoker, Jokers};
         use crate::spectral::Spectrals;
 
+        let mut kept = Vec::new();
+        for seed in 0..20 {
+            let mut g = Game::with_seed(crate::config::Config::default(), seed).unwrap();
+            g.jokers.push(Jokers::JollyJoker(JollyJoker::default()));
+            g.jokers.push(Jokers::GreedyJoker(GreedyJoker::default()));
+            g.consumables.push(Consumables::Spectral(Spectrals::Ankh));
+            g.use_consumable(Consumables::Spectral(Spectrals::Ankh), None)
+                .unwrap();
+            if !kept.contains(&g.jokers[0]) {
+                kept.push(g.jokers[0].clone());
+            }
+        }
+        assert_eq!(kept.len(), 2);
+    }
+
+    #[test]
+    fn test_spectral_hex() {
+        use crate::consumable::Consumables;
+        use crate::joker::{GreedyJoker, JollyJoker, Jokers, LustyJoker};
+        use crate::spectral::Spectrals;
+
         let mut g = Game::default();
 
-        // Add 2 jokers
+        // Add 3 jokers, one already Foil
         g.jokers.push(Jokers::JollyJoker(JollyJoker::default()));
         g.jokers.push(Jokers::GreedyJoker(GreedyJoker::default()));
+        g.jokers.push(Jokers::LustyJoker(LustyJoker::default()));
+        g.set_joker_edition(2, crate::card::Edition::Foil);
 
         g.consumables.push(Consumables::Spectral(Spectrals::Hex));
 
-        // Use Hex (add Polychrome to 1 joker, destroy others)
-        // Note: Current implementation doesn't have joker editions yet
+        // Use Hex (add Polychrome to a random joker, destroy others)
         g.use_consumable(Consumables::Spectral(Spectrals::Hex), None)
             .unwrap();
 
-        // Should only have 1 joker left
+        // Should only have 1 joker left, one without an edition, now Polychrome
         assert_eq!(g.jokers.len(), 1);
-        // Should be JollyJoker (the first one)
-        assert!(matches!(g.jokers[0], Jokers::JollyJoker(_)));
+        assert!(matches!(g.jokers[0], Jokers::JollyJoker(_) | Jokers::GreedyJoker(_)));
+        assert_eq!(g.joker_edition(0), crate::card::Edition::Polychrome);
     }
 
     #[test]
@@ -1837,8 +1975,10 @@ mod tests {
         // Hand size should decrease by 1
         assert_eq!(g.hand_size, initial_hand_size - 1);
 
-        // Joker should still exist
+        // Joker should still exist, now Negative with its own slot
         assert_eq!(g.jokers.len(), 1);
+        assert_eq!(g.joker_edition(0), crate::card::Edition::Negative);
+        assert_eq!(g.joker_slots(), g.config.joker_slots + 1);
     }
 
     // ==================== Phase 3C Edge Case Tests ====================
@@ -1946,8 +2086,8 @@ mod tests {
         g.use_consumable(Consumables::Spectral(Spectrals::Ankh), None)
             .unwrap();
 
-        // Should still have 1 joker (copy of itself, others destroyed = just itself)
-        assert_eq!(g.jokers.len(), 1);
+        // The joker and its copy
+        assert_eq!(g.jokers, vec![Jokers::JollyJoker(JollyJoker::default()); 2]);
     }
 
     // ==================== Phase 3A Tarot Edge Case Tests ====================
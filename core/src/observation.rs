@@ -0,0 +1,463 @@
+use crate::card::{Card, Edition, Suit, Value};
+use crate::consumable::{Consumable, ConsumableType, Consumables};
+use crate::game::Game;
+use crate::joker::{Joker, Jokers, Rarity};
+use crate::rank::HandRank;
+use crate::space::ActionSpace;
+use crate::sticker::Stickers;
+
+// Flat numeric observation of the game state.
+//
+// 0: score
+// 1: required score
+// 2: stage (see `Stage::index`)
+// 3: round
+// 4: plays remaining
+// 5: discards remaining
+// 6: money, 0 while in debt
+// 7: deck length
+// 8: selected length
+// 9: available length
+// 10: discarded length
+// 11: jokers length
+// 12..: one slot of OBSERVATION_CARD_LEN per available card (up to available max):
+//       value + 1, suit + 1, selected. All zero for an empty slot, value
+//       and suit zero for a face-down card unless `ObservabilityMode::Full`.
+pub const OBSERVATION_HEADER_LEN: usize = 12;
+pub const OBSERVATION_CARD_LEN: usize = 3;
+
+const SELECTED_LEN_INDEX: usize = 8;
+
+impl Game {
+    // Length of the observation vector for this game's config
+    pub fn observation_len(&self) -> usize {
+        return OBSERVATION_HEADER_LEN + OBSERVATION_CARD_LEN * self.config.available_max;
+    }
+
+    // Get the observation vector and the legal action mask (same layout as
+    // `gen_action_space().to_vec()`) in one call.
+    pub fn observe_with_mask(&self) -> (Vec<usize>, Vec<usize>) {
+        let mut obs = Vec::with_capacity(self.observation_len());
+        let mut space = ActionSpace::from(self.config.clone());
+        self.observe_into(&mut obs, &mut space);
+        return (obs, space.to_vec());
+    }
+
+    // Fill caller-provided buffers with the observation and the masked action
+    // space, reusing their allocations. The mask comes from
+    // `gen_action_space_into`, so the space must have been built from this
+    // game's config.
+    pub fn observe_into(&self, obs: &mut Vec<usize>, space: &mut ActionSpace) {
+        obs.clear();
+        obs.extend_from_slice(&[
+            self.score,
+            self.required_score(),
+            self.stage.index(),
+            self.round,
+            self.plays,
+            self.discards,
+            self.money_held(),
+            self.deck.len(),
+            0, // selected length, filled in after walking the hand
+            self.available.iter_cards().len(),
+            self.discarded.len(),
+            self.jokers.len(),
+        ]);
+
+        // Walk the hand once for both card features and the selection count
+        let reveal = self.reveals_face_down();
+        let mut selected = 0;
+        for (i, card) in self.available.iter_cards().enumerate() {
+            if i >= self.config.available_max {
+                break;
+            }
+            let is_selected = self.available.is_selected_index(i);
+            if is_selected {
+                selected += 1;
+            }
+            // A face-down card's rank and suit read as an empty slot's
+            let (value, suit) = match card.is_face_down && !reveal {
+                true => (0, 0),
+                false => (card.value as usize + 1, card.suit as usize + 1),
+            };
+            obs.extend_from_slice(&[value, suit, is_selected as usize]);
+        }
+        obs.resize(self.observation_len(), 0);
+        obs[SELECTED_LEN_INDEX] = selected;
+
+        self.gen_action_space_into(space);
+    }
+}
+
+// Structured observation, every section has a fixed length for a given
+// config and `Observation::to_vec` concatenates them in this order.
+//
+// state (OBS_STATE_LEN):
+//   0: money
+//   1: ante
+//   2: round
+//   3: score
+//   4: required score
+//   5: plays remaining
+//   6: discards remaining
+//   7: deck length
+//   8: available length
+//   9: jokers length
+//   10: consumables length
+//   11..21: stage one-hot by `Stage::index`, the blind stages give the blind
+// hand (OBS_CARD_LEN per available card, up to available max):
+//   0: present
+//   1..14: value one-hot, Two to Ace
+//   14..18: suit one-hot, Spade, Club, Heart, Diamond
+//   18..26: enhancement one-hot, Bonus to Lucky
+//   26..31: edition one-hot, Base to Negative
+//   31..35: seal one-hot, Gold, Red, Blue, Purple
+//   35: selected
+//   36: face down, with value and suit left zero unless `ObservabilityMode::Full`
+//   37: debuffed
+// jokers (OBS_JOKER_LEN per joker slot, up to joker slots max):
+//   0: present
+//   1: joker kind (`Jokers::index` + 1)
+//   2..6: rarity one-hot, Common to Legendary
+//   6..11: edition one-hot
+//   11: eternal
+//   12: perishable rounds left (0 when not perishable)
+//   13: rental
+//   14: debuffed
+//   15: sell value
+// consumables (OBS_CONSUMABLE_LEN per consumable slot, up to consumable slots max):
+//   0: present
+//   1..4: type one-hot, Tarot, Planet, Spectral
+//   4: consumable within its type (`Consumables::index` + 1)
+//   5: sell value
+// hand levels (OBS_HAND_LEVEL_LEN per hand, in `HandRank::all` order):
+//   level, chips, mult
+// shop (store consumable slots max jokers, then as many consumables):
+//   jokers and consumables as above, with the price instead of the sell value
+//
+// Empty slots are all zero.
+pub const OBS_STATE_LEN: usize = 11 + OBS_STAGE_COUNT;
+pub const OBS_CARD_LEN: usize = 38;
+pub const OBS_JOKER_LEN: usize = 16;
+pub const OBS_CONSUMABLE_LEN: usize = 6;
+pub const OBS_HAND_LEVEL_LEN: usize = 3;
+
+// Stage ids run 0..=9, see `Stage::index`
+const OBS_STAGE_COUNT: usize = 10;
+const OBS_ENHANCEMENT_COUNT: usize = 8;
+const OBS_EDITION_COUNT: usize = 5;
+const OBS_SEAL_COUNT: usize = 4;
+
+/// Fixed-layout numeric encoding of the game state, see the layout above
+#[derive(Debug, Clone, PartialEq)]
+pub struct Observation {
+    pub state: Vec<f32>,
+    pub hand: Vec<f32>,
+    pub jokers: Vec<f32>,
+    pub consumables: Vec<f32>,
+    pub hand_levels: Vec<f32>,
+    pub shop: Vec<f32>,
+}
+
+impl Observation {
+    /// Every section concatenated into one flat vector
+    pub fn to_vec(&self) -> Vec<f32> {
+        return [
+            &self.state[..],
+            &self.hand,
+            &self.jokers,
+            &self.consumables,
+            &self.hand_levels,
+            &self.shop,
+        ]
+        .concat();
+    }
+
+    pub fn len(&self) -> usize {
+        return self.state.len()
+            + self.hand.len()
+            + self.jokers.len()
+            + self.consumables.len()
+            + self.hand_levels.len()
+            + self.shop.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+}
+
+impl Game {
+    /// Structured, fixed-layout encoding of the current state for agents.
+    /// Its length only depends on the config, see `observe_len`.
+    pub fn observe(&self) -> Observation {
+        let mut state = vec![
+            self.money as f32,
+            self.ante_current.number() as f32,
+            self.round as f32,
+            self.score as f32,
+            self.required_score() as f32,
+            self.plays as f32,
+            self.discards as f32,
+            self.deck.len() as f32,
+            self.available.iter_cards().len() as f32,
+            self.jokers.len() as f32,
+            self.consumables.len() as f32,
+        ];
+        push_one_hot(&mut state, self.stage.index(), OBS_STAGE_COUNT);
+
+        let mut hand = Vec::with_capacity(OBS_CARD_LEN * self.config.available_max);
+        for (i, card) in self.available.iter_cards().enumerate().take(self.config.available_max) {
+            let selected = self.available.is_selected_index(i);
+            encode_card(&mut hand, &card, selected, self.reveals_face_down());
+        }
+        hand.resize(OBS_CARD_LEN * self.config.available_max, 0.0);
+
+        let mut jokers = Vec::with_capacity(OBS_JOKER_LEN * self.config.joker_slots_max);
+        for (i, joker) in self.jokers.iter().enumerate().take(self.config.joker_slots_max) {
+            let stickers = self.joker_stickers(i);
+            let value = joker.sell_value() + self.joker_edition(i).extra_cost() / 2;
+            encode_joker(&mut jokers, joker, self.joker_edition(i), stickers, value);
+        }
+        jokers.resize(OBS_JOKER_LEN * self.config.joker_slots_max, 0.0);
+
+        let mut consumables = Vec::with_capacity(OBS_CONSUMABLE_LEN * self.config.consumable_slots_max);
+        for consumable in self.consumables.iter().take(self.config.consumable_slots_max) {
+            encode_consumable(&mut consumables, consumable, consumable.sell_value());
+        }
+        consumables.resize(OBS_CONSUMABLE_LEN * self.config.consumable_slots_max, 0.0);
+
+        let mut hand_levels = Vec::with_capacity(OBS_HAND_LEVEL_LEN * HandRank::all().len());
+        for rank in HandRank::all() {
+            let level = self.get_hand_level(rank);
+            hand_levels.extend_from_slice(&[level.level as f32, level.chips as f32, level.mult as f32]);
+        }
+
+        let slots = self.config.store_consumable_slots_max;
+        let mut shop = Vec::with_capacity((OBS_JOKER_LEN + OBS_CONSUMABLE_LEN) * slots);
+        for joker in self.shop.jokers.iter().take(slots) {
+            let edition = self.shop.joker_edition(joker);
+            let stickers = self.shop.joker_stickers(joker);
+            encode_joker(&mut shop, joker, edition, stickers, self.shop.joker_cost(joker));
+        }
+        shop.resize(OBS_JOKER_LEN * slots, 0.0);
+        for consumable in self.shop.consumables.iter().take(slots) {
+            encode_consumable(&mut shop, consumable, consumable.cost());
+        }
+        shop.resize((OBS_JOKER_LEN + OBS_CONSUMABLE_LEN) * slots, 0.0);
+
+        return Observation {
+            state,
+            hand,
+            jokers,
+            consumables,
+            hand_levels,
+            shop,
+        };
+    }
+
+    /// Length of `observe().to_vec()` for this game's config
+    pub fn observe_len(&self) -> usize {
+        return OBS_STATE_LEN
+            + OBS_CARD_LEN * self.config.available_max
+            + OBS_JOKER_LEN * self.config.joker_slots_max
+            + OBS_CONSUMABLE_LEN * self.config.consumable_slots_max
+            + OBS_HAND_LEVEL_LEN * HandRank::all().len()
+            + (OBS_JOKER_LEN + OBS_CONSUMABLE_LEN) * self.config.store_consumable_slots_max;
+    }
+}
+
+fn push_one_hot(out: &mut Vec<f32>, index: usize, len: usize) {
+    let start = out.len();
+    out.resize(start + len, 0.0);
+    if index < len {
+        out[start + index] = 1.0;
+    }
+}
+
+// Rank and suit of face-down cards are left empty unless `reveal`
+fn encode_card(out: &mut Vec<f32>, card: &Card, selected: bool, reveal: bool) {
+    out.push(1.0);
+    match card.is_face_down && !reveal {
+        true => out.resize(out.len() + Value::values().len() + Suit::suits().len(), 0.0),
+        false => {
+            push_one_hot(out, card.value as usize, Value::values().len());
+            push_one_hot(out, card.suit as usize, Suit::suits().len());
+        }
+    }
+    // One past the end leaves the one-hot empty for cards without one
+    let enhancement = card.enhancement.map_or(OBS_ENHANCEMENT_COUNT, |e| e as usize);
+    push_one_hot(out, enhancement, OBS_ENHANCEMENT_COUNT);
+    push_one_hot(out, card.edition as usize, OBS_EDITION_COUNT);
+    push_one_hot(out, card.seal.map_or(OBS_SEAL_COUNT, |s| s as usize), OBS_SEAL_COUNT);
+    out.push(selected as usize as f32);
+    out.push(card.is_face_down as usize as f32);
+    out.push(card.debuffed as usize as f32);
+}
+
+fn encode_joker(out: &mut Vec<f32>, joker: &Jokers, edition: Edition, stickers: Stickers, value: usize) {
+    out.push(1.0);
+    out.push((joker.index() + 1) as f32);
+    let rarity = match joker.rarity() {
+        Rarity::Common => 0,
+        Rarity::Uncommon => 1,
+        Rarity::Rare => 2,
+        Rarity::Legendary => 3,
+    };
+    push_one_hot(out, rarity, 4);
+    push_one_hot(out, edition as usize, OBS_EDITION_COUNT);
+    out.extend_from_slice(&[
+        stickers.eternal as usize as f32,
+        stickers.perishable.unwrap_or(0) as f32,
+        stickers.rental as usize as f32,
+        stickers.is_debuffed() as usize as f32,
+        value as f32,
+    ]);
+}
+
+fn encode_consumable(out: &mut Vec<f32>, consumable: &Consumables, value: usize) {
+    out.push(1.0);
+    let kind = match consumable.consumable_type() {
+        ConsumableType::Tarot => 0,
+        ConsumableType::Planet => 1,
+        ConsumableType::Spectral => 2,
+    };
+    push_one_hot(out, kind, 3);
+    out.push((consumable.index() + 1) as f32);
+    out.push(value as f32);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::Action;
+    use crate::stage::{Blind, Stage};
+
+    #[test]
+    fn test_observe_with_mask_matches_action_space() {
+        let mut g = Game::default();
+        g.start();
+
+        let (obs, mask) = g.observe_with_mask();
+        assert_eq!(obs.len(), g.observation_len());
+        assert_eq!(mask, g.gen_action_space().to_vec());
+
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        let card = g.available.card_from_index(1).expect("second card");
+        g.handle_action(Action::SelectCard(card)).unwrap();
+
+        let (obs, mask) = g.observe_with_mask();
+        assert_eq!(mask, g.gen_action_space().to_vec());
+        assert_eq!(obs[2], g.stage.index());
+        assert_eq!(obs[SELECTED_LEN_INDEX], 1);
+        assert_eq!(obs[9], g.available.cards().len());
+
+        // Second card slot is the selected one
+        let slot = OBSERVATION_HEADER_LEN + OBSERVATION_CARD_LEN;
+        assert_eq!(obs[slot], card.value as usize + 1);
+        assert_eq!(obs[slot + 1], card.suit as usize + 1);
+        assert_eq!(obs[slot + 2], 1);
+
+        // Slots past the hand are empty
+        let empty = OBSERVATION_HEADER_LEN + OBSERVATION_CARD_LEN * g.available.cards().len();
+        assert!(obs[empty..].iter().all(|v| *v == 0));
+    }
+
+    #[test]
+    fn test_observe_into_reuses_buffers() {
+        let mut g = Game::default();
+        g.start();
+        let mut obs = Vec::new();
+        let mut space = ActionSpace::from(g.config.clone());
+
+        g.observe_into(&mut obs, &mut space);
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        g.observe_into(&mut obs, &mut space);
+
+        assert_eq!(obs, g.observe_with_mask().0);
+        assert_eq!(space, g.gen_action_space());
+    }
+
+    #[test]
+    fn test_structured_observation() {
+        use crate::card::{Card, Edition, Seal, Suit, Value};
+        use crate::consumable::Consumables;
+        use crate::joker::{GreedyJoker, Jokers};
+        use crate::planet::Planets;
+
+        let mut g = Game::default();
+        let empty = g.observe();
+        assert_eq!(empty.to_vec().len(), g.observe_len());
+        assert_eq!(empty.len(), g.observe_len());
+        assert_eq!(empty.state[11 + Stage::PreBlind().index()], 1.0);
+        assert!(empty.hand.iter().all(|v| *v == 0.0));
+
+        g.start();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        let mut card = Card::new(Value::Ace, Suit::Heart);
+        card.edition = Edition::Foil;
+        card.seal = Some(Seal::Red);
+        g.available.empty();
+        g.available.extend(vec![card]);
+        g.select_card(card).unwrap();
+        g.jokers = vec![Jokers::GreedyJoker(GreedyJoker {})];
+        g.consumables = vec![Consumables::Planet(Planets::Mercury)];
+        g.money = 7;
+
+        let obs = g.observe();
+        assert_eq!(obs.to_vec().len(), g.observe_len());
+        assert_eq!(obs.state[0], 7.0);
+        assert_eq!(obs.state[1], 1.0);
+        assert_eq!(obs.state[11 + g.stage.index()], 1.0);
+
+        let slot = &obs.hand[..OBS_CARD_LEN];
+        assert_eq!(slot[0], 1.0);
+        assert_eq!(slot[1 + Value::Ace as usize], 1.0);
+        assert_eq!(slot[14 + Suit::Heart as usize], 1.0);
+        assert!(slot[18..26].iter().all(|v| *v == 0.0));
+        assert_eq!(slot[26 + Edition::Foil as usize], 1.0);
+        assert_eq!(slot[31 + Seal::Red as usize], 1.0);
+        assert_eq!(slot[35], 1.0);
+        assert_eq!(slot.iter().sum::<f32>(), 6.0);
+        assert!(obs.hand[OBS_CARD_LEN..].iter().all(|v| *v == 0.0));
+
+        let joker = &obs.jokers[..OBS_JOKER_LEN];
+        assert_eq!(joker[1], (Jokers::GreedyJoker(GreedyJoker {}).index() + 1) as f32);
+        assert_eq!(joker[2], 1.0);
+        assert_eq!(joker[15], 2.0);
+        let consumable = &obs.consumables[..OBS_CONSUMABLE_LEN];
+        assert_eq!(consumable[..4], [1.0, 0.0, 1.0, 0.0]);
+        assert_eq!(consumable[5], 1.0);
+
+        // Pair is the second hand rank, level 1 with 10 chips and 2 mult
+        assert_eq!(obs.hand_levels[3..6], [1.0, 10.0, 2.0]);
+    }
+
+    #[test]
+    fn test_face_down_cards_follow_observability() {
+        use crate::card::{Card, Suit, Value};
+        use crate::config::ObservabilityMode;
+
+        let mut g = Game::default();
+        g.start();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        let mut card = Card::new(Value::King, Suit::Spade);
+        card.set_face_down(true);
+        g.available.empty();
+        g.available.extend(vec![card]);
+
+        // Hidden by default: face down but no rank or suit
+        let slot = OBSERVATION_HEADER_LEN;
+        assert_eq!(g.observe_with_mask().0[slot..slot + 2], [0, 0]);
+        let hand = g.observe().hand;
+        assert_eq!(hand[36], 1.0);
+        assert!(hand[1..18].iter().all(|v| *v == 0.0));
+
+        g.config.observability = ObservabilityMode::Full;
+        assert_eq!(g.observe_with_mask().0[slot..slot + 2], [Value::King as usize + 1, 1]);
+        let hand = g.observe().hand;
+        assert_eq!(hand[36], 1.0);
+        assert_eq!(hand[1 + Value::King as usize], 1.0);
+        assert_eq!(hand[14 + Suit::Spade as usize], 1.0);
+    }
+}
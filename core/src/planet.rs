@@ -3,6 +3,7 @@ use crate::consumable::{Consumable, ConsumableType};
 use crate::error::GameError;
 use crate::game::Game;
 use crate::rank::HandRank;
+#[cfg(feature = "python")]
 use pyo3::pyclass;
 use strum::{EnumIter, IntoEnumIterator};
 
@@ -44,6 +45,11 @@ impl Planets {
         }
     }
 
+    /// Get the planet that upgrades a hand rank, if any
+    pub fn planet_for(rank: HandRank) -> Option<Self> {
+        return Self::iter().find(|p| p.hand_rank() == rank);
+    }
+
     /// Check if this planet is a secret planet
     pub fn is_secret(&self) -> bool {
         matches!(
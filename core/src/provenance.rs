@@ -0,0 +1,173 @@
+use crate::card::Card;
+use crate::event::Event;
+use crate::game::Game;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Where a card came from
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardOrigin {
+    // Part of the deck the run started with
+    StartingDeck,
+    // Created by an effect, e.g. "Familiar" or "Standard Pack"
+    Created(String),
+    // Copy of another card, e.g. by "Cryptid"
+    Copied { from: usize, by: String },
+    // Added without a known source (direct `add_card_to_deck` calls)
+    Added,
+}
+
+/// Lifecycle of a single card, keyed by card id in `CardLedger`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardHistory {
+    pub origin: CardOrigin,
+    pub created_round: usize,
+    // Source that destroyed the card (e.g. "Immolate", "Glass") and when
+    pub destroyed_by: Option<String>,
+    pub destroyed_round: Option<usize>,
+}
+
+impl CardHistory {
+    pub fn is_destroyed(&self) -> bool {
+        return self.destroyed_by.is_some();
+    }
+}
+
+/// Provenance of every card that has been part of a run
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CardLedger {
+    // Shared between snapshots until a card is added or destroyed
+    cards: Arc<HashMap<usize, CardHistory>>,
+}
+
+impl CardLedger {
+    pub fn get(&self, id: usize) -> Option<&CardHistory> {
+        return self.cards.get(&id);
+    }
+
+    pub fn len(&self) -> usize {
+        return self.cards.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.cards.is_empty();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&usize, &CardHistory)> {
+        return self.cards.iter();
+    }
+
+    /// Ids of cards destroyed so far
+    pub fn destroyed(&self) -> impl Iterator<Item = usize> + '_ {
+        return self
+            .cards
+            .iter()
+            .filter(|(_, h)| h.is_destroyed())
+            .map(|(id, _)| *id);
+    }
+
+    pub(crate) fn record_origin(&mut self, id: usize, origin: CardOrigin, round: usize) {
+        Arc::make_mut(&mut self.cards).insert(
+            id,
+            CardHistory {
+                origin,
+                created_round: round,
+                destroyed_by: None,
+                destroyed_round: None,
+            },
+        );
+    }
+
+    pub(crate) fn record_destroyed(&mut self, id: usize, by: &str, round: usize) {
+        let history = Arc::make_mut(&mut self.cards).entry(id).or_insert(CardHistory {
+            origin: CardOrigin::Added,
+            created_round: round,
+            destroyed_by: None,
+            destroyed_round: None,
+        });
+        history.destroyed_by = Some(by.to_string());
+        history.destroyed_round = Some(round);
+    }
+}
+
+impl Game {
+    /// Where the card with this id came from and whether it was destroyed
+    pub fn card_history(&self, id: usize) -> Option<&CardHistory> {
+        return self.card_ledger.get(id);
+    }
+
+    pub fn card_ledger(&self) -> &CardLedger {
+        return &self.card_ledger;
+    }
+
+    /// Add a card to the deck, recording where it came from
+    pub fn add_card_with_origin(&mut self, card: Card, origin: CardOrigin) {
+        self.card_ledger.record_origin(card.id, origin, self.round);
+        self.next_card_id = self.next_card_id.max(card.id + 1);
+        self.deck.add_card(card);
+        self.notify_card_added();
+    }
+
+    /// Remove a card from the deck permanently, recording what destroyed it
+    pub fn destroy_card_by(&mut self, card: Card, by: &str) {
+        self.deck.remove_card(card);
+        self.card_ledger.record_destroyed(card.id, by, self.round);
+        self.destroyed.push(card);
+        self.events.push(Event::CardDestroyed {
+            card,
+            by: by.to_string(),
+        });
+        self.notify_card_destroyed(&card);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consumable::Consumable;
+    use crate::spectral::Spectrals;
+
+    #[test]
+    fn test_card_history() {
+        let mut g = Game::default();
+        let start = g.deck.cards()[0];
+        assert_eq!(g.card_ledger().len(), 52);
+        assert_eq!(g.card_history(start.id).unwrap().origin, CardOrigin::StartingDeck);
+
+        Spectrals::Cryptid.use_effect(&mut g, Some(vec![start])).unwrap();
+        let copies: Vec<(usize, CardHistory)> = g
+            .card_ledger()
+            .iter()
+            .filter(|(_, h)| matches!(h.origin, CardOrigin::Copied { .. }))
+            .map(|(id, h)| (*id, h.clone()))
+            .collect();
+        assert_eq!(copies.len(), 2);
+        assert_eq!(
+            copies[0].1.origin,
+            CardOrigin::Copied {
+                from: start.id,
+                by: "Cryptid".to_string()
+            }
+        );
+
+        Spectrals::Immolate.use_effect(&mut g, None).unwrap();
+        let destroyed: Vec<usize> = g.card_ledger().destroyed().collect();
+        assert_eq!(destroyed.len(), 5);
+        for id in destroyed {
+            let history = g.card_history(id).unwrap();
+            assert_eq!(history.destroyed_by.as_deref(), Some("Immolate"));
+            assert_eq!(history.destroyed_round, Some(g.round));
+        }
+
+        Spectrals::Familiar.use_effect(&mut g, None).unwrap();
+        let created = g
+            .card_ledger()
+            .iter()
+            .filter(|(_, h)| h.origin == CardOrigin::Created("Familiar".to_string()))
+            .count();
+        assert_eq!(created, 3);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::planet::Planets;
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct Level {
@@ -12,42 +14,58 @@ impl Level {
         Self { level, chips, mult }
     }
 
-    /// Upgrade this level to the next level using Balatro formula:
-    /// Level 1→2: +30 chips, +3 mult
-    /// Level 2→3: +25 chips, +2 mult
-    /// Level 3+: +20 chips, +2 mult
-    pub fn upgrade(&self) -> Self {
-        let (chip_bonus, mult_bonus) = match self.level {
-            1 => (30, 3),
-            2 => (25, 2),
-            _ => (20, 2),
-        };
+    /// Next level, adding the chips and mult of the rank's `HandScaling` row
+    pub fn upgrade(&self, chips: usize, mult: usize) -> Self {
         Self {
             level: self.level + 1,
-            chips: self.chips + chip_bonus,
-            mult: self.mult + mult_bonus,
+            chips: self.chips + chips,
+            mult: self.mult + mult,
         }
     }
 
-    /// Downgrade this level to the previous level (reverse of upgrade)
+    /// Previous level, removing what `upgrade` added (reverse of upgrade)
     /// Used by The Arm boss modifier
-    pub fn downgrade(&self) -> Self {
+    pub fn downgrade(&self, chips: usize, mult: usize) -> Self {
         if self.level <= 1 {
             return *self; // Can't downgrade below level 1
         }
-        let (chip_penalty, mult_penalty) = match self.level {
-            2 => (30, 3), // Level 2→1
-            3 => (25, 2), // Level 3→2
-            _ => (20, 2), // Level 4+→previous
-        };
         Self {
             level: self.level - 1,
-            chips: self.chips.saturating_sub(chip_penalty),
-            mult: self.mult.saturating_sub(mult_penalty),
+            chips: self.chips.saturating_sub(chips),
+            mult: self.mult.saturating_sub(mult),
         }
     }
 }
 
+/// Chips and mult each hand rank gains per level. Defaults to the Planet
+/// card values of Balatro (see `HandRank::level_up`), set through
+/// `Config::hand_scaling` for custom rules.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct HandScaling {
+    // (chips, mult) per level, indexed like `HandRank::all()`
+    per_level: [(usize, usize); 13],
+}
+
+impl HandScaling {
+    /// Chips and mult `rank` gains per level
+    pub fn get(&self, rank: HandRank) -> (usize, usize) {
+        return self.per_level[rank as usize];
+    }
+
+    pub fn set(&mut self, rank: HandRank, chips: usize, mult: usize) {
+        self.per_level[rank as usize] = (chips, mult);
+    }
+}
+
+impl Default for HandScaling {
+    fn default() -> Self {
+        return Self {
+            per_level: HandRank::all().map(|rank| rank.level_up()),
+        };
+    }
+}
+
 /// All the different possible hand ranks.
 /// For each hand rank the u32 corresponds to
 /// the strength of the hand in comparison to others
@@ -70,7 +88,59 @@ pub enum HandRank {
     FlushFive,
 }
 
+/// One row of the run-info hand table: a hand rank with its current level,
+/// how often it has been played, and the Planet card that upgrades it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct HandLevelInfo {
+    pub rank: HandRank,
+    pub level: usize,
+    pub chips: usize,
+    pub mult: usize,
+    pub times_played: usize,
+    pub planet: Option<Planets>,
+    pub planet_name: Option<String>,
+}
+
 impl HandRank {
+    /// All hand ranks, weakest first
+    pub fn all() -> [HandRank; 13] {
+        return [
+            HandRank::HighCard,
+            HandRank::OnePair,
+            HandRank::TwoPair,
+            HandRank::ThreeOfAKind,
+            HandRank::Straight,
+            HandRank::Flush,
+            HandRank::FullHouse,
+            HandRank::FourOfAKind,
+            HandRank::StraightFlush,
+            HandRank::RoyalFlush,
+            HandRank::FiveOfAKind,
+            HandRank::FlushHouse,
+            HandRank::FlushFive,
+        ];
+    }
+
+    /// Chips and mult a Planet card adds to this hand, e.g. Flush +15/+2
+    pub fn level_up(&self) -> (usize, usize) {
+        return match self {
+            Self::HighCard => (10, 1),
+            Self::OnePair => (15, 1),
+            Self::TwoPair => (20, 1),
+            Self::ThreeOfAKind => (20, 2),
+            Self::Straight => (30, 3),
+            Self::Flush => (15, 2),
+            Self::FullHouse => (25, 2),
+            Self::FourOfAKind => (30, 3),
+            // A Royal Flush is a Straight Flush and levels like one
+            Self::StraightFlush | Self::RoyalFlush => (40, 4),
+            Self::FiveOfAKind => (35, 3),
+            Self::FlushHouse => (40, 4),
+            Self::FlushFive => (50, 3),
+        };
+    }
+
     pub(crate) fn level(&self) -> Level {
         match self {
             Self::HighCard => Level {
@@ -155,55 +225,31 @@ mod tests {
     }
 
     #[test]
-    fn test_level_upgrade_1_to_2() {
-        // Level 1→2: +30 chips, +3 mult
-        let level1 = Level::new(1, 10, 2);
-        let level2 = level1.upgrade();
-        assert_eq!(level2.level, 2);
-        assert_eq!(level2.chips, 40); // 10 + 30
-        assert_eq!(level2.mult, 5); // 2 + 3
+    fn test_level_upgrade_and_downgrade() {
+        let level = Level::new(1, 35, 4);
+        let upgraded = level.upgrade(15, 2);
+        assert_eq!(upgraded, Level::new(2, 50, 6));
+        assert_eq!(upgraded.upgrade(15, 2), Level::new(3, 65, 8));
+        assert_eq!(upgraded.downgrade(15, 2), level);
+        // Level 1 is the floor
+        assert_eq!(level.downgrade(15, 2), level);
     }
 
     #[test]
-    fn test_level_upgrade_2_to_3() {
-        // Level 2→3: +25 chips, +2 mult
-        let level2 = Level::new(2, 40, 5);
-        let level3 = level2.upgrade();
-        assert_eq!(level3.level, 3);
-        assert_eq!(level3.chips, 65); // 40 + 25
-        assert_eq!(level3.mult, 7); // 5 + 2
-    }
-
-    #[test]
-    fn test_level_upgrade_3_to_4() {
-        // Level 3+: +20 chips, +2 mult
-        let level3 = Level::new(3, 65, 7);
-        let level4 = level3.upgrade();
-        assert_eq!(level4.level, 4);
-        assert_eq!(level4.chips, 85); // 65 + 20
-        assert_eq!(level4.mult, 9); // 7 + 2
-    }
-
-    #[test]
-    fn test_level_upgrade_chain() {
-        // Test upgrading from level 1 through level 5
-        let mut level = Level::new(1, 10, 2);
-
-        // 1→2
-        level = level.upgrade();
-        assert_eq!(level, Level::new(2, 40, 5));
-
-        // 2→3
-        level = level.upgrade();
-        assert_eq!(level, Level::new(3, 65, 7));
-
-        // 3→4
-        level = level.upgrade();
-        assert_eq!(level, Level::new(4, 85, 9));
+    fn test_hand_scaling() {
+        let mut scaling = HandScaling::default();
+        assert_eq!(scaling.get(HandRank::Flush), (15, 2));
+        assert_eq!(scaling.get(HandRank::OnePair), (15, 1));
+        assert_eq!(scaling.get(HandRank::FlushFive), (50, 3));
+        assert_eq!(
+            scaling.get(HandRank::RoyalFlush),
+            scaling.get(HandRank::StraightFlush)
+        );
 
-        // 4→5
-        level = level.upgrade();
-        assert_eq!(level, Level::new(5, 105, 11));
+        scaling.set(HandRank::Flush, 30, 3);
+        assert_eq!(scaling.get(HandRank::Flush), (30, 3));
+        assert_eq!(scaling.get(HandRank::Straight), (30, 3));
+        assert_ne!(scaling, HandScaling::default());
     }
 
     #[test]
@@ -0,0 +1,178 @@
+use crate::card::Edition;
+use crate::game::Game;
+use crate::joker::{Joker, Jokers};
+use crate::schedule::BlindState;
+use crate::stage::{End, Stage};
+use std::fmt::Write;
+
+impl Game {
+    /// Compact multi-line board view for the CLI and debug logs: stage and
+    /// blind target, resources, jokers (with their counters), consumables
+    /// and the hand with the selected cards in brackets.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = self.render_into(&mut out);
+        return out;
+    }
+
+    fn render_into(&self, out: &mut String) -> std::fmt::Result {
+        writeln!(
+            out,
+            "Ante {}/{} | Round {} | {}",
+            self.ante_current.number(),
+            self.ante_end.number(),
+            self.round,
+            self.render_stage()
+        )?;
+        writeln!(
+            out,
+            "Score {}/{} | Hands {} | Discards {} | ${}",
+            self.score,
+            self.required_score(),
+            self.plays,
+            self.discards,
+            self.money
+        )?;
+
+        let jokers = self.render_jokers();
+        writeln!(
+            out,
+            "Jokers {}/{}: {}",
+            self.jokers.len(),
+            self.joker_slots(),
+            or_dash(jokers.join(" | "))
+        )?;
+        let consumables: Vec<String> = self.consumables.iter().map(|c| c.to_string()).collect();
+        writeln!(
+            out,
+            "Consumables {}/{}: {}",
+            self.consumables.len(),
+            self.config.consumable_slots,
+            or_dash(consumables.join(" | "))
+        )?;
+
+        let hand: Vec<String> = self
+            .available
+            .iter_cards()
+            .enumerate()
+            .map(|(i, card)| match self.available.is_selected_index(i) {
+                true => format!("[{}]", card),
+                false => card.to_string(),
+            })
+            .collect();
+        write!(
+            out,
+            "Hand {} (deck {}): {}",
+            self.available.cards().len(),
+            self.deck.len(),
+            or_dash(hand.join(" "))
+        )
+    }
+
+    /// One label per joker slot: edition, name and counters,
+    /// e.g. "Foil Green Joker (bonus_mult: 2)"
+    pub fn render_jokers(&self) -> Vec<String> {
+        return self
+            .jokers
+            .iter()
+            .enumerate()
+            .map(|(i, joker)| render_joker(joker, self.joker_edition(i)))
+            .collect();
+    }
+
+    /// What the stage asks of the player, e.g. the blinds to choose from
+    /// before a blind or the pack being opened
+    pub fn render_stage(&self) -> String {
+        return match self.stage {
+            Stage::PreBlind() => {
+                let blinds: Vec<String> = self
+                    .blind_schedule()
+                    .iter()
+                    .filter(|info| info.state != BlindState::Done)
+                    .map(|info| {
+                        let name = match info.boss {
+                            Some(modifier) => modifier.name().to_string(),
+                            None => info.blind.to_string(),
+                        };
+                        format!("{} {} ${}", name, info.required_score, info.reward)
+                    })
+                    .collect();
+                format!("Choose blind: {}", blinds.join(" | "))
+            }
+            Stage::Blind(blind, Some(modifier)) => format!("{} ({})", blind, modifier.name()),
+            Stage::Blind(blind, None) => blind.to_string(),
+            Stage::PostBlind() => format!("Cash out ${}", self.reward),
+            Stage::Shop() => "Shop".to_string(),
+            Stage::OpenPack() => match &self.shop.open_pack {
+                Some(pack) => format!("Open {} ({} left)", pack.booster(), pack.choices_left),
+                None => "Open pack".to_string(),
+            },
+            Stage::End(End::Win) => "Won".to_string(),
+            Stage::End(End::Lose) => "Lost".to_string(),
+            Stage::End(End::Endless) => "Lost (endless)".to_string(),
+        };
+    }
+}
+
+// Edition, name and the joker's counters, taken from its Debug output so
+// jokers gaining state need no extra code here, e.g.
+// "Foil Green Joker (bonus_mult: 2)".
+fn render_joker(joker: &Jokers, edition: Edition) -> String {
+    let debug = format!("{:?}", joker);
+    let state = match (debug.find('{'), debug.rfind('}')) {
+        (Some(start), Some(end)) if end > start => debug[start + 1..end].trim().to_string(),
+        _ => String::new(),
+    };
+    let name = match edition {
+        Edition::Base => joker.name(),
+        edition => format!("{:?} {}", edition, joker.name()),
+    };
+    return match state.is_empty() {
+        true => name,
+        false => format!("{} ({})", name, state),
+    };
+}
+
+fn or_dash(s: String) -> String {
+    return match s.is_empty() {
+        true => "-".to_string(),
+        false => s,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::Action;
+    use crate::config::Config;
+    use crate::joker::GreenJoker;
+    use crate::stage::Blind;
+
+    #[test]
+    fn test_render() {
+        let mut g = Game::with_seed(Config::default(), 5).unwrap();
+        g.start();
+        let out = g.render();
+        assert!(out.starts_with("Ante 1/8 | Round 0 | Choose blind: Small Blind 300 $3 | Big Blind 450 $4 | The"));
+        assert!(out.contains("Jokers 0/5: -"));
+
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        g.handle_action(Action::SelectCardIndex(1)).unwrap();
+        g.jokers.push(Jokers::GreenJoker(GreenJoker { bonus_mult: 2 }));
+        let out = g.render();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].ends_with("Small Blind"));
+        assert!(lines[1].starts_with(&format!("Score 0/{} | Hands", g.required_score())));
+        assert!(lines[2].contains("Green Joker (bonus_mult: 2)"));
+        let selected = g.available.card_from_index(1).unwrap();
+        assert!(lines[4].contains(&format!("[{}]", selected)));
+        assert_eq!(lines[4].matches('[').count(), 1);
+
+        assert_eq!(g.to_string(), out);
+
+        g.set_joker_edition(0, Edition::Negative);
+        assert!(g.render().contains("Jokers 1/6: Negative Green Joker"));
+    }
+}
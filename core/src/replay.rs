@@ -0,0 +1,154 @@
+use crate::action::Action;
+use crate::config::Config;
+use crate::error::ReplayError;
+use crate::game::Game;
+use crate::summary::RunSummary;
+
+/// Current replay format version, bump it when old replays stop loading
+pub const REPLAY_VERSION: u32 = 1;
+
+/// Everything needed to play a run again: the seed, the config the game was
+/// created with and every action handled, failed ones included. Small
+/// enough to attach to a bug report, as JSON or compact CBOR.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Replay {
+    pub version: u32,
+    pub seed: u64,
+    pub config: Config,
+    pub actions: Vec<Action>,
+    // Summary of the recorded game after the last action, checked by
+    // `Game::replay`. None skips the check, e.g. for hand written replays.
+    pub end: Option<RunSummary>,
+}
+
+impl Replay {
+    pub fn new(seed: u64, config: Config, actions: Vec<Action>) -> Self {
+        return Self {
+            version: REPLAY_VERSION,
+            seed,
+            config,
+            actions,
+            end: None,
+        };
+    }
+
+    pub fn to_json(&self) -> Result<String, ReplayError> {
+        return serde_json::to_string(self).map_err(|e| ReplayError::Format(e.to_string()));
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ReplayError> {
+        let replay: Self =
+            serde_json::from_str(json).map_err(|e| ReplayError::Format(e.to_string()))?;
+        return replay.checked();
+    }
+
+    /// Binary (CBOR) encoding, a fraction of the JSON size for long runs
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ReplayError> {
+        return serde_cbor::to_vec(self).map_err(|e| ReplayError::Format(e.to_string()));
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ReplayError> {
+        let replay: Self =
+            serde_cbor::from_slice(bytes).map_err(|e| ReplayError::Format(e.to_string()))?;
+        return replay.checked();
+    }
+
+    fn checked(self) -> Result<Self, ReplayError> {
+        if self.version > REPLAY_VERSION {
+            return Err(ReplayError::UnsupportedVersion(self.version, REPLAY_VERSION));
+        }
+        return Ok(self);
+    }
+}
+
+impl Game {
+    /// Replay of this run so far, ending in its current state
+    pub fn to_replay(&self) -> Replay {
+        let config = self.start_config.clone();
+        let mut replay = Replay::new(self.seed, config, self.action_history.to_vec());
+        replay.end = Some(self.run_summary());
+        return replay;
+    }
+
+    /// Start a new game from the replay's seed and config and handle its
+    /// actions in order. Actions fail exactly as they did when recorded, so
+    /// their errors are ignored; the end state is compared instead.
+    pub fn replay(replay: &Replay) -> Result<Game, ReplayError> {
+        let mut game = Game::with_seed(replay.config.clone(), replay.seed)?;
+        game.start();
+        for action in replay.actions.iter().cloned() {
+            let _ = game.handle_action(action);
+        }
+        if let Some(expected) = &replay.end {
+            let actual = game.run_summary();
+            if actual != *expected {
+                return Err(ReplayError::Diverged {
+                    expected: Box::new(expected.clone()),
+                    actual: Box::new(actual),
+                });
+            }
+        }
+        return Ok(game);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Stake;
+
+    // A failed action, then the first legal action every step
+    fn played_game() -> Game {
+        let mut config = Config::default();
+        config.stake = Stake::Blue;
+        let mut g = Game::with_seed(config, 42).unwrap();
+        g.start();
+        assert!(g.handle_action(Action::Play()).is_err());
+        for _ in 0..60 {
+            let Some(action) = g.gen_actions().next() else {
+                break;
+            };
+            g.handle_action(action).unwrap();
+        }
+        return g;
+    }
+
+    #[test]
+    fn test_replay_round_trip() {
+        let g = played_game();
+        let replay = g.to_replay();
+        assert_eq!(replay.actions.len(), g.action_history.len());
+        // The stake's discard penalty is not baked into the recorded config
+        assert_eq!(replay.config.discards, Config::default().discards);
+
+        for loaded in [
+            Replay::from_json(&replay.to_json().unwrap()).unwrap(),
+            Replay::from_bytes(&replay.to_bytes().unwrap()).unwrap(),
+        ] {
+            let replayed = Game::replay(&loaded).unwrap();
+            assert_eq!(replayed.run_summary(), g.run_summary());
+            assert_eq!(replayed.available.cards(), g.available.cards());
+            assert_eq!(replayed.action_history, g.action_history);
+        }
+        assert!(replay.to_bytes().unwrap().len() < replay.to_json().unwrap().len());
+    }
+
+    #[test]
+    fn test_replay_diverged() {
+        let mut replay = played_game().to_replay();
+        replay.actions.pop();
+        assert!(matches!(Game::replay(&replay), Err(ReplayError::Diverged { .. })));
+
+        // Without a recorded end state any action list replays
+        replay.end = None;
+        assert!(Game::replay(&replay).is_ok());
+
+        replay.version = REPLAY_VERSION + 1;
+        let json = replay.to_json().unwrap();
+        assert_eq!(
+            Replay::from_json(&json).unwrap_err(),
+            ReplayError::UnsupportedVersion(REPLAY_VERSION + 1, REPLAY_VERSION)
+        );
+        assert!(matches!(Replay::from_bytes(b"junk"), Err(ReplayError::Format(_))));
+    }
+}
@@ -0,0 +1,198 @@
+use crate::action::Action;
+use crate::error::GameError;
+use crate::game::Game;
+#[cfg(feature = "python")]
+use pyo3::{pyclass, pymethods};
+
+/// Weights of the per-step reward components for RL training. Each
+/// component is multiplied by its weight and summed, 0.0 turns it off.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RewardConfig {
+    // Per fraction of the blind's required score gained, 1.0 for a whole blind
+    pub score_progress: f64,
+    // Per $1 gained, spending counts as negative
+    pub money: f64,
+    // Per ante advanced
+    pub ante: f64,
+    // Once when the run is won
+    pub win: f64,
+    // Once when the run is lost, usually negative
+    pub loss: f64,
+}
+
+impl RewardConfig {
+    pub fn new() -> Self {
+        return Self {
+            score_progress: 1.0,
+            money: 0.0,
+            ante: 1.0,
+            win: 10.0,
+            loss: -1.0,
+        };
+    }
+
+    /// Reward for going from `prev` to `curr`
+    pub fn reward(&self, prev: &RewardState, curr: &RewardState) -> f64 {
+        let mut reward = 0.0;
+        // `score` resets when the hand clears the blind, use the hand's own
+        // points instead. Overscoring counts up to the blind's requirement.
+        if prev.required_score > 0 {
+            let remaining = prev.required_score.saturating_sub(prev.score);
+            let gain = curr.total_score.saturating_sub(prev.total_score).min(remaining);
+            reward += self.score_progress * gain as f64 / prev.required_score as f64;
+        }
+        reward += self.money * (curr.money as f64 - prev.money as f64);
+        reward += self.ante * curr.ante.saturating_sub(prev.ante) as f64;
+        if !prev.over && curr.over {
+            reward += match curr.won {
+                true => self.win,
+                false => self.loss,
+            };
+        }
+        return reward;
+    }
+}
+
+impl Default for RewardConfig {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// The parts of a game the reward components look at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RewardState {
+    pub score: usize,
+    // Points scored by all hands so far, see `GameStats::total_score`
+    pub total_score: usize,
+    pub required_score: usize,
+    pub money: i64,
+    pub ante: usize,
+    pub over: bool,
+    pub won: bool,
+}
+
+impl RewardState {
+    pub fn from_game(game: &Game) -> Self {
+        let result = game.result();
+        return Self {
+            score: game.score,
+            total_score: game.stats.total_score,
+            required_score: game.required_score(),
+            money: game.money,
+            ante: game.ante_current.number(),
+            over: result.is_some(),
+            won: result.is_some_and(|end| end.is_win()),
+        };
+    }
+}
+
+impl Game {
+    /// Handle an action like `handle_action` and return its reward
+    pub fn handle_action_with_reward(
+        &mut self,
+        action: Action,
+        rewards: &RewardConfig,
+    ) -> Result<f64, GameError> {
+        let prev = RewardState::from_game(self);
+        self.handle_action(action)?;
+        return Ok(rewards.reward(&prev, &RewardState::from_game(self)));
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl RewardConfig {
+    #[new]
+    fn py_new() -> Self {
+        Self::default()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::stage::{Blind, End, Stage};
+
+    #[test]
+    fn test_reward_components() {
+        let rewards = RewardConfig::new();
+        let prev = RewardState {
+            score: 0,
+            total_score: 0,
+            required_score: 300,
+            money: 4,
+            ante: 1,
+            over: false,
+            won: false,
+        };
+        let mut curr = prev;
+        curr.score = 150;
+        curr.total_score = 150;
+        assert_eq!(rewards.reward(&prev, &curr), 0.5);
+
+        // The hand that clears the blind resets the score, its points count
+        // up to the requirement
+        let mut cleared = curr;
+        cleared.score = 0;
+        cleared.total_score = 650;
+        assert_eq!(rewards.reward(&curr, &cleared), 0.5);
+
+        let money = RewardConfig {
+            money: 0.5,
+            ..RewardConfig::new()
+        };
+        curr = prev;
+        curr.money = 2;
+        assert_eq!(money.reward(&prev, &curr), -1.0);
+
+        curr = prev;
+        curr.ante = 2;
+        curr.over = true;
+        curr.won = true;
+        assert_eq!(rewards.reward(&prev, &curr), 1.0 + 10.0);
+        curr.won = false;
+        assert_eq!(rewards.reward(&prev, &curr), 1.0 - 1.0);
+        // The terminal bonus is paid once
+        assert_eq!(rewards.reward(&curr, &curr), 0.0);
+    }
+
+    #[test]
+    fn test_handle_action_with_reward() {
+        let rewards = RewardConfig::new();
+        let mut g = Game::with_seed(Config::default(), 9).unwrap();
+        g.start();
+        let reward = g
+            .handle_action_with_reward(Action::SelectBlind(Blind::Small), &rewards)
+            .unwrap();
+        assert_eq!(reward, 0.0);
+        assert!(g.handle_action_with_reward(Action::NextRound(), &rewards).is_err());
+
+        // The clearing hand is rewarded although the score resets with it
+        g.score = g.required_score() - 1;
+        let required = g.required_score() as f64;
+        g.handle_action(Action::SelectCardIndex(0)).unwrap();
+        let reward = g.handle_action_with_reward(Action::Play(), &rewards).unwrap();
+        assert_eq!(g.stage, Stage::PostBlind());
+        assert_eq!(g.score, 0);
+        assert_eq!(reward, 1.0 / required);
+
+        // Beating the last blind wins the run
+        g.ante_end = g.ante_current;
+        g.stage = Stage::Blind(Blind::Boss, None);
+        g.blind = Some(Blind::Boss);
+        g.score = g.required_score() - 1;
+        let required = g.required_score() as f64;
+        g.handle_action(Action::SelectCardIndex(0)).unwrap();
+        let reward = g.handle_action_with_reward(Action::Play(), &rewards).unwrap();
+        assert_eq!(g.result(), Some(End::Win));
+        assert_eq!(reward, 1.0 / required + 10.0);
+    }
+}
@@ -0,0 +1,232 @@
+use rand::{Error, RngCore};
+use std::cell::RefCell;
+
+/// Seedable random number generator owned by a game (SplitMix64).
+/// Cloning a game clones its generator, so clones replay the same rolls.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameRng {
+    state: u64,
+    #[cfg(feature = "rng-audit")]
+    log: Vec<RngDraw>,
+}
+
+/// A single recorded draw from a game generator
+#[cfg(feature = "rng-audit")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RngDraw {
+    pub subsystem: String,
+    pub purpose: String,
+    pub value: u64,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        GameRng {
+            state: seed,
+            #[cfg(feature = "rng-audit")]
+            log: Vec::new(),
+        }
+    }
+
+    /// Seed from OS entropy
+    pub fn from_entropy() -> Self {
+        Self::new(rand::thread_rng().next_u64())
+    }
+
+    /// Every draw made through `rng_for` since creation or the last clear
+    #[cfg(feature = "rng-audit")]
+    pub fn log(&self) -> &[RngDraw] {
+        &self.log
+    }
+
+    #[cfg(feature = "rng-audit")]
+    pub fn clear_log(&mut self) {
+        self.log.clear();
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+thread_local! {
+    // Generator of the game currently handling an action on this thread
+    static ACTIVE: RefCell<Option<GameRng>> = const { RefCell::new(None) };
+}
+
+/// Handle to the generator of the game currently being stepped on this
+/// thread. `Game` methods that roll install their generator first. Outside of
+/// a game call (e.g. a `Shop` or `Deck` used on its own) it falls back to
+/// `rand::thread_rng()`.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(not(feature = "rng-audit"), allow(dead_code))]
+pub struct ActiveRng {
+    subsystem: &'static str,
+    purpose: &'static str,
+}
+
+/// Get a handle to the active game generator. Engine code should use this
+/// instead of `rand::thread_rng()` so seeded games stay reproducible.
+/// The labels name the draw in the audit log (`rng-audit` feature).
+pub fn rng_for(subsystem: &'static str, purpose: &'static str) -> ActiveRng {
+    ActiveRng { subsystem, purpose }
+}
+
+impl RngCore for ActiveRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        ACTIVE.with(|active| match active.borrow_mut().as_mut() {
+            Some(rng) => {
+                let value = rng.next_u64();
+                #[cfg(feature = "rng-audit")]
+                rng.log.push(RngDraw {
+                    subsystem: self.subsystem.to_string(),
+                    purpose: self.purpose.to_string(),
+                    value,
+                });
+                value
+            }
+            None => rand::thread_rng().next_u64(),
+        })
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+fn fill_bytes<R: RngCore + ?Sized>(rng: &mut R, dest: &mut [u8]) {
+    for chunk in dest.chunks_mut(8) {
+        let bytes = rng.next_u64().to_le_bytes();
+        chunk.copy_from_slice(&bytes[..chunk.len()]);
+    }
+}
+
+/// Installs a game's generator as the active one for the duration of a call.
+/// Nested scopes (a game call made from within another) reuse the outer
+/// generator so the sequence is not replayed.
+pub(crate) struct RngScope {
+    installed: bool,
+}
+
+impl RngScope {
+    /// Install `rng`. The audit log moves into the scope rather than being
+    /// copied and `exit` hands it back, so `rng` has an empty log until then.
+    pub(crate) fn enter(rng: &mut GameRng) -> Self {
+        let installed = ACTIVE.with(|active| {
+            let mut active = active.borrow_mut();
+            if active.is_some() {
+                return false;
+            }
+            *active = Some(GameRng {
+                state: rng.state,
+                #[cfg(feature = "rng-audit")]
+                log: std::mem::take(&mut rng.log),
+            });
+            true
+        });
+        RngScope { installed }
+    }
+
+    /// Uninstall the generator, writing the advanced state back
+    pub(crate) fn exit(mut self, rng: &mut GameRng) {
+        if self.installed {
+            if let Some(state) = ACTIVE.with(|active| active.borrow_mut().take()) {
+                *rng = state;
+            }
+            self.installed = false;
+        }
+    }
+}
+
+impl Drop for RngScope {
+    // Only reached without `exit` on unwind, drop the in-flight state
+    fn drop(&mut self) {
+        if self.installed {
+            ACTIVE.with(|active| active.borrow_mut().take());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = GameRng::new(7);
+        let mut b = GameRng::new(7);
+        let xs: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+        let ys: Vec<u64> = (0..8).map(|_| b.next_u64()).collect();
+        assert_eq!(xs, ys);
+        assert_ne!(GameRng::new(8).next_u64(), xs[0]);
+    }
+
+    #[test]
+    fn test_scope_advances_game_rng() {
+        let mut game_rng = GameRng::new(42);
+        let mut expected = game_rng.clone();
+
+        let scope = RngScope::enter(&mut game_rng);
+        let first: u64 = rng_for("test", "first").gen();
+        // Nested scope must not restart the sequence
+        let inner = RngScope::enter(&mut game_rng);
+        let second: u64 = rng_for("test", "second").gen();
+        inner.exit(&mut game_rng);
+        scope.exit(&mut game_rng);
+
+        assert_eq!(first, expected.next_u64());
+        assert_eq!(second, expected.next_u64());
+        assert_eq!(game_rng.next_u64(), expected.next_u64());
+    }
+
+    #[cfg(feature = "rng-audit")]
+    #[test]
+    fn test_audit_log_records_draws() {
+        let mut game_rng = GameRng::new(1);
+        let scope = RngScope::enter(&mut game_rng);
+        let value = rng_for("shop", "joker").next_u64();
+        scope.exit(&mut game_rng);
+
+        // A second scope appends to the log handed back by the first
+        let scope = RngScope::enter(&mut game_rng);
+        assert!(game_rng.log().is_empty());
+        rng_for("shop", "reroll").next_u64();
+        scope.exit(&mut game_rng);
+
+        assert_eq!(game_rng.log().len(), 2);
+        let draw = &game_rng.log()[0];
+        assert_eq!((draw.subsystem.as_str(), draw.purpose.as_str()), ("shop", "joker"));
+        assert_eq!(draw.value, value);
+    }
+}
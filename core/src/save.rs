@@ -0,0 +1,122 @@
+use crate::error::SaveError;
+use crate::game::Game;
+
+/// Current save format version. Bump it when a change to the game state
+/// breaks old saves and add a step to `migrate` that upgrades them.
+pub const SAVE_VERSION: u32 = 1;
+
+/// Serializable state of a game at any decision point: deck, hand, jokers
+/// with their counters, consumables, shop, tags, vouchers, hand levels,
+/// round state and the RNG, so a loaded game continues exactly where the
+/// saved one was. Pending events and step-back checkpoints are not saved.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SaveState {
+    pub version: u32,
+    game: Game,
+}
+
+impl SaveState {
+    pub fn to_json(&self) -> Result<String, SaveError> {
+        return serde_json::to_string(self).map_err(|e| SaveError::Format(e.to_string()));
+    }
+
+    /// Parse a save written by this or an older version of the engine
+    pub fn from_json(json: &str) -> Result<Self, SaveError> {
+        let mut value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| SaveError::Format(e.to_string()))?;
+        let version = value
+            .get("version")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| SaveError::Format("missing version".to_string()))?
+            as u32;
+        if version > SAVE_VERSION {
+            return Err(SaveError::UnsupportedVersion(version, SAVE_VERSION));
+        }
+        migrate(&mut value, version)?;
+        return serde_json::from_value(value).map_err(|e| SaveError::Format(e.to_string()));
+    }
+}
+
+// Upgrade an older save in place, one version at a time, and stamp it with
+// the current version. Version 1 is the first format, nothing to do yet.
+fn migrate(value: &mut serde_json::Value, from: u32) -> Result<(), SaveError> {
+    if from == 0 {
+        return Err(SaveError::Format("version 0 is not a save format".to_string()));
+    }
+    value["version"] = SAVE_VERSION.into();
+    return Ok(());
+}
+
+impl Game {
+    pub fn to_save(&self) -> SaveState {
+        return SaveState {
+            version: SAVE_VERSION,
+            game: self.clone(),
+        };
+    }
+
+    /// Restore a saved game. Joker effects and rule modifiers are rebuilt
+    /// from the saved jokers.
+    pub fn from_save(save: SaveState) -> Result<Self, SaveError> {
+        if save.version != SAVE_VERSION {
+            return Err(SaveError::UnsupportedVersion(save.version, SAVE_VERSION));
+        }
+        let mut game = save.game;
+        game.refresh_joker_effects();
+        game.update_modifiers();
+        return Ok(game);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::Action;
+    use crate::card::Card;
+    use crate::config::Config;
+    use crate::joker::{Constellation, Joker, Jokers};
+    use crate::stage::Blind;
+
+    #[test]
+    fn test_save_round_trip_mid_blind() {
+        let mut g = Game::with_seed(Config::default(), 17).unwrap();
+        g.start();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        g.jokers.push(Jokers::Constellation(Constellation {
+            planet_cards_used: 3,
+            bonus_mult: 1.3,
+        }));
+        g.refresh_joker_effects();
+        let card = g.available.card_from_index(0).unwrap();
+        g.handle_action(Action::SelectCard(card)).unwrap();
+
+        let json = g.to_save().to_json().unwrap();
+        let mut loaded = Game::from_save(SaveState::from_json(&json).unwrap()).unwrap();
+        assert_eq!(loaded.jokers, g.jokers);
+        assert_eq!(loaded.available.selected(), g.available.selected());
+        assert_eq!(loaded.deck.cards(), g.deck.cards());
+        assert_eq!(loaded.hand_levels, g.hand_levels);
+        assert_eq!(loaded.shop.jokers, g.shop.jokers);
+        assert_eq!(loaded.effect_registry.on_score.len(), g.effect_registry.on_score.len());
+        assert_eq!(loaded.jokers[0].name(), "Constellation");
+
+        // The loaded game plays on exactly like the original
+        g.handle_action(Action::Play()).unwrap();
+        loaded.handle_action(Action::Play()).unwrap();
+        assert_eq!(loaded.score, g.score);
+        let drawn: Vec<Card> = loaded.available.cards();
+        assert_eq!(drawn, g.available.cards());
+    }
+
+    #[test]
+    fn test_save_version() {
+        let g = Game::with_seed(Config::default(), 1).unwrap();
+        let mut value: serde_json::Value = serde_json::from_str(&g.to_save().to_json().unwrap()).unwrap();
+        assert_eq!(value["version"], SAVE_VERSION);
+
+        value["version"] = (SAVE_VERSION + 1).into();
+        let err = SaveState::from_json(&value.to_string()).unwrap_err();
+        assert_eq!(err, SaveError::UnsupportedVersion(SAVE_VERSION + 1, SAVE_VERSION));
+        assert!(matches!(SaveState::from_json("{}"), Err(SaveError::Format(_))));
+    }
+}
@@ -0,0 +1,260 @@
+use crate::ante::Ante;
+use crate::boss_modifier::BossModifier;
+use crate::game::Game;
+use crate::joker::Jokers;
+use crate::stage::{Blind, Stage};
+use crate::tag::Tag;
+#[cfg(feature = "python")]
+use pyo3::{pyclass, pymethods};
+
+/// Where a blind of the current ante is at
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(eq))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlindState {
+    // Beaten or skipped
+    Done,
+    // Being played
+    Current,
+    // To be selected (or skipped) next
+    Next,
+    // Later in the ante
+    Upcoming,
+}
+
+/// One blind of the current ante as shown on the blind select screen
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlindInfo {
+    pub blind: Blind,
+    pub state: BlindState,
+    // Boss modifier of the Boss Blind, None for the others and when Chicot
+    // disables it
+    pub boss: Option<BossModifier>,
+    pub required_score: usize,
+    // Money for beating it, before hands, discards and interest
+    pub reward: usize,
+    // Tag for skipping it, only known for the next blind. The Boss Blind
+    // cannot be skipped.
+    pub skip_tag: Option<Tag>,
+}
+
+/// Scores needed to beat the blinds of one ante
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnteTargets {
+    pub ante: usize,
+    pub small: usize,
+    pub big: usize,
+    pub boss: usize,
+    // Modifier of the Boss Blind, only rolled for the current ante. Later
+    // antes count the usual X2 base, The Wall and Violet Vessel ask for more.
+    pub boss_modifier: Option<BossModifier>,
+}
+
+impl Game {
+    // Boss modifier of the current ante's Boss Blind: the one being played,
+    // none with Chicot, otherwise the one rolled for the ante
+    fn scheduled_boss(&self) -> Option<BossModifier> {
+        let has_chicot = self.jokers.iter().any(|j| matches!(j, Jokers::Chicot(_)));
+        return match self.stage {
+            Stage::Blind(Blind::Boss, modifier) => modifier,
+            _ if has_chicot => None,
+            _ => self.upcoming_boss,
+        };
+    }
+
+    /// Scores needed for every blind from the current ante to the last one,
+    /// with the run's stake and deck (Plasma Deck). In endless mode past the
+    /// last ante only the current one is listed.
+    pub fn ante_schedule(&self) -> Vec<AnteTargets> {
+        let targets = |ante: Ante, boss: Option<BossModifier>| AnteTargets {
+            ante: ante.number(),
+            small: self.required_score_in(ante, Blind::Small, None),
+            big: self.required_score_in(ante, Blind::Big, None),
+            boss: self.required_score_in(ante, Blind::Boss, boss),
+            boss_modifier: boss,
+        };
+        let mut ante = self.ante_current;
+        let mut schedule = vec![targets(ante, self.scheduled_boss())];
+        while ante < self.ante_end {
+            ante = ante.following();
+            schedule.push(targets(ante, None));
+        }
+        return schedule;
+    }
+
+    /// Score needed for the blind being played or, between blinds, the one
+    /// to select next
+    pub fn current_blind_target(&self) -> usize {
+        return self
+            .blind_schedule()
+            .into_iter()
+            .find(|info| matches!(info.state, BlindState::Current | BlindState::Next))
+            .map_or(0, |info| info.required_score);
+    }
+
+    /// Small, Big and Boss Blind of the current ante, with the Boss Blind's
+    /// modifier, score and reward known from the first PreBlind of the
+    /// ante. A Boss Tag rerolls the boss when the Boss Blind is selected.
+    pub fn blind_schedule(&self) -> Vec<BlindInfo> {
+        let (position, playing) = match self.stage {
+            Stage::Blind(blind, _) => (blind, true),
+            _ => (self.blind.map_or(Blind::Small, |b| b.next()), false),
+        };
+        let boss = self.scheduled_boss();
+        return [Blind::Small, Blind::Big, Blind::Boss]
+            .into_iter()
+            .map(|blind| {
+                let state = match blind.cmp(&position) {
+                    std::cmp::Ordering::Less => BlindState::Done,
+                    std::cmp::Ordering::Equal if playing => BlindState::Current,
+                    std::cmp::Ordering::Equal => BlindState::Next,
+                    std::cmp::Ordering::Greater => BlindState::Upcoming,
+                };
+                let boss = match blind {
+                    Blind::Boss => boss,
+                    _ => None,
+                };
+                let skip_tag = match state {
+                    BlindState::Next => self.skip_tag_offer(),
+                    _ => None,
+                };
+                return BlindInfo {
+                    blind,
+                    state,
+                    boss,
+                    required_score: self.required_score_for(blind, boss),
+                    reward: self.blind_reward(blind),
+                    skip_tag,
+                };
+            })
+            .collect();
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl BlindInfo {
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl AnteTargets {
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::Action;
+    use crate::alternative_deck::DeckType;
+    use crate::config::{Config, Stake};
+    use crate::joker::Chicot;
+
+    #[test]
+    fn test_blind_schedule_preview() {
+        let mut g = Game::with_seed(Config::default(), 11).unwrap();
+        g.start();
+        let schedule = g.blind_schedule();
+        let states: Vec<BlindState> = schedule.iter().map(|b| b.state).collect();
+        assert_eq!(states, [BlindState::Next, BlindState::Upcoming, BlindState::Upcoming]);
+        // Ante 1 base score 300
+        let scores: Vec<usize> = schedule.iter().map(|b| b.required_score).collect();
+        let boss = g.upcoming_boss.unwrap();
+        assert_eq!(scores[..2], [300, 450]);
+        assert_eq!(scores[2], (300.0 * boss.score_multiplier()) as usize);
+        let rewards: Vec<usize> = schedule.iter().map(|b| b.reward).collect();
+        assert_eq!(rewards, [3, 4, 5]);
+        assert_eq!(schedule[0].skip_tag, g.skip_tag_offer());
+        assert!(schedule[0].skip_tag.is_some());
+        assert_eq!(schedule[1].skip_tag, None);
+        assert_eq!(schedule[2].boss, Some(boss));
+
+        // The previewed boss is the one played
+        g.handle_action(Action::SkipBlind()).unwrap();
+        g.handle_action(Action::SkipBlind()).unwrap();
+        let schedule = g.blind_schedule();
+        assert_eq!(schedule[1].state, BlindState::Done);
+        assert_eq!(schedule[2].state, BlindState::Next);
+        assert_eq!(schedule[2].skip_tag, None);
+        g.handle_action(Action::SelectBlind(Blind::Boss)).unwrap();
+        assert_eq!(g.stage, Stage::Blind(Blind::Boss, Some(boss)));
+        assert_eq!(g.blind_schedule()[2].state, BlindState::Current);
+        assert_eq!(g.blind_schedule()[2].required_score, g.required_score());
+    }
+
+    #[test]
+    fn test_blind_schedule_rolls_boss_per_ante() {
+        let mut g = Game::with_seed(Config::default(), 11).unwrap();
+        g.start();
+        g.handle_action(Action::SkipBlind()).unwrap();
+        g.handle_action(Action::SkipBlind()).unwrap();
+        g.handle_action(Action::SelectBlind(Blind::Boss)).unwrap();
+        g.score = g.required_score();
+        g.plays = 1;
+        let card = g.available.cards()[0];
+        g.handle_action(Action::SelectCard(card)).unwrap();
+        g.handle_action(Action::Play()).unwrap();
+        assert_eq!(g.stage, Stage::PostBlind());
+
+        // A new ante starts from its Small Blind with a new boss rolled
+        let schedule = g.blind_schedule();
+        assert_eq!(g.ante_current.number(), 2);
+        assert_eq!(schedule[0].state, BlindState::Next);
+        assert_eq!(schedule[2].boss, g.upcoming_boss);
+        assert!(g.upcoming_boss.is_some());
+
+        // Chicot disables the boss
+        g.jokers.push(Jokers::Chicot(Chicot {}));
+        assert_eq!(g.blind_schedule()[2].boss, None);
+        assert_eq!(g.blind_schedule()[2].required_score, g.required_score_for(Blind::Boss, None));
+    }
+
+    #[test]
+    fn test_ante_schedule() {
+        let mut g = Game::with_seed(Config::default(), 11).unwrap();
+        g.start();
+        let schedule = g.ante_schedule();
+        let antes: Vec<usize> = schedule.iter().map(|a| a.ante).collect();
+        assert_eq!(antes, (1..=8).collect::<Vec<_>>());
+
+        // The current ante matches the blind select screen
+        let blinds = g.blind_schedule();
+        assert_eq!(schedule[0].small, blinds[0].required_score);
+        assert_eq!(schedule[0].big, blinds[1].required_score);
+        assert_eq!(schedule[0].boss, blinds[2].required_score);
+        assert_eq!(schedule[0].boss_modifier, g.upcoming_boss);
+        // Later bosses aren't rolled yet
+        assert_eq!((schedule[1].small, schedule[1].big, schedule[1].boss), (800, 1200, 1600));
+        assert_eq!(schedule[1].boss_modifier, None);
+        assert_eq!(schedule[7].small, 50000);
+
+        assert_eq!(g.current_blind_target(), 300);
+        g.handle_action(Action::SkipBlind()).unwrap();
+        assert_eq!(g.current_blind_target(), 450);
+        g.handle_action(Action::SelectBlind(Blind::Big)).unwrap();
+        assert_eq!(g.current_blind_target(), g.required_score());
+    }
+
+    #[test]
+    fn test_ante_schedule_stake_and_deck() {
+        let mut config = Config::default();
+        config.stake = Stake::Green;
+        let g = Game::with_seed(config.clone(), 11).unwrap();
+        assert_eq!(g.ante_schedule()[1].small, 900);
+
+        config.deck_type = Some(DeckType::PlasmaDeck);
+        let g = Game::with_seed(config, 11).unwrap();
+        let schedule = g.ante_schedule();
+        assert_eq!(schedule[0].small, 600);
+        assert_eq!(schedule[1].small, 1800);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::config::Config;
+use crate::error::SeedError;
+use crate::game::Game;
+
+// Balatro-style seed codes: uppercase letters and digits 1-9 (no zero, it
+// reads like an O). Codes are the base-35 encoding of the u64 seed, padded
+// to SEED_STR_LEN with the zero digit ('1').
+pub const SEED_ALPHABET: &[u8; 35] = b"123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+pub const SEED_STR_LEN: usize = 8;
+const BASE: u64 = SEED_ALPHABET.len() as u64;
+
+/// Number of seeds with a code of exactly SEED_STR_LEN characters.
+/// Randomly created games draw their seed from this range.
+pub const SEED_STR_SPACE: u64 = BASE.pow(SEED_STR_LEN as u32);
+
+/// Encode a seed as a shareable code
+pub fn encode_seed(mut seed: u64) -> String {
+    let mut digits = Vec::with_capacity(SEED_STR_LEN);
+    while seed > 0 {
+        digits.push(SEED_ALPHABET[(seed % BASE) as usize]);
+        seed /= BASE;
+    }
+    while digits.len() < SEED_STR_LEN {
+        digits.push(SEED_ALPHABET[0]);
+    }
+    digits.reverse();
+    return String::from_utf8(digits).expect("seed alphabet is ascii");
+}
+
+/// Decode a seed code (case-insensitive). Any code decodes, not just
+/// those produced by `encode_seed`, so users can pick memorable ones.
+pub fn decode_seed(code: &str) -> Result<u64, SeedError> {
+    let code = code.trim();
+    if code.is_empty() {
+        return Err(SeedError::Empty);
+    }
+    let mut seed: u64 = 0;
+    for c in code.chars() {
+        let upper = c.to_ascii_uppercase();
+        let digit = SEED_ALPHABET
+            .iter()
+            .position(|a| *a as char == upper)
+            .ok_or(SeedError::InvalidChar(c))? as u64;
+        seed = seed
+            .checked_mul(BASE)
+            .and_then(|s| s.checked_add(digit))
+            .ok_or(SeedError::Overflow)?;
+    }
+    return Ok(seed);
+}
+
+impl Game {
+    /// Create a game from a shareable seed code, e.g. "7LB2WVPK"
+    pub fn from_seed_str(config: Config, code: &str) -> Result<Self, SeedError> {
+        return Ok(Self::with_seed(config, decode_seed(code)?)?);
+    }
+
+    /// Shareable code for this game's seed
+    pub fn seed_str(&self) -> String {
+        return encode_seed(self.seed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_str_round_trip() {
+        for seed in [0, 1, 34, 35, 123_456_789, SEED_STR_SPACE - 1, u64::MAX] {
+            let code = encode_seed(seed);
+            assert!(code.len() >= SEED_STR_LEN);
+            assert_eq!(decode_seed(&code), Ok(seed));
+        }
+        assert_eq!(encode_seed(0), "11111111");
+        assert_eq!(encode_seed(SEED_STR_SPACE - 1), "ZZZZZZZZ");
+    }
+
+    #[test]
+    fn test_decode_seed() {
+        assert_eq!(decode_seed("7lb2wvpk"), decode_seed("7LB2WVPK"));
+        assert_eq!(decode_seed("ABC"), decode_seed("11111ABC"));
+        assert_eq!(decode_seed(""), Err(SeedError::Empty));
+        assert_eq!(decode_seed("AB0C"), Err(SeedError::InvalidChar('0')));
+        assert_eq!(decode_seed("ZZZZZZZZZZZZZZ"), Err(SeedError::Overflow));
+    }
+
+    #[test]
+    fn test_game_from_seed_str() {
+        let g = Game::from_seed_str(Config::default(), "7LB2WVPK").unwrap();
+        assert_eq!(g.seed_str(), "7LB2WVPK");
+        let h = Game::from_seed_str(Config::default(), "7LB2WVPK").unwrap();
+        assert_eq!(g.seed, h.seed);
+
+        // Random games get Balatro-length codes
+        assert_eq!(Game::default().seed_str().len(), SEED_STR_LEN);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::action::Action;
+use crate::config::Config;
+use crate::error::SessionError;
+use crate::game::Game;
+use crate::snapshot::Snapshot;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+// A hosted game and when it was last touched, for idle expiry
+#[derive(Debug, Clone)]
+struct Session {
+    game: Game,
+    last_used: Instant,
+}
+
+/// Registry of many concurrent games keyed by session id, for servers
+/// hosting tournaments or several human testers. Ids are never reused, so
+/// a stale id from an expired session reports `NotFound` instead of
+/// reaching someone else's game.
+#[derive(Debug, Clone, Default)]
+pub struct SessionManager {
+    sessions: HashMap<u64, Session>,
+    next_id: u64,
+}
+
+impl SessionManager {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Start a new game and return its session id. Without a seed the game
+    /// is seeded from entropy like `Game::new`.
+    pub fn create(&mut self, config: Config, seed: Option<u64>) -> Result<u64, SessionError> {
+        let mut game = match seed {
+            Some(seed) => Game::with_seed(config, seed)?,
+            None => Game::new(config)?,
+        };
+        game.start();
+        return Ok(self.insert(game));
+    }
+
+    /// Host a new session starting from a snapshot, e.g. to fork a game
+    pub fn create_from_snapshot(&mut self, snapshot: &Snapshot) -> u64 {
+        return self.insert(snapshot.game().clone());
+    }
+
+    fn insert(&mut self, game: Game) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.sessions.insert(
+            id,
+            Session {
+                game,
+                last_used: Instant::now(),
+            },
+        );
+        return id;
+    }
+
+    /// Handle an action in the given session
+    pub fn step(&mut self, id: u64, action: Action) -> Result<(), SessionError> {
+        let session = self.touch(id)?;
+        session.game.handle_action(action)?;
+        return Ok(());
+    }
+
+    pub fn snapshot(&mut self, id: u64) -> Result<Snapshot, SessionError> {
+        return Ok(self.touch(id)?.game.snapshot());
+    }
+
+    pub fn restore(&mut self, id: u64, snapshot: &Snapshot) -> Result<(), SessionError> {
+        self.touch(id)?.game.restore(snapshot);
+        return Ok(());
+    }
+
+    /// Read-only access, does not count as activity for expiry
+    pub fn game(&self, id: u64) -> Result<&Game, SessionError> {
+        return self
+            .sessions
+            .get(&id)
+            .map(|s| &s.game)
+            .ok_or(SessionError::NotFound(id));
+    }
+
+    pub fn game_mut(&mut self, id: u64) -> Result<&mut Game, SessionError> {
+        return Ok(&mut self.touch(id)?.game);
+    }
+
+    /// End a session, returning its game
+    pub fn remove(&mut self, id: u64) -> Result<Game, SessionError> {
+        return self
+            .sessions
+            .remove(&id)
+            .map(|s| s.game)
+            .ok_or(SessionError::NotFound(id));
+    }
+
+    /// Drop sessions idle for longer than `idle` and return their ids
+    pub fn expire(&mut self, idle: Duration) -> Vec<u64> {
+        let now = Instant::now();
+        let mut expired: Vec<u64> = self
+            .sessions
+            .iter()
+            .filter(|(_, s)| now.duration_since(s.last_used) > idle)
+            .map(|(id, _)| *id)
+            .collect();
+        expired.sort_unstable();
+        for id in expired.iter() {
+            self.sessions.remove(id);
+        }
+        return expired;
+    }
+
+    /// Ids of live sessions in creation order
+    pub fn ids(&self) -> Vec<u64> {
+        let mut ids: Vec<u64> = self.sessions.keys().copied().collect();
+        ids.sort_unstable();
+        return ids;
+    }
+
+    pub fn contains(&self, id: u64) -> bool {
+        return self.sessions.contains_key(&id);
+    }
+
+    pub fn len(&self) -> usize {
+        return self.sessions.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.sessions.is_empty();
+    }
+
+    fn touch(&mut self, id: u64) -> Result<&mut Session, SessionError> {
+        let session = self.sessions.get_mut(&id).ok_or(SessionError::NotFound(id))?;
+        session.last_used = Instant::now();
+        return Ok(session);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::GameError;
+    use crate::stage::Blind;
+
+    #[test]
+    fn test_sessions_are_independent() {
+        let mut m = SessionManager::new();
+        let a = m.create(Config::default(), Some(1)).unwrap();
+        let b = m.create(Config::default(), Some(1)).unwrap();
+        assert_ne!(a, b);
+        assert_eq!(m.ids(), vec![a, b]);
+
+        m.step(a, Action::SelectBlind(Blind::Small)).unwrap();
+        assert_eq!(m.game(a).unwrap().action_history.len(), 1);
+        assert_eq!(m.game(b).unwrap().action_history.len(), 0);
+
+        let res = m.step(b, Action::Play());
+        assert!(matches!(res, Err(SessionError::Game(GameError::InvalidAction(_)))));
+    }
+
+    #[test]
+    fn test_session_snapshot_restore_and_fork() {
+        let mut m = SessionManager::new();
+        let id = m.create(Config::default(), Some(7)).unwrap();
+        let snap = m.snapshot(id).unwrap();
+        m.step(id, Action::SelectBlind(Blind::Small)).unwrap();
+
+        let fork = m.create_from_snapshot(&snap);
+        assert_eq!(m.game(fork).unwrap().action_history.len(), 0);
+        m.restore(id, &snap).unwrap();
+        assert_eq!(m.game(id).unwrap().stage, snap.stage());
+    }
+
+    #[test]
+    fn test_session_expire_and_remove() {
+        let mut m = SessionManager::new();
+        let old = m.create(Config::default(), Some(2)).unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+        let fresh = m.create(Config::default(), Some(3)).unwrap();
+
+        assert_eq!(m.expire(Duration::from_millis(10)), vec![old]);
+        assert_eq!(m.step(old, Action::Play()), Err(SessionError::NotFound(old)));
+        assert!(m.contains(fresh));
+
+        m.remove(fresh).unwrap();
+        assert!(m.is_empty());
+        // Ids are not reused after sessions end
+        assert!(m.create(Config::default(), None).unwrap() > fresh);
+    }
+}
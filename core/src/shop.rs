@@ -1,14 +1,19 @@
 use crate::action::Action;
-use crate::booster::{Pack, PackType};
-use crate::consumable::Consumables;
+use crate::availability::Availability;
+use crate::booster::{BoosterPack, Pack, PackSize, PackType};
+use crate::card::Edition;
+use crate::config::Stake;
+use crate::consumable::{Consumable, Consumables};
 use crate::error::GameError;
 use crate::joker::{Joker, Jokers, Rarity};
 use crate::planet::Planets;
 use crate::spectral::Spectrals;
+use crate::sticker::Stickers;
 use crate::tarot::Tarots;
 use crate::voucher::Vouchers;
 use rand::prelude::*;
 use rand::seq::SliceRandom;
+use strum::IntoEnumIterator;
 
 /// Shop configuration - determines how many slots are available
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -40,11 +45,17 @@ impl Default for ShopConfig {
 #[derive(Debug, Clone)]
 pub struct Shop {
     pub jokers: Vec<Jokers>,
+    pub joker_editions: Vec<Edition>, // Edition of each joker for sale, by index
+    pub joker_stickers: Vec<Stickers>, // Stickers of each joker for sale, by index
     pub consumables: Vec<Consumables>,
-    pub packs: Vec<PackType>,
+    pub packs: Vec<BoosterPack>,
     pub voucher: Option<Vouchers>,
+    pub voucher_ante: Option<usize>, // Ante the voucher slot was last stocked for
     pub config: ShopConfig,
     pub rerolls_this_round: usize,
+    // +$1 on the reroll cost for every paid reroll this shop
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub reroll_increase: usize,
 
     // Opened pack state
     pub open_pack: Option<Pack>,
@@ -53,6 +64,14 @@ pub struct Shop {
     pub free_joker_indices: Vec<usize>,    // Indices of jokers that are free ($0)
     pub coupon_active: bool,               // Coupon tag makes all initial items free
 
+    // Per-ante availability of items, checked against the ante the shop is stocked for
+    pub availability: Availability,
+    pub ante: usize,
+    // Stake of the run, rolls stickers on jokers for sale
+    pub stake: Stake,
+    // Spectral cards can be sold without a voucher (Ghost Deck)
+    pub spectrals_in_shop: bool,
+
     // Generators
     pub(crate) joker_gen: JokerGenerator,
     consumable_gen: ConsumableGenerator,
@@ -67,14 +86,22 @@ impl Shop {
     pub fn with_config(config: ShopConfig) -> Self {
         Shop {
             jokers: Vec::new(),
+            joker_editions: Vec::new(),
+            joker_stickers: Vec::new(),
             consumables: Vec::new(),
             packs: Vec::new(),
             voucher: None,
+            voucher_ante: None,
             config,
             rerolls_this_round: 0,
+            reroll_increase: 0,
             open_pack: None,
             free_joker_indices: Vec::new(),
             coupon_active: false,
+            availability: Availability::default(),
+            ante: 1,
+            stake: Stake::White,
+            spectrals_in_shop: false,
             joker_gen: JokerGenerator::new(),
             consumable_gen: ConsumableGenerator::new(),
             pack_gen: PackGenerator::new(),
@@ -117,15 +144,18 @@ impl Shop {
     /// Restock the shop with new items (convenience method)
     /// Checks for jokers like Oops! All 6s that affect probabilities
     pub fn restock_with_jokers(&mut self, jokers: &[Jokers], vouchers: &[Vouchers]) {
-        // Check for Oops! All 6s joker
+        self.update_joker_odds(jokers);
+        self.refresh_excluding(vouchers, jokers);
+    }
+
+    // Check for Oops! All 6s joker
+    fn update_joker_odds(&mut self, jokers: &[Jokers]) {
         let has_oops_all_6s = jokers.iter().any(|j| matches!(j, Jokers::OopsAll6s(_)));
         if has_oops_all_6s {
             self.joker_gen.set_probability_multiplier(2.0);
         } else {
             self.joker_gen.set_probability_multiplier(1.0);
         }
-
-        self.refresh(vouchers);
     }
 
     /// Restock the shop with new items (simple version without joker checks)
@@ -135,57 +165,168 @@ impl Shop {
 
     /// Refresh the shop with new items
     pub fn refresh(&mut self, vouchers: &[Vouchers]) {
-        self.jokers.clear();
-        self.consumables.clear();
+        self.refresh_excluding(vouchers, &[]);
+    }
+
+    /// Refresh the shop, never offering a joker the player already owns
+    /// or the same joker twice. Holding Showman lifts both restrictions.
+    pub fn refresh_excluding(&mut self, vouchers: &[Vouchers], owned: &[Jokers]) {
         self.packs.clear();
-        self.voucher = None;
         self.rerolls_this_round = 0;
+        self.reroll_increase = 0;
         self.free_joker_indices.clear();
         self.coupon_active = false;
 
+        self.stock_cards(vouchers, owned);
+        self.pack_gen.update_from_vouchers(vouchers);
+
+        let ante = self.ante;
+        let availability = &self.availability;
+
+        // Generate packs
+        for _ in 0..self.config.pack_slots {
+            self.packs.push(
+                self.pack_gen
+                    .gen_booster_where(|p| availability.pack_available(p, ante)),
+            );
+        }
+    }
+
+    // Restock the joker and consumable slots. Consumables roll Tarot or
+    // Planet by the generator's weights, Spectral only with a Spectral
+    // voucher or the Ghost Deck.
+    fn stock_cards(&mut self, vouchers: &[Vouchers], owned: &[Jokers]) {
+        self.jokers.clear();
+        self.joker_editions.clear();
+        self.joker_stickers.clear();
+        self.consumables.clear();
+
         // Update generators with voucher modifiers
         self.joker_gen.update_from_vouchers(vouchers);
         self.consumable_gen.update_from_vouchers(vouchers);
-        self.pack_gen.update_from_vouchers(vouchers);
+        if self.spectrals_in_shop {
+            self.consumable_gen.allow_spectrals();
+        }
+
+        let ante = self.ante;
+        let availability = &self.availability;
 
         // Generate jokers
+        let allow_duplicates = owned.iter().any(|j| matches!(j, Jokers::Showman(_)));
+        let unavailable: Vec<Jokers> = Jokers::iter()
+            .filter(|j| !availability.joker_available(j, ante))
+            .collect();
         for _ in 0..self.config.joker_slots {
-            self.jokers.push(self.joker_gen.gen_joker());
+            let mut taken = unavailable.clone();
+            if !allow_duplicates {
+                taken.extend(owned.iter().chain(self.jokers.iter()).cloned());
+            }
+            let joker = self.joker_gen.gen_joker_excluding(&taken);
+            let edition = self.joker_gen.gen_edition();
+            let stickers = self.gen_stickers(&joker);
+            self.jokers.push(joker);
+            self.joker_editions.push(edition);
+            self.joker_stickers.push(stickers);
         }
 
         // Generate consumables
         for _ in 0..self.config.consumable_slots {
-            self.consumables.push(self.consumable_gen.gen_consumable());
+            self.consumables.push(
+                self.consumable_gen
+                    .gen_consumable_where(|c| availability.consumable_available(c, ante)),
+            );
         }
+    }
 
-        // Generate packs
-        for _ in 0..self.config.pack_slots {
-            self.packs.push(self.pack_gen.gen_pack());
+    /// Stock the voucher slot once per ante. Rerolls and later shops in the
+    /// same ante keep the voucher, and a bought voucher is not replaced
+    /// until the next ante.
+    pub fn restock_voucher(&mut self, owned: &[Vouchers]) {
+        if self.voucher_ante == Some(self.ante) {
+            return;
         }
+        self.voucher_ante = Some(self.ante);
+        self.voucher = match self.config.voucher_slots > 0 {
+            true => self.random_voucher(owned),
+            false => None,
+        };
+    }
 
-        // Generate voucher (if slots available)
-        if self.config.voucher_slots > 0 {
-            // Vouchers appear with some probability (not always)
-            if thread_rng().gen_bool(0.5) {
-                // 50% chance per slot
-                // Voucher generation will be based on what's already owned
-                // For now, we'll handle this in game.rs with owned vouchers list
-                self.voucher = None; // Placeholder - will be set by game
-            }
-        }
+    /// Pick a voucher the player can buy next that is available at the shop's ante
+    pub fn random_voucher(&self, owned: &[Vouchers]) -> Option<Vouchers> {
+        Vouchers::random_available_where(owned, |v| self.availability.voucher_available(v, self.ante))
     }
 
-    /// Reroll the shop (costs money)
+    /// Reroll the shop, `Game::reroll_shop` charges for it. Only the joker
+    /// and consumable slots are restocked, packs and the voucher stay.
     pub fn reroll(&mut self, vouchers: &[Vouchers]) {
-        // Save rerolls before refresh
-        let rerolls = self.rerolls_this_round;
-        self.refresh(vouchers);
-        self.rerolls_this_round = rerolls + 1;
+        self.reroll_excluding(vouchers, &[]);
     }
 
-    /// Get the actual cost of rerolling (can increase per reroll)
+    /// Reroll the shop, skipping jokers the player already owns (see `refresh_excluding`)
+    pub fn reroll_with_jokers(&mut self, jokers: &[Jokers], vouchers: &[Vouchers]) {
+        self.update_joker_odds(jokers);
+        self.reroll_excluding(vouchers, jokers);
+    }
+
+    fn reroll_excluding(&mut self, vouchers: &[Vouchers], owned: &[Jokers]) {
+        self.free_joker_indices.clear();
+        self.coupon_active = false;
+        self.stock_cards(vouchers, owned);
+        self.rerolls_this_round += 1;
+    }
+
+    /// Cost of the next paid reroll: $5 less Reroll Surplus/Glut (or $0
+    /// after a D6 Tag), +$1 for every paid reroll this shop
     pub fn reroll_cost(&self) -> usize {
-        self.config.reroll_cost
+        self.config.reroll_cost + self.reroll_increase
+    }
+
+    /// Add a joker for sale, returns its index
+    pub fn push_joker(&mut self, joker: Jokers, edition: Edition) -> usize {
+        // Jokers pushed directly onto `jokers` have no edition or stickers
+        self.joker_editions.resize(self.jokers.len(), Edition::Base);
+        self.joker_stickers.resize(self.jokers.len(), Stickers::default());
+        self.jokers.push(joker);
+        self.joker_editions.push(edition);
+        self.joker_stickers.push(Stickers::default());
+        self.jokers.len() - 1
+    }
+
+    // Stickers for a new joker for sale or in a Buffoon Pack, only Black
+    // Stake and above roll any
+    pub(crate) fn gen_stickers(&self, joker: &Jokers) -> Stickers {
+        if self.stake < Stake::Black {
+            return Stickers::default();
+        }
+        Stickers::roll_for(joker, self.stake, &mut crate::rng::rng_for("shop", "joker stickers"))
+    }
+
+    /// Get the stickers of a joker for sale
+    pub fn joker_stickers(&self, joker: &Jokers) -> Stickers {
+        self.jokers
+            .iter()
+            .position(|j| j == joker)
+            .and_then(|i| self.joker_stickers.get(i).copied())
+            .unwrap_or_default()
+    }
+
+    /// Get the edition of a joker for sale
+    pub fn joker_edition(&self, joker: &Jokers) -> Edition {
+        self.jokers
+            .iter()
+            .position(|j| j == joker)
+            .and_then(|i| self.joker_editions.get(i).copied())
+            .unwrap_or(Edition::Base)
+    }
+
+    /// Cost of a joker for sale including its edition, before multipliers.
+    /// Rental jokers always cost $1.
+    pub fn joker_cost(&self, joker: &Jokers) -> usize {
+        if self.joker_stickers(joker).rental {
+            return 1;
+        }
+        joker.cost() + self.joker_edition(joker).extra_cost()
     }
 
     /// Get the price of a joker with multipliers applied
@@ -197,26 +338,25 @@ impl Shop {
                 return 0;
             }
         }
-        (joker.cost() as f32 * self.config.price_multiplier).floor() as usize
+        (self.joker_cost(joker) as f32 * self.config.price_multiplier).floor() as usize
     }
 
     /// Get the price of a consumable with multipliers applied
-    pub fn consumable_price(&self, _consumable: &Consumables) -> usize {
+    pub fn consumable_price(&self, consumable: &Consumables) -> usize {
         // Coupon tag makes initial items free
         if self.coupon_active {
             return 0;
         }
-        // Base consumable cost is typically $4
-        (4.0 * self.config.price_multiplier).floor() as usize
+        (consumable.cost() as f32 * self.config.price_multiplier).floor() as usize
     }
 
     /// Get the price of a pack with multipliers applied
-    pub fn pack_price(&self, pack_type: &PackType) -> usize {
+    pub fn pack_price(&self, pack: &BoosterPack) -> usize {
         // Coupon tag makes initial items free
         if self.coupon_active {
             return 0;
         }
-        (pack_type.base_cost() as f32 * self.config.price_multiplier).floor() as usize
+        (pack.base_cost() as f32 * self.config.price_multiplier).floor() as usize
     }
 
     /// Get the price of a voucher with multipliers applied
@@ -234,8 +374,8 @@ impl Shop {
         self.consumables.get(i).cloned()
     }
 
-    /// Get pack type by index
-    pub fn pack_from_index(&self, i: usize) -> Option<PackType> {
+    /// Get pack by index
+    pub fn pack_from_index(&self, i: usize) -> Option<BoosterPack> {
         self.packs.get(i).copied()
     }
 
@@ -247,52 +387,59 @@ impl Shop {
             .position(|j| j == joker)
             .ok_or(GameError::NoJokerMatch)?;
         let out = self.jokers.remove(i);
+        if i < self.joker_editions.len() {
+            self.joker_editions.remove(i);
+        }
+        if i < self.joker_stickers.len() {
+            self.joker_stickers.remove(i);
+        }
         Ok(out)
     }
 
-    /// Buy a consumable from the shop
-    pub fn buy_consumable(&mut self, consumable: &Consumables) -> Result<Consumables, GameError> {
-        let i = self
-            .consumables
-            .iter()
-            .position(|c| c == consumable)
-            .ok_or(GameError::InvalidAction)?;
-        let out = self.consumables.remove(i);
-        Ok(out)
+    /// Buy the consumable in this slot from the shop
+    pub fn buy_consumable(&mut self, slot: usize) -> Result<Consumables, GameError> {
+        if slot >= self.consumables.len() {
+            return Err(GameError::InvalidAction(None));
+        }
+        Ok(self.consumables.remove(slot))
     }
 
     /// Buy a pack from the shop and open it
-    pub fn buy_pack(&mut self, pack_type: PackType) -> Result<Pack, GameError> {
+    pub fn buy_pack(&mut self, pack: impl Into<BoosterPack>) -> Result<Pack, GameError> {
+        let booster = pack.into();
         let i = self
             .packs
             .iter()
-            .position(|p| *p == pack_type)
-            .ok_or(GameError::InvalidAction)?;
+            .position(|p| *p == booster)
+            .ok_or(GameError::InvalidAction(None))?;
         self.packs.remove(i);
 
         // Generate the pack with random contents
-        let pack = Pack::new(pack_type);
+        let (availability, ante) = (&self.availability, self.ante);
+        let pack = Pack::new_where(booster, |item| availability.selection_available(item, ante));
         self.open_pack = Some(pack.clone());
         Ok(pack)
     }
 
     /// Buy the voucher from the shop
     pub fn buy_voucher(&mut self) -> Result<Vouchers, GameError> {
-        self.voucher.take().ok_or(GameError::InvalidAction)
+        self.voucher.take().ok_or(GameError::InvalidAction(None))
     }
 
     /// Generate buy actions for affordable jokers
-    pub fn gen_moves_buy_joker(&self, balance: usize) -> Option<impl Iterator<Item = Action>> {
+    pub fn gen_moves_buy_joker(
+        &self,
+        balance: usize,
+    ) -> Option<impl Iterator<Item = Action> + use<'_>> {
         if self.jokers.is_empty() {
             return None;
         }
         let price_mult = self.config.price_multiplier;
         let buys = self
             .jokers
-            .clone()
-            .into_iter()
-            .filter(move |j| (j.cost() as f32 * price_mult).floor() as usize <= balance)
-            .map(Action::BuyJoker);
+            .iter()
+            .filter(move |j| (self.joker_cost(j) as f32 * price_mult).floor() as usize <= balance)
+            .map(|j| Action::BuyJoker(j.clone()));
         Some(buys)
     }
 
@@ -300,17 +447,38 @@ impl Shop {
     pub fn gen_moves_buy_consumable(
         &self,
         balance: usize,
-    ) -> Option<impl Iterator<Item = Action>> {
+    ) -> Option<impl Iterator<Item = Action> + use<'_>> {
         if self.consumables.is_empty() {
             return None;
         }
-        let consumable_price = self.consumable_price(&self.consumables[0]);
         let buys = self
             .consumables
-            .clone()
-            .into_iter()
-            .filter(move |_| consumable_price <= balance)
-            .map(Action::BuyConsumable);
+            .iter()
+            .enumerate()
+            .filter(move |(_, c)| self.consumable_price(c) <= balance)
+            .map(|(slot, _)| Action::BuyConsumable(slot));
+        Some(buys)
+    }
+
+    /// Generate the buy action for the voucher if affordable
+    pub fn gen_moves_buy_voucher(&self, balance: usize) -> Option<impl Iterator<Item = Action>> {
+        let voucher = self.voucher.filter(|v| self.voucher_price(v) <= balance)?;
+        Some(std::iter::once(Action::BuyVoucher(voucher)))
+    }
+
+    /// Generate buy actions for affordable booster packs
+    pub fn gen_moves_buy_booster(
+        &self,
+        balance: usize,
+    ) -> Option<impl Iterator<Item = Action> + use<'_>> {
+        if self.packs.is_empty() {
+            return None;
+        }
+        let buys = self
+            .packs
+            .iter()
+            .filter(move |p| self.pack_price(p) <= balance)
+            .map(|p| Action::BuyBooster(*p));
         Some(buys)
     }
 }
@@ -321,76 +489,196 @@ impl Default for Shop {
     }
 }
 
+/// Relative weights used to roll the rarity of a generated joker.
+/// Legendary is never rolled; it only comes from The Soul spectral.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RarityWeights {
+    pub common: u32,
+    pub uncommon: u32,
+    pub rare: u32,
+}
+
+impl RarityWeights {
+    pub fn new(common: u32, uncommon: u32, rare: u32) -> Self {
+        RarityWeights {
+            common,
+            uncommon,
+            rare,
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.common + self.uncommon + self.rare
+    }
+}
+
+impl Default for RarityWeights {
+    fn default() -> Self {
+        // Balatro base odds: 70% common, 25% uncommon, 5% rare
+        Self::new(70, 25, 5)
+    }
+}
+
 /// Joker Generator - creates random jokers with rarity weighting
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone)]
 pub struct JokerGenerator {
-    rarity_weights: [u32; 3], // Common, Uncommon, Rare
+    base_weights: RarityWeights, // Configured weights before vouchers are applied
+    rarity_weights: RarityWeights,
     probability_multiplier: f32, // Multiplier for probabilities (e.g., 2.0 for Oops! All 6s)
+    edition_multiplier: f32,     // Foil/Holographic/Polychrome odds (2.0 Hone, 4.0 Glow Up)
 }
 
 impl JokerGenerator {
     pub fn new() -> Self {
+        Self::with_weights(RarityWeights::default())
+    }
+
+    pub fn with_weights(weights: RarityWeights) -> Self {
         JokerGenerator {
-            rarity_weights: [70, 25, 5], // Default: 70% common, 25% uncommon, 5% rare
+            base_weights: weights,
+            rarity_weights: weights,
             probability_multiplier: 1.0,
+            edition_multiplier: 1.0,
         }
     }
 
-    pub fn update_from_vouchers(&mut self, _vouchers: &[Vouchers]) {
-        // Future: some vouchers might affect joker rarity distribution
-        self.rarity_weights = [70, 25, 5];
+    /// Replace the configured base weights (e.g. for a stake or custom rule set)
+    pub fn set_base_weights(&mut self, weights: RarityWeights) {
+        self.base_weights = weights;
+        self.rarity_weights = weights;
+    }
+
+    pub fn update_from_vouchers(&mut self, vouchers: &[Vouchers]) {
+        // No vanilla voucher changes joker rarity; start from the configured base
+        self.rarity_weights = self.base_weights;
+
+        self.edition_multiplier = if vouchers.contains(&Vouchers::Glow) {
+            4.0
+        } else if vouchers.contains(&Vouchers::Hone) {
+            2.0
+        } else {
+            1.0
+        };
     }
 
     pub fn set_probability_multiplier(&mut self, multiplier: f32) {
         self.probability_multiplier = multiplier;
     }
 
+    /// Weights actually used for the next roll.
+    /// Uncommon and rare are scaled by probability_multiplier (e.g. Oops! All 6s doubles them)
+    /// and common absorbs the difference, so the total stays the same.
+    pub fn effective_weights(&self) -> RarityWeights {
+        let total = self.rarity_weights.total();
+        let scale = |w: u32| ((w as f32) * self.probability_multiplier).min(total as f32) as u32;
+        let rare = scale(self.rarity_weights.rare);
+        let uncommon = scale(self.rarity_weights.uncommon).min(total - rare);
+        let common = total - uncommon - rare;
+        RarityWeights::new(common, uncommon, rare)
+    }
+
     /// Generate rarity of new joker
     /// 70% chance Common, 25% chance Uncommon, 5% Rare (base weights)
     /// Modified by probability_multiplier (e.g., Oops! All 6s doubles probabilities)
     /// Legendary can only appear from Soul Spectral Card
-    fn gen_rarity(&self) -> Rarity {
-        // Apply probability multiplier to uncommon and rare weights
-        // Common weight adjusted to fill remaining probability
-        let uncommon_weight = ((self.rarity_weights[1] as f32) * self.probability_multiplier).min(100.0) as u32;
-        let rare_weight = ((self.rarity_weights[2] as f32) * self.probability_multiplier).min(100.0) as u32;
-        let common_weight = 100 - uncommon_weight - rare_weight;
+    fn gen_rarity<R: Rng + ?Sized>(&self, rng: &mut R) -> Rarity {
+        let weights = self.effective_weights();
+        let total = weights.total();
+        if total == 0 {
+            return Rarity::Common;
+        }
 
-        let weights = [common_weight, uncommon_weight, rare_weight];
-        let total: u32 = weights.iter().sum();
-        let roll = thread_rng().gen_range(0..total);
+        let roll = rng.gen_range(0..total);
+        if roll < weights.common {
+            Rarity::Common
+        } else if roll < weights.common + weights.uncommon {
+            Rarity::Uncommon
+        } else {
+            Rarity::Rare
+        }
+    }
 
-        let mut cumulative = 0;
-        for (i, &weight) in weights.iter().enumerate() {
-            cumulative += weight;
-            if roll < cumulative {
-                return match i {
-                    0 => Rarity::Common,
-                    1 => Rarity::Uncommon,
-                    2 => Rarity::Rare,
-                    _ => Rarity::Common,
-                };
+    /// Roll the edition of a shop joker.
+    /// Base odds per 1000: 3 Negative, 3 Polychrome, 14 Holographic, 20 Foil.
+    /// Hone and Glow Up scale all but Negative.
+    pub fn gen_edition(&self) -> Edition {
+        self.gen_edition_with_rng(&mut crate::rng::rng_for("shop", "joker edition"))
+    }
+
+    pub fn gen_edition_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> Edition {
+        let scale = |odds: f32| odds * self.edition_multiplier;
+        let roll = rng.gen_range(0.0..1000.0);
+        let mut threshold = 3.0;
+        if roll < threshold {
+            return Edition::Negative;
+        }
+        for (odds, edition) in [
+            (3.0, Edition::Polychrome),
+            (14.0, Edition::Holographic),
+            (20.0, Edition::Foil),
+        ] {
+            threshold += scale(odds);
+            if roll < threshold {
+                return edition;
             }
         }
-        Rarity::Common
+        Edition::Base
     }
 
     /// Generate a random joker
     pub fn gen_joker(&self) -> Jokers {
-        let rarity = self.gen_rarity();
-        self.gen_joker_with_rarity(rarity)
+        self.gen_joker_with_rng(&mut crate::rng::rng_for("shop", "joker"))
+    }
+
+    /// Generate a random joker from the given RNG (for seeded runs)
+    pub fn gen_joker_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> Jokers {
+        let rarity = self.gen_rarity(rng);
+        self.gen_joker_with_rarity_rng(rarity, rng)
+    }
+
+    /// Generate a random joker that is not the same kind as any in `exclude`.
+    /// If every joker of the rolled rarity is excluded, falls back to any unexcluded
+    /// common, then to allowing duplicates.
+    pub fn gen_joker_excluding(&self, exclude: &[Jokers]) -> Jokers {
+        self.gen_joker_excluding_with_rng(exclude, &mut crate::rng::rng_for("shop", "joker"))
+    }
+
+    pub fn gen_joker_excluding_with_rng<R: Rng + ?Sized>(
+        &self,
+        exclude: &[Jokers],
+        rng: &mut R,
+    ) -> Jokers {
+        let rarity = self.gen_rarity(rng);
+        let is_excluded =
+            |j: &Jokers| exclude.iter().any(|e| std::mem::discriminant(e) == std::mem::discriminant(j));
+
+        for pool_rarity in [rarity.clone(), Rarity::Common] {
+            let choices: Vec<Jokers> = Jokers::by_rarity(pool_rarity)
+                .into_iter()
+                .filter(|j| !is_excluded(j))
+                .collect();
+            if let Some(joker) = choices.choose(rng) {
+                return joker.clone();
+            }
+        }
+        self.gen_joker_with_rarity_rng(rarity, rng)
     }
 
     /// Generate a joker of a specific rarity
     pub fn gen_joker_with_rarity(&self, rarity: Rarity) -> Jokers {
+        self.gen_joker_with_rarity_rng(rarity, &mut crate::rng::rng_for("shop", "joker of rarity"))
+    }
+
+    fn gen_joker_with_rarity_rng<R: Rng + ?Sized>(&self, rarity: Rarity, rng: &mut R) -> Jokers {
         let choices = Jokers::by_rarity(rarity);
         if choices.is_empty() {
             // Fallback to common if no jokers of rarity exist
             let common = Jokers::by_rarity(Rarity::Common);
-            return common.choose(&mut thread_rng()).unwrap().clone();
+            return common.choose(rng).unwrap().clone();
         }
-        choices.choose(&mut thread_rng()).unwrap().clone()
+        choices.choose(rng).unwrap().clone()
     }
 }
 
@@ -448,13 +736,18 @@ impl ConsumableGenerator {
         ];
     }
 
+    /// Let Spectral cards appear even without a Spectral voucher
+    pub fn allow_spectrals(&mut self) {
+        self.type_weights[2] = self.type_weights[2].max(20);
+    }
+
     fn choose_type(&self) -> usize {
         let total: u32 = self.type_weights.iter().sum();
         if total == 0 {
             return 0; // Default to Tarot
         }
 
-        let roll = thread_rng().gen_range(0..total);
+        let roll = crate::rng::rng_for("shop", "consumable type").gen_range(0..total);
         let mut cumulative = 0;
         for (i, &weight) in self.type_weights.iter().enumerate() {
             cumulative += weight;
@@ -466,30 +759,34 @@ impl ConsumableGenerator {
     }
 
     pub fn gen_consumable(&self) -> Consumables {
-        let consumable_type = self.choose_type();
-        match consumable_type {
-            0 => {
-                // Tarot
-                let all_tarots = Tarots::all();
-                let tarot = all_tarots.choose(&mut thread_rng()).unwrap();
-                Consumables::Tarot(*tarot)
-            }
-            1 => {
-                // Planet
-                let all_planets = Planets::all();
-                let planet = all_planets.choose(&mut thread_rng()).unwrap();
-                Consumables::Planet(*planet)
-            }
-            2 => {
-                // Spectral
-                let all_spectrals = Spectrals::all();
-                let spectral = all_spectrals.choose(&mut thread_rng()).unwrap();
-                Consumables::Spectral(spectral.clone())
-            }
-            _ => {
-                // Fallback to Tarot
+        self.gen_consumable_where(|_| true)
+    }
+
+    /// Generate a consumable, only picking items that pass `allowed`.
+    /// Falls back to Tarots if the rolled type has nothing allowed.
+    pub fn gen_consumable_where<F>(&self, allowed: F) -> Consumables
+    where
+        F: Fn(&Consumables) -> bool,
+    {
+        let pool = |consumable_type: usize| -> Vec<Consumables> {
+            let all: Vec<Consumables> = match consumable_type {
+                1 => Planets::all().into_iter().map(Consumables::Planet).collect(),
+                2 => Spectrals::all().into_iter().map(Consumables::Spectral).collect(),
+                _ => Tarots::all().into_iter().map(Consumables::Tarot).collect(),
+            };
+            all.into_iter().filter(|c| allowed(c)).collect()
+        };
+
+        let mut choices = pool(self.choose_type());
+        if choices.is_empty() {
+            choices = pool(0);
+        }
+        match choices.choose(&mut crate::rng::rng_for("shop", "consumable")) {
+            Some(consumable) => consumable.clone(),
+            None => {
+                // Nothing allowed at all, ignore the rules rather than leave the slot empty
                 let all_tarots = Tarots::all();
-                let tarot = all_tarots.choose(&mut thread_rng()).unwrap();
+                let tarot = all_tarots.choose(&mut crate::rng::rng_for("shop", "tarot fallback")).unwrap();
                 Consumables::Tarot(*tarot)
             }
         }
@@ -506,14 +803,17 @@ impl Default for ConsumableGenerator {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone)]
 pub struct PackGenerator {
-    // Weights for Arcana, Celestial, Spectral, Buffoon
-    type_weights: [u32; 4],
+    // Weights for Arcana, Celestial, Spectral, Buffoon, Standard
+    type_weights: [u32; 5],
+    // Weights for Normal, Jumbo, Mega
+    size_weights: [u32; 3],
 }
 
 impl PackGenerator {
     pub fn new() -> Self {
         PackGenerator {
-            type_weights: [25, 25, 0, 50], // 25% Arcana, 25% Celestial, 0% Spectral, 50% Buffoon
+            type_weights: [25, 25, 0, 50, 25], // 20% Arcana, 20% Celestial, 0% Spectral, 40% Buffoon, 20% Standard
+            size_weights: [65, 25, 10],
         }
     }
 
@@ -540,6 +840,7 @@ impl PackGenerator {
             25,                              // Celestial
             spectral_weight,                 // Spectral
             (50.0 * buffoon_mult) as u32,    // Buffoon
+            25,                              // Standard
         ];
     }
 
@@ -549,7 +850,7 @@ impl PackGenerator {
             return 0; // Default to Arcana
         }
 
-        let roll = thread_rng().gen_range(0..total);
+        let roll = crate::rng::rng_for("shop", "pack type").gen_range(0..total);
         let mut cumulative = 0;
         for (i, &weight) in self.type_weights.iter().enumerate() {
             cumulative += weight;
@@ -561,12 +862,48 @@ impl PackGenerator {
     }
 
     pub fn gen_pack(&self) -> PackType {
-        let pack_type = self.choose_type();
-        match pack_type {
+        self.gen_pack_where(|_| true)
+    }
+
+    /// Generate a pack type, never rolling a type that fails `allowed`
+    pub fn gen_pack_where<F>(&self, allowed: F) -> PackType
+    where
+        F: Fn(PackType) -> bool,
+    {
+        let mut gated = self.clone();
+        for (i, weight) in gated.type_weights.iter_mut().enumerate() {
+            if !allowed(Self::pack_type(i)) {
+                *weight = 0;
+            }
+        }
+        Self::pack_type(gated.choose_type())
+    }
+
+    /// Generate a shop pack: a type that passes `allowed` and a random size
+    pub fn gen_booster_where<F>(&self, allowed: F) -> BoosterPack
+    where
+        F: Fn(PackType) -> bool,
+    {
+        let pack_type = self.gen_pack_where(allowed);
+        let total: u32 = self.size_weights.iter().sum();
+        let roll = crate::rng::rng_for("shop", "pack size").gen_range(0..total);
+        let size = if roll < self.size_weights[0] {
+            PackSize::Normal
+        } else if roll < self.size_weights[0] + self.size_weights[1] {
+            PackSize::Jumbo
+        } else {
+            PackSize::Mega
+        };
+        BoosterPack::new(pack_type, size)
+    }
+
+    fn pack_type(i: usize) -> PackType {
+        match i {
             0 => PackType::Arcana,
             1 => PackType::Celestial,
             2 => PackType::Spectral,
             3 => PackType::Buffoon,
+            4 => PackType::Standard,
             _ => PackType::Arcana,
         }
     }
@@ -581,6 +918,7 @@ impl Default for PackGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::consumable::Consumable;
     use crate::joker::{CraftyJoker, TheJoker};
 
     #[test]
@@ -629,7 +967,8 @@ mod tests {
         shop.refresh(&[Vouchers::ClearanceSale]);
         let joker = shop.jokers[0].clone();
         let price = shop.joker_price(&joker);
-        assert_eq!(price, (joker.cost() as f32 * 0.75).floor() as usize);
+        // The discount applies to the edition surcharge too
+        assert_eq!(price, (shop.joker_cost(&joker) as f32 * 0.75).floor() as usize);
     }
 
     #[test]
@@ -665,10 +1004,50 @@ mod tests {
     fn test_shop_buy_consumable() {
         let mut shop = Shop::new();
         shop.refresh(&[]);
-        let consumable = shop.consumables[0].clone();
-        let result = shop.buy_consumable(&consumable);
-        assert!(result.is_ok());
+        let consumable = shop.consumables[1].clone();
+        assert_eq!(shop.buy_consumable(1), Ok(consumable));
         assert_eq!(shop.consumables.len(), 1);
+        assert!(shop.buy_consumable(1).is_err());
+    }
+
+    #[test]
+    fn test_shop_consumables_spectral_only_when_allowed() {
+        let spectrals = |shop: &mut Shop, vouchers: &[Vouchers]| {
+            let mut count = 0;
+            for _ in 0..200 {
+                shop.refresh(vouchers);
+                count += shop
+                    .consumables
+                    .iter()
+                    .filter(|c| matches!(c, Consumables::Spectral(_)))
+                    .count();
+            }
+            return count;
+        };
+        let mut shop = Shop::new();
+        assert_eq!(spectrals(&mut shop, &[]), 0);
+        assert!(spectrals(&mut shop, &[Vouchers::Spectral]) > 0);
+
+        // Ghost Deck
+        let mut shop = Shop::new();
+        shop.spectrals_in_shop = true;
+        assert!(spectrals(&mut shop, &[]) > 0);
+    }
+
+    #[test]
+    fn test_shop_reroll_restocks_cards() {
+        let mut shop = Shop::new();
+        shop.refresh(&[]);
+        shop.buy_consumable(0).unwrap();
+        shop.buy_joker(&shop.jokers[0].clone()).unwrap();
+        let packs = shop.packs.clone();
+
+        shop.reroll(&[]);
+        assert_eq!(shop.jokers.len(), shop.config.joker_slots);
+        assert_eq!(shop.joker_editions.len(), shop.config.joker_slots);
+        assert_eq!(shop.consumables.len(), shop.config.consumable_slots);
+        // Packs are not rerolled
+        assert_eq!(shop.packs, packs);
     }
 
     #[test]
@@ -724,10 +1103,19 @@ mod tests {
         // Just verify it generates something
         assert!(matches!(
             pack,
-            PackType::Arcana | PackType::Celestial | PackType::Buffoon
+            PackType::Arcana | PackType::Celestial | PackType::Buffoon | PackType::Standard
         ));
     }
 
+    #[test]
+    fn test_pack_generator_sizes() {
+        let gen = PackGenerator::new();
+        let sizes: Vec<PackSize> = (0..200).map(|_| gen.gen_booster_where(|_| true).size).collect();
+        assert!(sizes.contains(&PackSize::Normal));
+        assert!(sizes.contains(&PackSize::Jumbo));
+        assert!(sizes.contains(&PackSize::Mega));
+    }
+
     #[test]
     fn test_pack_generator_with_spectral() {
         let mut gen = PackGenerator::new();
@@ -787,7 +1175,7 @@ mod tests {
     #[test]
     fn test_shop_pack_opening() {
         let mut shop = Shop::new();
-        shop.packs.push(PackType::Arcana);
+        shop.packs.push(PackType::Arcana.into());
 
         let result = shop.buy_pack(PackType::Arcana);
         assert!(result.is_ok());
@@ -804,7 +1192,7 @@ mod tests {
     #[test]
     fn test_shop_pack_selection() {
         let mut shop = Shop::new();
-        shop.packs.push(PackType::Buffoon);
+        shop.packs.push(PackType::Buffoon.into());
 
         let pack = shop.buy_pack(PackType::Buffoon).unwrap();
 
@@ -821,7 +1209,7 @@ mod tests {
     #[test]
     fn test_shop_pack_selection_out_of_bounds() {
         let mut shop = Shop::new();
-        shop.packs.push(PackType::Arcana);
+        shop.packs.push(PackType::Arcana.into());
 
         let pack = shop.buy_pack(PackType::Arcana).unwrap();
 
@@ -877,8 +1265,8 @@ mod tests {
             }
         }
 
-        // Should have many buffoon packs
-        assert!(buffoon_count > 60);
+        // Should have many buffoon packs (100 of 175 weight, ~57%)
+        assert!(buffoon_count > 45);
     }
 
     #[test]
@@ -898,7 +1286,7 @@ mod tests {
         let mut shop = Shop::new();
         shop.jokers.push(Jokers::TheJoker(TheJoker::default()));
         shop.consumables.push(Consumables::Tarot(Tarots::TheFool));
-        shop.packs.push(PackType::Arcana);
+        shop.packs.push(PackType::Arcana.into());
         shop.voucher = Some(Vouchers::Overstock);
         shop.rerolls_this_round = 5;
 
@@ -934,15 +1322,236 @@ mod tests {
         assert!(common > 600);
     }
 
+    fn count_rarities(gen: &JokerGenerator, samples: usize, seed: u64) -> [usize; 3] {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let mut counts = [0; 3];
+        for _ in 0..samples {
+            match gen.gen_joker_with_rng(&mut rng).rarity() {
+                Rarity::Common => counts[0] += 1,
+                Rarity::Uncommon => counts[1] += 1,
+                Rarity::Rare => counts[2] += 1,
+                Rarity::Legendary => panic!("Legendary jokers should not be rolled"),
+            }
+        }
+        counts
+    }
+
+    #[test]
+    fn test_joker_generator_seeded_70_25_5() {
+        let gen = JokerGenerator::new();
+        let [common, uncommon, rare] = count_rarities(&gen, 10_000, 42);
+
+        // 10k samples: each bucket should be within ~2 percentage points of its weight
+        assert!((6800..=7200).contains(&common), "common: {}", common);
+        assert!((2300..=2700).contains(&uncommon), "uncommon: {}", uncommon);
+        assert!((300..=700).contains(&rare), "rare: {}", rare);
+    }
+
+    #[test]
+    fn test_joker_generator_seeded_is_deterministic() {
+        let gen = JokerGenerator::new();
+        assert_eq!(count_rarities(&gen, 500, 7), count_rarities(&gen, 500, 7));
+    }
+
+    #[test]
+    fn test_joker_generator_custom_weights() {
+        let mut gen = JokerGenerator::with_weights(RarityWeights::new(0, 0, 1));
+        let [common, uncommon, rare] = count_rarities(&gen, 200, 1);
+        assert_eq!((common, uncommon, rare), (0, 0, 200));
+
+        // Vouchers restore the configured base, not the Balatro default
+        gen.update_from_vouchers(&[Vouchers::Overstock]);
+        assert_eq!(gen.effective_weights(), RarityWeights::new(0, 0, 1));
+    }
+
+    #[test]
+    fn test_joker_generator_multiplier_doubles_odds() {
+        let mut gen = JokerGenerator::new();
+        gen.set_probability_multiplier(2.0);
+        assert_eq!(gen.effective_weights(), RarityWeights::new(40, 50, 10));
+
+        let [common, uncommon, rare] = count_rarities(&gen, 10_000, 42);
+        assert!((3800..=4200).contains(&common), "common: {}", common);
+        assert!((4800..=5200).contains(&uncommon), "uncommon: {}", uncommon);
+        assert!((800..=1200).contains(&rare), "rare: {}", rare);
+    }
+
+    #[test]
+    fn test_shop_never_offers_duplicates() {
+        let mut shop = Shop::new();
+        shop.config.joker_slots = 5;
+        for _ in 0..200 {
+            shop.refresh(&[]);
+            for (i, a) in shop.jokers.iter().enumerate() {
+                for b in &shop.jokers[i + 1..] {
+                    assert_ne!(a.name(), b.name(), "Shop offered {} twice", a.name());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_shop_skips_owned_jokers() {
+        // Own every rare joker so a rare roll must fall back
+        let mut owned = Jokers::by_rarity(Rarity::Rare);
+        owned.push(Jokers::TheJoker(TheJoker::default()));
+        let mut shop = Shop::new();
+        shop.joker_gen = JokerGenerator::with_weights(RarityWeights::new(1, 0, 1));
+        for _ in 0..200 {
+            shop.restock_with_jokers(&owned, &[]);
+            for joker in &shop.jokers {
+                assert!(
+                    !owned.iter().any(|o| o.name() == joker.name()),
+                    "Shop offered owned joker {}",
+                    joker.name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_shop_showman_allows_duplicates() {
+        use crate::joker::Showman;
+
+        let owned = vec![
+            Jokers::Showman(Showman::default()),
+            Jokers::TheJoker(TheJoker::default()),
+        ];
+        // Commons only, so five slots over 200 restocks will hit a repeat
+        let mut shop = Shop::new();
+        shop.config.joker_slots = 5;
+        shop.joker_gen = JokerGenerator::with_weights(RarityWeights::new(1, 0, 0));
+
+        let mut saw_duplicate = false;
+        for _ in 0..200 {
+            shop.restock_with_jokers(&owned, &[]);
+            let names: Vec<String> = shop.jokers.iter().map(|j| j.name()).collect();
+            if names.contains(&"Joker".to_string())
+                || names.iter().enumerate().any(|(i, n)| names[i + 1..].contains(n))
+            {
+                saw_duplicate = true;
+                break;
+            }
+        }
+        assert!(saw_duplicate, "Showman should allow owned and repeated jokers");
+    }
+
+    #[test]
+    fn test_shop_respects_ante_gates() {
+        use crate::availability::ItemKind;
+
+        let mut shop = Shop::new();
+        shop.availability = Availability::none();
+        shop.availability.gate(ItemKind::Joker, "Joker", 3);
+        shop.availability.gate(ItemKind::Pack, "Buffoon Pack", 3);
+        shop.availability.gate(ItemKind::Consumable, "The Fool", 3);
+        shop.config.joker_slots = 5;
+        shop.joker_gen = JokerGenerator::with_weights(RarityWeights::new(1, 0, 0));
+
+        shop.ante = 1;
+        for _ in 0..200 {
+            shop.refresh(&[]);
+            assert!(shop.jokers.iter().all(|j| j.name() != "Joker"));
+            assert!(shop.packs.iter().all(|p| p.pack_type != PackType::Buffoon));
+            assert!(shop.consumables.iter().all(|c| *c != Consumables::Tarot(Tarots::TheFool)));
+        }
+
+        shop.ante = 3;
+        let mut seen_buffoon = false;
+        for _ in 0..200 {
+            shop.refresh(&[]);
+            seen_buffoon |= shop.packs.iter().any(|p| p.pack_type == PackType::Buffoon);
+        }
+        assert!(seen_buffoon, "Buffoon Packs should appear once their ante is reached");
+    }
+
+    #[test]
+    fn test_shop_pack_contents_respect_ante_gates() {
+        use crate::availability::ItemKind;
+        use crate::booster::PackContents;
+
+        let mut shop = Shop::new();
+        shop.availability = Availability::none();
+        for tarot in Tarots::all().into_iter().skip(3) {
+            shop.availability.gate(ItemKind::Consumable, Consumables::Tarot(tarot).name(), 2);
+        }
+        let allowed: Vec<Tarots> = Tarots::all().into_iter().take(3).collect();
+
+        for _ in 0..50 {
+            shop.packs = vec![PackType::Arcana.into()];
+            let pack = shop.buy_pack(PackType::Arcana).unwrap();
+            match pack.contents {
+                PackContents::Tarots(tarots) => {
+                    assert!(tarots.iter().all(|t| allowed.contains(t)));
+                }
+                _ => panic!("Arcana pack should contain tarots"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_shop_voucher_respects_ante_gates() {
+        let mut shop = Shop::new();
+        let owned = Vouchers::tier_1();
+
+        // Only tier 2 vouchers remain and they are gated to ante 2 by default
+        shop.ante = 1;
+        assert_eq!(shop.random_voucher(&owned), None);
+        shop.ante = 2;
+        assert!(shop.random_voucher(&owned).is_some());
+    }
+
+    #[test]
+    fn test_game_config_rarity_weights_reach_shop() {
+        use crate::config::Config;
+        use crate::game::Game;
+
+        let mut config = Config::default();
+        config.joker_rarity_weights = RarityWeights::new(0, 1, 0);
+        let mut g = Game::new(config).unwrap();
+        g.shop.refresh(&[]);
+        assert!(g.shop.jokers.iter().all(|j| j.rarity() == Rarity::Uncommon));
+    }
+
+    #[test]
+    fn test_joker_generator_editions() {
+        let mut gen = JokerGenerator::new();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+        let count = |gen: &JokerGenerator, rng: &mut StdRng| {
+            (0..10000)
+                .filter(|_| gen.gen_edition_with_rng(rng) != Edition::Base)
+                .count()
+        };
+        // 4% base odds, Glow Up makes Foil/Holographic/Polychrome 4x as common
+        let base = count(&gen, &mut rng);
+        assert!((250..550).contains(&base), "base editions {}", base);
+        gen.update_from_vouchers(&[Vouchers::Hone, Vouchers::Glow]);
+        let glow = count(&gen, &mut rng);
+        assert!(glow > base * 3, "glow up editions {}", glow);
+    }
+
+    #[test]
+    fn test_shop_joker_edition_price() {
+        let mut shop = Shop::new();
+        let joker = Jokers::TheJoker(TheJoker::default());
+        let idx = shop.push_joker(joker.clone(), Edition::Polychrome);
+        assert_eq!(idx, 0);
+        assert_eq!(shop.joker_edition(&joker), Edition::Polychrome);
+        assert_eq!(shop.joker_price(&joker), joker.cost() + 5);
+
+        shop.buy_joker(&joker).unwrap();
+        assert!(shop.joker_editions.is_empty());
+    }
+
     #[test]
     fn test_shop_price_calculation() {
         let mut shop = Shop::new();
         shop.update_config(&[Vouchers::ClearanceSale]);
         shop.refresh(&[]);
 
-        // Get a joker and check price
+        // Get a joker and check price, edition surcharge included
         if let Some(joker) = shop.jokers.first() {
-            let base_cost = joker.cost();
+            let base_cost = shop.joker_cost(joker);
             let shop_price = shop.joker_price(joker);
             assert_eq!(shop_price, (base_cost as f32 * 0.75).floor() as usize);
         }
@@ -950,7 +1559,7 @@ mod tests {
         // Check consumable price
         if let Some(consumable) = shop.consumables.first() {
             let shop_price = shop.consumable_price(consumable);
-            assert_eq!(shop_price, (4_f32 * 0.75).floor() as usize);
+            assert_eq!(shop_price, (consumable.cost() as f32 * 0.75).floor() as usize);
         }
     }
 
@@ -1013,7 +1622,15 @@ mod tests {
         assert!(moves.is_some());
 
         let move_vec: Vec<_> = moves.unwrap().collect();
-        assert!(move_vec.len() > 0);
+        assert_eq!(move_vec, vec![Action::BuyConsumable(0), Action::BuyConsumable(1)]);
+
+        // Only the slots the balance covers
+        shop.consumables = vec![
+            Consumables::Tarot(Tarots::TheFool),
+            Consumables::Spectral(Spectrals::Familiar),
+        ];
+        let moves: Vec<_> = shop.gen_moves_buy_consumable(3).unwrap().collect();
+        assert_eq!(moves, vec![Action::BuyConsumable(0)]);
     }
 
     #[test]
@@ -0,0 +1,311 @@
+use crate::action::Action;
+use crate::config::Config;
+use crate::error::SimulationError;
+use crate::event::Event;
+use crate::game::Game;
+use crate::rng::GameRng;
+use crate::summary::RunSummary;
+use rand::seq::IteratorRandom;
+use rand::RngCore;
+use rayon::prelude::*;
+use rayon::ThreadPool;
+
+// Games still running after this many actions are stopped, so a policy
+// that never makes progress (e.g. only moving cards) cannot hang a batch
+pub const MAX_ACTIONS_PER_GAME: usize = 10_000;
+
+// Mixed into the game seed for the policy's own generator
+const POLICY_SEED_SALT: u64 = 0xD1B5_4A32_D192_ED03;
+
+/// Chooses the actions of batch games. One policy plays many games at once
+/// on different threads, so it must be `Sync`; per-game randomness comes
+/// from `rng`, which is seeded from the game's seed.
+pub trait Policy: Sync {
+    /// Next action for `game`, `None` stops playing it
+    fn choose(&self, game: &Game, rng: &mut GameRng) -> Option<Action>;
+}
+
+impl<F> Policy for F
+where
+    F: Fn(&Game, &mut GameRng) -> Option<Action> + Sync,
+{
+    fn choose(&self, game: &Game, rng: &mut GameRng) -> Option<Action> {
+        return self(game, rng);
+    }
+}
+
+/// Plays a uniformly random legal action
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomPolicy;
+
+impl Policy for RandomPolicy {
+    fn choose(&self, game: &Game, rng: &mut GameRng) -> Option<Action> {
+        return game.gen_actions().choose(rng);
+    }
+}
+
+/// Plays the cards with the highest expected score right away
+/// (`Game::best_play`) and never
+/// discards. Outside of blinds it takes the first blind, cashes out, buys
+/// the first joker it can afford and takes the first item of a pack.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GreedyPolicy;
+
+impl GreedyPolicy {
+    fn choose_in_blind(game: &Game, actions: &[Action]) -> Option<Action> {
+        let Some((played, _, _)) = game.best_play() else {
+            return actions.first().cloned();
+        };
+        let best = played.cards();
+        let selected = game.available.selected();
+        if selected.iter().any(|card| !best.contains(card)) {
+            return Some(Action::DeselectAll());
+        }
+        return match best.iter().find(|card| !selected.contains(card)) {
+            Some(card) => Some(Action::SelectCard(*card)),
+            None => Some(Action::Play()),
+        };
+    }
+}
+
+impl Policy for GreedyPolicy {
+    fn choose(&self, game: &Game, _rng: &mut GameRng) -> Option<Action> {
+        let actions: Vec<Action> = game.gen_actions().collect();
+        if game.stage.is_blind() {
+            return Self::choose_in_blind(game, &actions);
+        }
+        let preferred = actions.iter().find(|action| {
+            matches!(
+                action,
+                Action::SelectBlind(_)
+                    | Action::CashOut(_)
+                    | Action::BuyJoker(_)
+                    | Action::SelectFromPack(_)
+            )
+        });
+        let leave = actions
+            .iter()
+            .find(|action| matches!(action, Action::NextRound() | Action::SkipPack()));
+        return preferred.or(leave).or(actions.first()).cloned();
+    }
+}
+
+/// Outcome of one batch game
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameResult {
+    // Index of the policy that played the game
+    pub policy: usize,
+    // Win/loss (`summary.won`, `summary.result`), final ante, money, ...
+    pub summary: RunSummary,
+    // Score of every hand played, in order
+    pub score_history: Vec<usize>,
+    // The game did not end: the policy stopped, chose an illegal action or
+    // hit `MAX_ACTIONS_PER_GAME`
+    pub truncated: bool,
+}
+
+/// Play `n_games` games with every policy in parallel with the default
+/// config, see `run_batch_with_config`
+pub fn run_batch(
+    policies: &[&dyn Policy],
+    n_games: usize,
+    seeds: &[u64],
+    threads: usize,
+) -> Result<Vec<GameResult>, SimulationError> {
+    return run_batch_with_config(&Config::default(), policies, n_games, seeds, threads);
+}
+
+/// Play `n_games` games with every policy in parallel. Game `i` of each
+/// policy uses `seeds[i % seeds.len()]` (entropy when `seeds` is empty), so
+/// policies are compared on the same deals. Results are ordered by policy,
+/// then game. `threads` of 0 uses one thread per core.
+pub fn run_batch_with_config(
+    config: &Config,
+    policies: &[&dyn Policy],
+    n_games: usize,
+    seeds: &[u64],
+    threads: usize,
+) -> Result<Vec<GameResult>, SimulationError> {
+    if policies.is_empty() {
+        return Err(SimulationError::NoPolicies);
+    }
+    let (pool, seeds) = batch_setup(config, n_games, seeds, threads)?;
+    let results = pool.install(|| {
+        (0..policies.len() * n_games)
+            .into_par_iter()
+            .map(|i| {
+                let policy = i / n_games;
+                let seed = seeds[(i % n_games) % seeds.len()];
+                play_game(config, policies[policy], policy, seed)
+            })
+            .collect()
+    });
+    return Ok(results);
+}
+
+/// Play `n_games` games with `policy` in parallel, handing every result to
+/// `on_result` together with the game's index as soon as the game ends, in
+/// no particular order. Seeds and threads as in `run_batch_with_config`.
+pub fn run_batch_streaming<F>(
+    config: &Config,
+    policy: &dyn Policy,
+    n_games: usize,
+    seeds: &[u64],
+    threads: usize,
+    on_result: F,
+) -> Result<(), SimulationError>
+where
+    F: Fn(usize, GameResult) + Sync,
+{
+    let (pool, seeds) = batch_setup(config, n_games, seeds, threads)?;
+    pool.install(|| {
+        (0..n_games).into_par_iter().for_each(|i| {
+            on_result(i, play_game(config, policy, 0, seeds[i % seeds.len()]));
+        })
+    });
+    return Ok(());
+}
+
+// Validated config, worker pool and the seed of every game
+fn batch_setup(
+    config: &Config,
+    n_games: usize,
+    seeds: &[u64],
+    threads: usize,
+) -> Result<(ThreadPool, Vec<u64>), SimulationError> {
+    config.validate()?;
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .map_err(|e| SimulationError::ThreadPool(e.to_string()))?;
+    let seeds: Vec<u64> = match seeds.is_empty() {
+        true => (0..n_games).map(|_| GameRng::from_entropy().next_u64()).collect(),
+        false => seeds.to_vec(),
+    };
+    return Ok((pool, seeds));
+}
+
+fn play_game(config: &Config, policy: &dyn Policy, index: usize, seed: u64) -> GameResult {
+    let mut game = Game::with_seed(config.clone(), seed).expect("config validated by the batch");
+    // Kept apart from the game's generator so the policy's draws do not
+    // change the deals
+    let mut rng = GameRng::new(seed ^ POLICY_SEED_SALT);
+    let mut score_history = Vec::new();
+    game.start();
+    for _ in 0..MAX_ACTIONS_PER_GAME {
+        if game.is_over() {
+            break;
+        }
+        let Some(action) = policy.choose(&game, &mut rng) else {
+            break;
+        };
+        if game.handle_action(action).is_err() {
+            break;
+        }
+        for event in game.drain_events() {
+            if let Event::HandPlayed { score, .. } = event {
+                score_history.push(score);
+            }
+        }
+    }
+    return GameResult {
+        policy: index,
+        summary: game.run_summary(),
+        score_history,
+        truncated: !game.is_over(),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stage::Stage;
+
+    // Play the first five cards of every hand, otherwise the first legal
+    // action, until the first blind is over
+    fn play_first_cards(game: &Game, _rng: &mut GameRng) -> Option<Action> {
+        if game.stage.is_blind() && game.available.selected_count() < 5 {
+            let card = game.available.not_selected().first().copied()?;
+            return Some(Action::SelectCard(card));
+        }
+        if game.stage.is_blind() {
+            return Some(Action::Play());
+        }
+        if game.stage == Stage::PreBlind() {
+            return game.gen_actions().next();
+        }
+        return None;
+    }
+
+    #[test]
+    fn test_run_batch() {
+        let seeds = [1, 2, 3];
+        let policies: [&dyn Policy; 2] = [&play_first_cards, &RandomPolicy];
+        let results = run_batch(&policies, 3, &seeds, 2).unwrap();
+        assert_eq!(results.len(), 6);
+        assert_eq!(
+            results.iter().map(|r| (r.policy, r.summary.seed)).collect::<Vec<_>>(),
+            vec![(0, 1), (0, 2), (0, 3), (1, 1), (1, 2), (1, 3)]
+        );
+
+        // Every hand of the first blind was played
+        for result in &results[..3] {
+            assert!(!result.score_history.is_empty());
+            assert_eq!(result.score_history.len(), result.summary.hands_played);
+        }
+
+        // Same seeds give the same games whatever the thread count
+        let again = run_batch(&policies, 3, &seeds, 1).unwrap();
+        assert_eq!(results, again);
+
+        assert_eq!(run_batch(&[], 3, &seeds, 1), Err(SimulationError::NoPolicies));
+    }
+
+    #[test]
+    fn test_run_batch_streaming() {
+        let seeds = [4, 5, 6, 7];
+        let streamed = std::sync::Mutex::new(Vec::new());
+        run_batch_streaming(&Config::default(), &RandomPolicy, 4, &seeds, 2, |i, result| {
+            streamed.lock().unwrap().push((i, result));
+        })
+        .unwrap();
+        let mut streamed = streamed.into_inner().unwrap();
+        streamed.sort_by_key(|(i, _)| *i);
+
+        // Same games as a batch, tagged with their index
+        let batch = run_batch(&[&RandomPolicy], 4, &seeds, 1).unwrap();
+        assert_eq!(streamed.into_iter().map(|(_, r)| r).collect::<Vec<_>>(), batch);
+    }
+
+    #[test]
+    fn test_greedy_policy() {
+        let mut g = Game::with_seed(Config::default(), 7).unwrap();
+        g.start();
+        let mut rng = GameRng::new(0);
+        let action = GreedyPolicy.choose(&g, &mut rng).unwrap();
+        assert!(matches!(action, Action::SelectBlind(_)));
+        g.handle_action(action).unwrap();
+
+        // Selects cards one at a time, then plays them
+        let mut selections = 0;
+        loop {
+            let action = GreedyPolicy.choose(&g, &mut rng).unwrap();
+            if action == Action::Play() {
+                break;
+            }
+            assert!(matches!(action, Action::SelectCard(_)));
+            g.handle_action(action).unwrap();
+            selections += 1;
+        }
+        assert_eq!(selections, g.available.selected_count());
+
+        // The best play of the available cards
+        let (played, _, _) = g.best_play().unwrap();
+        assert_eq!(g.available.selected(), played.cards());
+
+        // Several greedy games are played to the end
+        let results = run_batch(&[&GreedyPolicy], 3, &[1, 2, 3], 1).unwrap();
+        assert!(results.iter().all(|r| !r.truncated && r.summary.hands_played > 0));
+    }
+}
@@ -0,0 +1,249 @@
+use crate::error::GameError;
+use crate::game::Game;
+use crate::stage::Stage;
+use std::collections::VecDeque;
+
+/// Most step-back checkpoints a game keeps, the oldest is dropped past this
+pub const MAX_CHECKPOINTS: usize = 64;
+
+/// Point-in-time copy of a game that can be restored later. It can be taken
+/// at any decision point, including inside a blind: the current selection,
+/// remaining plays/discards, partial score, round state and the RNG state
+/// are all captured, so branches taken from it replay deterministically.
+/// The action history, card ledger and availability rules are shared with
+/// the game rather than copied, so taking and restoring snapshots stays
+/// cheap for tree search however long the run has been.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    game: Game,
+}
+
+impl Snapshot {
+    /// Stage the game was in when the snapshot was taken
+    pub fn stage(&self) -> Stage {
+        return self.game.stage;
+    }
+
+    /// Number of actions that had been handled when the snapshot was taken
+    pub fn actions(&self) -> usize {
+        return self.game.action_history.len();
+    }
+
+    pub fn game(&self) -> &Game {
+        return &self.game;
+    }
+}
+
+// Snapshots taken every `interval` actions so `step_back` only has to
+// replay a bounded number of actions. Only the latest MAX_CHECKPOINTS are
+// kept. Saved games hold no checkpoints of their own.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Checkpoints {
+    interval: usize,
+    saved: VecDeque<Snapshot>,
+}
+
+impl Checkpoints {
+    fn is_enabled(&self) -> bool {
+        return self.interval > 0;
+    }
+}
+
+impl Game {
+    /// Start recording checkpoints every `interval` actions (at least 1) so
+    /// `step_back` can be used. The current state is the earliest point
+    /// that can be stepped back to, until `MAX_CHECKPOINTS` checkpoints
+    /// have been recorded and the oldest start being dropped.
+    pub fn enable_step_back(&mut self, interval: usize) {
+        self.checkpoints = Checkpoints {
+            interval: interval.max(1),
+            saved: VecDeque::new(),
+        };
+        self.save_checkpoint();
+    }
+
+    pub fn disable_step_back(&mut self) {
+        self.checkpoints = Checkpoints::default();
+    }
+
+    /// Reconstruct the state `n` actions ago by restoring the nearest earlier
+    /// checkpoint and replaying the recorded actions from there.
+    pub fn step_back(&mut self, n: usize) -> Result<(), GameError> {
+        let earliest = match self.checkpoints.saved.front() {
+            Some(checkpoint) => checkpoint.actions(),
+            None => return Err(GameError::InvalidStepBack),
+        };
+        let current = self.action_history.len();
+        if n > current - earliest {
+            return Err(GameError::InvalidStepBack);
+        }
+        let target = current - n;
+        let replay: Vec<_> = self.action_history.iter().take(target).cloned().collect();
+
+        let mut checkpoints = std::mem::take(&mut self.checkpoints);
+        checkpoints.saved.retain(|c| c.actions() <= target);
+        let start = checkpoints.saved.back().expect("earliest checkpoint kept");
+        self.restore(start);
+        self.checkpoints = checkpoints;
+
+        // Replay reproduces the original outcomes, including failed actions
+        for action in replay[self.action_history.len()..].iter().cloned() {
+            let _ = self.handle_action(action);
+        }
+        return Ok(());
+    }
+
+    pub(crate) fn record_checkpoint(&mut self) {
+        if !self.checkpoints.is_enabled() {
+            return;
+        }
+        let last = self.checkpoints.saved.back().map_or(0, |c| c.actions());
+        if self.action_history.len() >= last + self.checkpoints.interval {
+            self.save_checkpoint();
+        }
+    }
+
+    fn save_checkpoint(&mut self) {
+        let checkpoints = std::mem::take(&mut self.checkpoints);
+        let snapshot = self.snapshot();
+        self.checkpoints = checkpoints;
+        self.checkpoints.saved.push_back(snapshot);
+        if self.checkpoints.saved.len() > MAX_CHECKPOINTS {
+            self.checkpoints.saved.pop_front();
+        }
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        return Snapshot { game: self.clone() };
+    }
+
+    /// Roll this game back to the snapshot, discarding everything since.
+    /// Step-back checkpoints recorded by this game are kept.
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        let checkpoints = std::mem::take(&mut self.checkpoints);
+        *self = snapshot.game.clone();
+        self.checkpoints = checkpoints;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::Action;
+    use crate::card::Card;
+    use crate::config::Config;
+    use crate::stage::Blind;
+
+    #[test]
+    fn test_restore_mid_blind() {
+        let mut g = Game::with_seed(Config::default(), 11).unwrap();
+        g.start();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        for i in 0..2 {
+            let card = g.available.card_from_index(i).unwrap();
+            g.handle_action(Action::SelectCard(card)).unwrap();
+        }
+
+        let snap = g.snapshot();
+        assert!(snap.stage().is_blind());
+        assert_eq!(snap.actions(), 3);
+
+        g.handle_action(Action::Play()).unwrap();
+        let played_score = g.score;
+        let drawn: Vec<Card> = g.available.cards();
+        assert_ne!(g.plays, snap.game().plays);
+
+        g.restore(&snap);
+        assert_eq!(g.plays, snap.game().plays);
+        assert_eq!(g.discards, snap.game().discards);
+        assert_eq!(g.score, snap.game().score);
+        assert_eq!(g.available.selected(), snap.game().available.selected());
+        assert_eq!(g.action_history.len(), 3);
+
+        // Branching again from the snapshot replays the same outcome
+        g.handle_action(Action::Play()).unwrap();
+        assert_eq!(g.score, played_score);
+        let redrawn: Vec<Card> = g.available.cards();
+        let faces = |cards: &[Card]| cards.iter().map(|c| (c.value, c.suit)).collect::<Vec<_>>();
+        assert_eq!(faces(&redrawn), faces(&drawn));
+    }
+
+    #[test]
+    fn test_restore_long_history() {
+        let mut g = Game::with_seed(Config::default(), 11).unwrap();
+        g.start();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        for _ in 0..100 {
+            g.handle_action(Action::SelectCardIndex(0)).unwrap();
+            g.handle_action(Action::DeselectAll()).unwrap();
+        }
+        let snap = g.snapshot();
+        let history = g.action_history.to_vec();
+
+        g.handle_action(Action::SelectCardIndex(0)).unwrap();
+        g.handle_action(Action::Play()).unwrap();
+        assert_eq!(g.action_history.len(), history.len() + 2);
+        assert_eq!(snap.game().action_history.to_vec(), history);
+
+        g.restore(&snap);
+        assert_eq!(g.action_history.to_vec(), history);
+        assert_eq!(g.action_history.last(), Some(&Action::DeselectAll()));
+        assert_eq!(g.card_ledger(), snap.game().card_ledger());
+    }
+
+    #[test]
+    fn test_step_back() {
+        let mut g = Game::with_seed(Config::default(), 21).unwrap();
+        g.start();
+        g.enable_step_back(2);
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+
+        let mut states = Vec::new();
+        for _ in 0..3 {
+            states.push((g.action_history.len(), g.score, g.plays, g.available.selected().len()));
+            let card = g.available.card_from_index(0).unwrap();
+            g.handle_action(Action::SelectCard(card)).unwrap();
+            g.handle_action(Action::Play()).unwrap();
+        }
+
+        for (i, n) in [(2, 2), (0, 4)] {
+            g.step_back(n).unwrap();
+            let (actions, score, plays, selected) = states[i];
+            assert_eq!(g.action_history.len(), actions);
+            assert_eq!((g.score, g.plays, g.available.selected().len()), (score, plays, selected));
+        }
+
+        // Cannot go back further than where checkpoints were enabled
+        assert_eq!(g.step_back(2), Err(GameError::InvalidStepBack));
+        g.step_back(1).unwrap();
+        assert_eq!(g.action_history.len(), 0);
+        assert_eq!(g.stage, Stage::PreBlind());
+    }
+
+    #[test]
+    fn test_step_back_keeps_latest_checkpoints() {
+        let mut g = Game::with_seed(Config::default(), 21).unwrap();
+        g.start();
+        g.enable_step_back(1);
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        for _ in 0..MAX_CHECKPOINTS {
+            g.handle_action(Action::SelectCardIndex(0)).unwrap();
+            g.handle_action(Action::DeselectAll()).unwrap();
+        }
+        assert_eq!(g.checkpoints.saved.len(), MAX_CHECKPOINTS);
+
+        // The oldest checkpoints were dropped, back to the earliest kept one
+        let earliest = g.action_history.len() - (MAX_CHECKPOINTS - 1);
+        assert_eq!(g.step_back(MAX_CHECKPOINTS), Err(GameError::InvalidStepBack));
+        g.step_back(MAX_CHECKPOINTS - 1).unwrap();
+        assert_eq!(g.action_history.len(), earliest);
+    }
+
+    #[test]
+    fn test_step_back_disabled() {
+        let mut g = Game::default();
+        g.start();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        assert_eq!(g.step_back(1), Err(GameError::InvalidStepBack));
+    }
+}
@@ -1,15 +1,18 @@
 use crate::action::{Action, MoveDirection};
 use crate::config::Config;
+use crate::consumable::Consumable;
 use crate::error::ActionSpaceError;
 use crate::game::Game;
 use crate::stage::Blind;
-use pyo3::pyclass;
+#[cfg(feature = "python")]
+use pyo3::{pyclass, pymethods};
 
 // Hard code a bounded action space.
 // Given constraints:
 // available max = 24
 // store consumable slots max = 4
 // consumable slots max = 4
+// joker slots max = 10
 //
 // 0-23: select card
 // 24-46: move card (left)
@@ -22,9 +25,57 @@ use pyo3::pyclass;
 // 81-84: use consumable
 // 85: next round
 // 86: select blind
+// 87-96: sell joker
+// 97-105: move joker (left)
+// 106-114: move joker (right)
+// 115-118: sell consumable
+// 119: reroll shop
 //
-// We end up with a vector of length 87 (so far) where each index
-// represents a potential action.
+// We end up with a vector of length 120 (so far) where each index
+// represents a potential action. The offsets above are for the default
+// config, `layout` gives them for any config. `to_action` maps an index to
+// its action and `action_to_index` maps it back.
+//
+// Within a segment the index is a position:
+// - select card: card in available
+// - move card left: card in available, from the second card
+// - move card right: card in available, up to the second to last card
+// - buy joker, buy consumable: shop slot
+// - use consumable, sell consumable: consumable slot, targeted consumables
+//   act on the selected cards
+// - sell joker: joker slot
+// - move joker left: joker slot, from the second joker
+// - move joker right: joker slot, up to the second to last joker
+// - the rest are single actions; select blind selects the next blind
+//
+// Actions not listed (skipping blinds, booster packs, vouchers, sorting...)
+// are only reachable through `Action`, not through an index.
+
+// Version of the layout, bumped whenever a segment is added, removed,
+// reordered or changes meaning so agents can tell a policy trained on an
+// older layout apart.
+pub const ACTION_SPACE_VERSION: u32 = 1;
+
+// Names of the segments in index order, as used by `layout`
+pub const SEGMENT_NAMES: [&str; 16] = [
+    "select_card",
+    "move_card_left",
+    "move_card_right",
+    "play",
+    "discard",
+    "cash_out",
+    "buy_joker",
+    "buy_consumable",
+    "use_consumable",
+    "next_round",
+    "select_blind",
+    "sell_joker",
+    "move_joker_left",
+    "move_joker_right",
+    "sell_consumable",
+    "reroll",
+];
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "python", pyclass(eq))]
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
@@ -40,6 +91,110 @@ pub struct ActionSpace {
     pub use_consumable: Vec<usize>,
     pub next_round: Vec<usize>,
     pub select_blind: Vec<usize>,
+    pub sell_joker: Vec<usize>,
+    pub move_joker_left: Vec<usize>,
+    pub move_joker_right: Vec<usize>,
+    pub sell_consumable: Vec<usize>,
+    pub reroll: Vec<usize>,
+}
+
+/// Where one kind of action sits in the action space
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpaceSegment {
+    // One of `SEGMENT_NAMES`
+    pub name: String,
+    // Index of the first action of the segment
+    pub offset: usize,
+    pub len: usize,
+}
+
+/// Versioned description of the action space indices, see `layout`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpaceLayout {
+    // `ACTION_SPACE_VERSION` the layout was built with
+    pub version: u32,
+    // Length of the action space vector
+    pub size: usize,
+    // Segments in index order, back to back
+    pub segments: Vec<SpaceSegment>,
+}
+
+impl SpaceLayout {
+    /// Segment by name, e.g. "play"
+    pub fn segment(&self, name: &str) -> Option<&SpaceSegment> {
+        return self.segments.iter().find(|s| s.name == name);
+    }
+}
+
+/// Layout of the action space of games with this config
+pub fn layout(config: &Config) -> SpaceLayout {
+    return ActionSpace::from(config.clone()).layout();
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl SpaceLayout {
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl SpaceSegment {
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// Fixed capacity of `ActionMask`, comfortably above the default space of 120.
+pub const ACTION_MASK_BITS: usize = 256;
+const ACTION_MASK_WORDS: usize = ACTION_MASK_BITS / 64;
+
+/// Bitset of legal actions, indexed the same way as `ActionSpace::to_vec`.
+/// It is `Copy` and never allocates, so it can be regenerated every step.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct ActionMask {
+    words: [u64; ACTION_MASK_WORDS],
+    len: usize,
+}
+
+impl ActionMask {
+    /// Number of actions in the space (legal or not)
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    /// Whether the space has no actions at all (not whether none are legal)
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    /// Whether the action at `index` is legal, false past the end
+    pub fn is_legal(&self, index: usize) -> bool {
+        if index >= self.len {
+            return false;
+        }
+        return self.words[index / 64] & (1 << (index % 64)) != 0;
+    }
+
+    /// Number of legal actions
+    pub fn count_legal(&self) -> usize {
+        return self.words.iter().map(|w| w.count_ones() as usize).sum();
+    }
+
+    /// Indices of the legal actions, in order
+    pub fn legal_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        return (0..self.len).filter(|i| self.is_legal(*i));
+    }
+
+    fn set(&mut self, index: usize) {
+        self.words[index / 64] |= 1 << (index % 64);
+    }
 }
 
 impl ActionSpace {
@@ -54,7 +209,12 @@ impl ActionSpace {
             + self.buy_consumable.len()
             + self.use_consumable.len()
             + self.next_round.len()
-            + self.select_blind.len();
+            + self.select_blind.len()
+            + self.sell_joker.len()
+            + self.move_joker_left.len()
+            + self.move_joker_right.len()
+            + self.sell_consumable.len()
+            + self.reroll.len();
     }
 
     fn select_card_min(&self) -> usize {
@@ -121,7 +281,7 @@ impl ActionSpace {
         return self.buy_consumable_min() + self.buy_consumable.len() - 1;
     }
 
-    fn use_consumable_min(&self) -> usize {
+    pub(crate) fn use_consumable_min(&self) -> usize {
         return self.buy_consumable_max() + 1;
     }
 
@@ -145,6 +305,139 @@ impl ActionSpace {
         return self.select_blind_min() + self.select_blind.len() - 1;
     }
 
+    fn sell_joker_min(&self) -> usize {
+        return self.select_blind_max() + 1;
+    }
+
+    fn sell_joker_max(&self) -> usize {
+        return self.sell_joker_min() + self.sell_joker.len() - 1;
+    }
+
+    fn move_joker_left_min(&self) -> usize {
+        return self.sell_joker_max() + 1;
+    }
+
+    fn move_joker_left_max(&self) -> usize {
+        return self.move_joker_left_min() + self.move_joker_left.len() - 1;
+    }
+
+    fn move_joker_right_min(&self) -> usize {
+        return self.move_joker_left_max() + 1;
+    }
+
+    fn move_joker_right_max(&self) -> usize {
+        return self.move_joker_right_min() + self.move_joker_right.len() - 1;
+    }
+
+    pub(crate) fn sell_consumable_min(&self) -> usize {
+        return self.move_joker_right_max() + 1;
+    }
+
+    fn sell_consumable_max(&self) -> usize {
+        return self.sell_consumable_min() + self.sell_consumable.len() - 1;
+    }
+
+    pub(crate) fn reroll_min(&self) -> usize {
+        return self.sell_consumable_max() + 1;
+    }
+
+    fn reroll_max(&self) -> usize {
+        return self.reroll_min() + self.reroll.len() - 1;
+    }
+
+    // Each segment in index order, borrowed so iterating never allocates
+    fn segments(&self) -> [&[usize]; 16] {
+        return [
+            &self.select_card,
+            &self.move_card_left,
+            &self.move_card_right,
+            &self.play,
+            &self.discard,
+            &self.cash_out,
+            &self.buy_joker,
+            &self.buy_consumable,
+            &self.use_consumable,
+            &self.next_round,
+            &self.select_blind,
+            &self.sell_joker,
+            &self.move_joker_left,
+            &self.move_joker_right,
+            &self.sell_consumable,
+            &self.reroll,
+        ];
+    }
+
+    fn segments_mut(&mut self) -> [&mut Vec<usize>; 16] {
+        return [
+            &mut self.select_card,
+            &mut self.move_card_left,
+            &mut self.move_card_right,
+            &mut self.play,
+            &mut self.discard,
+            &mut self.cash_out,
+            &mut self.buy_joker,
+            &mut self.buy_consumable,
+            &mut self.use_consumable,
+            &mut self.next_round,
+            &mut self.select_blind,
+            &mut self.sell_joker,
+            &mut self.move_joker_left,
+            &mut self.move_joker_right,
+            &mut self.sell_consumable,
+            &mut self.reroll,
+        ];
+    }
+
+    // Mask out every action, keeping the allocated segments for reuse
+    pub(crate) fn clear(&mut self) {
+        for segment in self.segments_mut() {
+            segment.fill(0);
+        }
+    }
+
+    /// True if the action at `index` is unmasked
+    pub fn is_legal(&self, index: usize) -> bool {
+        let mut offset = index;
+        for segment in self.segments() {
+            if offset < segment.len() {
+                return segment[offset] != 0;
+            }
+            offset -= segment.len();
+        }
+        return false;
+    }
+
+    /// Copy the legal actions into a fixed-size bitset
+    pub fn to_mask(&self) -> Result<ActionMask, ActionSpaceError> {
+        let len = self.size();
+        if len > ACTION_MASK_BITS {
+            return Err(ActionSpaceError::MaskOverflow);
+        }
+        let mut mask = ActionMask {
+            words: [0; ACTION_MASK_WORDS],
+            len,
+        };
+        let mut index = 0;
+        for segment in self.segments() {
+            for v in segment {
+                if *v != 0 {
+                    mask.set(index);
+                }
+                index += 1;
+            }
+        }
+        return Ok(mask);
+    }
+
+    /// Write the action space vector into a caller-provided buffer,
+    /// reusing its allocation instead of building a new vector.
+    pub fn fill_vec(&self, out: &mut Vec<usize>) {
+        out.clear();
+        for segment in self.segments() {
+            out.extend_from_slice(segment);
+        }
+    }
+
     // Not all actions are always legal, by default all actions
     // are masked out, but provide methods to unmask valid.
     pub(crate) fn unmask_select_card(&mut self, i: usize) -> Result<(), ActionSpaceError> {
@@ -215,15 +508,49 @@ impl ActionSpace {
         self.select_blind[0] = 1;
     }
 
+    pub(crate) fn unmask_sell_joker(&mut self, i: usize) -> Result<(), ActionSpaceError> {
+        if i >= self.sell_joker.len() {
+            return Err(ActionSpaceError::InvalidIndex);
+        }
+        self.sell_joker[i] = 1;
+        return Ok(());
+    }
+
+    pub(crate) fn unmask_move_joker_left(&mut self, i: usize) -> Result<(), ActionSpaceError> {
+        if i >= self.move_joker_left.len() {
+            return Err(ActionSpaceError::InvalidIndex);
+        }
+        self.move_joker_left[i] = 1;
+        return Ok(());
+    }
+
+    pub(crate) fn unmask_move_joker_right(&mut self, i: usize) -> Result<(), ActionSpaceError> {
+        if i >= self.move_joker_right.len() {
+            return Err(ActionSpaceError::InvalidIndex);
+        }
+        self.move_joker_right[i] = 1;
+        return Ok(());
+    }
+
+    pub(crate) fn unmask_sell_consumable(&mut self, i: usize) -> Result<(), ActionSpaceError> {
+        if i >= self.sell_consumable.len() {
+            return Err(ActionSpaceError::InvalidIndex);
+        }
+        self.sell_consumable[i] = 1;
+        return Ok(());
+    }
+
+    pub(crate) fn unmask_reroll(&mut self) {
+        self.reroll[0] = 1;
+    }
+
     pub fn to_action(&self, index: usize, game: &Game) -> Result<Action, ActionSpaceError> {
-        let vec = self.to_vec();
-        if let Some(v) = vec.get(index) {
-            if *v == 0 {
-                return Err(ActionSpaceError::MaskedAction);
-            }
-        } else {
+        if index >= self.size() {
             return Err(ActionSpaceError::InvalidIndex);
         }
+        if !self.is_legal(index) {
+            return Err(ActionSpaceError::MaskedAction);
+        }
         match index {
             // Cannot reference runtime values in patterns, so this is workaround
             n if (self.select_card_min()..=self.select_card_max()).contains(&n) => {
@@ -269,8 +596,8 @@ impl ActionSpace {
             }
             n if (self.buy_consumable_min()..=self.buy_consumable_max()).contains(&n) => {
                 let n_offset = n - self.buy_consumable_min();
-                if let Some(consumable) = game.shop.consumable_from_index(n_offset) {
-                    return Ok(Action::BuyConsumable(consumable));
+                if game.shop.consumable_from_index(n_offset).is_some() {
+                    return Ok(Action::BuyConsumable(n_offset));
                 } else {
                     return Err(ActionSpaceError::InvalidActionConversion);
                 }
@@ -278,9 +605,12 @@ impl ActionSpace {
             n if (self.use_consumable_min()..=self.use_consumable_max()).contains(&n) => {
                 let n_offset = n - self.use_consumable_min();
                 if let Some(consumable) = game.consumable_from_index(n_offset) {
-                    // For now, use consumables without targets
-                    // TODO: Handle targeted consumables
-                    return Ok(Action::UseConsumable(consumable, None));
+                    // Targeted consumables act on the selected cards
+                    let targets = match consumable.requires_target() {
+                        true => Some(game.available.selected().iter().map(|c| c.id).collect()),
+                        false => None,
+                    };
+                    return Ok(Action::UseConsumable(consumable, targets));
                 } else {
                     return Err(ActionSpaceError::InvalidActionConversion);
                 }
@@ -294,31 +624,157 @@ impl ActionSpace {
                     None => Ok(Action::SelectBlind(Blind::Small)),
                 }
             }
+            n if (self.sell_joker_min()..=self.sell_joker_max()).contains(&n) => {
+                let slot = n - self.sell_joker_min();
+                if slot < game.jokers.len() {
+                    return Ok(Action::SellJoker(slot));
+                } else {
+                    return Err(ActionSpaceError::InvalidActionConversion);
+                }
+            }
+            n if (self.move_joker_left_min()..=self.move_joker_left_max()).contains(&n) => {
+                // Index shifted to right (+1), since leftmost joker cannot move left
+                let slot = n - self.move_joker_left_min() + 1;
+                if slot < game.jokers.len() {
+                    return Ok(Action::MoveJoker(slot, slot - 1));
+                } else {
+                    return Err(ActionSpaceError::InvalidActionConversion);
+                }
+            }
+            n if (self.move_joker_right_min()..=self.move_joker_right_max()).contains(&n) => {
+                let slot = n - self.move_joker_right_min();
+                if slot + 1 < game.jokers.len() {
+                    return Ok(Action::MoveJoker(slot, slot + 1));
+                } else {
+                    return Err(ActionSpaceError::InvalidActionConversion);
+                }
+            }
+            n if (self.sell_consumable_min()..=self.sell_consumable_max()).contains(&n) => {
+                let slot = n - self.sell_consumable_min();
+                if slot < game.consumables.len() {
+                    return Ok(Action::SellConsumable(slot));
+                } else {
+                    return Err(ActionSpaceError::InvalidActionConversion);
+                }
+            }
+            n if (self.reroll_min()..=self.reroll_max()).contains(&n) => {
+                return Ok(Action::Reroll());
+            }
             _ => return Err(ActionSpaceError::InvalidActionConversion),
         }
     }
 
+    /// Index of `action` in the space, the inverse of `to_action`. Cards,
+    /// shop jokers and consumables are looked up in `game`, the mask is not
+    /// checked. Actions without an index fail with
+    /// `InvalidActionConversion`.
+    pub fn action_to_index(&self, action: &Action, game: &Game) -> Result<usize, ActionSpaceError> {
+        let (segment_min, segment_len, offset) = match action {
+            Action::SelectCard(card) => (
+                self.select_card_min(),
+                self.select_card.len(),
+                game.available.index_of(*card),
+            ),
+            Action::SelectCardIndex(i) => {
+                (self.select_card_min(), self.select_card.len(), Some(*i))
+            }
+            // Index shifted to left (-1), since leftmost card cannot move left
+            Action::MoveCard(MoveDirection::Left, card) => (
+                self.move_card_left_min(),
+                self.move_card_left.len(),
+                game.available
+                    .index_of(*card)
+                    .and_then(|i| i.checked_sub(1)),
+            ),
+            Action::MoveCard(MoveDirection::Right, card) => (
+                self.move_card_right_min(),
+                self.move_card_right.len(),
+                game.available
+                    .index_of(*card)
+                    .filter(|i| i + 1 < game.available.iter_cards().len()),
+            ),
+            Action::Play() => (self.play_min(), self.play.len(), Some(0)),
+            Action::Discard() => (self.discard_min(), self.discard.len(), Some(0)),
+            Action::CashOut(_) => (self.cash_out_min(), self.cash_out.len(), Some(0)),
+            Action::BuyJoker(joker) => (
+                self.buy_joker_min(),
+                self.buy_joker.len(),
+                game.shop.jokers.iter().position(|j| j == joker),
+            ),
+            Action::BuyConsumable(slot) => (
+                self.buy_consumable_min(),
+                self.buy_consumable.len(),
+                Some(*slot),
+            ),
+            Action::UseConsumable(consumable, _) => (
+                self.use_consumable_min(),
+                self.use_consumable.len(),
+                game.consumables.iter().position(|c| c == consumable),
+            ),
+            Action::NextRound() => (self.next_round_min(), self.next_round.len(), Some(0)),
+            Action::SelectBlind(_) => (self.select_blind_min(), self.select_blind.len(), Some(0)),
+            Action::SellJoker(slot) => (self.sell_joker_min(), self.sell_joker.len(), Some(*slot)),
+            Action::MoveJoker(from, to) if *to + 1 == *from => (
+                self.move_joker_left_min(),
+                self.move_joker_left.len(),
+                Some(*to),
+            ),
+            Action::MoveJoker(from, to) if *from + 1 == *to => (
+                self.move_joker_right_min(),
+                self.move_joker_right.len(),
+                Some(*from),
+            ),
+            Action::SellConsumable(slot) => (
+                self.sell_consumable_min(),
+                self.sell_consumable.len(),
+                Some(*slot),
+            ),
+            Action::Reroll() => (self.reroll_min(), self.reroll.len(), Some(0)),
+            _ => return Err(ActionSpaceError::InvalidActionConversion),
+        };
+        match offset {
+            Some(offset) if offset < segment_len => return Ok(segment_min + offset),
+            Some(_) => return Err(ActionSpaceError::InvalidIndex),
+            None => return Err(ActionSpaceError::InvalidActionConversion),
+        }
+    }
+
+    /// Offsets and lengths of the segments, see `SEGMENT_NAMES`
+    pub fn layout(&self) -> SpaceLayout {
+        let mut offset = 0;
+        let segments = self
+            .segments()
+            .iter()
+            .zip(SEGMENT_NAMES)
+            .map(|(segment, name)| {
+                let info = SpaceSegment {
+                    name: name.to_string(),
+                    offset,
+                    len: segment.len(),
+                };
+                offset += segment.len();
+                return info;
+            })
+            .collect();
+        return SpaceLayout {
+            version: ACTION_SPACE_VERSION,
+            size: self.size(),
+            segments,
+        };
+    }
+
     pub fn to_vec(&self) -> Vec<usize> {
-        return [
-            self.select_card.clone(),
-            self.move_card_left.clone(),
-            self.move_card_right.clone(),
-            self.play.clone(),
-            self.discard.clone(),
-            self.cash_out.clone(),
-            self.buy_joker.clone(),
-            self.buy_consumable.clone(),
-            self.use_consumable.clone(),
-            self.next_round.clone(),
-            self.select_blind.clone(),
-        ]
-        .concat();
+        let mut vec = Vec::with_capacity(self.size());
+        self.fill_vec(&mut vec);
+        return vec;
     }
 
     // True is all elements are masked
     pub fn is_empty(&self) -> bool {
-        let vec = self.to_vec();
-        return (*vec.iter().min().unwrap() == 0) && (*vec.iter().max().unwrap() == 0);
+        return self
+            .segments()
+            .iter()
+            .all(|segment| segment.iter().all(|v| *v == 0));
     }
 }
 
@@ -336,6 +792,11 @@ impl From<Config> for ActionSpace {
             use_consumable: vec![0; c.consumable_slots_max],
             next_round: vec![0; 1],
             select_blind: vec![0; 1],
+            sell_joker: vec![0; c.joker_slots_max],
+            move_joker_left: vec![0; c.joker_slots_max - 1], // every joker but leftmost can move left
+            move_joker_right: vec![0; c.joker_slots_max - 1], // every joker but rightmost can move right
+            sell_consumable: vec![0; c.consumable_slots_max],
+            reroll: vec![0; 1],
         };
     }
 }
@@ -355,6 +816,11 @@ impl From<ActionSpace> for Vec<usize> {
             a.use_consumable,
             a.next_round,
             a.select_blind,
+            a.sell_joker,
+            a.move_joker_left,
+            a.move_joker_right,
+            a.sell_consumable,
+            a.reroll,
         ]
         .concat();
     }
@@ -364,7 +830,8 @@ impl From<ActionSpace> for Vec<usize> {
 mod tests {
     use super::*;
     use crate::config::Config;
-    use crate::stage::Blind;
+    use crate::joker::{Jokers, Misprint, TheJoker};
+    use crate::stage::{Blind, Stage};
 
     #[test]
     fn test_unmask() {
@@ -395,6 +862,164 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn test_mask_matches_vec() {
+        let mut g = Game::default();
+        g.start();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        let card = g.available.card_from_index(2).expect("third card");
+        g.handle_action(Action::SelectCard(card)).unwrap();
+
+        let space = g.gen_action_space();
+        let vec = space.to_vec();
+        let mask = space.to_mask().expect("fits in mask");
+        assert_eq!(mask.len(), vec.len());
+        assert!(!mask.is_empty());
+        for (i, v) in vec.iter().enumerate() {
+            assert_eq!(mask.is_legal(i), *v == 1, "index {}", i);
+            assert_eq!(space.is_legal(i), *v == 1, "index {}", i);
+        }
+        assert_eq!(mask.count_legal(), vec.iter().sum::<usize>());
+        assert!(mask.legal_indices().all(|i| vec[i] == 1));
+        assert!(!mask.is_legal(vec.len()));
+        assert!(!space.is_legal(vec.len()));
+    }
+
+    #[test]
+    fn test_reuse_space_and_buffer() {
+        let mut g = Game::default();
+        let mut space = ActionSpace::from(g.config.clone());
+        let mut buf = Vec::new();
+
+        g.gen_action_space_into(&mut space);
+        space.fill_vec(&mut buf);
+        assert_eq!(buf, g.gen_action_space().to_vec());
+
+        // Stale entries from the previous state must be cleared
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        g.gen_action_space_into(&mut space);
+        assert_eq!(space, g.gen_action_space());
+
+        let capacity = buf.capacity();
+        space.fill_vec(&mut buf);
+        assert_eq!(buf, space.to_vec());
+        assert_eq!(buf.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_mask_overflow() {
+        let mut c = Config::default();
+        c.available_max = ACTION_MASK_BITS;
+        let a = ActionSpace::from(c);
+        assert!(matches!(a.to_mask(), Err(ActionSpaceError::MaskOverflow)));
+    }
+
+    #[test]
+    fn test_layout() {
+        let space = ActionSpace::from(Config::default());
+        let layout = space.layout();
+        assert_eq!(layout.version, ACTION_SPACE_VERSION);
+        assert_eq!(layout.size, 120);
+        assert_eq!(layout.segments.len(), SEGMENT_NAMES.len());
+        let mins = [
+            space.select_card_min(),
+            space.move_card_left_min(),
+            space.move_card_right_min(),
+            space.play_min(),
+            space.discard_min(),
+            space.cash_out_min(),
+            space.buy_joker_min(),
+            space.buy_consumable_min(),
+            space.use_consumable_min(),
+            space.next_round_min(),
+            space.select_blind_min(),
+            space.sell_joker_min(),
+            space.move_joker_left_min(),
+            space.move_joker_right_min(),
+            space.sell_consumable_min(),
+            space.reroll_min(),
+        ];
+        for (segment, min) in layout.segments.iter().zip(mins) {
+            assert_eq!(segment.offset, min, "{}", segment.name);
+        }
+        let play = layout.segment("play").unwrap();
+        assert_eq!((play.offset, play.len), (70, 1));
+        assert_eq!(layout.segment("reroll").unwrap().offset, 119);
+        assert_eq!(layout.segment("sell_joker").unwrap().len, 10);
+        assert!(layout.segment("skip_blind").is_none());
+
+        let mut c = Config::default();
+        c.joker_slots_max = 5;
+        // Five fewer slots to sell and to move left and right
+        assert_eq!(super::layout(&c).size, 120 - 3 * 5);
+    }
+
+    #[test]
+    fn test_action_to_index_round_trip() {
+        let mut g = Game::default();
+        g.start();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        let card = g.available.card_from_index(0).expect("first card");
+        g.handle_action(Action::SelectCard(card)).unwrap();
+        g.jokers = vec![Jokers::TheJoker(TheJoker {}), Jokers::Misprint(Misprint {})];
+
+        let space = g.gen_action_space();
+        let mask = space.to_mask().unwrap();
+        assert!(mask.count_legal() > 0);
+        for index in mask.legal_indices() {
+            let action = space.to_action(index, &g).unwrap();
+            assert_eq!(
+                space.action_to_index(&action, &g).unwrap(),
+                index,
+                "{}",
+                action
+            );
+        }
+
+        let first = space
+            .action_to_index(&Action::SelectCard(card), &g)
+            .unwrap();
+        assert_eq!(first, 0);
+        assert_eq!(
+            space.action_to_index(&Action::MoveJoker(1, 0), &g).unwrap(),
+            97
+        );
+        assert_eq!(
+            space.action_to_index(&Action::MoveJoker(0, 1), &g).unwrap(),
+            106
+        );
+        assert!(matches!(
+            space.action_to_index(&Action::MoveCard(MoveDirection::Left, card), &g),
+            Err(ActionSpaceError::InvalidActionConversion)
+        ));
+        assert!(matches!(
+            space.action_to_index(&Action::MoveJoker(0, 2), &g),
+            Err(ActionSpaceError::InvalidActionConversion)
+        ));
+        assert!(matches!(
+            space.action_to_index(&Action::SkipBlind(), &g),
+            Err(ActionSpaceError::InvalidActionConversion)
+        ));
+        assert!(matches!(
+            space.action_to_index(&Action::SellJoker(10), &g),
+            Err(ActionSpaceError::InvalidIndex)
+        ));
+    }
+
+    #[test]
+    fn test_action_to_index_shop() {
+        let mut g = Game::default();
+        g.stage = Stage::Shop();
+        g.money = 100;
+        g.shop.jokers = vec![Jokers::TheJoker(TheJoker {}), Jokers::Misprint(Misprint {})];
+        let space = g.gen_action_space();
+        let buy = Action::BuyJoker(Jokers::Misprint(Misprint {}));
+        let index = space.action_to_index(&buy, &g).unwrap();
+        assert_eq!(index, 74);
+        assert_eq!(space.to_action(index, &g).unwrap(), buy);
+        assert_eq!(space.action_to_index(&Action::Reroll(), &g).unwrap(), 119);
+    }
+
     #[test]
     fn test_index_to_action() {
         let mut g = Game::default();
@@ -402,12 +1027,11 @@ mod tests {
         let space_vec = g.gen_action_space().to_vec();
 
         // Game hasn't started yet, so only valid action is select blind
-        for b in space_vec.iter().rev().skip(1).rev() {
-            assert_eq!(*b, 0);
+        let blind_index = space.select_blind_min();
+        for (i, b) in space_vec.iter().enumerate() {
+            assert_eq!(*b, (i == blind_index) as usize);
         }
-        assert_eq!(*space_vec.last().unwrap(), 1);
-        let last_index = space_vec.len() - 1;
-        let action = space.to_action(last_index, &g).expect("to action");
+        let action = space.to_action(blind_index, &g).expect("to action");
         assert_eq!(action, Action::SelectBlind(Blind::Small));
         g.handle_action(action).unwrap();
 
@@ -2,6 +2,8 @@ use crate::card::Card;
 use crate::consumable::{Consumable, ConsumableType};
 use crate::error::GameError;
 use crate::game::Game;
+use crate::provenance::CardOrigin;
+#[cfg(feature = "python")]
 use pyo3::pyclass;
 use strum::{EnumIter, IntoEnumIterator};
 
@@ -32,8 +34,8 @@ pub enum Spectrals {
 
     // Joker manipulation (5)
     Wraith,    // Create Rare Joker, set money to $0
-    Ankh,      // Copy 1 Joker, destroy others
-    Hex,       // Add Polychrome to 1 Joker, destroy others
+    Ankh,      // Copy a random Joker, destroy others
+    Hex,       // Add Polychrome to a random Joker, destroy others
     Ectoplasm, // Add Negative to random Joker, -1 hand size
     TheSoul,   // Create Legendary Joker
 
@@ -79,9 +81,9 @@ impl Consumable for Spectrals {
             Self::Ouija => "Convert all cards to a single random rank, -1 hand size".to_string(),
             Self::Ectoplasm => "Add Negative to a random Joker, -1 hand size".to_string(),
             Self::Immolate => "Destroy 5 random cards, gain $20".to_string(),
-            Self::Ankh => "Create a copy of 1 Joker, destroy all other Jokers".to_string(),
+            Self::Ankh => "Create a copy of a random Joker, destroy all other Jokers (removes Negative from copy)".to_string(),
             Self::DejaVu => "Add a Red Seal to 1 selected card".to_string(),
-            Self::Hex => "Add Polychrome to 1 Joker, destroy all other Jokers".to_string(),
+            Self::Hex => "Add Polychrome to a random Joker, destroy all other Jokers".to_string(),
             Self::Trance => "Add a Blue Seal to 1 selected card".to_string(),
             Self::Medium => "Add a Purple Seal to 1 selected card".to_string(),
             Self::Cryptid => "Create 2 copies of 1 selected card".to_string(),
@@ -103,9 +105,6 @@ impl Consumable for Spectrals {
                 | Self::Trance
                 | Self::Medium
                 | Self::Cryptid
-                // Ankh and Hex target jokers, not cards - for now they don't require targets
-                // | Self::Ankh
-                // | Self::Hex
         )
     }
 
@@ -117,9 +116,6 @@ impl Consumable for Spectrals {
             | Self::Trance
             | Self::Medium
             | Self::Cryptid => 1,
-            // Ankh and Hex would target jokers, not cards - for now they don't use targets
-            // | Self::Ankh
-            // | Self::Hex
             _ => 0,
         }
     }
@@ -133,7 +129,7 @@ impl Consumable for Spectrals {
                 // Add Gold Seal to 1 card
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_seal(Seal::Gold);
                         });
                     }
@@ -144,7 +140,7 @@ impl Consumable for Spectrals {
                 // Add Red Seal to 1 card
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_seal(Seal::Red);
                         });
                     }
@@ -155,7 +151,7 @@ impl Consumable for Spectrals {
                 // Add Blue Seal to 1 card
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_seal(Seal::Blue);
                         });
                     }
@@ -166,7 +162,7 @@ impl Consumable for Spectrals {
                 // Add Purple Seal to 1 card
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_seal(Seal::Purple);
                         });
                     }
@@ -178,33 +174,33 @@ impl Consumable for Spectrals {
             Self::Familiar => {
                 // Destroy 1 random card, add 3 enhanced face cards
                 if let Some(random_card) = game.get_random_card_from_deck() {
-                    game.destroy_card(random_card);
+                    game.destroy_card_by(random_card, "Familiar");
                 }
                 for _ in 0..3 {
                     let face_card = game.create_enhanced_face_card();
-                    game.add_card_to_deck(face_card);
+                    game.add_card_with_origin(face_card, CardOrigin::Created("Familiar".to_string()));
                 }
                 Ok(())
             }
             Self::Grim => {
                 // Destroy 1 random card, add 2 enhanced Aces
                 if let Some(random_card) = game.get_random_card_from_deck() {
-                    game.destroy_card(random_card);
+                    game.destroy_card_by(random_card, "Grim");
                 }
                 for _ in 0..2 {
                     let ace = game.create_enhanced_ace();
-                    game.add_card_to_deck(ace);
+                    game.add_card_with_origin(ace, CardOrigin::Created("Grim".to_string()));
                 }
                 Ok(())
             }
             Self::Incantation => {
                 // Destroy 1 random card, add 4 enhanced number cards
                 if let Some(random_card) = game.get_random_card_from_deck() {
-                    game.destroy_card(random_card);
+                    game.destroy_card_by(random_card, "Incantation");
                 }
                 for _ in 0..4 {
                     let number_card = game.create_enhanced_number();
-                    game.add_card_to_deck(number_card);
+                    game.add_card_with_origin(number_card, CardOrigin::Created("Incantation".to_string()));
                 }
                 Ok(())
             }
@@ -212,7 +208,7 @@ impl Consumable for Spectrals {
                 // Destroy 5 random cards, gain $20
                 let cards_to_destroy = game.get_random_cards(5);
                 for card in cards_to_destroy {
-                    game.destroy_card(card);
+                    game.destroy_card_by(card, "Immolate");
                 }
                 game.money += 20;
                 Ok(())
@@ -224,10 +220,10 @@ impl Consumable for Spectrals {
                 if let Some(cards) = targets {
                     use rand::seq::SliceRandom;
                     let editions = vec![Edition::Foil, Edition::Holographic, Edition::Polychrome];
-                    let edition = *editions.choose(&mut rand::thread_rng()).unwrap();
+                    let edition = *editions.choose(&mut crate::rng::rng_for("spectral", "aura edition")).unwrap();
 
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_edition(edition);
                         });
                     }
@@ -240,11 +236,12 @@ impl Consumable for Spectrals {
                     if let Some(card) = cards.first() {
                         // Add 2 copies of the card
                         for _ in 0..2 {
-                            let mut copy = Card::new(card.value, card.suit);
-                            copy.edition = card.edition;
-                            copy.enhancement = card.enhancement;
-                            copy.seal = card.seal;
-                            game.add_card_to_deck(copy);
+                            let copy = game.copy_card(card);
+                            let origin = CardOrigin::Copied {
+                                from: card.id,
+                                by: "Cryptid".to_string(),
+                            };
+                            game.add_card_with_origin(copy, origin);
                         }
                     }
                 }
@@ -256,6 +253,7 @@ impl Consumable for Spectrals {
                 // Create Rare Joker, set money to $0
                 let joker = game.generate_rare_joker();
                 game.jokers.push(joker);
+                game.refresh_joker_effects();
                 game.money = 0;
                 Ok(())
             }
@@ -263,34 +261,51 @@ impl Consumable for Spectrals {
                 // Create Legendary Joker
                 let joker = game.generate_legendary_joker();
                 game.jokers.push(joker);
+                game.refresh_joker_effects();
                 Ok(())
             }
             Self::Ankh => {
-                // Copy 1 Joker, destroy others
-                // Note: targets should be joker indices, but we're using Card
-                // This is a simplified implementation - just keep first joker
-                if !game.jokers.is_empty() {
-                    let joker_to_copy = game.jokers[0].clone();
-                    game.jokers.clear();
-                    game.jokers.push(joker_to_copy);
+                // Copy a random Joker, destroy others. The copy keeps the
+                // edition and stickers, except Negative.
+                use rand::seq::SliceRandom;
+                let indices: Vec<usize> = (0..game.jokers.len()).collect();
+                let chosen = indices.choose(&mut crate::rng::rng_for("spectral", "ankh joker"));
+                if let Some(kept) = chosen.and_then(|&i| game.destroy_all_jokers_except(i)) {
+                    let copy = game.copy_joker(&game.jokers[kept]);
+                    let edition = match game.joker_edition(kept) {
+                        Edition::Negative => Edition::Base,
+                        edition => edition,
+                    };
+                    let stickers = game.joker_stickers(kept);
+                    game.jokers.push(copy);
+                    let index = game.jokers.len() - 1;
+                    game.set_joker_edition(index, edition);
+                    game.set_joker_stickers(index, stickers);
+                    game.refresh_joker_effects();
+                    game.update_modifiers();
                 }
                 Ok(())
             }
             Self::Hex => {
-                // Add Polychrome to 1 Joker, destroy others
-                // Note: Jokers don't have editions yet, this is a placeholder
-                // Just destroy all but one joker for now
-                if !game.jokers.is_empty() {
-                    let kept_joker = game.jokers[0].clone();
-                    game.jokers.clear();
-                    game.jokers.push(kept_joker);
+                // Add Polychrome to a random Joker without an edition,
+                // destroy others
+                use rand::seq::SliceRandom;
+                let candidates = game.base_edition_jokers();
+                let chosen = candidates.choose(&mut crate::rng::rng_for("spectral", "hex joker"));
+                if let Some(kept) = chosen.and_then(|&i| game.destroy_all_jokers_except(i)) {
+                    game.set_joker_edition(kept, Edition::Polychrome);
+                    game.refresh_joker_effects();
+                    game.update_modifiers();
                 }
                 Ok(())
             }
             Self::Ectoplasm => {
-                // Add Negative to random Joker, -1 hand size
-                // Note: Jokers don't have editions yet, this is a placeholder
-                // Just decrease hand size for now
+                // Add Negative to random Joker without an edition, -1 hand size
+                use rand::seq::SliceRandom;
+                let candidates = game.base_edition_jokers();
+                if let Some(&i) = candidates.choose(&mut crate::rng::rng_for("spectral", "ectoplasm joker")) {
+                    game.set_joker_edition(i, Edition::Negative);
+                }
                 game.modify_hand_size(-1);
                 Ok(())
             }
@@ -300,7 +315,7 @@ impl Consumable for Spectrals {
                 // Convert all cards to same random suit
                 use rand::seq::SliceRandom;
                 let suits = vec![Suit::Heart, Suit::Diamond, Suit::Club, Suit::Spade];
-                let chosen_suit = *suits.choose(&mut rand::thread_rng()).unwrap();
+                let chosen_suit = *suits.choose(&mut crate::rng::rng_for("spectral", "sigil suit")).unwrap();
                 game.convert_all_cards_to_suit(chosen_suit);
                 Ok(())
             }
@@ -312,7 +327,7 @@ impl Consumable for Spectrals {
                     Value::Seven, Value::Eight, Value::Nine, Value::Ten,
                     Value::Jack, Value::Queen, Value::King, Value::Ace
                 ];
-                let chosen_rank = *ranks.choose(&mut rand::thread_rng()).unwrap();
+                let chosen_rank = *ranks.choose(&mut crate::rng::rng_for("spectral", "ouija rank")).unwrap();
                 game.convert_all_cards_to_rank(chosen_rank);
                 game.modify_hand_size(-1);
                 Ok(())
@@ -337,9 +352,7 @@ impl Consumable for Spectrals {
                     HandRank::FlushHouse,
                     HandRank::FlushFive,
                 ] {
-                    if let Some(level) = game.hand_levels.get_mut(&hand_rank) {
-                        *level = level.upgrade();
-                    }
+                    game.upgrade_hand(hand_rank);
                 }
                 Ok(())
             }
@@ -50,6 +50,15 @@ impl fmt::Display for Blind {
 pub enum End {
     Win,
     Lose,
+    // Beat the final ante, then lost a blind in endless mode
+    Endless,
+}
+
+impl End {
+    /// Whether the final ante was beaten
+    pub fn is_win(&self) -> bool {
+        return matches!(self, End::Win | End::Endless);
+    }
 }
 
 /// Stages of playing.
@@ -67,6 +76,8 @@ pub enum Stage {
     PostBlind(),
     // Buy jokers, consumables
     Shop(),
+    // Choose from a booster pack bought in the shop, back to the shop after
+    OpenPack(),
     // Game ending
     End(End),
 }
@@ -87,19 +98,8 @@ impl Stage {
         }
     }
 
-    /// Get the boss modifier if this is a Boss Blind stage
-    pub fn boss_modifier(&self) -> Option<BossModifier> {
-        match self {
-            Stage::Blind(Blind::Boss, modifier) => *modifier,
-            _ => None,
-        }
-    }
-}
-
-#[cfg(feature = "python")]
-#[pymethods]
-impl Stage {
-    fn int(&self) -> usize {
+    /// Stable integer id for the stage (used by observations)
+    pub fn index(&self) -> usize {
         match self {
             Self::PreBlind() => 0,
             Self::Blind(blind, _) => match blind {
@@ -112,7 +112,25 @@ impl Stage {
             Self::End(end) => match end {
                 End::Win => 6,
                 End::Lose => 7,
+                End::Endless => 9,
             },
+            Self::OpenPack() => 8,
         }
     }
+
+    /// Get the boss modifier if this is a Boss Blind stage
+    pub fn boss_modifier(&self) -> Option<BossModifier> {
+        match self {
+            Stage::Blind(Blind::Boss, modifier) => *modifier,
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl Stage {
+    fn int(&self) -> usize {
+        return self.index();
+    }
 }
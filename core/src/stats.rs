@@ -0,0 +1,261 @@
+use crate::consumable::Consumables;
+use crate::rank::HandRank;
+use crate::summary::RunSummary;
+#[cfg(feature = "python")]
+use pyo3::{pyclass, pymethods};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Counters over the whole run, kept in `Game::stats`. Jokers that scale
+/// with the run (Supernova, Satellite) read them from here.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass)]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GameStats {
+    // Times each hand rank was played (Supernova, Obelisk, Telescope)
+    pub hands_played: HashMap<HandRank, usize>,
+    // Highest score of a single hand
+    pub best_hand_score: usize,
+    // Points scored by every hand played, unlike `Game::score` this does
+    // not reset when a blind is cleared
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub total_score: usize,
+    // Shop rerolls, free ones included
+    pub rerolls: usize,
+    pub cards_discarded: usize,
+    // Net money gained or spent by each action, see `Event::MoneyGained`
+    pub money_earned: usize,
+    pub money_spent: usize,
+    pub jokers_bought: usize,
+    pub jokers_sold: usize,
+    pub tarots_used: usize,
+    pub planets_used: usize,
+    pub spectrals_used: usize,
+    // Hand ranks of the Planet cards used (Satellite)
+    pub unique_planets_used: HashSet<HandRank>,
+}
+
+impl GameStats {
+    /// Times `rank` was played this run
+    pub fn times_played(&self, rank: HandRank) -> usize {
+        return self.hands_played.get(&rank).copied().unwrap_or(0);
+    }
+
+    /// Most played hand rank, ties go to the higher hand so the pick does
+    /// not depend on map order
+    pub fn most_played(&self) -> Option<HandRank> {
+        return self
+            .hands_played
+            .iter()
+            .max_by_key(|(rank, count)| (**count, **rank))
+            .map(|(rank, _)| *rank);
+    }
+
+    /// Consumables of every type used this run
+    pub fn consumables_used(&self) -> usize {
+        return self.tarots_used + self.planets_used + self.spectrals_used;
+    }
+
+    pub(crate) fn record_consumable(&mut self, consumable: &Consumables) {
+        match consumable {
+            Consumables::Tarot(_) => self.tarots_used += 1,
+            Consumables::Planet(planet) => {
+                self.planets_used += 1;
+                self.unique_planets_used.insert(planet.hand_rank());
+            }
+            Consumables::Spectral(_) => self.spectrals_used += 1,
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl GameStats {
+    /// Times played by hand name, e.g. {"Flush": 3}
+    #[getter]
+    fn get_hands_played(&self) -> HashMap<String, usize> {
+        return self
+            .hands_played
+            .iter()
+            .map(|(rank, count)| (rank.to_string(), *count))
+            .collect();
+    }
+
+    #[getter]
+    fn get_best_hand_score(&self) -> usize {
+        return self.best_hand_score;
+    }
+
+    #[getter]
+    fn get_total_score(&self) -> usize {
+        return self.total_score;
+    }
+
+    #[getter]
+    fn get_rerolls(&self) -> usize {
+        return self.rerolls;
+    }
+
+    #[getter]
+    fn get_cards_discarded(&self) -> usize {
+        return self.cards_discarded;
+    }
+
+    #[getter]
+    fn get_money_earned(&self) -> usize {
+        return self.money_earned;
+    }
+
+    #[getter]
+    fn get_money_spent(&self) -> usize {
+        return self.money_spent;
+    }
+
+    #[getter]
+    fn get_jokers_bought(&self) -> usize {
+        return self.jokers_bought;
+    }
+
+    #[getter]
+    fn get_jokers_sold(&self) -> usize {
+        return self.jokers_sold;
+    }
+
+    /// Consumables used by type: "Tarot", "Planet" and "Spectral"
+    #[getter]
+    fn get_consumables_used(&self) -> HashMap<String, usize> {
+        return HashMap::from([
+            ("Tarot".to_string(), self.tarots_used),
+            ("Planet".to_string(), self.planets_used),
+            ("Spectral".to_string(), self.spectrals_used),
+        ]);
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// Column order of `to_csv` / `write_csv`
+pub const CSV_HEADER: &[&str] = &[
+    "seed",
+    "seed_str",
+    "deck",
+    "won",
+    "ante",
+    "round",
+    "score",
+    "money",
+    "hands_played",
+    "discards_used",
+    "blinds_skipped",
+    "actions",
+    "jokers",
+];
+
+/// Write one row per run to a CSV file at `path`
+pub fn to_csv<P: AsRef<Path>>(summaries: &[RunSummary], path: P) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_csv(summaries, &mut writer)?;
+    return writer.flush();
+}
+
+/// Write one row per run as CSV. Jokers are joined with ';' in one column.
+pub fn write_csv<W: Write>(summaries: &[RunSummary], writer: &mut W) -> io::Result<()> {
+    writeln!(writer, "{}", CSV_HEADER.join(","))?;
+    for s in summaries {
+        let row = [
+            s.seed.to_string(),
+            crate::seed::encode_seed(s.seed),
+            escape(&s.deck),
+            s.won.to_string(),
+            s.ante.to_string(),
+            s.round.to_string(),
+            s.score.to_string(),
+            s.money.to_string(),
+            s.hands_played.to_string(),
+            s.discards_used.to_string(),
+            s.blinds_skipped.to_string(),
+            s.actions.to_string(),
+            escape(&s.jokers.join(";")),
+        ];
+        writeln!(writer, "{}", row.join(","))?;
+    }
+    return Ok(());
+}
+
+// Quote fields containing separators or quotes
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        return format!("\"{}\"", field.replace('"', "\"\""));
+    }
+    return field.to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::Action;
+    use crate::game::Game;
+    use crate::joker::{Jokers, TheJoker};
+    use crate::planet::Planets;
+    use crate::stage::{Blind, Stage};
+
+    #[test]
+    fn test_game_stats() {
+        let mut g = Game::with_seed(Default::default(), 42).unwrap();
+        g.start();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+        for i in 0..3 {
+            g.handle_action(Action::SelectCardIndex(i)).unwrap();
+        }
+        g.handle_action(Action::Discard()).unwrap();
+        g.handle_action(Action::SelectCardIndex(0)).unwrap();
+        g.handle_action(Action::Play()).unwrap();
+        assert_eq!(g.stats.cards_discarded, 3);
+        let (rank, count) = g.stats.hands_played.iter().next().unwrap();
+        assert_eq!(*count, 1);
+        assert_eq!(g.stats.most_played(), Some(*rank));
+        assert_eq!(g.stats.best_hand_score, g.score);
+        assert_eq!(g.stats.total_score, g.score);
+
+        g.stage = Stage::Shop();
+        g.money = 20;
+        g.shop.jokers = vec![Jokers::TheJoker(TheJoker {})];
+        g.shop.joker_editions.clear();
+        g.shop.joker_stickers.clear();
+        g.handle_action(Action::BuyJoker(Jokers::TheJoker(TheJoker {}))).unwrap();
+        g.handle_action(Action::SellJoker(0)).unwrap();
+        g.handle_action(Action::Reroll()).unwrap();
+        assert_eq!((g.stats.jokers_bought, g.stats.jokers_sold, g.stats.rerolls), (1, 1, 1));
+        // Bought for $2, sold for $1, rerolled for $5
+        assert_eq!((g.stats.money_spent, g.stats.money_earned), (7, 1));
+
+        let planet = Consumables::Planet(Planets::Pluto);
+        g.consumables = vec![planet.clone(), planet.clone()];
+        g.handle_action(Action::UseConsumable(planet.clone(), None)).unwrap();
+        g.handle_action(Action::UseConsumable(planet, None)).unwrap();
+        assert_eq!((g.stats.planets_used, g.stats.unique_planets_used.len()), (2, 1));
+        assert_eq!(g.stats.consumables_used(), 2);
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut g = Game::with_seed(Default::default(), 42).unwrap();
+        g.jokers.push(crate::joker::Jokers::TheJoker(Default::default()));
+        let mut summary = g.run_summary();
+        summary.jokers.push("Odd, \"Quoted\"".to_string());
+
+        let mut out = Vec::new();
+        write_csv(&[summary.clone(), summary], &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], CSV_HEADER.join(","));
+        assert!(lines[1].starts_with(&format!("42,{},Standard,false,1,", g.seed_str())));
+        assert!(lines[1].ends_with(",0,\"Joker;Odd, \"\"Quoted\"\"\""));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::config::Stake;
+use crate::joker::Jokers;
+use rand::Rng;
+
+// Rounds a Perishable joker works for before it is debuffed
+const PERISHABLE_ROUNDS: usize = 5;
+// Money a Rental joker costs at the end of every round
+const RENTAL_COST_PER_ROUND: usize = 3;
+// Chance of each sticker on a shop joker once its stake is reached
+const STICKER_CHANCE: f64 = 0.3;
+
+/// Stickers a joker gets from higher stakes
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Stickers {
+    // Cannot be sold or destroyed (Black Stake)
+    pub eternal: bool,
+    // Rounds left before the joker is debuffed (Orange Stake)
+    pub perishable: Option<usize>,
+    // Bought for $1, costs $3 at the end of each round (Gold Stake)
+    pub rental: bool,
+    // Debuffed by an effect, on top of a Perishable joker running out
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub debuffed: bool,
+}
+
+impl Stickers {
+    /// Roll the stickers of a shop joker. Eternal and Perishable share one
+    /// roll so a joker never has both, Rental is rolled on its own.
+    pub fn roll<R: Rng + ?Sized>(stake: Stake, rng: &mut R) -> Self {
+        let mut stickers = Stickers::default();
+        let roll: f64 = rng.gen();
+        if stake >= Stake::Black && roll < STICKER_CHANCE {
+            stickers.eternal = true;
+        } else if stake >= Stake::Orange && roll < STICKER_CHANCE * 2.0 {
+            stickers.perishable = Some(PERISHABLE_ROUNDS);
+        }
+        if stake >= Stake::Gold && rng.gen_bool(STICKER_CHANCE) {
+            stickers.rental = true;
+        }
+        return stickers;
+    }
+
+    /// Roll the stickers of a joker for sale or in a Buffoon Pack, leaving
+    /// off stickers the joker cannot have
+    pub fn roll_for<R: Rng + ?Sized>(joker: &Jokers, stake: Stake, rng: &mut R) -> Self {
+        let mut stickers = Self::roll(stake, rng);
+        stickers.eternal &= joker.eternal_compatible();
+        if !joker.perishable_compatible() {
+            stickers.perishable = None;
+        }
+        return stickers;
+    }
+
+    pub fn perishable() -> Self {
+        return Stickers {
+            perishable: Some(PERISHABLE_ROUNDS),
+            ..Default::default()
+        };
+    }
+
+    /// Debuffed jokers have no effect, Perishable jokers with no rounds left
+    /// included
+    pub fn is_debuffed(&self) -> bool {
+        return self.debuffed || self.perishable == Some(0);
+    }
+
+    /// Money charged at the end of the round
+    pub fn round_cost(&self) -> usize {
+        return match self.rental {
+            true => RENTAL_COST_PER_ROUND,
+            false => 0,
+        };
+    }
+
+    /// Count down a Perishable joker at the end of the round
+    pub fn end_round(&mut self) {
+        if let Some(rounds) = self.perishable.as_mut() {
+            *rounds = rounds.saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_roll_stickers_by_stake() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let rolls = |stake: Stake, rng: &mut StdRng| -> Vec<Stickers> {
+            (0..200).map(|_| Stickers::roll(stake, rng)).collect()
+        };
+
+        assert!(rolls(Stake::Green, &mut rng).iter().all(|s| *s == Stickers::default()));
+
+        let orange = rolls(Stake::Orange, &mut rng);
+        assert!(orange.iter().any(|s| s.eternal));
+        assert!(orange.iter().any(|s| s.perishable.is_some()));
+        assert!(orange.iter().all(|s| !(s.eternal && s.perishable.is_some())));
+        assert!(orange.iter().all(|s| !s.rental));
+
+        assert!(rolls(Stake::Gold, &mut rng).iter().any(|s| s.rental));
+    }
+
+    #[test]
+    fn test_roll_stickers_for_joker() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let rolls = |joker: Jokers, rng: &mut StdRng| -> Vec<Stickers> {
+            (0..200).map(|_| Stickers::roll_for(&joker, Stake::Gold, rng)).collect()
+        };
+
+        let popcorn = rolls(Jokers::Popcorn(Default::default()), &mut rng);
+        assert!(popcorn.iter().all(|s| !s.eternal));
+        assert!(popcorn.iter().any(|s| s.perishable.is_some() && s.rental));
+
+        let runner = rolls(Jokers::Runner(Default::default()), &mut rng);
+        assert!(runner.iter().all(|s| s.perishable.is_none()));
+        assert!(runner.iter().any(|s| s.eternal));
+    }
+
+    #[test]
+    fn test_perishable_countdown() {
+        let mut stickers = Stickers::perishable();
+        for _ in 0..4 {
+            stickers.end_round();
+            assert!(!stickers.is_debuffed());
+        }
+        stickers.end_round();
+        assert!(stickers.is_debuffed());
+        stickers.end_round();
+        assert_eq!(stickers.perishable, Some(0));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::game::Game;
+use crate::joker::Joker;
+use crate::stage::End;
+#[cfg(feature = "python")]
+use pyo3::{pyclass, pymethods};
+
+/// End-of-run (or current progress) summary of a game, for logging and
+/// the `info` dict of the gym env.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunSummary {
+    pub seed: u64,
+    pub deck: String,
+    pub result: Option<End>,
+    pub won: bool,
+    pub ante: usize,
+    pub round: usize,
+    pub score: usize,
+    pub required_score: usize,
+    pub money: i64,
+    pub hands_played: usize,
+    pub discards_used: usize,
+    pub blinds_skipped: usize,
+    pub jokers: Vec<String>,
+    // Jokers that cancelled a loss (Mr. Bones), see `Game::on_would_lose`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub saved_by: Vec<String>,
+    pub actions: usize,
+}
+
+impl Game {
+    pub fn run_summary(&self) -> RunSummary {
+        let result = self.result();
+        return RunSummary {
+            seed: self.seed,
+            deck: self
+                .config
+                .deck_type
+                .map_or("Standard", |deck| deck.name())
+                .to_string(),
+            result,
+            won: result.is_some_and(|end| end.is_win()),
+            ante: self.ante_current.number(),
+            round: self.round,
+            score: self.score,
+            required_score: self.required_score(),
+            money: self.money,
+            hands_played: self.hands_played_count,
+            discards_used: self.discards_used,
+            blinds_skipped: self.blinds_skipped_count,
+            jokers: self.jokers.iter().map(|j| j.name()).collect(),
+            saved_by: self.saved_by.clone(),
+            actions: self.action_history.len(),
+        };
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl RunSummary {
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::Action;
+    use crate::config::Config;
+    use crate::stage::{Blind, Stage};
+
+    #[test]
+    fn test_run_summary() {
+        let mut g = Game::with_seed(Config::default(), 3).unwrap();
+        g.start();
+        g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+
+        let summary = g.run_summary();
+        assert_eq!(summary.seed, 3);
+        assert_eq!(summary.result, None);
+        assert!(!summary.won);
+        assert_eq!(summary.ante, 1);
+        assert_eq!(summary.actions, 1);
+
+        g.stage = Stage::End(End::Win);
+        assert!(g.run_summary().won);
+    }
+}
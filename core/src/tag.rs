@@ -3,6 +3,7 @@ use crate::joker::Jokers;
 use crate::planet::Planets;
 use crate::spectral::Spectrals;
 use crate::tarot::Tarots;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 use rand::seq::SliceRandom;
 
@@ -392,7 +393,7 @@ impl TagPack {
     pub fn new_mega_arcana() -> Self {
         let all_tarots = Tarots::all();
         let selected: Vec<Tarots> = all_tarots
-            .choose_multiple(&mut rand::thread_rng(), 5)
+            .choose_multiple(&mut crate::rng::rng_for("tag", "mega arcana pack"), 5)
             .copied()
             .collect();
         TagPack::MegaArcana(selected)
@@ -401,7 +402,7 @@ impl TagPack {
     pub fn new_mega_celestial() -> Self {
         let all_planets = Planets::all();
         let selected: Vec<Planets> = all_planets
-            .choose_multiple(&mut rand::thread_rng(), 5)
+            .choose_multiple(&mut crate::rng::rng_for("tag", "mega celestial pack"), 5)
             .copied()
             .collect();
         TagPack::MegaCelestial(selected)
@@ -411,7 +412,7 @@ impl TagPack {
         // For now, use all common jokers for Mega Buffoon pack
         let all_jokers = Jokers::all_common();
         let selected: Vec<Jokers> = all_jokers
-            .choose_multiple(&mut rand::thread_rng(), 4)
+            .choose_multiple(&mut crate::rng::rng_for("tag", "mega buffoon pack"), 4)
             .cloned()
             .collect();
         TagPack::MegaBuffoon(selected)
@@ -424,7 +425,7 @@ impl TagPack {
         let mut cards = Vec::new();
         for _ in 0..5 {
             let suit = *[Suit::Heart, Suit::Diamond, Suit::Club, Suit::Spade]
-                .choose(&mut rand::thread_rng())
+                .choose(&mut crate::rng::rng_for("tag", "mega standard pack"))
                 .unwrap();
             let value = *[
                 Value::Two,
@@ -441,7 +442,7 @@ impl TagPack {
                 Value::King,
                 Value::Ace,
             ]
-            .choose(&mut rand::thread_rng())
+            .choose(&mut crate::rng::rng_for("tag", "mega standard pack"))
             .unwrap();
 
             cards.push(Card::new(value, suit));
@@ -452,7 +453,7 @@ impl TagPack {
     pub fn new_spectral() -> Self {
         let all_spectrals = Spectrals::all();
         let selected: Vec<Spectrals> = all_spectrals
-            .choose_multiple(&mut rand::thread_rng(), 2)
+            .choose_multiple(&mut crate::rng::rng_for("tag", "spectral pack"), 2)
             .cloned()
             .collect();
         TagPack::Spectral(selected)
@@ -2,6 +2,7 @@ use crate::card::Card;
 use crate::consumable::{Consumable, ConsumableType};
 use crate::error::GameError;
 use crate::game::Game;
+#[cfg(feature = "python")]
 use pyo3::pyclass;
 use strum::{EnumIter, IntoEnumIterator};
 
@@ -133,9 +134,10 @@ impl Consumable for Tarots {
         match self {
             // Category A: No targets needed
             Self::TheHermit => {
-                // Double money (max $20)
-                let doubled = game.money * 2;
-                game.money = doubled.min(20);
+                // Double money (max $20), debt is not doubled
+                if game.money > 0 {
+                    game.money = (game.money * 2).min(20);
+                }
                 Ok(())
             }
             Self::Temperance => {
@@ -164,6 +166,7 @@ impl Consumable for Tarots {
                 // Create random Joker
                 let joker = game.generate_random_joker();
                 game.jokers.push(joker);
+                game.refresh_joker_effects();
                 Ok(())
             }
 
@@ -172,7 +175,7 @@ impl Consumable for Tarots {
                 // 2 cards → Lucky
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_enhancement(Enhancement::Lucky);
                         });
                     }
@@ -183,7 +186,7 @@ impl Consumable for Tarots {
                 // 2 cards → Mult
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_enhancement(Enhancement::Mult);
                         });
                     }
@@ -194,7 +197,7 @@ impl Consumable for Tarots {
                 // 2 cards → Bonus
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_enhancement(Enhancement::Bonus);
                         });
                     }
@@ -205,7 +208,7 @@ impl Consumable for Tarots {
                 // 1 card → Wild
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_enhancement(Enhancement::Wild);
                         });
                     }
@@ -216,7 +219,7 @@ impl Consumable for Tarots {
                 // 1 card → Steel
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_enhancement(Enhancement::Steel);
                         });
                     }
@@ -227,7 +230,7 @@ impl Consumable for Tarots {
                 // 1 card → Glass
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_enhancement(Enhancement::Glass);
                         });
                     }
@@ -238,7 +241,7 @@ impl Consumable for Tarots {
                 // 1 card → Gold
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_enhancement(Enhancement::Gold);
                         });
                     }
@@ -249,7 +252,7 @@ impl Consumable for Tarots {
                 // 1 card → Stone
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_enhancement(Enhancement::Stone);
                         });
                     }
@@ -262,7 +265,7 @@ impl Consumable for Tarots {
                 // Up to 3 cards → Diamonds
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_suit(crate::card::Suit::Diamond);
                         });
                     }
@@ -273,7 +276,7 @@ impl Consumable for Tarots {
                 // Up to 3 cards → Clubs
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_suit(crate::card::Suit::Club);
                         });
                     }
@@ -284,7 +287,7 @@ impl Consumable for Tarots {
                 // Up to 3 cards → Hearts
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_suit(crate::card::Suit::Heart);
                         });
                     }
@@ -295,7 +298,7 @@ impl Consumable for Tarots {
                 // Up to 3 cards → Spades
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             c.set_suit(crate::card::Suit::Spade);
                         });
                     }
@@ -308,7 +311,7 @@ impl Consumable for Tarots {
                 // Up to 2 cards, raise rank by 1
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.modify_card_in_deck(card.id, |c| {
+                        game.modify_card_everywhere(card.id, |c| {
                             if let Some(new_rank) = c.value.raise_rank() {
                                 c.set_rank(new_rank);
                             }
@@ -321,7 +324,7 @@ impl Consumable for Tarots {
                 // Destroy up to 2 cards
                 if let Some(cards) = targets {
                     for card in cards {
-                        game.destroy_card(card);
+                        game.destroy_card_by(card, "The Hanged Man");
                     }
                 }
                 Ok(())
@@ -334,7 +337,7 @@ impl Consumable for Tarots {
                         let target_value = cards[1].value;
                         let target_suit = cards[1].suit;
 
-                        game.modify_card_in_deck(source_id, |c| {
+                        game.modify_card_everywhere(source_id, |c| {
                             c.set_rank(target_value);
                             c.set_suit(target_suit);
                         });
@@ -352,18 +355,16 @@ impl Consumable for Tarots {
             Self::WheelOfFortune => {
                 // 1/4 chance to add edition to random Joker
                 use rand::Rng;
-                if rand::thread_rng().gen_range(0..4) == 0 {
-                    // Success! Add random edition to random joker
-                    if !game.jokers.is_empty() {
-                        use crate::card::Edition;
-                        use rand::seq::SliceRandom;
+                if crate::rng::rng_for("tarot", "wheel of fortune roll").gen_range(0..4) == 0 {
+                    // Success! Add random edition to random joker without one
+                    use crate::card::Edition;
+                    use rand::seq::SliceRandom;
 
+                    let candidates = game.base_edition_jokers();
+                    if let Some(&i) = candidates.choose(&mut crate::rng::rng_for("tarot", "wheel of fortune joker")) {
                         let editions = vec![Edition::Foil, Edition::Holographic, Edition::Polychrome];
-                        let edition = editions.choose(&mut rand::thread_rng()).unwrap();
-
-                        // Note: Jokers don't have editions in current implementation
-                        // This is a placeholder - would need to add edition field to Jokers
-                        // For now, just don't crash
+                        let edition = *editions.choose(&mut crate::rng::rng_for("tarot", "wheel of fortune edition")).unwrap();
+                        game.set_joker_edition(i, edition);
                     }
                 }
                 Ok(())
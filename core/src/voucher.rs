@@ -1,11 +1,21 @@
-use crate::error::GameError;
+use crate::booster::{PackContents, PackType};
+use crate::consumable::Consumables;
 use crate::game::Game;
+use crate::planet::Planets;
+use crate::rank::HandRank;
+#[cfg(feature = "python")]
+use pyo3::pyclass;
 use rand::seq::SliceRandom;
 use std::fmt;
 
+// Interest caps raised by Seed Money and Money Tree
+const SEED_MONEY_INTEREST_MAX: usize = 10;
+const MONEY_TREE_INTEREST_MAX: usize = 20;
+
 /// Voucher - permanent shop upgrades that persist across rounds
 /// Vouchers provide bonuses like extra slots, reduced prices, better packs, etc.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Vouchers {
     // Tier 1 Vouchers
@@ -21,6 +31,8 @@ pub enum Vouchers {
     Planet,         // Planet cards appear 2x more frequently
     Spectral,       // Spectral cards may appear in shop
     Buffoon,        // Buffoon packs appear 2x more frequently
+    Blank,          // Does nothing
+    SeedMoney,      // Raise the cap on interest earned each round to $10
 
     // Tier 2 Vouchers (upgrades of Tier 1)
     Overstock2,     // +1 additional card slot in shop (total +2)
@@ -28,13 +40,15 @@ pub enum Vouchers {
     Glow,           // Foil, Holographic, Polychrome cards 4x more common (upgrade of Hone)
     RerollPlus,     // Rerolls cost $5 less (upgrade of Reroll)
     Illusion,       // +1 additional consumable slot (total +2) (upgrade of Crystal)
-    Observatory,    // Planet cards in Celestial Packs upgraded to next level (upgrade of Telescope)
+    Observatory,    // Held Planet cards give X1.5 Mult to their hand (upgrade of Telescope)
     Nacho,          // Permanently gain +2 hands per round (upgrade of Grabber)
     Recyclomancy,   // Permanently gain +2 discards per round (upgrade of Wasteful)
     TarotPlus,      // Tarot cards appear 4x more frequently (upgrade of Tarot)
     PlanetPlus,     // Planet cards appear 4x more frequently (upgrade of Planet)
     SpectralPlus,   // Spectral cards appear 2x more frequently (upgrade of Spectral)
     BuffoonPlus,    // Buffoon packs appear 4x more frequently (upgrade of Buffoon)
+    Antimatter,     // +1 joker slot (upgrade of Blank)
+    MoneyTree,      // Raise the cap on interest earned each round to $20 (upgrade of SeedMoney)
 }
 
 impl Vouchers {
@@ -53,6 +67,8 @@ impl Vouchers {
             Vouchers::Planet => "Planet Merchant",
             Vouchers::Spectral => "Omen Globe",
             Vouchers::Buffoon => "Buffoon",
+            Vouchers::Blank => "Blank",
+            Vouchers::SeedMoney => "Seed Money",
             Vouchers::Overstock2 => "Overstock Plus",
             Vouchers::Liquidation => "Liquidation",
             Vouchers::Glow => "Glow Up",
@@ -65,6 +81,8 @@ impl Vouchers {
             Vouchers::PlanetPlus => "Planet Tycoon",
             Vouchers::SpectralPlus => "Seance",
             Vouchers::BuffoonPlus => "Gros Michel",
+            Vouchers::Antimatter => "Antimatter",
+            Vouchers::MoneyTree => "Money Tree",
         }
     }
 
@@ -83,18 +101,22 @@ impl Vouchers {
             Vouchers::Planet => "Planet cards appear 2x more frequently in the shop",
             Vouchers::Spectral => "Spectral cards may appear in the shop and Arcana Packs",
             Vouchers::Buffoon => "Buffoon Packs appear 2x more frequently",
+            Vouchers::Blank => "Does nothing?",
+            Vouchers::SeedMoney => "Raise the cap on interest earned in each round to $10",
             Vouchers::Overstock2 => "+1 card slot available in shop",
             Vouchers::Liquidation => "All cards and packs in shop are 50% off",
             Vouchers::Glow => "Foil, Holographic, and Polychrome cards appear 4x more frequently",
             Vouchers::RerollPlus => "Rerolls cost $5 less",
             Vouchers::Illusion => "+1 consumable slot",
-            Vouchers::Observatory => "Planet cards in your consumable slots give X1.5 to their hand when used",
+            Vouchers::Observatory => "Planet cards in your consumable slots give X1.5 Mult for their specified poker hand",
             Vouchers::Nacho => "+1 hand per round",
             Vouchers::Recyclomancy => "+1 discard per round",
             Vouchers::TarotPlus => "Tarot cards appear 4x more frequently in the shop",
             Vouchers::PlanetPlus => "Planet cards appear 4x more frequently in the shop",
             Vouchers::SpectralPlus => "Spectral cards appear 2x more frequently in the shop",
             Vouchers::BuffoonPlus => "Buffoon Packs appear 4x more frequently",
+            Vouchers::Antimatter => "+1 Joker slot",
+            Vouchers::MoneyTree => "Raise the cap on interest earned in each round to $20",
         }
     }
 
@@ -105,13 +127,15 @@ impl Vouchers {
             Vouchers::Overstock | Vouchers::ClearanceSale | Vouchers::Hone |
             Vouchers::Reroll | Vouchers::Crystal | Vouchers::Telescope |
             Vouchers::Grabber | Vouchers::Wasteful | Vouchers::Tarot |
-            Vouchers::Planet | Vouchers::Spectral | Vouchers::Buffoon => 10,
+            Vouchers::Planet | Vouchers::Spectral | Vouchers::Buffoon |
+            Vouchers::Blank | Vouchers::SeedMoney => 10,
 
             // Tier 2 vouchers (upgrades)
             Vouchers::Overstock2 | Vouchers::Liquidation | Vouchers::Glow |
             Vouchers::RerollPlus | Vouchers::Illusion | Vouchers::Observatory |
             Vouchers::Nacho | Vouchers::Recyclomancy | Vouchers::TarotPlus |
-            Vouchers::PlanetPlus | Vouchers::SpectralPlus | Vouchers::BuffoonPlus => 10,
+            Vouchers::PlanetPlus | Vouchers::SpectralPlus | Vouchers::BuffoonPlus |
+            Vouchers::Antimatter | Vouchers::MoneyTree => 10,
         }
     }
 
@@ -130,6 +154,8 @@ impl Vouchers {
             Vouchers::PlanetPlus => Some(Vouchers::Planet),
             Vouchers::SpectralPlus => Some(Vouchers::Spectral),
             Vouchers::BuffoonPlus => Some(Vouchers::Buffoon),
+            Vouchers::Antimatter => Some(Vouchers::Blank),
+            Vouchers::MoneyTree => Some(Vouchers::SeedMoney),
             _ => None,
         }
     }
@@ -154,6 +180,28 @@ impl Vouchers {
             Vouchers::Planet,
             Vouchers::Spectral,
             Vouchers::Buffoon,
+            Vouchers::Blank,
+            Vouchers::SeedMoney,
+        ]
+    }
+
+    /// Get all tier 2 vouchers (upgrades)
+    pub fn tier_2() -> Vec<Vouchers> {
+        vec![
+            Vouchers::Overstock2,
+            Vouchers::Liquidation,
+            Vouchers::Glow,
+            Vouchers::RerollPlus,
+            Vouchers::Illusion,
+            Vouchers::Observatory,
+            Vouchers::Nacho,
+            Vouchers::Recyclomancy,
+            Vouchers::TarotPlus,
+            Vouchers::PlanetPlus,
+            Vouchers::SpectralPlus,
+            Vouchers::BuffoonPlus,
+            Vouchers::Antimatter,
+            Vouchers::MoneyTree,
         ]
     }
 
@@ -178,6 +226,15 @@ impl Vouchers {
             Vouchers::Illusion => {
                 game.config.consumable_slots += 1; // Additional +1 (total +2 with Crystal)
             }
+            Vouchers::Antimatter => {
+                game.config.joker_slots += 1;
+            }
+            Vouchers::SeedMoney => {
+                game.config.interest_max = game.config.interest_max.max(SEED_MONEY_INTEREST_MAX);
+            }
+            Vouchers::MoneyTree => {
+                game.config.interest_max = game.config.interest_max.max(MONEY_TREE_INTEREST_MAX);
+            }
             // Other vouchers are passive and checked when needed
             _ => {}
         }
@@ -185,26 +242,22 @@ impl Vouchers {
 
     /// Generate a random available voucher
     pub fn random_available(owned: &[Vouchers]) -> Option<Vouchers> {
+        Self::random_available_where(owned, |_| true)
+    }
+
+    /// Generate a random available voucher, only considering vouchers that pass `allowed`
+    /// (e.g. per-ante availability rules)
+    pub fn random_available_where<F>(owned: &[Vouchers], allowed: F) -> Option<Vouchers>
+    where
+        F: Fn(Vouchers) -> bool,
+    {
         let mut available: Vec<Vouchers> = Vouchers::tier_1()
             .into_iter()
             .filter(|v| !owned.contains(v))
             .collect();
 
         // Add tier 2 upgrades if their prerequisite is owned
-        for upgrade in [
-            Vouchers::Overstock2,
-            Vouchers::Liquidation,
-            Vouchers::Glow,
-            Vouchers::RerollPlus,
-            Vouchers::Illusion,
-            Vouchers::Observatory,
-            Vouchers::Nacho,
-            Vouchers::Recyclomancy,
-            Vouchers::TarotPlus,
-            Vouchers::PlanetPlus,
-            Vouchers::SpectralPlus,
-            Vouchers::BuffoonPlus,
-        ] {
+        for upgrade in Vouchers::tier_2() {
             if !owned.contains(&upgrade) {
                 if let Some(required) = upgrade.requires() {
                     if owned.contains(&required) {
@@ -214,7 +267,57 @@ impl Vouchers {
             }
         }
 
-        available.choose(&mut rand::thread_rng()).copied()
+        available.retain(|v| allowed(*v));
+        available.choose(&mut crate::rng::rng_for("shop", "voucher")).copied()
+    }
+}
+
+impl Game {
+    pub fn has_voucher(&self, voucher: Vouchers) -> bool {
+        return self.vouchers.contains(&voucher);
+    }
+
+    /// Apply the effects of vouchers the run starts with (e.g. from the deck)
+    pub(crate) fn apply_starting_vouchers(&mut self) {
+        for voucher in self.vouchers.clone() {
+            voucher.apply_effect(self);
+        }
+    }
+
+    /// Observatory: each held Planet for the played hand gives X1.5 mult
+    pub(crate) fn observatory_x_mult(&self, rank: HandRank) -> f64 {
+        if !self.has_voucher(Vouchers::Observatory) {
+            return 1.0;
+        }
+        let held = self
+            .consumables
+            .iter()
+            .filter(|c| matches!(c, Consumables::Planet(p) if p.hand_rank() == rank))
+            .count();
+        return 1.5_f64.powi(held as i32);
+    }
+
+    /// Telescope: an opened Celestial Pack always contains the Planet for the
+    /// most played hand, replacing its first card if missing
+    pub(crate) fn apply_telescope(&mut self) {
+        if !self.has_voucher(Vouchers::Telescope) {
+            return;
+        }
+        let most_played = self.stats.most_played().and_then(Planets::planet_for);
+        let (Some(planet), Some(pack)) = (most_played, self.shop.open_pack.as_mut()) else {
+            return;
+        };
+        if pack.pack_type != PackType::Celestial {
+            return;
+        }
+        if let PackContents::Planets(planets) = &mut pack.contents {
+            if !planets.contains(&planet) {
+                match planets.first_mut() {
+                    Some(first) => *first = planet,
+                    None => planets.push(planet),
+                }
+            }
+        }
     }
 }
 
@@ -271,6 +374,57 @@ mod tests {
         // With 50 tries and multiple options, we should see the upgrade eventually
     }
 
+    #[test]
+    fn test_starting_vouchers_applied() {
+        use crate::alternative_deck::DeckType;
+        use crate::config::Config;
+
+        let g = Game::new(Config::with_deck(DeckType::MagicDeck)).unwrap();
+        assert_eq!(g.config.consumable_slots, Config::default().consumable_slots + 1);
+    }
+
+    #[test]
+    fn test_antimatter_joker_slot() {
+        let mut g = Game::default();
+        let slots = g.joker_slots();
+        Vouchers::Blank.apply_effect(&mut g);
+        assert_eq!(g.joker_slots(), slots);
+        Vouchers::Antimatter.apply_effect(&mut g);
+        assert_eq!(g.joker_slots(), slots + 1);
+    }
+
+    #[test]
+    fn test_observatory_x_mult() {
+        let mut g = Game::default();
+        g.consumables = vec![
+            Consumables::Planet(Planets::Venus),
+            Consumables::Planet(Planets::Venus),
+            Consumables::Planet(Planets::Pluto),
+        ];
+        assert_eq!(g.observatory_x_mult(HandRank::Flush), 1.0);
+        g.vouchers.push(Vouchers::Observatory);
+        assert_eq!(g.observatory_x_mult(HandRank::Flush), 2.25);
+        assert_eq!(g.observatory_x_mult(HandRank::HighCard), 1.5);
+        assert_eq!(g.observatory_x_mult(HandRank::OnePair), 1.0);
+    }
+
+    #[test]
+    fn test_telescope_celestial_pack() {
+        use crate::booster::Pack;
+
+        let mut g = Game::default();
+        g.stats.hands_played.insert(HandRank::Flush, 3);
+        g.stats.hands_played.insert(HandRank::OnePair, 1);
+        g.vouchers.push(Vouchers::Telescope);
+        for _ in 0..10 {
+            g.shop.open_pack = Some(Pack::new(PackType::Celestial));
+            g.apply_telescope();
+            let pack = g.shop.open_pack.as_ref().unwrap();
+            assert_eq!(pack.len(), 3);
+            assert!(matches!(&pack.contents, PackContents::Planets(p) if p.contains(&Planets::Venus)));
+        }
+    }
+
     #[test]
     fn test_random_available_all_owned() {
         let mut owned = Vouchers::tier_1();
@@ -287,6 +441,8 @@ mod tests {
             Vouchers::PlanetPlus,
             Vouchers::SpectralPlus,
             Vouchers::BuffoonPlus,
+            Vouchers::Antimatter,
+            Vouchers::MoneyTree,
         ]);
 
         let voucher = Vouchers::random_available(&owned);
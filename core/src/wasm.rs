@@ -0,0 +1,105 @@
+use crate::action::Action;
+use crate::config::Config;
+use crate::game::Game;
+use wasm_bindgen::prelude::*;
+
+/// JavaScript handle to a game. Actions cross the boundary as JSON (the
+/// serde encoding of `Action`) or as indices into the action space.
+#[wasm_bindgen(js_name = Game)]
+pub struct WasmGame {
+    game: Game,
+}
+
+#[wasm_bindgen(js_class = Game)]
+impl WasmGame {
+    /// New game with the default config, from `seed` when given
+    #[wasm_bindgen(constructor)]
+    pub fn new(seed: Option<u64>) -> WasmGame {
+        let game = match seed {
+            Some(seed) => Game::with_seed(Config::default(), seed),
+            None => Game::new(Config::default()),
+        };
+        return WasmGame {
+            game: game.expect("default config is valid"),
+        };
+    }
+
+    /// New game from a shareable seed string, e.g. "7LB2WVPK"
+    #[wasm_bindgen(js_name = fromSeedStr)]
+    pub fn from_seed_str(code: &str) -> Result<WasmGame, JsError> {
+        let game = Game::from_seed_str(Config::default(), code)?;
+        return Ok(WasmGame { game });
+    }
+
+    pub fn start(&mut self) {
+        self.game.start();
+    }
+
+    /// Legal actions as JSON strings
+    #[wasm_bindgen(js_name = genActions)]
+    pub fn gen_actions(&self) -> Result<Vec<String>, JsError> {
+        return self
+            .game
+            .gen_actions()
+            .map(|action| serde_json::to_string(&action).map_err(JsError::from))
+            .collect();
+    }
+
+    /// Names of the legal action kinds, e.g. "Play" or "BuyJoker"
+    #[wasm_bindgen(js_name = legalActionKinds)]
+    pub fn legal_action_kinds(&self) -> Vec<String> {
+        return self.game.legal_action_kinds().iter().map(|kind| kind.name().to_string()).collect();
+    }
+
+    /// Action mask, 1 for every legal index
+    #[wasm_bindgen(js_name = genActionSpace)]
+    pub fn gen_action_space(&self) -> Vec<usize> {
+        return self.game.gen_action_space().to_vec();
+    }
+
+    /// Action at `index` of the action space, as JSON
+    #[wasm_bindgen(js_name = actionFromIndex)]
+    pub fn action_from_index(&self, index: usize) -> Result<String, JsError> {
+        let action = self.game.gen_action_space().to_action(index, &self.game)?;
+        return Ok(serde_json::to_string(&action)?);
+    }
+
+    /// Handle an action given as JSON, see `genActions`
+    #[wasm_bindgen(js_name = handleAction)]
+    pub fn handle_action(&mut self, action: &str) -> Result<(), JsError> {
+        let action: Action = serde_json::from_str(action)?;
+        self.game.handle_action(action)?;
+        return Ok(());
+    }
+
+    #[wasm_bindgen(js_name = handleActionIndex)]
+    pub fn handle_action_index(&mut self, index: usize) -> Result<(), JsError> {
+        self.game.handle_action_index(index)?;
+        return Ok(());
+    }
+
+    /// Flat observation vector, see `Game::observe`
+    pub fn observe(&self) -> Vec<f32> {
+        return self.game.observe().to_vec();
+    }
+
+    /// Run summary (ante, score, money, result...) as JSON
+    #[wasm_bindgen(js_name = runSummary)]
+    pub fn run_summary(&self) -> Result<String, JsError> {
+        return Ok(serde_json::to_string(&self.game.run_summary())?);
+    }
+
+    /// Text board view, see `Game::render`
+    pub fn render(&self) -> String {
+        return self.game.render();
+    }
+
+    pub fn seed(&self) -> u64 {
+        return self.game.seed;
+    }
+
+    #[wasm_bindgen(js_name = isOver)]
+    pub fn is_over(&self) -> bool {
+        return self.game.is_over();
+    }
+}
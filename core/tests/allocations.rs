@@ -0,0 +1,85 @@
+// Counts heap allocations on the current thread to keep the scoring hot
+// path from regressing. Lives in its own test binary because it installs a
+// global allocator.
+use balatro_rs::action::Action;
+use balatro_rs::card::{Card, Suit, Value};
+use balatro_rs::config::Config;
+use balatro_rs::game::Game;
+use balatro_rs::hand::SelectHand;
+use balatro_rs::joker::Jokers;
+use balatro_rs::stage::{Blind, Stage};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|a| a.set(a.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|a| a.set(a.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(|a| a.get());
+    let out = f();
+    let after = ALLOCATIONS.with(|a| a.get());
+    (out, after - before)
+}
+
+fn full_house() -> SelectHand {
+    SelectHand::new(vec![
+        Card::new(Value::Ten, Suit::Heart),
+        Card::new(Value::Ten, Suit::Club),
+        Card::new(Value::Ten, Suit::Spade),
+        Card::new(Value::Four, Suit::Diamond),
+        Card::new(Value::Four, Suit::Heart),
+    ])
+}
+
+#[test]
+fn test_calc_score_allocations() {
+    let mut g = Game::with_seed(Config::default(), 7).unwrap();
+    g.start();
+    g.stage = Stage::Shop();
+    g.money = 1000;
+    for joker in Jokers::all_common().into_iter().take(5) {
+        g.shop.jokers = vec![joker.clone()];
+        g.handle_action(Action::BuyJoker(joker)).expect("buy joker");
+    }
+    g.stage = Stage::PreBlind();
+    g.handle_action(Action::SelectBlind(Blind::Small)).unwrap();
+
+    let hand = full_house().best_hand().unwrap();
+    // Each joker's effects registered once, not again on every purchase
+    assert_eq!(g.effect_registry.on_score.len(), 5);
+
+    let (score, allocations) = count_allocations(|| g.calc_score(hand));
+    assert!(score > 0);
+    // Was 42 before the hot path was reworked
+    assert!(allocations <= 16, "calc_score allocated {allocations} times");
+}
+
+#[test]
+fn test_best_hand_allocations() {
+    let hand = full_house();
+    let (_, allocations) = count_allocations(|| hand.best_hand().unwrap());
+    // Was 115 before the frequency maps stopped cloning per card
+    assert!(allocations <= 48, "best_hand allocated {allocations} times");
+}
@@ -1,12 +1,45 @@
+import json
 import gymnasium as gym
 from gymnasium import spaces
 import pylatro
 from typing import Optional
 import numpy as np
+from reward import (
+    AUX_REWARD_CHANNELS,
+    ILLEGAL_ACTION_REWARD,
+    RewardMode,
+    RewardState,
+    auxiliary_rewards,
+    compute_reward,
+)
+
+
+RUN_SUMMARY_FIELDS = (
+    "seed",
+    "deck",
+    "won",
+    "ante",
+    "round",
+    "score",
+    "required_score",
+    "money",
+    "hands_played",
+    "discards_used",
+    "blinds_skipped",
+    "jokers",
+    "actions",
+)
 
 
 class BalatroEnv(gym.Env):
-    def __init__(self):
+    def __init__(
+        self,
+        max_steps: int = 10_000,
+        reward_mode: RewardMode = RewardMode.SCORE_DELTA,
+        money_weight: float = 1.0,
+        export_path: Optional[str] = None,
+        reward_config: Optional[pylatro.RewardConfig] = None,
+    ):
         super(BalatroEnv, self).__init__()
 
         config = pylatro.Config()
@@ -14,6 +47,18 @@ class BalatroEnv(gym.Env):
 
         self._config = config
         self._game = pylatro.GameEngine(self._config)
+        # Episodes cut off after this many steps are truncated, not terminated
+        self._max_steps = max_steps
+        self._steps = 0
+        self._reward_mode = RewardMode(reward_mode)
+        self._money_weight = money_weight
+        # Engine-side reward components, replaces `reward_mode` when set
+        self._reward_config = reward_config
+        self._reward_state = RewardState.from_game(self._game)
+        self._aux_rewards = np.zeros(len(AUX_REWARD_CHANNELS), dtype=np.float32)
+        # One JSON line per step (same fields as the engine's JsonlExporter)
+        self._export = open(export_path, "a") if export_path else None
+        self._export_step = 0
         self._score = 0
         self._last_score = 0
         self._target_score = 0
@@ -57,19 +102,54 @@ class BalatroEnv(gym.Env):
         }
         return obs
 
+    def _run_summary(self):
+        summary = self._game.run_summary()
+        return {field: getattr(summary, field) for field in RUN_SUMMARY_FIELDS}
+
     def _get_info(self):
-        return {"difference": self._target_score - self._score}
+        return {
+            "difference": self._target_score - self._score,
+            "steps": self._steps,
+            "aux_rewards": self._aux_rewards,
+            "aux_reward_channels": AUX_REWARD_CHANNELS,
+            "run_summary": self._run_summary(),
+        }
+
+    def _export_step_record(self, index, action, legal, reward, pre, post):
+        record = {
+            "step": self._export_step,
+            "action": action,
+            "action_index": int(index),
+            "legal": legal,
+            "reward": float(reward),
+            "pre": pre,
+            "post": post,
+        }
+        self._export.write(json.dumps(record) + "\n")
+        self._export_step += 1
 
     def step(self, index):
         legal = False
+        action = None
+        engine_reward = 0.0
+        pre_summary = self._run_summary() if self._export else None
         space = self._game.gen_action_space()
         # Action must be legal
         if space[index] == 1:
             legal = True
-            self._game.handle_action_index(index)
-
+            if self._export:
+                action = repr(self._game.action_from_index(index))
+            if self._reward_config is not None:
+                engine_reward = self._game.handle_action_index_with_reward(
+                    index, self._reward_config
+                )
+            else:
+                self._game.handle_action_index(index)
+
+        self._steps += 1
+        # Terminated: the run was won or lost. Truncated: step limit hit first.
         terminated = self._game.is_over
-        truncated = terminated
+        truncated = not terminated and self._steps >= self._max_steps
 
         self._last_score = self._score
         self._score = self._game.state.score
@@ -80,31 +160,46 @@ class BalatroEnv(gym.Env):
             # print(f"new high score: {score}")
             # print(f"win: {self._game.is_win}")
 
-        reward = 0
-        score_diff = self._score - self._last_score
-        if score_diff > 0:
-            reward = score_diff / 100
+        prev_reward_state = self._reward_state
+        self._reward_state = RewardState.from_game(self._game)
+        if self._reward_config is not None:
+            reward = engine_reward if legal else ILLEGAL_ACTION_REWARD
+        else:
+            reward = compute_reward(
+                self._reward_mode,
+                prev_reward_state,
+                self._reward_state,
+                terminated,
+                self._game.is_win,
+                legal=legal,
+                money_weight=self._money_weight,
+            )
+        self._aux_rewards = auxiliary_rewards(prev_reward_state, self._reward_state)
         if terminated:
-            reward = 20 if self._game.is_win else 0
-            # if self._game.is_win:
-            # print(f"game win: {self._game.state}")
-            # print(f"score: {self._game.state.score}")
-
             self.actions_queue.append(len(self._game.state.action_history))
             self.score_queue.append(self._score)
         if not legal:
-            reward = -10
             print("illegal")
             print(index)
             print(self.action_mask())
 
         observation = self._get_obs()
         info = self._get_info()
+        if self._export:
+            self._export_step_record(
+                index, action, legal, reward, pre_summary, info["run_summary"]
+            )
         return observation, reward, terminated, truncated, info
 
     def reset(self, seed: Optional[int] = None, options: Optional[dict] = None):
         super().reset(seed=seed)
-        self._game = pylatro.GameEngine(self._config)
+        # Draw the engine seed from the env's generator so a seeded reset
+        # (and every reset after it) replays the same sequence of runs
+        engine_seed = int(self.np_random.integers(0, 2**63))
+        self._game = pylatro.GameEngine(self._config, seed=engine_seed)
+        self._steps = 0
+        self._reward_state = RewardState.from_game(self._game)
+        self._aux_rewards = np.zeros(len(AUX_REWARD_CHANNELS), dtype=np.float32)
         self._score = 0
         self._last_score = 0
         self._target_score = 0
@@ -116,6 +211,12 @@ class BalatroEnv(gym.Env):
     def render(self, mode="human"):
         return
 
+    def close(self):
+        if self._export:
+            self._export.close()
+            self._export = None
+        super().close()
+
     def action_mask(self):
         mask = np.asarray(self._game.gen_action_space(), dtype=np.int8)
         return mask
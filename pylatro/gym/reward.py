@@ -0,0 +1,111 @@
+from dataclasses import dataclass
+from enum import Enum
+import math
+import numpy as np
+
+
+class RewardMode(Enum):
+    # Positive score gained / 100, +20 on a win (original env behavior)
+    SCORE_DELTA = "score_delta"
+    # +1 on a win, -1 on a loss, 0 otherwise
+    SPARSE = "sparse"
+    # Fraction of the blind's required score gained this step, +1 per blind cleared
+    BLIND_PROGRESS = "blind_progress"
+    # Change in log(1 + score), keeps late antes from dominating the return
+    LOG_SCORE = "log_score"
+    # Score delta / 100 plus money gained/lost weighted by `money_weight`
+    MONEY_WEIGHTED = "money_weighted"
+
+
+ILLEGAL_ACTION_REWARD = -10
+WIN_REWARD = 20
+
+
+@dataclass
+class RewardState:
+    score: int
+    # Points scored by every hand so far, does not reset with the blind
+    total_score: int
+    required_score: int
+    money: int
+    round: int
+
+    @classmethod
+    def from_game(cls, game):
+        state = game.state
+        return cls(
+            score=state.score,
+            total_score=state.stats.total_score,
+            required_score=state.required_score,
+            money=state.money,
+            round=state.round,
+        )
+
+
+def compute_reward(
+    mode: RewardMode,
+    prev: RewardState,
+    curr: RewardState,
+    terminated: bool,
+    won: bool,
+    legal: bool = True,
+    money_weight: float = 1.0,
+) -> float:
+    if not legal:
+        return ILLEGAL_ACTION_REWARD
+
+    score_gain = _score_gain(prev, curr)
+
+    if mode == RewardMode.SCORE_DELTA:
+        if terminated:
+            return WIN_REWARD if won else 0
+        return score_gain / 100
+
+    if mode == RewardMode.SPARSE:
+        if terminated:
+            return 1 if won else -1
+        return 0
+
+    if mode == RewardMode.BLIND_PROGRESS:
+        reward = 0.0
+        if prev.required_score > 0:
+            remaining = max(prev.required_score - prev.score, 0)
+            reward += min(score_gain, remaining) / prev.required_score
+        reward += max(curr.round - prev.round, 0)
+        if terminated and won:
+            reward += 1
+        return reward
+
+    if mode == RewardMode.LOG_SCORE:
+        return math.log1p(prev.score + score_gain) - math.log1p(prev.score)
+
+    if mode == RewardMode.MONEY_WEIGHTED:
+        return score_gain / 100 + money_weight * (curr.money - prev.money)
+
+    raise ValueError(f"unknown reward mode: {mode}")
+
+
+def _score_gain(prev: RewardState, curr: RewardState) -> int:
+    # `score` resets when a hand clears the blind, so take the hand's points
+    # from the run total instead
+    return max(curr.total_score - prev.total_score, 0)
+
+
+# Fixed order of the auxiliary reward vector returned in `info["aux_rewards"]`
+AUX_REWARD_CHANNELS = ("score_gain", "money_delta", "blind_progress")
+
+
+def auxiliary_rewards(prev: RewardState, curr: RewardState) -> np.ndarray:
+    """Per-step auxiliary signals, one float per AUX_REWARD_CHANNELS entry.
+
+    score_gain: points scored by the hand played this step
+    money_delta: money gained or spent this step
+    blind_progress: fraction of the current blind's required score reached
+    """
+    score_gain = _score_gain(prev, curr)
+    progress = 0.0
+    if curr.required_score > 0:
+        progress = min(curr.score / curr.required_score, 1.0)
+    return np.array(
+        [score_gain, curr.money - prev.money, progress], dtype=np.float32
+    )
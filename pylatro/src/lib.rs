@@ -1,11 +1,24 @@
-use balatro_rs::action::Action;
-use balatro_rs::card::Card;
-use balatro_rs::config::Config;
-use balatro_rs::error::GameError;
+use balatro_rs::action::{Action, ActionKind, SortMode};
+use balatro_rs::booster::{BoosterPack, PackSize, PackType};
+use balatro_rs::card::{Card, Edition};
+use balatro_rs::cashout::CashOutBreakdown;
+use balatro_rs::composition::DeckComposition;
+use balatro_rs::config::{Config, ConfigPreset, ObservabilityMode, Stake};
+use balatro_rs::consumable::TargetSpec;
+use balatro_rs::error::{ConfigError, GameError, SeedError, SessionError};
 use balatro_rs::game::Game;
 use balatro_rs::joker::Jokers;
-use balatro_rs::stage::{End, Stage};
+use balatro_rs::reward::RewardConfig;
+use balatro_rs::schedule::{AnteTargets, BlindInfo, BlindState};
+use balatro_rs::session::SessionManager;
+use balatro_rs::snapshot::Snapshot;
+use balatro_rs::space::{layout, SpaceLayout, SpaceSegment};
+use balatro_rs::stage::Stage;
+use balatro_rs::stats::GameStats;
+use balatro_rs::summary::RunSummary;
+use balatro_rs::voucher::Vouchers;
 use pyo3::prelude::*;
+use std::time::Duration;
 
 #[pyclass]
 struct GameEngine {
@@ -15,21 +28,43 @@ struct GameEngine {
 #[pymethods]
 impl GameEngine {
     #[new]
-    #[pyo3(signature = (config=None))]
-    fn new(config: Option<Config>) -> Self {
-        GameEngine {
-            game: Game::new(config.unwrap_or(Config::default())),
-        }
+    #[pyo3(signature = (config=None, seed=None))]
+    fn new(config: Option<Config>, seed: Option<u64>) -> Result<Self, ConfigError> {
+        let config = config.unwrap_or(Config::default());
+        let game = match seed {
+            Some(seed) => Game::with_seed(config, seed)?,
+            None => Game::new(config)?,
+        };
+        Ok(GameEngine { game })
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (code, config=None))]
+    fn from_seed_str(code: &str, config: Option<Config>) -> Result<Self, SeedError> {
+        let game = Game::from_seed_str(config.unwrap_or(Config::default()), code)?;
+        Ok(GameEngine { game })
     }
 
     fn gen_actions(&self) -> Vec<Action> {
         return self.game.gen_actions().collect();
     }
 
+    fn legal_action_kinds(&self) -> Vec<ActionKind> {
+        return self.game.legal_action_kinds().iter().collect();
+    }
+
     fn gen_action_space(&self) -> Vec<usize> {
         return self.game.gen_action_space().to_vec();
     }
 
+    fn observe(&self) -> Vec<f32> {
+        return self.game.observe().to_vec();
+    }
+
+    fn observe_with_mask(&self) -> (Vec<usize>, Vec<usize>) {
+        return self.game.observe_with_mask();
+    }
+
     fn handle_action(&mut self, action: Action) -> Result<(), GameError> {
         return self.game.handle_action(action);
     }
@@ -38,6 +73,75 @@ impl GameEngine {
         return self.game.handle_action_index(index);
     }
 
+    fn handle_action_with_reward(
+        &mut self,
+        action: Action,
+        rewards: &RewardConfig,
+    ) -> Result<f64, GameError> {
+        return self.game.handle_action_with_reward(action, rewards);
+    }
+
+    fn handle_action_index_with_reward(
+        &mut self,
+        index: usize,
+        rewards: &RewardConfig,
+    ) -> Result<f64, GameError> {
+        let space = self.game.gen_action_space();
+        let action = space.to_action(index, &self.game)?;
+        return self.game.handle_action_with_reward(action, rewards);
+    }
+
+    fn action_from_index(&self, index: usize) -> Result<Action, GameError> {
+        let space = self.game.gen_action_space();
+        return Ok(space.to_action(index, &self.game)?);
+    }
+
+    fn action_to_index(&self, action: Action) -> Result<usize, GameError> {
+        let space = self.game.gen_action_space();
+        return Ok(space.action_to_index(&action, &self.game)?);
+    }
+
+    fn action_space_layout(&self) -> SpaceLayout {
+        return layout(&self.game.config);
+    }
+
+    fn run_summary(&self) -> RunSummary {
+        return self.game.run_summary();
+    }
+
+    // Best play as (cards to play, scoring cards, projected score)
+    fn best_play(&self) -> Option<(Vec<Card>, Vec<Card>, f64)> {
+        return self.top_k_plays(1).pop();
+    }
+
+    fn top_k_plays(&self, k: usize) -> Vec<(Vec<Card>, Vec<Card>, f64)> {
+        return self
+            .game
+            .top_k_plays(k)
+            .into_iter()
+            .map(|(played, hand, score)| (played.cards(), hand.hand.cards(), score))
+            .collect();
+    }
+
+    fn snapshot(&self) -> GameSnapshot {
+        return GameSnapshot {
+            snapshot: self.game.snapshot(),
+        };
+    }
+
+    fn restore(&mut self, snapshot: &GameSnapshot) {
+        self.game.restore(&snapshot.snapshot);
+    }
+
+    #[pyo3(signature = (interval=16))]
+    fn enable_step_back(&mut self, interval: usize) {
+        self.game.enable_step_back(interval);
+    }
+
+    fn step_back(&mut self, n: usize) -> Result<(), GameError> {
+        return self.game.step_back(n);
+    }
+
     #[getter]
     fn state(&self) -> GameState {
         return GameState {
@@ -45,17 +149,37 @@ impl GameEngine {
         };
     }
     #[getter]
+    fn seed(&self) -> u64 {
+        return self.game.seed;
+    }
+    #[getter]
+    fn seed_str(&self) -> String {
+        return self.game.seed_str();
+    }
+    #[getter]
     fn is_over(&self) -> bool {
         return self.game.is_over();
     }
     #[getter]
     fn is_win(&self) -> bool {
-        if let Some(end) = self.game.result() {
-            if end == End::Win {
-                return true;
-            }
-        }
-        return false;
+        return self.game.result().is_some_and(|end| end.is_win());
+    }
+}
+
+#[pyclass]
+struct GameSnapshot {
+    snapshot: Snapshot,
+}
+
+#[pymethods]
+impl GameSnapshot {
+    #[getter]
+    fn stage(&self) -> Stage {
+        return self.snapshot.stage();
+    }
+    #[getter]
+    fn actions(&self) -> usize {
+        return self.snapshot.actions();
     }
 }
 
@@ -76,11 +200,16 @@ impl GameState {
     }
     #[getter]
     fn action_history(&self) -> Vec<Action> {
-        return self.game.action_history.clone();
+        return self.game.action_history.to_vec();
     }
+    // Draw order only with `ObservabilityMode.Full`
     #[getter]
     fn deck(&self) -> Vec<Card> {
-        return self.game.deck.cards();
+        return self.game.remaining_cards();
+    }
+    #[getter]
+    fn deck_composition(&self) -> DeckComposition {
+        return self.game.remaining_deck_composition();
     }
     #[getter]
     fn selected(&self) -> Vec<Card> {
@@ -116,21 +245,152 @@ impl GameState {
         return self.game.jokers.clone();
     }
     #[getter]
-    fn money(&self) -> usize {
+    fn joker_editions(&self) -> Vec<Edition> {
+        return (0..self.game.jokers.len())
+            .map(|i| self.game.joker_edition(i))
+            .collect();
+    }
+    #[getter]
+    fn vouchers(&self) -> Vec<Vouchers> {
+        return self.game.vouchers.clone();
+    }
+    #[getter]
+    fn money(&self) -> i64 {
         return self.game.money;
     }
+    #[getter]
+    fn cash_out(&self) -> Option<CashOutBreakdown> {
+        return self.game.cash_out().cloned();
+    }
+    #[getter]
+    fn blind_schedule(&self) -> Vec<BlindInfo> {
+        return self.game.blind_schedule();
+    }
+    #[getter]
+    fn ante_schedule(&self) -> Vec<AnteTargets> {
+        return self.game.ante_schedule();
+    }
+    #[getter]
+    fn current_blind_target(&self) -> usize {
+        return self.game.current_blind_target();
+    }
+    #[getter]
+    fn stats(&self) -> GameStats {
+        return self.game.stats.clone();
+    }
 
     fn __repr__(&self) -> String {
         format!("GameState:\n{}", self.game)
     }
 }
 
+/// Many concurrent games keyed by session id
+#[pyclass]
+struct SessionServer {
+    sessions: SessionManager,
+}
+
+#[pymethods]
+impl SessionServer {
+    #[new]
+    fn new() -> Self {
+        SessionServer {
+            sessions: SessionManager::new(),
+        }
+    }
+
+    #[pyo3(signature = (config=None, seed=None))]
+    fn create(&mut self, config: Option<Config>, seed: Option<u64>) -> Result<u64, SessionError> {
+        return self.sessions.create(config.unwrap_or(Config::default()), seed);
+    }
+
+    fn create_from_snapshot(&mut self, snapshot: &GameSnapshot) -> u64 {
+        return self.sessions.create_from_snapshot(&snapshot.snapshot);
+    }
+
+    fn step(&mut self, id: u64, action: Action) -> Result<(), SessionError> {
+        return self.sessions.step(id, action);
+    }
+
+    fn gen_actions(&self, id: u64) -> Result<Vec<Action>, SessionError> {
+        return Ok(self.sessions.game(id)?.gen_actions().collect());
+    }
+
+    fn snapshot(&mut self, id: u64) -> Result<GameSnapshot, SessionError> {
+        return Ok(GameSnapshot {
+            snapshot: self.sessions.snapshot(id)?,
+        });
+    }
+
+    fn restore(&mut self, id: u64, snapshot: &GameSnapshot) -> Result<(), SessionError> {
+        return self.sessions.restore(id, &snapshot.snapshot);
+    }
+
+    fn state(&self, id: u64) -> Result<GameState, SessionError> {
+        return Ok(GameState {
+            game: self.sessions.game(id)?.clone(),
+        });
+    }
+
+    fn run_summary(&self, id: u64) -> Result<RunSummary, SessionError> {
+        return Ok(self.sessions.game(id)?.run_summary());
+    }
+
+    fn remove(&mut self, id: u64) -> Result<RunSummary, SessionError> {
+        return Ok(self.sessions.remove(id)?.run_summary());
+    }
+
+    /// Drop sessions idle for longer than `idle_secs`, returning their ids
+    fn expire(&mut self, idle_secs: f64) -> Vec<u64> {
+        return self.sessions.expire(Duration::from_secs_f64(idle_secs));
+    }
+
+    fn ids(&self) -> Vec<u64> {
+        return self.sessions.ids();
+    }
+
+    fn __len__(&self) -> usize {
+        return self.sessions.len();
+    }
+}
+
+/// Write run summaries to a CSV file, one row per run
+#[pyfunction]
+fn stats_to_csv(summaries: Vec<RunSummary>, path: &str) -> PyResult<()> {
+    balatro_rs::stats::to_csv(&summaries, path)
+        .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))
+}
+
 #[pymodule]
 fn pylatro(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Config>()?;
     m.add_class::<GameEngine>()?;
     m.add_class::<GameState>()?;
+    m.add_class::<GameSnapshot>()?;
+    m.add_class::<SessionServer>()?;
     m.add_class::<Stage>()?;
     m.add_class::<Action>()?;
+    m.add_class::<ActionKind>()?;
+    m.add_class::<SortMode>()?;
+    m.add_class::<BoosterPack>()?;
+    m.add_class::<PackType>()?;
+    m.add_class::<PackSize>()?;
+    m.add_class::<Edition>()?;
+    m.add_class::<Vouchers>()?;
+    m.add_class::<Stake>()?;
+    m.add_class::<ObservabilityMode>()?;
+    m.add_class::<DeckComposition>()?;
+    m.add_class::<ConfigPreset>()?;
+    m.add_class::<RunSummary>()?;
+    m.add_class::<CashOutBreakdown>()?;
+    m.add_class::<BlindInfo>()?;
+    m.add_class::<BlindState>()?;
+    m.add_class::<AnteTargets>()?;
+    m.add_class::<GameStats>()?;
+    m.add_class::<RewardConfig>()?;
+    m.add_class::<SpaceLayout>()?;
+    m.add_class::<SpaceSegment>()?;
+    m.add_class::<TargetSpec>()?;
+    m.add_function(wrap_pyfunction!(stats_to_csv, m)?)?;
     Ok(())
 }
@@ -0,0 +1,3 @@
+// The bindings live in `balatro_rs::wasm` behind the `wasm` feature, this
+// crate only links them into a cdylib for wasm-pack
+pub use balatro_rs::wasm::WasmGame;